@@ -1,6 +1,8 @@
 use crate::response_handlers::handle_engine_response;
+use crate::response_handlers::scan_results::handler_scan_results_watch_response::log_watched_scan_result;
 use olorin_engine::engine_execution_context::EngineExecutionContext;
 use olorin_engine_api::commands::engine_command::EngineCommand;
+use olorin_engine_api::events::scan_results::values_changed::scan_results_values_changed_event::ScanResultsValuesChangedEvent;
 use std::io;
 use std::io::Write;
 use std::sync::Arc;
@@ -14,6 +16,13 @@ impl Cli {
         let stdin = io::stdin();
         let mut stdout = io::stdout();
 
+        // Print value changes pushed by the engine for any scan results watched via `results watch`.
+        engine_execution_context.listen_for_engine_event::<ScanResultsValuesChangedEvent>(|scan_results_values_changed_event| {
+            for scan_result in &scan_results_values_changed_event.scan_results {
+                log_watched_scan_result(scan_result);
+            }
+        });
+
         loop {
             if let Err(error) = stdout.flush() {
                 log::error!("Error flushing stdout {}", error);
@@ -16,26 +16,23 @@ fn main() {
     };
 
     // Start Olorin engine.
-    let mut olorin_engine = match OlorinEngine::new(EngineMode::Standalone) {
+    let mut olorin_engine = match OlorinEngine::new(engine_mode) {
         Ok(olorin_engine) => olorin_engine,
         Err(error) => panic!("Fatal error initializing Olorin engine: {}", error),
     };
 
-    // Hook into engine logging for the cli to display.
-    let _cli_log_listener = CliLogListener::new(
-        match olorin_engine
-            .get_engine_execution_context()
-            .as_ref()
-            .unwrap_or_else(|| panic!("Engine context failed to initialize."))
-            .get_logger()
-            .subscribe_to_logs()
-        {
-            Ok(listener) => listener,
-            Err(error) => {
-                panic!("Fatal error hooking into engine log events: {}", error);
-            }
-        },
-    );
+    // Hook into engine logging for the cli to display. A privileged shell has no execution context, and thus no logs to display.
+    let _cli_log_listener = olorin_engine
+        .get_engine_execution_context()
+        .as_ref()
+        .map(|engine_execution_context| {
+            CliLogListener::new(match engine_execution_context.get_logger().subscribe_to_logs() {
+                Ok(listener) => listener,
+                Err(error) => {
+                    panic!("Fatal error hooking into engine log events: {}", error);
+                }
+            })
+        });
 
     // Start the log event sending now that both the CLI and engine are ready to receive log messages.
     olorin_engine.initialize();
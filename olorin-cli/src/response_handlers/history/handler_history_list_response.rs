@@ -0,0 +1,15 @@
+use olorin_engine_api::commands::history::list::history_list_response::HistoryListResponse;
+
+pub fn handle_history_list_response(history_list_response: HistoryListResponse) {
+    log::info!("Undo ({} entries, most recent last):", history_list_response.undo_entries.len());
+
+    for undo_entry in &history_list_response.undo_entries {
+        log::info!("  {}", undo_entry);
+    }
+
+    log::info!("Redo ({} entries, most recent last):", history_list_response.redo_entries.len());
+
+    for redo_entry in &history_list_response.redo_entries {
+        log::info!("  {}", redo_entry);
+    }
+}
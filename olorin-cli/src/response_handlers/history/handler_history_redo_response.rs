@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::history::redo::history_redo_response::HistoryRedoResponse;
+
+pub fn handle_history_redo_response(history_redo_response: HistoryRedoResponse) {
+    match history_redo_response.redone_entry {
+        Some(redone_entry) if history_redo_response.success => log::info!("Redid: {}", redone_entry),
+        Some(redone_entry) => log::error!("Failed to fully redo: {}", redone_entry),
+        None => log::info!("Nothing to redo."),
+    }
+}
@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::history::undo::history_undo_response::HistoryUndoResponse;
+
+pub fn handle_history_undo_response(history_undo_response: HistoryUndoResponse) {
+    match history_undo_response.undone_entry {
+        Some(undone_entry) if history_undo_response.success => log::info!("Undid: {}", undone_entry),
+        Some(undone_entry) => log::error!("Failed to fully undo: {}", undone_entry),
+        None => log::info!("Nothing to undo."),
+    }
+}
@@ -0,0 +1,16 @@
+pub mod handler_history_list_response;
+pub mod handler_history_redo_response;
+pub mod handler_history_undo_response;
+
+use crate::response_handlers::history::handler_history_list_response::handle_history_list_response;
+use crate::response_handlers::history::handler_history_redo_response::handle_history_redo_response;
+use crate::response_handlers::history::handler_history_undo_response::handle_history_undo_response;
+use olorin_engine_api::commands::history::history_response::HistoryResponse;
+
+pub fn handle_history_response(cmd: HistoryResponse) {
+    match cmd {
+        HistoryResponse::Undo { history_undo_response } => handle_history_undo_response(history_undo_response),
+        HistoryResponse::Redo { history_redo_response } => handle_history_redo_response(history_redo_response),
+        HistoryResponse::List { history_list_response } => handle_history_list_response(history_list_response),
+    }
+}
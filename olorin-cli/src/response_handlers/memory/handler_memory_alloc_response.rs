@@ -0,0 +1,13 @@
+use olorin_engine_api::commands::memory::alloc::memory_alloc_response::MemoryAllocResponse;
+
+pub fn handle_memory_alloc_response(memory_alloc_response: MemoryAllocResponse) {
+    match memory_alloc_response.address {
+        Some(address) => log::info!(
+            "Allocated {:X} bytes ({}) at {:X}",
+            memory_alloc_response.size,
+            memory_alloc_response.protection,
+            address
+        ),
+        None => log::error!("Failed to allocate {:X} bytes.", memory_alloc_response.size),
+    }
+}
@@ -0,0 +1,8 @@
+use olorin_engine_api::commands::memory::diff::memory_diff_response::MemoryDiffResponse;
+
+pub fn handle_memory_diff_response(memory_diff_response: MemoryDiffResponse) {
+    match memory_diff_response.trackable_task_handle {
+        Some(trackable_task_handle) => log::info!("Started task {} ({}).", trackable_task_handle.name, trackable_task_handle.task_identifier),
+        None => log::error!("Failed to start memory diff."),
+    }
+}
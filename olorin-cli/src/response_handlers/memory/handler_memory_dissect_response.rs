@@ -0,0 +1,18 @@
+use olorin_engine_api::commands::memory::dissect::memory_dissect_response::MemoryDissectResponse;
+
+pub fn handle_memory_dissect_response(memory_dissect_response: MemoryDissectResponse) {
+    for dissected_field in &memory_dissect_response.dissected_fields {
+        log::info!(
+            "+{:04X} {:<8} {}{} {}",
+            dissected_field.get_offset(),
+            dissected_field.get_kind(),
+            dissected_field.get_data_type(),
+            dissected_field.get_container_type(),
+            dissected_field.get_preview(),
+        );
+    }
+
+    if let Some(symbolic_struct_definition) = &memory_dissect_response.symbolic_struct_definition {
+        log::info!("Struct definition at {:X}: {}", memory_dissect_response.address, symbolic_struct_definition);
+    }
+}
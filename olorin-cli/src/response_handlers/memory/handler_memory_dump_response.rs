@@ -0,0 +1,8 @@
+use olorin_engine_api::commands::memory::dump::memory_dump_response::MemoryDumpResponse;
+
+pub fn handle_memory_dump_response(memory_dump_response: MemoryDumpResponse) {
+    match memory_dump_response.trackable_task_handle {
+        Some(trackable_task_handle) => log::info!("Started task {} ({}).", trackable_task_handle.name, trackable_task_handle.task_identifier),
+        None => log::error!("Failed to start memory dump."),
+    }
+}
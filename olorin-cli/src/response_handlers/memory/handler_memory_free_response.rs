@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::memory::free::memory_free_response::MemoryFreeResponse;
+
+pub fn handle_memory_free_response(memory_free_response: MemoryFreeResponse) {
+    if memory_free_response.success {
+        log::info!("Memory freed.");
+    } else {
+        log::error!("Failed to free memory.");
+    }
+}
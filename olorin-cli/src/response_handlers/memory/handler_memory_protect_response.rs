@@ -0,0 +1,8 @@
+use olorin_engine_api::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+
+pub fn handle_memory_protect_response(memory_protect_response: MemoryProtectResponse) {
+    match memory_protect_response.protection {
+        Some(protection) => log::info!("Changed the protection at {:X} to {}", memory_protect_response.address, protection),
+        None => log::error!("Failed to change the memory protection."),
+    }
+}
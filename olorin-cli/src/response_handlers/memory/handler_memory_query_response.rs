@@ -0,0 +1,21 @@
+use olorin_engine_api::commands::memory::query::memory_query_response::MemoryQueryResponse;
+
+pub fn handle_memory_query_response(memory_query_response: MemoryQueryResponse) {
+    if memory_query_response.success {
+        log::info!(
+            "Address {:X} is in region {:X} (size {:X}, protection: {}), module: {}+{:X}",
+            memory_query_response.address,
+            memory_query_response.region_base_address,
+            memory_query_response.region_size,
+            memory_query_response.protection,
+            memory_query_response.module_name,
+            memory_query_response.module_offset,
+        );
+
+        if let Some(symbol) = &memory_query_response.symbol {
+            log::info!("Symbol: {}", symbol);
+        }
+    } else if memory_query_response.address_error.is_none() {
+        log::error!("Address {:X} is not within a committed region.", memory_query_response.address);
+    }
+}
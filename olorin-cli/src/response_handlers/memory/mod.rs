@@ -1,6 +1,20 @@
+pub mod handler_memory_alloc_response;
+pub mod handler_memory_diff_response;
+pub mod handler_memory_dissect_response;
+pub mod handler_memory_dump_response;
+pub mod handler_memory_free_response;
+pub mod handler_memory_protect_response;
+pub mod handler_memory_query_response;
 pub mod handler_memory_read_response;
 pub mod handler_memory_write_response;
 
+use crate::response_handlers::memory::handler_memory_alloc_response::handle_memory_alloc_response;
+use crate::response_handlers::memory::handler_memory_diff_response::handle_memory_diff_response;
+use crate::response_handlers::memory::handler_memory_dissect_response::handle_memory_dissect_response;
+use crate::response_handlers::memory::handler_memory_dump_response::handle_memory_dump_response;
+use crate::response_handlers::memory::handler_memory_free_response::handle_memory_free_response;
+use crate::response_handlers::memory::handler_memory_protect_response::handle_memory_protect_response;
+use crate::response_handlers::memory::handler_memory_query_response::handle_memory_query_response;
 use crate::response_handlers::memory::handler_memory_read_response::handle_memory_read_response;
 use crate::response_handlers::memory::handler_memory_write_response::handle_memory_response_write;
 use olorin_engine_api::commands::memory::memory_response::MemoryResponse;
@@ -9,5 +23,12 @@ pub fn handle_memory_response(cmd: MemoryResponse) {
     match cmd {
         MemoryResponse::Read { memory_read_response } => handle_memory_read_response(memory_read_response),
         MemoryResponse::Write { memory_write_response } => handle_memory_response_write(memory_write_response),
+        MemoryResponse::Query { memory_query_response } => handle_memory_query_response(memory_query_response),
+        MemoryResponse::Dump { memory_dump_response } => handle_memory_dump_response(memory_dump_response),
+        MemoryResponse::Diff { memory_diff_response } => handle_memory_diff_response(memory_diff_response),
+        MemoryResponse::Dissect { memory_dissect_response } => handle_memory_dissect_response(memory_dissect_response),
+        MemoryResponse::Alloc { memory_alloc_response } => handle_memory_alloc_response(memory_alloc_response),
+        MemoryResponse::Free { memory_free_response } => handle_memory_free_response(memory_free_response),
+        MemoryResponse::Protect { memory_protect_response } => handle_memory_protect_response(memory_protect_response),
     }
 }
@@ -1,10 +1,12 @@
+mod history;
 mod memory;
 mod process;
 mod project;
 mod scan;
-mod scan_results;
+pub mod scan_results;
 mod settings;
 
+use crate::response_handlers::history::handle_history_response;
 use crate::response_handlers::memory::handle_memory_response;
 use crate::response_handlers::process::handle_process_response;
 use crate::response_handlers::project::handle_project_response;
@@ -15,6 +17,7 @@ use olorin_engine_api::commands::engine_command_response::EngineCommandResponse;
 
 pub fn handle_engine_response(response: EngineCommandResponse) {
     match response {
+        EngineCommandResponse::History(response) => handle_history_response(response),
         EngineCommandResponse::Scan(response) => handle_scan_response(response),
         EngineCommandResponse::Memory(response) => handle_memory_response(response),
         EngineCommandResponse::Process(response) => handle_process_response(response),
@@ -22,5 +25,6 @@ pub fn handle_engine_response(response: EngineCommandResponse) {
         EngineCommandResponse::Project(response) => handle_project_response(response),
         EngineCommandResponse::Scan(response) => handle_scan_response(response),
         EngineCommandResponse::Settings(response) => handle_settings_response(response),
+        EngineCommandResponse::Failed(error) => log::error!("{}", error),
     }
 }
@@ -0,0 +1,11 @@
+use olorin_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_eject_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Eject { process_eject_response } = process_response {
+        if process_eject_response.success {
+            log::info!("Ejected library.");
+        } else {
+            log::info!("Failed to eject library");
+        }
+    }
+}
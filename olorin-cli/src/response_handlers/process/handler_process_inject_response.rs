@@ -0,0 +1,11 @@
+use olorin_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_inject_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Inject { process_inject_response } = process_response {
+        if let Some(module_base_address) = process_inject_response.module_base_address {
+            log::info!("Injected library at 0x{:X}", module_base_address);
+        } else {
+            log::error!("Failed to inject library");
+        }
+    }
+}
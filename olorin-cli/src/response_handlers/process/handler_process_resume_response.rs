@@ -0,0 +1,11 @@
+use olorin_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_resume_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Resume { process_resume_response } = process_response {
+        if process_resume_response.success {
+            log::info!("Resumed process.");
+        } else {
+            log::info!("Failed to resume process");
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use olorin_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_save_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Save { process_save_response } = process_response {
+        if process_save_response.success {
+            log::info!("Saved changes.");
+        } else {
+            log::info!("Failed to save changes");
+        }
+    }
+}
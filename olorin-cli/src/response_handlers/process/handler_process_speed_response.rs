@@ -0,0 +1,11 @@
+use olorin_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_speed_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Speed { process_speed_response } = process_response {
+        if process_speed_response.success {
+            log::info!("Set process speed.");
+        } else {
+            log::info!("Failed to set process speed.");
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use olorin_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_threads_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Threads { process_threads_response } = process_response {
+        let threads = process_threads_response.threads;
+
+        if threads.is_empty() {
+            log::warn!("No threads found!");
+            return;
+        }
+
+        for thread_info in threads {
+            if thread_info.has_stack() {
+                log::info!(
+                    "thread_id: {}, name: {}, state: {}, {}: 0x{:X}-0x{:X}",
+                    thread_info.get_thread_id(),
+                    thread_info.get_name(),
+                    thread_info.get_state(),
+                    thread_info.get_stack_module_name(),
+                    thread_info.get_stack_base_address(),
+                    thread_info.get_stack_base_address() + thread_info.get_stack_size(),
+                );
+            } else {
+                log::info!(
+                    "thread_id: {}, name: {}, state: {}, stack: unknown",
+                    thread_info.get_thread_id(),
+                    thread_info.get_name(),
+                    thread_info.get_state(),
+                );
+            }
+        }
+    }
+}
@@ -1,10 +1,24 @@
 pub mod handler_process_close_response;
+pub mod handler_process_eject_response;
+pub mod handler_process_inject_response;
+pub mod handler_process_launch_response;
 pub mod handler_process_list_response;
 pub mod handler_process_open_response;
+pub mod handler_process_resume_response;
+pub mod handler_process_save_response;
+pub mod handler_process_speed_response;
+pub mod handler_process_threads_response;
 
 use crate::response_handlers::process::handler_process_close_response::handle_process_close_response;
+use crate::response_handlers::process::handler_process_eject_response::handle_process_eject_response;
+use crate::response_handlers::process::handler_process_inject_response::handle_process_inject_response;
+use crate::response_handlers::process::handler_process_launch_response::handle_process_launch_response;
 use crate::response_handlers::process::handler_process_list_response::handle_process_list_response;
 use crate::response_handlers::process::handler_process_open_response::handle_process_open_response;
+use crate::response_handlers::process::handler_process_resume_response::handle_process_resume_response;
+use crate::response_handlers::process::handler_process_save_response::handle_process_save_response;
+use crate::response_handlers::process::handler_process_speed_response::handle_process_speed_response;
+use crate::response_handlers::process::handler_process_threads_response::handle_process_threads_response;
 use olorin_engine_api::commands::process::process_response::ProcessResponse;
 
 pub fn handle_process_response(response: ProcessResponse) {
@@ -12,5 +26,12 @@ pub fn handle_process_response(response: ProcessResponse) {
         ProcessResponse::List { .. } => handle_process_list_response(response),
         ProcessResponse::Close { .. } => handle_process_close_response(response),
         ProcessResponse::Open { .. } => handle_process_open_response(response),
+        ProcessResponse::Save { .. } => handle_process_save_response(response),
+        ProcessResponse::Launch { .. } => handle_process_launch_response(response),
+        ProcessResponse::Resume { .. } => handle_process_resume_response(response),
+        ProcessResponse::Inject { .. } => handle_process_inject_response(response),
+        ProcessResponse::Eject { .. } => handle_process_eject_response(response),
+        ProcessResponse::Speed { .. } => handle_process_speed_response(response),
+        ProcessResponse::Threads { .. } => handle_process_threads_response(response),
     }
 }
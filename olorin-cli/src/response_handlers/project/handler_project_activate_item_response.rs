@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::project::activate_item::project_activate_item_response::ProjectActivateItemResponse;
+
+pub fn handle_project_activate_item_response(project_activate_item_response: ProjectActivateItemResponse) {
+    if project_activate_item_response.success {
+        log::info!("Updated project item activation.");
+    } else {
+        log::error!("Failed to update project item activation.");
+    }
+}
@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::project::add_speed::project_add_speed_response::ProjectAddSpeedResponse;
+
+pub fn handle_project_add_speed_response(project_add_speed_response: ProjectAddSpeedResponse) {
+    if project_add_speed_response.success {
+        log::info!("Speed item added to project.");
+    } else {
+        log::error!("Failed to add speed item to project.");
+    }
+}
@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::project::add_struct::project_add_struct_response::ProjectAddStructResponse;
+
+pub fn handle_project_add_struct_response(project_add_struct_response: ProjectAddStructResponse) {
+    if project_add_struct_response.success {
+        log::info!("Struct added to project.");
+    } else {
+        log::error!("Failed to add struct to project.");
+    }
+}
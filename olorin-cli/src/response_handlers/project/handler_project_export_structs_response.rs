@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::project::export_structs::project_export_structs_response::ProjectExportStructsResponse;
+
+pub fn handle_project_export_structs_response(project_export_structs_response: ProjectExportStructsResponse) {
+    if project_export_structs_response.success {
+        log::info!("Exported {} structs.", project_export_structs_response.exported_struct_count);
+    } else {
+        log::error!("Failed to export structs.");
+    }
+}
@@ -0,0 +1,27 @@
+use olorin_engine_api::commands::project::import_structs::project_import_structs_response::ProjectImportStructsResponse;
+
+pub fn handle_project_import_structs_response(project_import_structs_response: ProjectImportStructsResponse) {
+    if !project_import_structs_response.success {
+        log::error!("Failed to import structs.");
+        return;
+    }
+
+    for struct_name in &project_import_structs_response.imported_struct_names {
+        log::info!("Imported struct: {}", struct_name);
+    }
+
+    for struct_name in &project_import_structs_response.skipped_struct_names {
+        log::warn!("Skipped existing struct: {}", struct_name);
+    }
+
+    for warning in &project_import_structs_response.warnings {
+        log::warn!("{}", warning);
+    }
+
+    log::info!(
+        "Imported {} structs, skipped {}, with {} warnings.",
+        project_import_structs_response.imported_struct_names.len(),
+        project_import_structs_response.skipped_struct_names.len(),
+        project_import_structs_response.warnings.len()
+    );
+}
@@ -0,0 +1,11 @@
+use olorin_engine_api::commands::project::list_structs::project_list_structs_response::ProjectListStructsResponse;
+
+pub fn handle_project_list_structs_response(project_list_structs_response: ProjectListStructsResponse) {
+    if project_list_structs_response.struct_names.is_empty() {
+        log::info!("No structs are defined in the opened project.");
+    }
+
+    for struct_name in project_list_structs_response.struct_names {
+        log::info!("{}", struct_name);
+    }
+}
@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::project::set_item_property::project_set_item_property_response::ProjectSetItemPropertyResponse;
+
+pub fn handle_project_set_item_property_response(project_set_item_property_response: ProjectSetItemPropertyResponse) {
+    if project_set_item_property_response.success {
+        log::info!("Updated project item property.");
+    } else {
+        log::error!("Failed to update project item property.");
+    }
+}
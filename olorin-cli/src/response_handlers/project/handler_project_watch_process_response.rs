@@ -0,0 +1,9 @@
+use olorin_engine_api::commands::project::watch_process::project_watch_process_response::ProjectWatchProcessResponse;
+
+pub fn handle_project_watch_process_response(project_watch_process_response: ProjectWatchProcessResponse) {
+    if project_watch_process_response.success {
+        log::info!("Updated the watched process for the project.");
+    } else {
+        log::error!("Failed to update the watched process for the project.");
+    }
+}
@@ -1,10 +1,44 @@
+pub mod handler_project_activate_item_response;
+pub mod handler_project_add_speed_response;
+pub mod handler_project_add_struct_response;
+pub mod handler_project_export_structs_response;
+pub mod handler_project_import_structs_response;
 pub mod handler_project_list_response;
+pub mod handler_project_list_structs_response;
+pub mod handler_project_set_item_property_response;
+pub mod handler_project_watch_process_response;
 
+use crate::response_handlers::project::handler_project_activate_item_response::handle_project_activate_item_response;
+use crate::response_handlers::project::handler_project_add_speed_response::handle_project_add_speed_response;
+use crate::response_handlers::project::handler_project_add_struct_response::handle_project_add_struct_response;
+use crate::response_handlers::project::handler_project_export_structs_response::handle_project_export_structs_response;
+use crate::response_handlers::project::handler_project_import_structs_response::handle_project_import_structs_response;
 use crate::response_handlers::project::handler_project_list_response::handle_project_list_response;
+use crate::response_handlers::project::handler_project_list_structs_response::handle_project_list_structs_response;
+use crate::response_handlers::project::handler_project_set_item_property_response::handle_project_set_item_property_response;
+use crate::response_handlers::project::handler_project_watch_process_response::handle_project_watch_process_response;
 use olorin_engine_api::commands::project::project_response::ProjectResponse;
 
 pub fn handle_project_response(cmd: ProjectResponse) {
     match cmd {
         ProjectResponse::List { project_list_response } => handle_project_list_response(project_list_response),
+        ProjectResponse::AddStruct { project_add_struct_response } => handle_project_add_struct_response(project_add_struct_response),
+        ProjectResponse::ImportStructs {
+            project_import_structs_response,
+        } => handle_project_import_structs_response(project_import_structs_response),
+        ProjectResponse::ExportStructs {
+            project_export_structs_response,
+        } => handle_project_export_structs_response(project_export_structs_response),
+        ProjectResponse::ListStructs { project_list_structs_response } => handle_project_list_structs_response(project_list_structs_response),
+        ProjectResponse::WatchProcess {
+            project_watch_process_response,
+        } => handle_project_watch_process_response(project_watch_process_response),
+        ProjectResponse::AddSpeed { project_add_speed_response } => handle_project_add_speed_response(project_add_speed_response),
+        ProjectResponse::ActivateItem {
+            project_activate_item_response,
+        } => handle_project_activate_item_response(project_activate_item_response),
+        ProjectResponse::SetItemProperty {
+            project_set_item_property_response,
+        } => handle_project_set_item_property_response(project_set_item_property_response),
     }
 }
@@ -0,0 +1,10 @@
+use olorin_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_grouped_response(scan_response: ScanResponse) {
+    if let ScanResponse::GroupedScan { grouped_scan_response } = scan_response {
+        match grouped_scan_response.trackable_task_handle {
+            Some(trackable_task_handle) => log::info!("Started task {} ({}).", trackable_task_handle.name, trackable_task_handle.task_identifier),
+            None => log::error!("Failed to start grouped scan."),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use olorin_engine_api::commands::scan::scan_response::ScanResponse;
+use olorin_engine_api::conversions::conversions::Conversions;
+
+pub fn handle_scan_history_response(scan_response: ScanResponse) {
+    if let ScanResponse::History { scan_history_response } = scan_response {
+        for (index, scan_history_entry) in scan_history_response.scan_history_entries.iter().enumerate() {
+            let scan_value = match scan_history_entry.get_scan_value() {
+                Some(scan_value) => scan_value.to_string(),
+                None => String::new(),
+            };
+
+            log::info!(
+                "{}: {} {} [{}] -> {} results ({})",
+                index + 1,
+                scan_history_entry.get_compare_type(),
+                scan_value,
+                scan_history_entry.get_data_type_ids().join(", "),
+                scan_history_entry.get_result_count(),
+                Conversions::value_to_metric_size(scan_history_entry.get_memory_cost())
+            );
+        }
+
+        log::info!(
+            "{} scan(s) in history, using {}.",
+            scan_history_response.scan_history_entries.len(),
+            Conversions::value_to_metric_size(scan_history_response.total_memory_cost)
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use olorin_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_signature_response(scan_response: ScanResponse) {
+    if let ScanResponse::SignatureScan { signature_scan_response } = scan_response {
+        for signature_scan_result in &signature_scan_response.signature_scan_results {
+            let location = if signature_scan_result.is_module() {
+                format!("{}+{:X}", signature_scan_result.get_module_name(), signature_scan_result.get_module_offset())
+            } else {
+                format!("{:X}", signature_scan_result.get_address())
+            };
+
+            match signature_scan_result.get_resolved_address() {
+                Some(_) if !signature_scan_result.get_resolved_module_name().is_empty() => log::info!(
+                    "{} -> {}+{:X}",
+                    location,
+                    signature_scan_result.get_resolved_module_name(),
+                    signature_scan_result.get_resolved_module_offset()
+                ),
+                Some(resolved_address) => log::info!("{} -> {:X}", location, resolved_address),
+                None => log::info!("{}", location),
+            }
+        }
+
+        log::info!("Found {} signature matches.", signature_scan_response.signature_scan_results.len());
+    }
+}
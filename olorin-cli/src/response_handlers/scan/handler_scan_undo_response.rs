@@ -0,0 +1,15 @@
+use olorin_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_undo_response(scan_response: ScanResponse) {
+    if let ScanResponse::Undo { scan_undo_response } = scan_response {
+        if scan_undo_response.scans_undone > 0 {
+            log::info!(
+                "Rolled back {} scan(s), {} results restored.",
+                scan_undo_response.scans_undone,
+                scan_undo_response.result_count
+            );
+        } else {
+            log::info!("No scans to undo.");
+        }
+    }
+}
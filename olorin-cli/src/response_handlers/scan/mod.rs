@@ -1,12 +1,20 @@
 pub mod handler_scan_collect_values_response;
 pub mod handler_scan_executor_response;
+pub mod handler_scan_grouped_response;
+pub mod handler_scan_history_response;
 pub mod handler_scan_new_response;
 pub mod handler_scan_reset_response;
+pub mod handler_scan_signature_response;
+pub mod handler_scan_undo_response;
 
 use crate::response_handlers::scan::handler_scan_collect_values_response::handle_scan_collect_values_response;
 use crate::response_handlers::scan::handler_scan_executor_response::handle_scan_execute_response;
+use crate::response_handlers::scan::handler_scan_grouped_response::handle_scan_grouped_response;
+use crate::response_handlers::scan::handler_scan_history_response::handle_scan_history_response;
 use crate::response_handlers::scan::handler_scan_new_response::handle_scan_new_response;
 use crate::response_handlers::scan::handler_scan_reset_response::handle_scan_reset_response;
+use crate::response_handlers::scan::handler_scan_signature_response::handle_scan_signature_response;
+use crate::response_handlers::scan::handler_scan_undo_response::handle_scan_undo_response;
 use olorin_engine_api::commands::scan::scan_response::ScanResponse;
 
 pub fn handle_scan_response(cmd: ScanResponse) {
@@ -15,5 +23,9 @@ pub fn handle_scan_response(cmd: ScanResponse) {
         ScanResponse::New { .. } => handle_scan_new_response(cmd),
         ScanResponse::CollectValues { .. } => handle_scan_collect_values_response(cmd),
         ScanResponse::Execute { .. } => handle_scan_execute_response(cmd),
+        ScanResponse::SignatureScan { .. } => handle_scan_signature_response(cmd),
+        ScanResponse::GroupedScan { .. } => handle_scan_grouped_response(cmd),
+        ScanResponse::Undo { .. } => handle_scan_undo_response(cmd),
+        ScanResponse::History { .. } => handle_scan_history_response(cmd),
     }
 }
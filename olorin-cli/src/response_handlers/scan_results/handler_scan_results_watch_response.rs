@@ -0,0 +1,16 @@
+use olorin_engine_api::commands::scan_results::watch::scan_results_watch_response::ScanResultsWatchResponse;
+use olorin_engine_api::structures::scan_results::scan_result::ScanResult;
+
+pub fn handle_scan_results_watch_response(results_watch_response: ScanResultsWatchResponse) {
+    for scan_result in &results_watch_response.scan_results {
+        log_watched_scan_result(scan_result);
+    }
+}
+
+/// Logs the latest value of a watched scan result. This is shared by the initial watch response and subsequent change events.
+pub fn log_watched_scan_result(scan_result: &ScanResult) {
+    match scan_result.get_recently_read_value() {
+        Some(data_value) => log::info!("{:X} = {}", scan_result.get_address(), data_value),
+        None => log::info!("{:X} = ??", scan_result.get_address()),
+    }
+}
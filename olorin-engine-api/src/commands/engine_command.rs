@@ -1,3 +1,4 @@
+use crate::commands::history::history_command::HistoryCommand;
 use crate::commands::memory::memory_command::MemoryCommand;
 use crate::commands::process::process_command::ProcessCommand;
 use crate::commands::project::project_command::ProjectCommand;
@@ -10,6 +11,9 @@ use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
 pub enum EngineCommand {
+    #[structopt(alias = "hist")]
+    History(HistoryCommand),
+
     #[structopt(alias = "mem", alias = "m")]
     Memory(MemoryCommand),
 
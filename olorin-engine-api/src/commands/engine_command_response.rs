@@ -1,3 +1,4 @@
+use crate::commands::history::history_response::HistoryResponse;
 use crate::commands::memory::memory_response::MemoryResponse;
 use crate::commands::process::process_response::ProcessResponse;
 use crate::commands::project::project_response::ProjectResponse;
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum EngineCommandResponse {
+    History(HistoryResponse),
     Memory(MemoryResponse),
     Process(ProcessResponse),
     Results(ScanResultsResponse),
@@ -16,6 +18,8 @@ pub enum EngineCommandResponse {
     Scan(ScanResponse),
     Settings(SettingsResponse),
     TrackableTasks(TrackableTasksResponse),
+    /// The engine could not answer the request, ie because it timed out or the privileged shell exited.
+    Failed(String),
 }
 
 pub trait TypedEngineCommandResponse: Sized {
@@ -0,0 +1,24 @@
+use crate::commands::history::list::history_list_request::HistoryListRequest;
+use crate::commands::history::redo::history_redo_request::HistoryRedoRequest;
+use crate::commands::history::undo::history_undo_request::HistoryUndoRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum HistoryCommand {
+    /// Reverts the most recent memory write, freeze, or project edit.
+    Undo {
+        #[structopt(flatten)]
+        history_undo_request: HistoryUndoRequest,
+    },
+    /// Reapplies the most recently undone edit.
+    Redo {
+        #[structopt(flatten)]
+        history_redo_request: HistoryRedoRequest,
+    },
+    /// Lists the edits that can be undone and redone.
+    List {
+        #[structopt(flatten)]
+        history_list_request: HistoryListRequest,
+    },
+}
@@ -0,0 +1,11 @@
+use crate::commands::history::list::history_list_response::HistoryListResponse;
+use crate::commands::history::redo::history_redo_response::HistoryRedoResponse;
+use crate::commands::history::undo::history_undo_response::HistoryUndoResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum HistoryResponse {
+    Undo { history_undo_response: HistoryUndoResponse },
+    Redo { history_redo_response: HistoryRedoResponse },
+    List { history_list_response: HistoryListResponse },
+}
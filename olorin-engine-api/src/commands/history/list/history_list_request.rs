@@ -0,0 +1,26 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::history::history_command::HistoryCommand;
+use crate::commands::history::history_response::HistoryResponse;
+use crate::commands::history::list::history_list_response::HistoryListResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct HistoryListRequest {}
+
+impl EngineCommandRequest for HistoryListRequest {
+    type ResponseType = HistoryListResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::History(HistoryCommand::List {
+            history_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<HistoryListResponse> for HistoryResponse {
+    fn from(history_list_response: HistoryListResponse) -> Self {
+        HistoryResponse::List { history_list_response }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::history::history_response::HistoryResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HistoryListResponse {
+    pub undo_entries: Vec<String>,
+    pub redo_entries: Vec<String>,
+}
+
+impl TypedEngineCommandResponse for HistoryListResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::History(HistoryResponse::List {
+            history_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::History(HistoryResponse::List { history_list_response }) = response {
+            Ok(history_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
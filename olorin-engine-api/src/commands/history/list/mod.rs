@@ -0,0 +1,2 @@
+pub mod history_list_request;
+pub mod history_list_response;
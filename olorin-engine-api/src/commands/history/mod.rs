@@ -0,0 +1,5 @@
+pub mod history_command;
+pub mod history_response;
+pub mod list;
+pub mod redo;
+pub mod undo;
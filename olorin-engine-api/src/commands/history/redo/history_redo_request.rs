@@ -0,0 +1,26 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::history::history_command::HistoryCommand;
+use crate::commands::history::history_response::HistoryResponse;
+use crate::commands::history::redo::history_redo_response::HistoryRedoResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct HistoryRedoRequest {}
+
+impl EngineCommandRequest for HistoryRedoRequest {
+    type ResponseType = HistoryRedoResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::History(HistoryCommand::Redo {
+            history_redo_request: self.clone(),
+        })
+    }
+}
+
+impl From<HistoryRedoResponse> for HistoryResponse {
+    fn from(history_redo_response: HistoryRedoResponse) -> Self {
+        HistoryResponse::Redo { history_redo_response }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::history::history_response::HistoryResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HistoryRedoResponse {
+    pub redone_entry: Option<String>,
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for HistoryRedoResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::History(HistoryResponse::Redo {
+            history_redo_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::History(HistoryResponse::Redo { history_redo_response }) = response {
+            Ok(history_redo_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod history_redo_request;
+pub mod history_redo_response;
@@ -0,0 +1,26 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::history::history_command::HistoryCommand;
+use crate::commands::history::history_response::HistoryResponse;
+use crate::commands::history::undo::history_undo_response::HistoryUndoResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct HistoryUndoRequest {}
+
+impl EngineCommandRequest for HistoryUndoRequest {
+    type ResponseType = HistoryUndoResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::History(HistoryCommand::Undo {
+            history_undo_request: self.clone(),
+        })
+    }
+}
+
+impl From<HistoryUndoResponse> for HistoryResponse {
+    fn from(history_undo_response: HistoryUndoResponse) -> Self {
+        HistoryResponse::Undo { history_undo_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod history_undo_request;
+pub mod history_undo_response;
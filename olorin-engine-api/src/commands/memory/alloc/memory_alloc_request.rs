@@ -0,0 +1,37 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::alloc::memory_alloc_response::MemoryAllocResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::conversions::conversions::Conversions;
+use crate::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Allocates memory within the opened process, such as for a code cave. The size is rounded up to whole pages.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryAllocRequest {
+    /// The number of bytes to allocate.
+    #[structopt(short = "s", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub size: u64,
+
+    /// The protection of the allocated memory, ie `rw` or `rwx`.
+    #[structopt(short = "p", long, default_value = "rw")]
+    pub protection: MemoryProtectionEnum,
+}
+
+impl EngineCommandRequest for MemoryAllocRequest {
+    type ResponseType = MemoryAllocResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Alloc {
+            memory_alloc_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryAllocResponse> for MemoryResponse {
+    fn from(memory_alloc_response: MemoryAllocResponse) -> Self {
+        MemoryResponse::Alloc { memory_alloc_response }
+    }
+}
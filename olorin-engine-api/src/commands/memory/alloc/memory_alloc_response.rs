@@ -0,0 +1,29 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryAllocResponse {
+    /// The address of the allocated memory, if it was allocated successfully.
+    pub address: Option<u64>,
+    pub size: u64,
+    pub protection: MemoryProtectionEnum,
+}
+
+impl TypedEngineCommandResponse for MemoryAllocResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Memory(MemoryResponse::Alloc {
+            memory_alloc_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Memory(MemoryResponse::Alloc { memory_alloc_response }) = response {
+            Ok(memory_alloc_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_alloc_request;
+pub mod memory_alloc_response;
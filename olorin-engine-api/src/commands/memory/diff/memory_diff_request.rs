@@ -0,0 +1,43 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::diff::memory_diff_response::MemoryDiffResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryDiffRequest {
+    /// The dump to compare against.
+    #[structopt(short = "b", long, parse(from_os_str))]
+    pub base_dump_path: PathBuf,
+
+    /// A second dump to compare. If omitted, the base dump is compared against live memory of the opened process.
+    #[structopt(short = "c", long, parse(from_os_str))]
+    pub compare_dump_path: Option<PathBuf>,
+
+    /// A data type with which to interpret changed ranges.
+    #[structopt(short = "d", long)]
+    pub data_type_id: Option<String>,
+
+    /// A file to which the full list of changed ranges is written.
+    #[structopt(short = "o", long, parse(from_os_str))]
+    pub output_path: Option<PathBuf>,
+}
+
+impl EngineCommandRequest for MemoryDiffRequest {
+    type ResponseType = MemoryDiffResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Diff {
+            memory_diff_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryDiffResponse> for MemoryResponse {
+    fn from(memory_diff_response: MemoryDiffResponse) -> Self {
+        MemoryResponse::Diff { memory_diff_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_diff_request;
+pub mod memory_diff_response;
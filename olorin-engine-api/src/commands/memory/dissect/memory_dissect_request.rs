@@ -0,0 +1,32 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::dissect::memory_dissect_response::MemoryDissectResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::structures::address_expressions::address_expression::AddressExpression;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryDissectRequest {
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: AddressExpression,
+    #[structopt(short = "l", long, default_value = "256")]
+    pub length: u64,
+}
+
+impl EngineCommandRequest for MemoryDissectRequest {
+    type ResponseType = MemoryDissectResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Dissect {
+            memory_dissect_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryDissectResponse> for MemoryResponse {
+    fn from(memory_dissect_response: MemoryDissectResponse) -> Self {
+        MemoryResponse::Dissect { memory_dissect_response }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::structures::dissection::dissected_field::DissectedField;
+use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryDissectResponse {
+    pub address: u64,
+    pub dissected_fields: Vec<DissectedField>,
+    /// The inferred layout, which can be edited and saved to the project as a named struct.
+    pub symbolic_struct_definition: Option<SymbolicStructDefinition>,
+}
+
+impl TypedEngineCommandResponse for MemoryDissectResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Memory(MemoryResponse::Dissect {
+            memory_dissect_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Memory(MemoryResponse::Dissect { memory_dissect_response }) = response {
+            Ok(memory_dissect_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_dissect_request;
+pub mod memory_dissect_response;
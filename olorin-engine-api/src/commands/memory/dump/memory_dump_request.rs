@@ -0,0 +1,45 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::dump::memory_dump_response::MemoryDumpResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::conversions::conversions::Conversions;
+use crate::structures::address_expressions::address_expression::AddressExpression;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryDumpRequest {
+    /// The file to which memory is dumped.
+    #[structopt(short = "f", long, parse(from_os_str))]
+    pub file_path: PathBuf,
+
+    /// The start address of the dump. Ignored if a module name is provided.
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: Option<AddressExpression>,
+
+    /// The number of bytes to dump. Ignored if a module name is provided.
+    #[structopt(short = "s", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub size: Option<u64>,
+
+    /// The name of a module to dump in its entirety.
+    #[structopt(short = "m", long)]
+    pub module_name: Option<String>,
+}
+
+impl EngineCommandRequest for MemoryDumpRequest {
+    type ResponseType = MemoryDumpResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Dump {
+            memory_dump_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryDumpResponse> for MemoryResponse {
+    fn from(memory_dump_response: MemoryDumpResponse) -> Self {
+        MemoryResponse::Dump { memory_dump_response }
+    }
+}
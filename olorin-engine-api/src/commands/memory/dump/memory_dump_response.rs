@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::structures::tasks::trackable_task_handle::TrackableTaskHandle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryDumpResponse {
+    pub trackable_task_handle: Option<TrackableTaskHandle>,
+}
+
+impl TypedEngineCommandResponse for MemoryDumpResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Memory(MemoryResponse::Dump {
+            memory_dump_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Memory(MemoryResponse::Dump { memory_dump_response }) = response {
+            Ok(memory_dump_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_dump_request;
+pub mod memory_dump_response;
@@ -0,0 +1,36 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::free::memory_free_response::MemoryFreeResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::conversions::conversions::Conversions;
+use crate::structures::address_expressions::address_expression::AddressExpression;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Frees memory within the opened process. The size may be omitted for memory allocated with `memory alloc`.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryFreeRequest {
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: AddressExpression,
+
+    /// The number of bytes to free. Required for memory that was not allocated by the engine.
+    #[structopt(short = "s", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub size: Option<u64>,
+}
+
+impl EngineCommandRequest for MemoryFreeRequest {
+    type ResponseType = MemoryFreeResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Free {
+            memory_free_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryFreeResponse> for MemoryResponse {
+    fn from(memory_free_response: MemoryFreeResponse) -> Self {
+        MemoryResponse::Free { memory_free_response }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::memory::memory_response::MemoryResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryFreeResponse {
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for MemoryFreeResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Memory(MemoryResponse::Free {
+            memory_free_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Memory(MemoryResponse::Free { memory_free_response }) = response {
+            Ok(memory_free_response)
+        } else {
+            Err(response)
+        }
+    }
+}
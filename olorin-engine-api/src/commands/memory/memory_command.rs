@@ -1,3 +1,10 @@
+use crate::commands::memory::alloc::memory_alloc_request::MemoryAllocRequest;
+use crate::commands::memory::diff::memory_diff_request::MemoryDiffRequest;
+use crate::commands::memory::dissect::memory_dissect_request::MemoryDissectRequest;
+use crate::commands::memory::dump::memory_dump_request::MemoryDumpRequest;
+use crate::commands::memory::free::memory_free_request::MemoryFreeRequest;
+use crate::commands::memory::protect::memory_protect_request::MemoryProtectRequest;
+use crate::commands::memory::query::memory_query_request::MemoryQueryRequest;
 use crate::commands::memory::read::memory_read_request::MemoryReadRequest;
 use crate::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use serde::{Deserialize, Serialize};
@@ -13,4 +20,32 @@ pub enum MemoryCommand {
         #[structopt(flatten)]
         memory_write_request: MemoryWriteRequest,
     },
+    Query {
+        #[structopt(flatten)]
+        memory_query_request: MemoryQueryRequest,
+    },
+    Dump {
+        #[structopt(flatten)]
+        memory_dump_request: MemoryDumpRequest,
+    },
+    Diff {
+        #[structopt(flatten)]
+        memory_diff_request: MemoryDiffRequest,
+    },
+    Dissect {
+        #[structopt(flatten)]
+        memory_dissect_request: MemoryDissectRequest,
+    },
+    Alloc {
+        #[structopt(flatten)]
+        memory_alloc_request: MemoryAllocRequest,
+    },
+    Free {
+        #[structopt(flatten)]
+        memory_free_request: MemoryFreeRequest,
+    },
+    Protect {
+        #[structopt(flatten)]
+        memory_protect_request: MemoryProtectRequest,
+    },
 }
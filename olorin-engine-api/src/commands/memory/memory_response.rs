@@ -1,3 +1,10 @@
+use crate::commands::memory::alloc::memory_alloc_response::MemoryAllocResponse;
+use crate::commands::memory::diff::memory_diff_response::MemoryDiffResponse;
+use crate::commands::memory::dissect::memory_dissect_response::MemoryDissectResponse;
+use crate::commands::memory::dump::memory_dump_response::MemoryDumpResponse;
+use crate::commands::memory::free::memory_free_response::MemoryFreeResponse;
+use crate::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+use crate::commands::memory::query::memory_query_response::MemoryQueryResponse;
 use crate::commands::memory::read::memory_read_response::MemoryReadResponse;
 use crate::commands::memory::write::memory_write_response::MemoryWriteResponse;
 use serde::{Deserialize, Serialize};
@@ -6,4 +13,11 @@ use serde::{Deserialize, Serialize};
 pub enum MemoryResponse {
     Read { memory_read_response: MemoryReadResponse },
     Write { memory_write_response: MemoryWriteResponse },
+    Query { memory_query_response: MemoryQueryResponse },
+    Dump { memory_dump_response: MemoryDumpResponse },
+    Diff { memory_diff_response: MemoryDiffResponse },
+    Dissect { memory_dissect_response: MemoryDissectResponse },
+    Alloc { memory_alloc_response: MemoryAllocResponse },
+    Free { memory_free_response: MemoryFreeResponse },
+    Protect { memory_protect_response: MemoryProtectResponse },
 }
@@ -1,4 +1,11 @@
+pub mod alloc;
+pub mod diff;
+pub mod dissect;
+pub mod dump;
+pub mod free;
 pub mod memory_command;
 pub mod memory_response;
+pub mod protect;
+pub mod query;
 pub mod read;
 pub mod write;
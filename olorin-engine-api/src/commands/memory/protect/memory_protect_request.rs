@@ -0,0 +1,42 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+use crate::conversions::conversions::Conversions;
+use crate::structures::address_expressions::address_expression::AddressExpression;
+use crate::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Changes the protection of memory within the opened process, such as to make read only pages writable. The change applies to every
+/// page overlapping the given range.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryProtectRequest {
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: AddressExpression,
+
+    /// The number of bytes to change the protection of.
+    #[structopt(short = "s", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub size: u64,
+
+    /// The new protection, ie `r`, `rw`, or `rx`.
+    #[structopt(short = "p", long)]
+    pub protection: MemoryProtectionEnum,
+}
+
+impl EngineCommandRequest for MemoryProtectRequest {
+    type ResponseType = MemoryProtectResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Protect {
+            memory_protect_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryProtectResponse> for MemoryResponse {
+    fn from(memory_protect_response: MemoryProtectResponse) -> Self {
+        MemoryResponse::Protect { memory_protect_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryProtectResponse {
+    pub address: u64,
+    /// The protection of the memory after the change, if it was changed successfully.
+    pub protection: Option<MemoryProtectionEnum>,
+}
+
+impl TypedEngineCommandResponse for MemoryProtectResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Memory(MemoryResponse::Protect {
+            memory_protect_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Memory(MemoryResponse::Protect { memory_protect_response }) = response {
+            Ok(memory_protect_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_protect_request;
+pub mod memory_protect_response;
@@ -0,0 +1,30 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::query::memory_query_response::MemoryQueryResponse;
+use crate::structures::address_expressions::address_expression::AddressExpression;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryQueryRequest {
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: AddressExpression,
+}
+
+impl EngineCommandRequest for MemoryQueryRequest {
+    type ResponseType = MemoryQueryResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Memory(MemoryCommand::Query {
+            memory_query_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryQueryResponse> for MemoryResponse {
+    fn from(memory_query_response: MemoryQueryResponse) -> Self {
+        MemoryResponse::Query { memory_query_response }
+    }
+}
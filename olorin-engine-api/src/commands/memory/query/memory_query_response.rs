@@ -0,0 +1,35 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryQueryResponse {
+    pub address: u64,
+    pub region_base_address: u64,
+    pub region_size: u64,
+    pub module_name: String,
+    pub module_offset: u64,
+    pub symbol: Option<String>,
+    pub is_writable: bool,
+    pub protection: MemoryProtectionEnum,
+    pub success: bool,
+    pub address_error: Option<String>,
+}
+
+impl TypedEngineCommandResponse for MemoryQueryResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Memory(MemoryResponse::Query {
+            memory_query_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Memory(MemoryResponse::Query { memory_query_response }) = response {
+            Ok(memory_query_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_query_request;
+pub mod memory_query_response;
@@ -3,7 +3,7 @@ use crate::commands::engine_command_request::EngineCommandRequest;
 use crate::commands::memory::memory_command::MemoryCommand;
 use crate::commands::memory::memory_response::MemoryResponse;
 use crate::commands::memory::read::memory_read_response::MemoryReadResponse;
-use crate::conversions::conversions::Conversions;
+use crate::structures::address_expressions::address_expression::AddressExpression;
 use crate::structures::structs::valued_struct::ValuedStruct;
 use serde::Deserialize;
 use serde::Serialize;
@@ -11,9 +11,8 @@ use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
 pub struct MemoryReadRequest {
-    // JIRA: Should probably bias hex and fall back on int? Maybe this can be made more explicit.
-    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
-    pub address: u64,
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: AddressExpression,
 
     #[structopt(short = "v")]
     pub valued_struct: ValuedStruct,
@@ -3,15 +3,14 @@ use crate::commands::engine_command_request::EngineCommandRequest;
 use crate::commands::memory::memory_command::MemoryCommand;
 use crate::commands::memory::memory_response::MemoryResponse;
 use crate::commands::memory::write::memory_write_response::MemoryWriteResponse;
-use crate::conversions::conversions::Conversions;
+use crate::structures::address_expressions::address_expression::AddressExpression;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
 pub struct MemoryWriteRequest {
-    // JIRA: Seems sus to just have generic int or hex parser.
-    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
-    pub address: u64,
+    #[structopt(short = "a", long, parse(try_from_str = AddressExpression::parse_command_line_address))]
+    pub address: AddressExpression,
 
     #[structopt(short = "v")]
     pub value: Vec<u8>,
@@ -1,6 +1,7 @@
 pub mod engine_command;
 pub mod engine_command_request;
 pub mod engine_command_response;
+pub mod history;
 pub mod memory;
 pub mod process;
 pub mod project;
@@ -0,0 +1,2 @@
+pub mod process_eject_request;
+pub mod process_eject_response;
@@ -0,0 +1,31 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::eject::process_eject_response::ProcessEjectResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Unloads a shared library that was injected into the opened process with `process inject`.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessEjectRequest {
+    #[structopt(parse(from_os_str))]
+    pub library_path: PathBuf,
+}
+
+impl EngineCommandRequest for ProcessEjectRequest {
+    type ResponseType = ProcessEjectResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Eject {
+            process_eject_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessEjectResponse> for ProcessResponse {
+    fn from(process_eject_response: ProcessEjectResponse) -> Self {
+        ProcessResponse::Eject { process_eject_response }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessEjectResponse {
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for ProcessEjectResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Process(ProcessResponse::Eject {
+            process_eject_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Process(ProcessResponse::Eject { process_eject_response }) = response {
+            Ok(process_eject_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod process_inject_request;
+pub mod process_inject_response;
@@ -0,0 +1,31 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::inject::process_inject_response::ProcessInjectResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Loads a shared library into the opened process. To load a library before the process starts, use `process launch --preload`.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessInjectRequest {
+    #[structopt(parse(from_os_str))]
+    pub library_path: PathBuf,
+}
+
+impl EngineCommandRequest for ProcessInjectRequest {
+    type ResponseType = ProcessInjectResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Inject {
+            process_inject_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessInjectResponse> for ProcessResponse {
+    fn from(process_inject_response: ProcessInjectResponse) -> Self {
+        ProcessResponse::Inject { process_inject_response }
+    }
+}
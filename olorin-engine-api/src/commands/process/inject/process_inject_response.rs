@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessInjectResponse {
+    /// The address at which the library was mapped, if it was loaded successfully.
+    pub module_base_address: Option<u64>,
+}
+
+impl TypedEngineCommandResponse for ProcessInjectResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Process(ProcessResponse::Inject {
+            process_inject_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Process(ProcessResponse::Inject { process_inject_response }) = response {
+            Ok(process_inject_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod process_launch_request;
+pub mod process_launch_response;
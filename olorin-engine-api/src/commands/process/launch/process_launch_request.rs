@@ -0,0 +1,45 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::launch::process_launch_response::ProcessLaunchResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Launches an executable and attaches to it, forwarding its standard output and error to the output log.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessLaunchRequest {
+    #[structopt(parse(from_os_str))]
+    pub executable_path: PathBuf,
+    /// Arguments to pass to the executable, given after `--`.
+    #[structopt(last = true)]
+    pub arguments: Vec<String>,
+    #[structopt(short = "w", long, parse(from_os_str))]
+    pub working_directory: Option<PathBuf>,
+    /// Environment variable overrides, each in the form `KEY=VALUE`.
+    #[structopt(short = "e", long = "env")]
+    pub environment_variables: Vec<String>,
+    /// Shared libraries to load into the process before any of its own modules.
+    #[structopt(short = "l", long = "preload", parse(from_os_str))]
+    pub preload_library_paths: Vec<PathBuf>,
+    /// Stops the process before any of its code runs, until it is resumed with `process resume`.
+    #[structopt(short = "s", long)]
+    pub suspended: bool,
+}
+
+impl EngineCommandRequest for ProcessLaunchRequest {
+    type ResponseType = ProcessLaunchResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Launch {
+            process_launch_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessLaunchResponse> for ProcessResponse {
+    fn from(process_launch_response: ProcessLaunchResponse) -> Self {
+        ProcessResponse::Launch { process_launch_response }
+    }
+}
@@ -1,5 +1,12 @@
 pub mod close;
+pub mod eject;
+pub mod inject;
+pub mod launch;
 pub mod list;
 pub mod open;
 pub mod process_command;
 pub mod process_response;
+pub mod resume;
+pub mod save;
+pub mod speed;
+pub mod threads;
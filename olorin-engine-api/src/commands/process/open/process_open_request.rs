@@ -4,6 +4,7 @@ use crate::commands::process::open::process_open_response::ProcessOpenResponse;
 use crate::commands::process::process_command::ProcessCommand;
 use crate::commands::process::process_response::ProcessResponse;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
@@ -14,6 +15,12 @@ pub struct ProcessOpenRequest {
     pub search_name: Option<String>,
     #[structopt(short = "m", long)]
     pub match_case: bool,
+    /// An ELF core dump to open as a read only virtual process, rather than a running process.
+    #[structopt(short = "c", long)]
+    pub core_file_path: Option<PathBuf>,
+    /// A file to open as a writable virtual process, where addresses are file offsets. Changes are written back with `process save`.
+    #[structopt(short = "f", long)]
+    pub file_path: Option<PathBuf>,
 }
 
 impl EngineCommandRequest for ProcessOpenRequest {
@@ -1,6 +1,13 @@
 use crate::commands::process::close::process_close_request::ProcessCloseRequest;
+use crate::commands::process::eject::process_eject_request::ProcessEjectRequest;
+use crate::commands::process::inject::process_inject_request::ProcessInjectRequest;
+use crate::commands::process::launch::process_launch_request::ProcessLaunchRequest;
 use crate::commands::process::list::process_list_request::ProcessListRequest;
 use crate::commands::process::open::process_open_request::ProcessOpenRequest;
+use crate::commands::process::resume::process_resume_request::ProcessResumeRequest;
+use crate::commands::process::save::process_save_request::ProcessSaveRequest;
+use crate::commands::process::speed::process_speed_request::ProcessSpeedRequest;
+use crate::commands::process::threads::process_threads_request::ProcessThreadsRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -18,4 +25,32 @@ pub enum ProcessCommand {
         #[structopt(flatten)]
         process_close_request: ProcessCloseRequest,
     },
+    Save {
+        #[structopt(flatten)]
+        process_save_request: ProcessSaveRequest,
+    },
+    Launch {
+        #[structopt(flatten)]
+        process_launch_request: ProcessLaunchRequest,
+    },
+    Resume {
+        #[structopt(flatten)]
+        process_resume_request: ProcessResumeRequest,
+    },
+    Inject {
+        #[structopt(flatten)]
+        process_inject_request: ProcessInjectRequest,
+    },
+    Eject {
+        #[structopt(flatten)]
+        process_eject_request: ProcessEjectRequest,
+    },
+    Speed {
+        #[structopt(flatten)]
+        process_speed_request: ProcessSpeedRequest,
+    },
+    Threads {
+        #[structopt(flatten)]
+        process_threads_request: ProcessThreadsRequest,
+    },
 }
@@ -1,6 +1,13 @@
 use crate::commands::process::close::process_close_response::ProcessCloseResponse;
+use crate::commands::process::eject::process_eject_response::ProcessEjectResponse;
+use crate::commands::process::inject::process_inject_response::ProcessInjectResponse;
+use crate::commands::process::launch::process_launch_response::ProcessLaunchResponse;
 use crate::commands::process::list::process_list_response::ProcessListResponse;
 use crate::commands::process::open::process_open_response::ProcessOpenResponse;
+use crate::commands::process::resume::process_resume_response::ProcessResumeResponse;
+use crate::commands::process::save::process_save_response::ProcessSaveResponse;
+use crate::commands::process::speed::process_speed_response::ProcessSpeedResponse;
+use crate::commands::process::threads::process_threads_response::ProcessThreadsResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -8,4 +15,11 @@ pub enum ProcessResponse {
     List { process_list_response: ProcessListResponse },
     Close { process_close_response: ProcessCloseResponse },
     Open { process_open_response: ProcessOpenResponse },
+    Save { process_save_response: ProcessSaveResponse },
+    Launch { process_launch_response: ProcessLaunchResponse },
+    Resume { process_resume_response: ProcessResumeResponse },
+    Inject { process_inject_response: ProcessInjectResponse },
+    Eject { process_eject_response: ProcessEjectResponse },
+    Speed { process_speed_response: ProcessSpeedResponse },
+    Threads { process_threads_response: ProcessThreadsResponse },
 }
@@ -0,0 +1,2 @@
+pub mod process_resume_request;
+pub mod process_resume_response;
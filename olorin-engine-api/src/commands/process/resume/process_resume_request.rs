@@ -0,0 +1,27 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::commands::process::resume::process_resume_response::ProcessResumeResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Resumes the opened process, if it was launched suspended.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessResumeRequest {}
+
+impl EngineCommandRequest for ProcessResumeRequest {
+    type ResponseType = ProcessResumeResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Resume {
+            process_resume_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessResumeResponse> for ProcessResponse {
+    fn from(process_resume_response: ProcessResumeResponse) -> Self {
+        ProcessResponse::Resume { process_resume_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod process_save_request;
+pub mod process_save_response;
@@ -0,0 +1,27 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::commands::process::save::process_save_response::ProcessSaveResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Writes changes made to the opened target back to its source, such as the file of a file target.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessSaveRequest {}
+
+impl EngineCommandRequest for ProcessSaveRequest {
+    type ResponseType = ProcessSaveResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Save {
+            process_save_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessSaveResponse> for ProcessResponse {
+    fn from(process_save_response: ProcessSaveResponse) -> Self {
+        ProcessResponse::Save { process_save_response }
+    }
+}
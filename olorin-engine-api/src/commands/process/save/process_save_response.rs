@@ -0,0 +1,25 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessSaveResponse {
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for ProcessSaveResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Process(ProcessResponse::Save {
+            process_save_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Process(ProcessResponse::Save { process_save_response }) = response {
+            Ok(process_save_response)
+        } else {
+            Err(response)
+        }
+    }
+}
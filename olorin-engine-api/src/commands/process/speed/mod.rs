@@ -0,0 +1,2 @@
+pub mod process_speed_request;
+pub mod process_speed_response;
@@ -0,0 +1,30 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::commands::process::speed::process_speed_response::ProcessSpeedResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Speeds up or slows down time in the opened process, where a factor of 1 is real time. The speedhack library is injected into
+/// the process the first time its speed is changed, unless it was already preloaded at launch.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessSpeedRequest {
+    pub speed_factor: f64,
+}
+
+impl EngineCommandRequest for ProcessSpeedRequest {
+    type ResponseType = ProcessSpeedResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Speed {
+            process_speed_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessSpeedResponse> for ProcessResponse {
+    fn from(process_speed_response: ProcessSpeedResponse) -> Self {
+        ProcessResponse::Speed { process_speed_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod process_threads_request;
+pub mod process_threads_response;
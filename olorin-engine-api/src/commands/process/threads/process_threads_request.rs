@@ -0,0 +1,28 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::commands::process::threads::process_threads_response::ProcessThreadsResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Lists the threads of the opened process, along with the stack of each thread. Stacks can be referenced in addresses by the
+/// listed stack module name, ie `threadstack0+1A8`.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessThreadsRequest {}
+
+impl EngineCommandRequest for ProcessThreadsRequest {
+    type ResponseType = ProcessThreadsResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Process(ProcessCommand::Threads {
+            process_threads_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessThreadsResponse> for ProcessResponse {
+    fn from(process_threads_response: ProcessThreadsResponse) -> Self {
+        ProcessResponse::Threads { process_threads_response }
+    }
+}
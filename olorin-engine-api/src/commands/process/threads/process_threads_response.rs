@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::structures::processes::thread_info::ThreadInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessThreadsResponse {
+    pub threads: Vec<ThreadInfo>,
+}
+
+impl TypedEngineCommandResponse for ProcessThreadsResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Process(ProcessResponse::Threads {
+            process_threads_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Process(ProcessResponse::Threads { process_threads_response }) = response {
+            Ok(process_threads_response)
+        } else {
+            Err(response)
+        }
+    }
+}
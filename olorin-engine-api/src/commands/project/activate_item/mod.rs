@@ -0,0 +1,2 @@
+pub mod project_activate_item_request;
+pub mod project_activate_item_response;
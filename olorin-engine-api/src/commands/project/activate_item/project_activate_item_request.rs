@@ -0,0 +1,34 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::activate_item::project_activate_item_response::ProjectActivateItemResponse;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectActivateItemRequest {
+    /// The path of the project item, relative to the root of the opened project.
+    #[structopt(parse(from_os_str))]
+    pub project_item_path: PathBuf,
+
+    #[structopt(short = "d", long)]
+    pub deactivate: bool,
+}
+
+impl EngineCommandRequest for ProjectActivateItemRequest {
+    type ResponseType = ProjectActivateItemResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::ActivateItem {
+            project_activate_item_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectActivateItemResponse> for ProjectResponse {
+    fn from(project_activate_item_response: ProjectActivateItemResponse) -> Self {
+        ProjectResponse::ActivateItem { project_activate_item_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod project_add_speed_request;
+pub mod project_add_speed_response;
@@ -0,0 +1,36 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::add_speed::project_add_speed_response::ProjectAddSpeedResponse;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectAddSpeedRequest {
+    #[structopt(short = "n", long)]
+    pub item_name: String,
+
+    /// The speed applied to the opened process while the item is activated, where 1 is real time.
+    #[structopt(short = "s", long)]
+    pub speed_factor: f64,
+
+    #[structopt(short = "d", long, default_value = "")]
+    pub description: String,
+}
+
+impl EngineCommandRequest for ProjectAddSpeedRequest {
+    type ResponseType = ProjectAddSpeedResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::AddSpeed {
+            project_add_speed_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectAddSpeedResponse> for ProjectResponse {
+    fn from(project_add_speed_response: ProjectAddSpeedResponse) -> Self {
+        ProjectResponse::AddSpeed { project_add_speed_response }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::add_struct::project_add_struct_response::ProjectAddStructResponse;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectAddStructRequest {
+    #[structopt(short = "n", long)]
+    pub struct_name: String,
+
+    #[structopt(short = "d", long)]
+    pub symbolic_struct_definition: SymbolicStructDefinition,
+}
+
+impl EngineCommandRequest for ProjectAddStructRequest {
+    type ResponseType = ProjectAddStructResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::AddStruct {
+            project_add_struct_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectAddStructResponse> for ProjectResponse {
+    fn from(project_add_struct_response: ProjectAddStructResponse) -> Self {
+        ProjectResponse::AddStruct { project_add_struct_response }
+    }
+}
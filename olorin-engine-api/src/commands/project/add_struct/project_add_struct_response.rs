@@ -0,0 +1,25 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProjectAddStructResponse {
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for ProjectAddStructResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Project(ProjectResponse::AddStruct {
+            project_add_struct_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Project(ProjectResponse::AddStruct { project_add_struct_response }) = response {
+            Ok(project_add_struct_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod project_export_structs_request;
+pub mod project_export_structs_response;
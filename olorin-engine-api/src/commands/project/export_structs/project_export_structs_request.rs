@@ -0,0 +1,37 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::export_structs::project_export_structs_response::ProjectExportStructsResponse;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectExportStructsRequest {
+    /// The C header to write.
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+
+    /// The structs to export. All structs in the project are exported if none are specified.
+    #[structopt(short = "n", long)]
+    pub struct_names: Vec<String>,
+}
+
+impl EngineCommandRequest for ProjectExportStructsRequest {
+    type ResponseType = ProjectExportStructsResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::ExportStructs {
+            project_export_structs_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectExportStructsResponse> for ProjectResponse {
+    fn from(project_export_structs_response: ProjectExportStructsResponse) -> Self {
+        ProjectResponse::ExportStructs {
+            project_export_structs_response,
+        }
+    }
+}
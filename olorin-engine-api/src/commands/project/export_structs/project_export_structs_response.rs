@@ -0,0 +1,29 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectExportStructsResponse {
+    pub success: bool,
+    pub exported_struct_count: u64,
+}
+
+impl TypedEngineCommandResponse for ProjectExportStructsResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Project(ProjectResponse::ExportStructs {
+            project_export_structs_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Project(ProjectResponse::ExportStructs {
+            project_export_structs_response,
+        }) = response
+        {
+            Ok(project_export_structs_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod project_import_structs_request;
+pub mod project_import_structs_response;
@@ -0,0 +1,33 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::import_structs::project_import_structs_response::ProjectImportStructsResponse;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectImportStructsRequest {
+    /// A C/C++ header, or a ReClass.NET project (`.rcnet`).
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+}
+
+impl EngineCommandRequest for ProjectImportStructsRequest {
+    type ResponseType = ProjectImportStructsResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::ImportStructs {
+            project_import_structs_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectImportStructsResponse> for ProjectResponse {
+    fn from(project_import_structs_response: ProjectImportStructsResponse) -> Self {
+        ProjectResponse::ImportStructs {
+            project_import_structs_response,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectImportStructsResponse {
+    pub success: bool,
+    pub imported_struct_names: Vec<String>,
+    pub skipped_struct_names: Vec<String>,
+    /// Problems found while importing, such as fields of unknown types that were imported as padding.
+    pub warnings: Vec<String>,
+}
+
+impl TypedEngineCommandResponse for ProjectImportStructsResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Project(ProjectResponse::ImportStructs {
+            project_import_structs_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Project(ProjectResponse::ImportStructs {
+            project_import_structs_response,
+        }) = response
+        {
+            Ok(project_import_structs_response)
+        } else {
+            Err(response)
+        }
+    }
+}
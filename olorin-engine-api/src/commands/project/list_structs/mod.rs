@@ -0,0 +1,2 @@
+pub mod project_list_structs_request;
+pub mod project_list_structs_response;
@@ -0,0 +1,26 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::list_structs::project_list_structs_response::ProjectListStructsResponse;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectListStructsRequest {}
+
+impl EngineCommandRequest for ProjectListStructsRequest {
+    type ResponseType = ProjectListStructsResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::ListStructs {
+            project_list_structs_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectListStructsResponse> for ProjectResponse {
+    fn from(project_list_structs_response: ProjectListStructsResponse) -> Self {
+        ProjectResponse::ListStructs { project_list_structs_response }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectListStructsResponse {
+    /// The names of the structs registered by the opened project, in sorted order.
+    pub struct_names: Vec<String>,
+}
+
+impl TypedEngineCommandResponse for ProjectListStructsResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Project(ProjectResponse::ListStructs {
+            project_list_structs_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Project(ProjectResponse::ListStructs { project_list_structs_response }) = response {
+            Ok(project_list_structs_response)
+        } else {
+            Err(response)
+        }
+    }
+}
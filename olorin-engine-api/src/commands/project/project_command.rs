@@ -1,10 +1,18 @@
+use crate::commands::project::activate_item::project_activate_item_request::ProjectActivateItemRequest;
+use crate::commands::project::add_speed::project_add_speed_request::ProjectAddSpeedRequest;
+use crate::commands::project::add_struct::project_add_struct_request::ProjectAddStructRequest;
 use crate::commands::project::close::project_close_request::ProjectCloseRequest;
 use crate::commands::project::create::project_create_request::ProjectCreateRequest;
 use crate::commands::project::export::project_export_request::ProjectExportRequest;
+use crate::commands::project::export_structs::project_export_structs_request::ProjectExportStructsRequest;
+use crate::commands::project::import_structs::project_import_structs_request::ProjectImportStructsRequest;
 use crate::commands::project::list::project_list_request::ProjectListRequest;
+use crate::commands::project::list_structs::project_list_structs_request::ProjectListStructsRequest;
 use crate::commands::project::open::project_open_request::ProjectOpenRequest;
 use crate::commands::project::rename::project_rename_request::ProjectRenameRequest;
 use crate::commands::project::save::project_save_request::ProjectSaveRequest;
+use crate::commands::project::set_item_property::project_set_item_property_request::ProjectSetItemPropertyRequest;
+use crate::commands::project::watch_process::project_watch_process_request::ProjectWatchProcessRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -45,4 +53,44 @@ pub enum ProjectCommand {
         #[structopt(flatten)]
         project_list_request: ProjectListRequest,
     },
+    /// Add a named struct definition to the opened project.
+    AddStruct {
+        #[structopt(flatten)]
+        project_add_struct_request: ProjectAddStructRequest,
+    },
+    /// Import struct definitions from a C header or ReClass.NET project into the opened project.
+    ImportStructs {
+        #[structopt(flatten)]
+        project_import_structs_request: ProjectImportStructsRequest,
+    },
+    /// Export struct definitions from the opened project as a C header.
+    ExportStructs {
+        #[structopt(flatten)]
+        project_export_structs_request: ProjectExportStructsRequest,
+    },
+    /// List the names of the struct definitions in the opened project.
+    ListStructs {
+        #[structopt(flatten)]
+        project_list_structs_request: ProjectListStructsRequest,
+    },
+    /// Set the process to attach to automatically while the opened project is open, re-resolving project items on re-attach.
+    WatchProcess {
+        #[structopt(flatten)]
+        project_watch_process_request: ProjectWatchProcessRequest,
+    },
+    /// Add an item to the opened project that sets the speed of the opened process while activated.
+    AddSpeed {
+        #[structopt(flatten)]
+        project_add_speed_request: ProjectAddSpeedRequest,
+    },
+    /// Activate or deactivate an item in the opened project, applying its effect to the opened process.
+    ActivateItem {
+        #[structopt(flatten)]
+        project_activate_item_request: ProjectActivateItemRequest,
+    },
+    /// Set a property of an item in the opened project, such as the description, address or freeze value of an address item.
+    SetItemProperty {
+        #[structopt(flatten)]
+        project_set_item_property_request: ProjectSetItemPropertyRequest,
+    },
 }
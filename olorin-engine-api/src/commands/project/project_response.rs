@@ -1,10 +1,18 @@
+use crate::commands::project::activate_item::project_activate_item_response::ProjectActivateItemResponse;
+use crate::commands::project::add_speed::project_add_speed_response::ProjectAddSpeedResponse;
+use crate::commands::project::add_struct::project_add_struct_response::ProjectAddStructResponse;
 use crate::commands::project::close::project_close_response::ProjectCloseResponse;
 use crate::commands::project::create::project_create_response::ProjectCreateResponse;
 use crate::commands::project::export::project_export_response::ProjectExportResponse;
+use crate::commands::project::export_structs::project_export_structs_response::ProjectExportStructsResponse;
+use crate::commands::project::import_structs::project_import_structs_response::ProjectImportStructsResponse;
 use crate::commands::project::list::project_list_response::ProjectListResponse;
+use crate::commands::project::list_structs::project_list_structs_response::ProjectListStructsResponse;
 use crate::commands::project::open::project_open_response::ProjectOpenResponse;
 use crate::commands::project::rename::project_rename_response::ProjectRenameResponse;
 use crate::commands::project::save::project_save_response::ProjectSaveResponse;
+use crate::commands::project::set_item_property::project_set_item_property_response::ProjectSetItemPropertyResponse;
+use crate::commands::project::watch_process::project_watch_process_response::ProjectWatchProcessResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -16,4 +24,12 @@ pub enum ProjectResponse {
     Save { project_save_response: ProjectSaveResponse },
     Export { project_export_response: ProjectExportResponse },
     List { project_list_response: ProjectListResponse },
+    AddStruct { project_add_struct_response: ProjectAddStructResponse },
+    ImportStructs { project_import_structs_response: ProjectImportStructsResponse },
+    ExportStructs { project_export_structs_response: ProjectExportStructsResponse },
+    ListStructs { project_list_structs_response: ProjectListStructsResponse },
+    WatchProcess { project_watch_process_response: ProjectWatchProcessResponse },
+    AddSpeed { project_add_speed_response: ProjectAddSpeedResponse },
+    ActivateItem { project_activate_item_response: ProjectActivateItemResponse },
+    SetItemProperty { project_set_item_property_response: ProjectSetItemPropertyResponse },
 }
@@ -0,0 +1,2 @@
+pub mod project_set_item_property_request;
+pub mod project_set_item_property_response;
@@ -0,0 +1,39 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use crate::commands::project::set_item_property::project_set_item_property_response::ProjectSetItemPropertyResponse;
+use crate::structures::data_values::data_value::DataValue;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectSetItemPropertyRequest {
+    /// The path of the project item, relative to the root of the opened project.
+    #[structopt(parse(from_os_str))]
+    pub project_item_path: PathBuf,
+    #[structopt(short = "v", long)]
+    pub data_value: DataValue,
+    /// The name of the property to set, such as `description`, `address`, or `freeze_value` for address items.
+    #[structopt(short = "f", long)]
+    pub field_namespace: String,
+}
+
+impl EngineCommandRequest for ProjectSetItemPropertyRequest {
+    type ResponseType = ProjectSetItemPropertyResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::SetItemProperty {
+            project_set_item_property_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectSetItemPropertyResponse> for ProjectResponse {
+    fn from(project_set_item_property_response: ProjectSetItemPropertyResponse) -> Self {
+        ProjectResponse::SetItemProperty {
+            project_set_item_property_response,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProjectSetItemPropertyResponse {
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for ProjectSetItemPropertyResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Project(ProjectResponse::SetItemProperty {
+            project_set_item_property_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Project(ProjectResponse::SetItemProperty {
+            project_set_item_property_response,
+        }) = response
+        {
+            Ok(project_set_item_property_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod project_watch_process_request;
+pub mod project_watch_process_response;
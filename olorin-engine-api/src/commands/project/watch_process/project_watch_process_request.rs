@@ -0,0 +1,30 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::project::project_command::ProjectCommand;
+use crate::commands::project::project_response::ProjectResponse;
+use crate::commands::project::watch_process::project_watch_process_response::ProjectWatchProcessResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectWatchProcessRequest {
+    /// The process name or executable path to attach to automatically, ie `game*.exe`. Omit to stop watching.
+    #[structopt(short = "p", long)]
+    pub process_pattern: Option<String>,
+}
+
+impl EngineCommandRequest for ProjectWatchProcessRequest {
+    type ResponseType = ProjectWatchProcessResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Project(ProjectCommand::WatchProcess {
+            project_watch_process_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectWatchProcessResponse> for ProjectResponse {
+    fn from(project_watch_process_response: ProjectWatchProcessResponse) -> Self {
+        ProjectResponse::WatchProcess { project_watch_process_response }
+    }
+}
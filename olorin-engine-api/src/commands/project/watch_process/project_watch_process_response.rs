@@ -0,0 +1,25 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::project::project_response::ProjectResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProjectWatchProcessResponse {
+    pub success: bool,
+}
+
+impl TypedEngineCommandResponse for ProjectWatchProcessResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Project(ProjectResponse::WatchProcess {
+            project_watch_process_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Project(ProjectResponse::WatchProcess { project_watch_process_response }) = response {
+            Ok(project_watch_process_response)
+        } else {
+            Err(response)
+        }
+    }
+}
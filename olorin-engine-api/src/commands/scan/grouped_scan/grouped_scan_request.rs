@@ -0,0 +1,32 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::scan::grouped_scan::grouped_scan_response::GroupedScanResponse;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::data_values::data_value::DataValue;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct GroupedScanRequest {
+    #[structopt(short = "v", long)]
+    pub scan_values: Vec<DataValue>,
+    #[structopt(short = "s", long, default_value = "256")]
+    pub max_span: u64,
+}
+
+impl EngineCommandRequest for GroupedScanRequest {
+    type ResponseType = GroupedScanResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Scan(ScanCommand::GroupedScan {
+            grouped_scan_request: self.clone(),
+        })
+    }
+}
+
+impl From<GroupedScanResponse> for ScanResponse {
+    fn from(grouped_scan_response: GroupedScanResponse) -> Self {
+        ScanResponse::GroupedScan { grouped_scan_response }
+    }
+}
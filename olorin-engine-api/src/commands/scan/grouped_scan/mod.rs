@@ -0,0 +1,2 @@
+pub mod grouped_scan_request;
+pub mod grouped_scan_response;
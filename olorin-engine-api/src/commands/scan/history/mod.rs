@@ -0,0 +1,2 @@
+pub mod scan_history_request;
+pub mod scan_history_response;
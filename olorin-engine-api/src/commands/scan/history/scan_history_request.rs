@@ -0,0 +1,26 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::scan::history::scan_history_response::ScanHistoryResponse;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanHistoryRequest {}
+
+impl EngineCommandRequest for ScanHistoryRequest {
+    type ResponseType = ScanHistoryResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Scan(ScanCommand::History {
+            scan_history_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanHistoryResponse> for ScanResponse {
+    fn from(scan_history_response: ScanHistoryResponse) -> Self {
+        ScanResponse::History { scan_history_response }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::scanning::history::scan_history_entry::ScanHistoryEntry;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanHistoryResponse {
+    pub scan_history_entries: Vec<ScanHistoryEntry>,
+    pub total_memory_cost: u64,
+}
+
+impl TypedEngineCommandResponse for ScanHistoryResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Scan(ScanResponse::History {
+            scan_history_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Scan(ScanResponse::History { scan_history_response }) = response {
+            Ok(scan_history_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -1,7 +1,11 @@
 pub mod collect_values;
 pub mod element_scan;
+pub mod grouped_scan;
+pub mod history;
 pub mod new;
 pub mod reset;
 pub mod scan_command;
 pub mod scan_response;
+pub mod signature_scan;
 pub mod struct_scan;
+pub mod undo;
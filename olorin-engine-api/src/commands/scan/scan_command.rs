@@ -1,8 +1,12 @@
 use crate::commands::scan::collect_values::scan_collect_values_request::ScanCollectValuesRequest;
 use crate::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use crate::commands::scan::grouped_scan::grouped_scan_request::GroupedScanRequest;
+use crate::commands::scan::history::scan_history_request::ScanHistoryRequest;
 use crate::commands::scan::new::scan_new_request::ScanNewRequest;
 use crate::commands::scan::reset::scan_reset_request::ScanResetRequest;
+use crate::commands::scan::signature_scan::signature_scan_request::SignatureScanRequest;
 use crate::commands::scan::struct_scan::struct_scan_request::StructScanRequest;
+use crate::commands::scan::undo::scan_undo_request::ScanUndoRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -34,4 +38,24 @@ pub enum ScanCommand {
         #[structopt(flatten)]
         struct_scan_request: StructScanRequest,
     },
+    /// Performs a wildcard array of byte (signature) scan over executable regions, optionally restricted to a module.
+    SignatureScan {
+        #[structopt(flatten)]
+        signature_scan_request: SignatureScanRequest,
+    },
+    /// Scans for several typed values that all appear, in any order, within a maximum span of bytes of one another.
+    GroupedScan {
+        #[structopt(flatten)]
+        grouped_scan_request: GroupedScanRequest,
+    },
+    /// Rolls back one or more element scans, restoring the prior results without re-reading memory.
+    Undo {
+        #[structopt(flatten)]
+        scan_undo_request: ScanUndoRequest,
+    },
+    /// Lists the scans that can be rolled back, along with their result counts and memory cost.
+    History {
+        #[structopt(flatten)]
+        scan_history_request: ScanHistoryRequest,
+    },
 }
@@ -1,8 +1,12 @@
 use crate::commands::scan::collect_values::scan_collect_values_response::ScanCollectValuesResponse;
 use crate::commands::scan::element_scan::element_scan_response::ElementScanResponse;
+use crate::commands::scan::grouped_scan::grouped_scan_response::GroupedScanResponse;
+use crate::commands::scan::history::scan_history_response::ScanHistoryResponse;
 use crate::commands::scan::new::scan_new_response::ScanNewResponse;
 use crate::commands::scan::reset::scan_reset_response::ScanResetResponse;
+use crate::commands::scan::signature_scan::signature_scan_response::SignatureScanResponse;
 use crate::commands::scan::struct_scan::struct_scan_response::StructScanResponse;
+use crate::commands::scan::undo::scan_undo_response::ScanUndoResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -12,4 +16,8 @@ pub enum ScanResponse {
     CollectValues { scan_value_collector_response: ScanCollectValuesResponse },
     ElementScan { element_scan_response: ElementScanResponse },
     StructScan { struct_scan_response: StructScanResponse },
+    SignatureScan { signature_scan_response: SignatureScanResponse },
+    GroupedScan { grouped_scan_response: GroupedScanResponse },
+    Undo { scan_undo_response: ScanUndoResponse },
+    History { scan_history_response: ScanHistoryResponse },
 }
@@ -0,0 +1,2 @@
+pub mod signature_scan_request;
+pub mod signature_scan_response;
@@ -0,0 +1,44 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::scan::signature_scan::signature_scan_response::SignatureScanResponse;
+use crate::conversions::conversions::Conversions;
+use crate::structures::scanning::signatures::byte_signature::ByteSignature;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct SignatureScanRequest {
+    /// The IDA-style signature to scan for, ie "48 8B ?? ?? 89 05 ?? ?? ?? ??".
+    #[structopt(short = "s", long)]
+    pub signature: ByteSignature,
+
+    /// The module to restrict the scan to. If not provided, all executable regions are scanned.
+    #[structopt(short = "m", long)]
+    pub module_name: Option<String>,
+
+    /// The offset into each match of a RIP-relative disp32 operand to resolve into an absolute address.
+    #[structopt(short = "r", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub rip_offset: Option<u64>,
+
+    /// The length of the instruction containing the RIP-relative operand. Defaults to the end of the disp32 operand.
+    #[structopt(short = "l", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub instruction_length: Option<u64>,
+}
+
+impl EngineCommandRequest for SignatureScanRequest {
+    type ResponseType = SignatureScanResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Scan(ScanCommand::SignatureScan {
+            signature_scan_request: self.clone(),
+        })
+    }
+}
+
+impl From<SignatureScanResponse> for ScanResponse {
+    fn from(signature_scan_response: SignatureScanResponse) -> Self {
+        ScanResponse::SignatureScan { signature_scan_response }
+    }
+}
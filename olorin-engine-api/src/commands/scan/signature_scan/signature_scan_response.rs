@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::scanning::signatures::signature_scan_result::SignatureScanResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignatureScanResponse {
+    pub signature_scan_results: Vec<SignatureScanResult>,
+}
+
+impl TypedEngineCommandResponse for SignatureScanResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Scan(ScanResponse::SignatureScan {
+            signature_scan_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Scan(ScanResponse::SignatureScan { signature_scan_response }) = response {
+            Ok(signature_scan_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod scan_undo_request;
+pub mod scan_undo_response;
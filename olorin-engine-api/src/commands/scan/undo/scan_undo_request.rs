@@ -0,0 +1,29 @@
+use crate::commands::engine_command::EngineCommand;
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::scan::undo::scan_undo_response::ScanUndoResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanUndoRequest {
+    #[structopt(short = "c", long, default_value = "1")]
+    pub scan_count: u32,
+}
+
+impl EngineCommandRequest for ScanUndoRequest {
+    type ResponseType = ScanUndoResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Scan(ScanCommand::Undo {
+            scan_undo_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanUndoResponse> for ScanResponse {
+    fn from(scan_undo_response: ScanUndoResponse) -> Self {
+        ScanResponse::Undo { scan_undo_response }
+    }
+}
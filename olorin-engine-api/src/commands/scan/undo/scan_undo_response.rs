@@ -0,0 +1,26 @@
+use crate::commands::engine_command_response::EngineCommandResponse;
+use crate::commands::engine_command_response::TypedEngineCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanUndoResponse {
+    pub scans_undone: u32,
+    pub result_count: u64,
+}
+
+impl TypedEngineCommandResponse for ScanUndoResponse {
+    fn to_engine_response(&self) -> EngineCommandResponse {
+        EngineCommandResponse::Scan(ScanResponse::Undo {
+            scan_undo_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: EngineCommandResponse) -> Result<Self, EngineCommandResponse> {
+        if let EngineCommandResponse::Scan(ScanResponse::Undo { scan_undo_response }) = response {
+            Ok(scan_undo_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -10,6 +10,8 @@ use structopt::StructOpt;
 pub struct ScanResultsAddToProjectRequest {
     #[structopt(short = "s", long)]
     pub scan_results: Vec<ScanResultBase>,
+    #[structopt(short = "n", long)]
+    pub directory_name: Option<String>,
 }
 
 impl EngineCommandRequest for ScanResultsAddToProjectRequest {
@@ -7,3 +7,4 @@ pub mod refresh;
 pub mod scan_results_command;
 pub mod scan_results_response;
 pub mod set_property;
+pub mod watch;
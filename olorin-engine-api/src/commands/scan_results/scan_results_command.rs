@@ -5,6 +5,7 @@ use crate::commands::scan_results::list::scan_results_list_request::ScanResultsL
 use crate::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
 use crate::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
 use crate::commands::scan_results::set_property::scan_results_set_property_request::ScanResultsSetPropertyRequest;
+use crate::commands::scan_results::watch::scan_results_watch_request::ScanResultsWatchRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -25,6 +26,12 @@ pub enum ScanResultsCommand {
         #[structopt(flatten)]
         results_refresh_request: ScanResultsRefreshRequest,
     },
+    /// Subscribes to value changes for a specified set of scan results, replacing any prior set with the same watch id.
+    /// Changes are published as events, and an empty set of scan results ends the subscription.
+    Watch {
+        #[structopt(flatten)]
+        results_watch_request: ScanResultsWatchRequest,
+    },
     /// Adds a specified set of scan results to the opened project.
     AddToProject {
         #[structopt(flatten)]
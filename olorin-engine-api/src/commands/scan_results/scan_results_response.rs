@@ -5,6 +5,7 @@ use crate::commands::scan_results::list::scan_results_list_response::ScanResults
 use crate::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
 use crate::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
 use crate::commands::scan_results::set_property::scan_results_set_property_response::ScanResultsSetPropertyResponse;
+use crate::commands::scan_results::watch::scan_results_watch_response::ScanResultsWatchResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -18,6 +19,9 @@ pub enum ScanResultsResponse {
     Refresh {
         scan_results_refresh_response: ScanResultsRefreshResponse,
     },
+    Watch {
+        scan_results_watch_response: ScanResultsWatchResponse,
+    },
     AddToProject {
         scan_results_add_to_project_response: ScanResultsAddToProjectResponse,
     },
@@ -0,0 +1,2 @@
+pub mod scan_results_watch_request;
+pub mod scan_results_watch_response;
@@ -0,0 +1,33 @@
+use crate::commands::engine_command_request::EngineCommandRequest;
+use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::commands::scan_results::watch::scan_results_watch_response::ScanResultsWatchResponse;
+use crate::{commands::engine_command::EngineCommand, structures::scan_results::scan_result_valued::ScanResultValued};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanResultsWatchRequest {
+    #[structopt(short = "w", long)]
+    pub watch_id: String,
+    #[structopt(short = "r", long)]
+    pub scan_results: Vec<ScanResultValued>,
+    #[structopt(short = "i", long)]
+    pub refresh_interval_ms: Option<u64>,
+}
+
+impl EngineCommandRequest for ScanResultsWatchRequest {
+    type ResponseType = ScanResultsWatchResponse;
+
+    fn to_engine_command(&self) -> EngineCommand {
+        EngineCommand::Results(ScanResultsCommand::Watch {
+            results_watch_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanResultsWatchResponse> for ScanResultsResponse {
+    fn from(scan_results_watch_response: ScanResultsWatchResponse) -> Self {
+        ScanResultsResponse::Watch { scan_results_watch_response }
+    }
+}
@@ -5,6 +5,7 @@ use crate::commands::settings::scan::set::scan_settings_set_response::ScanSettin
 use crate::commands::settings::settings_command::SettingsCommand;
 use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use crate::structures::scanning::memory_read_mode::MemoryReadMode;
+use crate::structures::scanning::parameters::mapped::vectorization_size_override::VectorizationSizeOverride;
 use crate::{commands::engine_command::EngineCommand, structures::memory::memory_alignment::MemoryAlignment};
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
@@ -27,8 +28,15 @@ pub struct ScanSettingsSetRequest {
     pub floating_point_tolerance: Option<FloatingPointTolerance>,
     #[structopt(short = "st", long)]
     pub is_single_threaded_scan: bool,
+    /// Forces scans to use the given vector size in bytes, or `auto` to select it from the CPU features detected at runtime.
+    #[structopt(short = "v_size", long)]
+    pub vectorization_size_override: Option<VectorizationSizeOverride>,
+    #[structopt(short = "chunk_threshold", long)]
+    pub chunked_scan_region_size_threshold: Option<u64>,
     #[structopt(short = "dbg", long)]
     pub debug_perform_validation_scan: bool,
+    #[structopt(short = "hist_depth", long)]
+    pub scan_history_depth: Option<u32>,
 }
 
 impl EngineCommandRequest for ScanSettingsSetRequest {
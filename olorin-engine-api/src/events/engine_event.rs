@@ -1,3 +1,4 @@
+use crate::events::log::log_event::LogEvent;
 use crate::events::process::process_event::ProcessEvent;
 use crate::events::project::project_event::ProjectEvent;
 use crate::events::scan_results::scan_results_event::ScanResultsEvent;
@@ -10,6 +11,7 @@ pub enum EngineEvent {
     Project(ProjectEvent),
     TrackableTask(TrackableTaskEvent),
     ScanResults(ScanResultsEvent),
+    Log(LogEvent),
 }
 
 pub trait EngineEventRequest: Clone + Serialize + DeserializeOwned {
@@ -0,0 +1,7 @@
+use crate::events::log::message::log_message_event::LogMessageEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum LogEvent {
+    LogMessage { log_message_event: LogMessageEvent },
+}
@@ -0,0 +1,19 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    log::log_event::LogEvent,
+};
+use serde::{Deserialize, Serialize};
+
+/// A log message emitted by an engine running in another process, such as a privileged shell, already formatted for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogMessageEvent {
+    pub log_message: String,
+}
+
+impl EngineEventRequest for LogMessageEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Log(LogEvent::LogMessage {
+            log_message_event: self.clone(),
+        })
+    }
+}
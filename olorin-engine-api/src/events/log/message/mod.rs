@@ -0,0 +1 @@
+pub mod log_message_event;
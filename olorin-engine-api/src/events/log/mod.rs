@@ -0,0 +1,2 @@
+pub mod log_event;
+pub mod message;
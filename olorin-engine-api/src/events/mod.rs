@@ -1,4 +1,5 @@
 pub mod engine_event;
+pub mod log;
 pub mod process;
 pub mod project;
 pub mod scan_results;
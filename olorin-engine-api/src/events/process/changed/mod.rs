@@ -1 +1,2 @@
+pub mod process_change_kind;
 pub mod process_changed_event;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes why the opened process changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProcessChangeKind {
+    /// A process was attached to, with no relation to any previously opened process. Any project items have been re-resolved against it.
+    Attached,
+    /// A new instance of a previously opened process that died was attached to, such as after a game restart.
+    /// Addresses from the previous instance are stale, and any project items have been re-resolved against the new instance.
+    Reattached,
+    /// The opened process was closed or died.
+    Detached,
+}
@@ -1,7 +1,7 @@
 use crate::{
     events::{
         engine_event::{EngineEvent, EngineEventRequest},
-        process::process_event::ProcessEvent,
+        process::{changed::process_change_kind::ProcessChangeKind, process_event::ProcessEvent},
     },
     structures::processes::opened_process_info::OpenedProcessInfo,
 };
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProcessChangedEvent {
     pub process_info: Option<OpenedProcessInfo>,
+    pub change_kind: ProcessChangeKind,
 }
 
 impl EngineEventRequest for ProcessChangedEvent {
@@ -1,2 +1,3 @@
 pub mod scan_results_event;
 pub mod updated;
+pub mod values_changed;
@@ -1,7 +1,9 @@
 use crate::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use crate::events::scan_results::values_changed::scan_results_values_changed_event::ScanResultsValuesChangedEvent;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ScanResultsEvent {
     ScanResultsUpdated { scan_results_updated_event: ScanResultsUpdatedEvent },
+    ScanResultsValuesChanged { scan_results_values_changed_event: ScanResultsValuesChangedEvent },
 }
@@ -0,0 +1 @@
+pub mod scan_results_values_changed_event;
@@ -0,0 +1,20 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    scan_results::scan_results_event::ScanResultsEvent,
+};
+use crate::structures::scan_results::scan_result::ScanResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanResultsValuesChangedEvent {
+    pub watch_id: String,
+    pub scan_results: Vec<ScanResult>,
+}
+
+impl EngineEventRequest for ScanResultsValuesChangedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::ScanResults(ScanResultsEvent::ScanResultsValuesChanged {
+            scan_results_values_changed_event: self.clone(),
+        })
+    }
+}
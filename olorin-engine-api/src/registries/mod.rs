@@ -1,3 +1,4 @@
 pub mod data_types;
 pub mod project_item_types;
 pub mod scan_rules;
+pub mod symbolic_structs;
@@ -1,7 +1,8 @@
 use crate::structures::projects::project_items::{
     built_in_types::{
         project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory,
-        project_item_type_pointer::ProjectItemTypePointer,
+        project_item_type_pointer::ProjectItemTypePointer, project_item_type_speed::ProjectItemTypeSpeed,
+        project_item_type_struct::ProjectItemTypeStruct,
     },
     project_item_type::ProjectItemType,
 };
@@ -47,6 +48,8 @@ impl ProjectItemTypeRegistry {
             Arc::new(ProjectItemTypeDirectory {}),
             Arc::new(ProjectItemTypeAddress {}),
             Arc::new(ProjectItemTypePointer {}),
+            Arc::new(ProjectItemTypeStruct {}),
+            Arc::new(ProjectItemTypeSpeed {}),
         ];
 
         for built_in_project_item_type in built_in_project_item_types.into_iter() {
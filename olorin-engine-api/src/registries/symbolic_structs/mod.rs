@@ -0,0 +1 @@
+pub mod symbolic_struct_registry;
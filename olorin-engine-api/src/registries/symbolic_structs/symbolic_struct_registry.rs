@@ -0,0 +1,107 @@
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use crate::structures::structs::symbolic_struct_ref::SymbolicStructRef;
+use crate::structures::structs::valued_struct::ValuedStruct;
+use std::{
+    collections::HashMap,
+    sync::{Once, RwLock},
+};
+
+/// Holds the named struct definitions of the opened project, such that structs can be referenced by name
+/// when reading memory, viewing structs, and scanning.
+pub struct SymbolicStructRegistry {
+    registry: RwLock<HashMap<String, SymbolicStructDefinition>>,
+}
+
+impl SymbolicStructRegistry {
+    pub fn get_instance() -> &'static SymbolicStructRegistry {
+        static mut INSTANCE: Option<SymbolicStructRegistry> = None;
+        static INIT: Once = Once::new();
+
+        unsafe {
+            INIT.call_once(|| {
+                let instance = SymbolicStructRegistry::new();
+                INSTANCE = Some(instance);
+            });
+
+            #[allow(static_mut_refs)]
+            INSTANCE.as_ref().unwrap_unchecked()
+        }
+    }
+
+    fn new() -> Self {
+        Self {
+            registry: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_registry(&self) -> &RwLock<HashMap<String, SymbolicStructDefinition>> {
+        &self.registry
+    }
+
+    pub fn get(
+        &self,
+        symbolic_struct_name: &str,
+    ) -> Option<SymbolicStructDefinition> {
+        match self.registry.read() {
+            Ok(registry) => registry.get(symbolic_struct_name).cloned(),
+            Err(error) => {
+                log::error!("Error reading from symbolic struct registry: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Gets the names of all registered structs, in sorted order.
+    pub fn get_names(&self) -> Vec<String> {
+        match self.registry.read() {
+            Ok(registry) => {
+                let mut names: Vec<String> = registry.keys().cloned().collect();
+
+                names.sort();
+                names
+            }
+            Err(error) => {
+                log::error!("Error reading from symbolic struct registry: {}", error);
+                vec![]
+            }
+        }
+    }
+
+    pub fn register(
+        &self,
+        symbolic_struct_name: &str,
+        symbolic_struct_definition: SymbolicStructDefinition,
+    ) {
+        match self.registry.write() {
+            Ok(mut registry) => {
+                registry.insert(symbolic_struct_name.to_string(), symbolic_struct_definition);
+            }
+            Err(error) => log::error!("Error writing to symbolic struct registry: {}", error),
+        }
+    }
+
+    /// Replaces all registered structs, for example when a project is opened or closed.
+    pub fn set_definitions(
+        &self,
+        symbolic_struct_definitions: Vec<(String, SymbolicStructDefinition)>,
+    ) {
+        match self.registry.write() {
+            Ok(mut registry) => {
+                *registry = symbolic_struct_definitions.into_iter().collect();
+            }
+            Err(error) => log::error!("Error writing to symbolic struct registry: {}", error),
+        }
+    }
+
+    /// Creates a zero-initialized valued struct for the named struct laid out for the given target bitness, or `None` if no such struct is registered.
+    pub fn create_valued_struct(
+        &self,
+        symbolic_struct_name: &str,
+        bitness: Bitness,
+    ) -> Option<ValuedStruct> {
+        self.get(symbolic_struct_name).map(|symbolic_struct_definition| {
+            symbolic_struct_definition.create_valued_struct(SymbolicStructRef::new(symbolic_struct_name.to_string()), bitness)
+        })
+    }
+}
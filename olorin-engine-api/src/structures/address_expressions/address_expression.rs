@@ -0,0 +1,89 @@
+use crate::conversions::conversions::Conversions;
+use crate::structures::address_expressions::address_expression_node::AddressExpressionNode;
+use crate::structures::address_expressions::address_expression_parser::AddressExpressionParser;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// An address, written either as a plain value or as an expression that is resolved against the opened process.
+/// Numbers are hexadecimal unless prefixed by `#`, module names may be quoted, and brackets dereference a pointer.
+/// Symbols are written as `module!symbol`. For example: `7FF6A000`, `"game.exe"+1A2B`, `[[game.exe+10]+8]-#16`, `libc.so.6!malloc+10`.
+/// On the command line, a bare number such as `1000` is instead decimal, see `parse_command_line_address`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressExpression {
+    expression: String,
+    root_node: AddressExpressionNode,
+}
+
+impl AddressExpression {
+    pub fn new(
+        expression: String,
+        root_node: AddressExpressionNode,
+    ) -> Self {
+        Self { expression, root_node }
+    }
+
+    pub fn get_expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub fn get_root_node(&self) -> &AddressExpressionNode {
+        &self.root_node
+    }
+
+    /// Parses an address given on the command line. A bare number keeps its historical meaning of decimal unless prefixed by `0x`,
+    /// while any other expression follows the usual rules, where numbers are hexadecimal.
+    pub fn parse_command_line_address(string: &str) -> Result<Self, String> {
+        let trimmed = string.trim();
+
+        if !trimmed.is_empty() && trimmed.chars().all(|character| character.is_ascii_digit()) {
+            return Conversions::parse_hex_or_int(trimmed)
+                .map(Self::from)
+                .map_err(|error| format!("Invalid address {}: {}", trimmed, error));
+        }
+
+        Self::from_str(string)
+    }
+
+    /// Gets the address if it can be determined without access to a process, ie for plain numeric addresses.
+    pub fn get_constant_value(&self) -> Option<u64> {
+        self.root_node.get_constant_value()
+    }
+}
+
+impl From<u64> for AddressExpression {
+    fn from(address: u64) -> Self {
+        Self {
+            expression: format!("{:X}", address),
+            root_node: AddressExpressionNode::Constant(address),
+        }
+    }
+}
+
+impl Default for AddressExpression {
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+impl FromStr for AddressExpression {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let root_node = AddressExpressionParser::parse(string)?;
+
+        Ok(Self {
+            expression: string.trim().to_string(),
+            root_node,
+        })
+    }
+}
+
+impl fmt::Display for AddressExpression {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}", self.expression)
+    }
+}
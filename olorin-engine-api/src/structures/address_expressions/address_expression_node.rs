@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A node in a parsed address expression, such as `"game.exe"+1A2B` or `[[base]+10]+8`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressExpressionNode {
+    /// A literal value.
+    Constant(u64),
+    /// The name of a module, which resolves to the base address of that module.
+    Module(String),
+    /// A symbol within a module, which resolves to the address of that symbol.
+    Symbol { module_name: String, symbol_name: String },
+    /// Reads a pointer-sized value from the address produced by the inner expression.
+    Dereference(Box<AddressExpressionNode>),
+    Add(Box<AddressExpressionNode>, Box<AddressExpressionNode>),
+    Subtract(Box<AddressExpressionNode>, Box<AddressExpressionNode>),
+    Multiply(Box<AddressExpressionNode>, Box<AddressExpressionNode>),
+}
+
+impl AddressExpressionNode {
+    /// Gets the value of this node if it can be determined without access to a process (ie contains no modules or dereferences).
+    pub fn get_constant_value(&self) -> Option<u64> {
+        match self {
+            AddressExpressionNode::Constant(value) => Some(*value),
+            AddressExpressionNode::Module(_) | AddressExpressionNode::Symbol { .. } | AddressExpressionNode::Dereference(_) => None,
+            AddressExpressionNode::Add(left, right) => left.get_constant_value()?.checked_add(right.get_constant_value()?),
+            AddressExpressionNode::Subtract(left, right) => left.get_constant_value()?.checked_sub(right.get_constant_value()?),
+            AddressExpressionNode::Multiply(left, right) => left.get_constant_value()?.checked_mul(right.get_constant_value()?),
+        }
+    }
+
+    /// Determines whether a character may appear in an unquoted symbol name, which allows for C++ and MSVC name decorations.
+    pub fn is_symbol_character(character: char) -> bool {
+        character.is_alphanumeric() || matches!(character, '_' | '.' | ':' | '@' | '?' | '$')
+    }
+
+    fn fmt_operand(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            AddressExpressionNode::Add(..) | AddressExpressionNode::Subtract(..) => write!(formatter, "({})", self),
+            _ => write!(formatter, "{}", self),
+        }
+    }
+}
+
+impl fmt::Display for AddressExpressionNode {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            AddressExpressionNode::Constant(value) => write!(formatter, "{:X}", value),
+            AddressExpressionNode::Module(module_name) => write!(formatter, "\"{}\"", module_name),
+            AddressExpressionNode::Symbol { module_name, symbol_name } => {
+                if symbol_name.chars().all(Self::is_symbol_character) {
+                    write!(formatter, "\"{}\"!{}", module_name, symbol_name)
+                } else {
+                    write!(formatter, "\"{}\"!\"{}\"", module_name, symbol_name)
+                }
+            }
+            AddressExpressionNode::Dereference(inner) => write!(formatter, "[{}]", inner),
+            AddressExpressionNode::Add(left, right) => write!(formatter, "{}+{}", left, right),
+            AddressExpressionNode::Subtract(left, right) => {
+                write!(formatter, "{}-", left)?;
+                right.fmt_operand(formatter)
+            }
+            AddressExpressionNode::Multiply(left, right) => {
+                left.fmt_operand(formatter)?;
+                write!(formatter, "*")?;
+                right.fmt_operand(formatter)
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::structures::address_expressions::address_expression_node::AddressExpressionNode;
+
+/// A recursive descent parser for address expressions, with the following grammar:
+/// ```text
+/// expression := term (('+' | '-') term)*
+/// term       := primary ('*' primary)*
+/// primary    := number | module | symbol | '[' expression ']' | '(' expression ')'
+/// number     := hex digits, optionally prefixed by `0x`, or decimal digits prefixed by `#`
+/// module     := a quoted string, or an unquoted word that is not a valid hex number (ie `game.exe`)
+/// symbol     := module '!' (a quoted string, or an unquoted word that may also contain `:`, `@`, `?` and `$`)
+/// ```
+pub struct AddressExpressionParser<'a> {
+    expression: &'a str,
+    characters: Vec<(usize, char)>,
+    position: usize,
+}
+
+impl<'a> AddressExpressionParser<'a> {
+    pub fn parse(expression: &'a str) -> Result<AddressExpressionNode, String> {
+        let mut parser = AddressExpressionParser {
+            expression,
+            characters: expression.char_indices().collect(),
+            position: 0,
+        };
+
+        parser.skip_whitespace();
+
+        if parser.peek().is_none() {
+            return Err("Address expression is empty.".to_string());
+        }
+
+        let root_node = parser.parse_expression()?;
+
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            None => Ok(root_node),
+            Some(character) => Err(parser.error_at(parser.position, &format!("Unexpected '{}'", character))),
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<AddressExpressionNode, String> {
+        let mut node = self.parse_term()?;
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some('+') => {
+                    self.position += 1;
+                    node = AddressExpressionNode::Add(Box::new(node), Box::new(self.parse_term()?));
+                }
+                Some('-') => {
+                    self.position += 1;
+                    node = AddressExpressionNode::Subtract(Box::new(node), Box::new(self.parse_term()?));
+                }
+                _ => return Ok(node),
+            }
+        }
+    }
+
+    fn parse_term(&mut self) -> Result<AddressExpressionNode, String> {
+        let mut node = self.parse_primary()?;
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some('*') => {
+                    self.position += 1;
+                    node = AddressExpressionNode::Multiply(Box::new(node), Box::new(self.parse_primary()?));
+                }
+                _ => return Ok(node),
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<AddressExpressionNode, String> {
+        self.skip_whitespace();
+
+        let start_position = self.position;
+
+        match self.peek() {
+            Some('[') => {
+                self.position += 1;
+                let inner_node = self.parse_expression()?;
+                self.expect_closing(']', start_position)?;
+
+                Ok(AddressExpressionNode::Dereference(Box::new(inner_node)))
+            }
+            Some('(') => {
+                self.position += 1;
+                let inner_node = self.parse_expression()?;
+                self.expect_closing(')', start_position)?;
+
+                Ok(inner_node)
+            }
+            Some('"') => {
+                let module_name = self.read_quoted_name(start_position, "module")?;
+
+                self.parse_module_or_symbol(module_name)
+            }
+            Some('#') => {
+                self.position += 1;
+                let word = self.read_word();
+
+                match word.parse::<u64>() {
+                    Ok(value) => Ok(AddressExpressionNode::Constant(value)),
+                    Err(_) => Err(self.error_at(start_position, "Invalid decimal number")),
+                }
+            }
+            Some(character) if Self::is_word_character(character) => {
+                let word = self.read_word();
+                let hex_digits = word
+                    .strip_prefix("0x")
+                    .or_else(|| word.strip_prefix("0X"))
+                    .unwrap_or(word);
+
+                // A word followed by '!' is always a module, even if it happens to look like a hex number.
+                if self.peek() == Some('!') {
+                    self.parse_module_or_symbol(word.to_string())
+                } else if !hex_digits.is_empty() && hex_digits.chars().all(|character| character.is_ascii_hexdigit()) {
+                    match u64::from_str_radix(hex_digits, 16) {
+                        Ok(value) => Ok(AddressExpressionNode::Constant(value)),
+                        Err(_) => Err(self.error_at(start_position, "Number is too large")),
+                    }
+                } else {
+                    Ok(AddressExpressionNode::Module(word.to_string()))
+                }
+            }
+            Some(character) => Err(self.error_at(start_position, &format!("Unexpected '{}'", character))),
+            None => Err(self.error_at(start_position, "Unexpected end of expression")),
+        }
+    }
+
+    /// Parses an optional `!symbol` suffix following a module name.
+    fn parse_module_or_symbol(
+        &mut self,
+        module_name: String,
+    ) -> Result<AddressExpressionNode, String> {
+        if self.peek() != Some('!') {
+            return Ok(AddressExpressionNode::Module(module_name));
+        }
+
+        let symbol_position = self.position;
+        self.position += 1;
+
+        let symbol_name = if self.peek() == Some('"') {
+            self.read_quoted_name(symbol_position, "symbol")?
+        } else {
+            let symbol_start = self.position;
+
+            while let Some(character) = self.peek() {
+                if !AddressExpressionNode::is_symbol_character(character) {
+                    break;
+                }
+
+                self.position += 1;
+            }
+
+            self.slice(symbol_start, self.position).to_string()
+        };
+
+        if symbol_name.is_empty() {
+            return Err(self.error_at(symbol_position, "Missing symbol name"));
+        }
+
+        Ok(AddressExpressionNode::Symbol { module_name, symbol_name })
+    }
+
+    /// Reads a non-empty quoted string, starting at the opening quote.
+    fn read_quoted_name(
+        &mut self,
+        start_position: usize,
+        name_kind: &str,
+    ) -> Result<String, String> {
+        self.position += 1;
+        let name_start = self.position;
+
+        while let Some(character) = self.peek() {
+            if character == '"' {
+                break;
+            }
+
+            self.position += 1;
+        }
+
+        if self.peek().is_none() {
+            return Err(self.error_at(start_position, &format!("Unterminated {} name", name_kind)));
+        }
+
+        let name = self.slice(name_start, self.position).to_string();
+        self.position += 1;
+
+        if name.is_empty() {
+            return Err(self.error_at(start_position, &format!("Empty {} name", name_kind)));
+        }
+
+        Ok(name)
+    }
+
+    fn expect_closing(
+        &mut self,
+        closing_character: char,
+        opening_position: usize,
+    ) -> Result<(), String> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(closing_character) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error_at(opening_position, &format!("Missing '{}' to close", closing_character)))
+        }
+    }
+
+    fn read_word(&mut self) -> &'a str {
+        let start_position = self.position;
+
+        while let Some(character) = self.peek() {
+            if !Self::is_word_character(character) {
+                break;
+            }
+
+            self.position += 1;
+        }
+
+        self.slice(start_position, self.position)
+    }
+
+    fn is_word_character(character: char) -> bool {
+        character.is_alphanumeric() || character == '_' || character == '.'
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(character) = self.peek() {
+            if !character.is_whitespace() {
+                break;
+            }
+
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.characters
+            .get(self.position)
+            .map(|(_, character)| *character)
+    }
+
+    /// Gets the text between two character positions.
+    fn slice(
+        &self,
+        start_position: usize,
+        end_position: usize,
+    ) -> &'a str {
+        let byte_index = |position: usize| {
+            self.characters
+                .get(position)
+                .map(|(byte_index, _)| *byte_index)
+                .unwrap_or(self.expression.len())
+        };
+
+        &self.expression[byte_index(start_position)..byte_index(end_position)]
+    }
+
+    /// Creates an error that includes the failing sub-expression, starting from the given position.
+    fn error_at(
+        &self,
+        position: usize,
+        message: &str,
+    ) -> String {
+        let remaining = self.slice(position, self.characters.len());
+
+        if remaining.is_empty() {
+            format!("{} at end of address expression '{}'.", message, self.expression)
+        } else {
+            format!("{} at '{}' in address expression '{}'.", message, remaining, self.expression)
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod address_expression;
+pub mod address_expression_node;
+pub mod address_expression_parser;
@@ -0,0 +1,64 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::dissection::dissected_field_kind::DissectedFieldKind;
+use crate::structures::structs::container_type::ContainerType;
+use crate::structures::structs::symbolic_struct_field_definition::SymbolicStructFieldDefinition;
+use serde::{Deserialize, Serialize};
+
+/// A field inferred from a live block of memory, along with a human readable preview of the value it held.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DissectedField {
+    offset: u64,
+    size_in_bytes: u64,
+    kind: DissectedFieldKind,
+    data_type: DataTypeRef,
+    container_type: ContainerType,
+    preview: String,
+}
+
+impl DissectedField {
+    pub fn new(
+        offset: u64,
+        size_in_bytes: u64,
+        kind: DissectedFieldKind,
+        data_type: DataTypeRef,
+        container_type: ContainerType,
+        preview: String,
+    ) -> Self {
+        Self {
+            offset,
+            size_in_bytes,
+            kind,
+            data_type,
+            container_type,
+            preview,
+        }
+    }
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn get_size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    pub fn get_kind(&self) -> DissectedFieldKind {
+        self.kind
+    }
+
+    pub fn get_data_type(&self) -> &DataTypeRef {
+        &self.data_type
+    }
+
+    pub fn get_container_type(&self) -> ContainerType {
+        self.container_type
+    }
+
+    pub fn get_preview(&self) -> &str {
+        &self.preview
+    }
+
+    pub fn to_symbolic_struct_field_definition(&self) -> SymbolicStructFieldDefinition {
+        SymbolicStructFieldDefinition::new(self.data_type.clone(), self.container_type)
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of value a dissected field is believed to hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DissectedFieldKind {
+    /// A pointer into a known region of memory.
+    Pointer,
+    /// A pointer into a module, which itself points to executable code (ie a C++ virtual function table).
+    VTablePointer,
+    /// A 32-bit float within a plausible range.
+    Float,
+    /// A 64-bit float within a plausible range.
+    Double,
+    /// A small signed integer, including zero.
+    Integer,
+    /// A null terminated run of printable ASCII characters.
+    StringUtf8,
+    /// A null terminated run of printable ASCII characters, encoded as UTF-16.
+    StringUtf16,
+    /// Bytes that did not match any other heuristic.
+    Unknown,
+}
+
+impl fmt::Display for DissectedFieldKind {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let kind_str = match self {
+            DissectedFieldKind::Pointer => "pointer",
+            DissectedFieldKind::VTablePointer => "vtable",
+            DissectedFieldKind::Float => "float",
+            DissectedFieldKind::Double => "double",
+            DissectedFieldKind::Integer => "integer",
+            DissectedFieldKind::StringUtf8 => "string",
+            DissectedFieldKind::StringUtf16 => "string16",
+            DissectedFieldKind::Unknown => "unknown",
+        };
+
+        write!(formatter, "{}", kind_str)
+    }
+}
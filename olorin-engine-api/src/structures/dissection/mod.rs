@@ -0,0 +1,2 @@
+pub mod dissected_field;
+pub mod dissected_field_kind;
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct MemoryProtectionEnum: u32 {
+        const NONE = 0x0;
+        const READ = 0x1;
+        const WRITE = 0x2;
+        const EXECUTE = 0x4;
+        const COPY_ON_WRITE = 0x8;
+    }
+}
+
+impl Default for MemoryProtectionEnum {
+    fn default() -> Self {
+        MemoryProtectionEnum::NONE
+    }
+}
+
+impl fmt::Display for MemoryProtectionEnum {
+    /// Formats the protection in the style of `/proc/<pid>/maps`, ie `rw-`, with `c` in place of `w` for copy on write pages.
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let read_char = if self.contains(MemoryProtectionEnum::READ) { 'r' } else { '-' };
+        let write_char = if self.contains(MemoryProtectionEnum::WRITE) {
+            'w'
+        } else if self.contains(MemoryProtectionEnum::COPY_ON_WRITE) {
+            'c'
+        } else {
+            '-'
+        };
+        let execute_char = if self.contains(MemoryProtectionEnum::EXECUTE) { 'x' } else { '-' };
+
+        write!(formatter, "{}{}{}", read_char, write_char, execute_char)
+    }
+}
+
+impl FromStr for MemoryProtectionEnum {
+    type Err = String;
+
+    /// Parses a protection in the same style it is formatted in, ie `rwx` or `r-x`. Dashes are optional, such that `rx` is also accepted.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut protection = MemoryProtectionEnum::NONE;
+
+        for character in string.trim().chars() {
+            match character.to_ascii_lowercase() {
+                'r' => protection |= MemoryProtectionEnum::READ,
+                'w' => protection |= MemoryProtectionEnum::WRITE,
+                'x' => protection |= MemoryProtectionEnum::EXECUTE,
+                'c' => protection |= MemoryProtectionEnum::COPY_ON_WRITE,
+                '-' => {}
+                _ => return Err(format!("Invalid protection '{}', expected a combination of r, w, x, and c.", string)),
+            }
+        }
+
+        Ok(protection)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod bitness;
 pub mod endian;
 pub mod memory_alignment;
+pub mod memory_protection_enum;
 pub mod normalized_module;
 pub mod normalized_region;
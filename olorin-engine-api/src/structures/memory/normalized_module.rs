@@ -1,9 +1,12 @@
 use crate::structures::memory::normalized_region::NormalizedRegion;
 use std::hash::{Hash, Hasher};
 
+#[derive(Clone)]
 pub struct NormalizedModule {
     base_region: NormalizedRegion,
     module_name: String,
+    module_path: String,
+    is_anchored_at_end: bool,
 }
 
 impl NormalizedModule {
@@ -15,6 +18,20 @@ impl NormalizedModule {
         Self {
             base_region: NormalizedRegion::new(base_address, size),
             module_name: module_name.to_string(),
+            module_path: String::new(),
+            is_anchored_at_end: false,
+        }
+    }
+
+    /// Creates a module whose name resolves to its end address, such as a thread stack, which grows down from a stable top.
+    pub fn new_anchored_at_end(
+        module_name: &str,
+        base_address: u64,
+        size: u64,
+    ) -> Self {
+        Self {
+            is_anchored_at_end: true,
+            ..Self::new(module_name, base_address, size)
         }
     }
 
@@ -25,6 +42,8 @@ impl NormalizedModule {
         Self {
             base_region: normalized_region,
             module_name: module_name.to_string(),
+            module_path: String::new(),
+            is_anchored_at_end: false,
         }
     }
 
@@ -36,6 +55,18 @@ impl NormalizedModule {
         &self.module_name
     }
 
+    /// Gets the path to the on-disk image of this module, if known. This is used to parse the symbols of the module.
+    pub fn get_module_path(&self) -> &str {
+        &self.module_path
+    }
+
+    pub fn set_module_path(
+        &mut self,
+        module_path: &str,
+    ) {
+        self.module_path = module_path.to_string();
+    }
+
     pub fn get_base_address(&self) -> u64 {
         self.base_region.get_base_address()
     }
@@ -47,6 +78,20 @@ impl NormalizedModule {
         self.base_region.set_base_address(base_address);
     }
 
+    /// Gets whether the name of this module resolves to its end address, with addresses within it written as negative offsets.
+    pub fn is_anchored_at_end(&self) -> bool {
+        self.is_anchored_at_end
+    }
+
+    /// Gets the address that the name of this module resolves to in address expressions.
+    pub fn get_anchor_address(&self) -> u64 {
+        if self.is_anchored_at_end {
+            self.base_region.get_end_address()
+        } else {
+            self.base_region.get_base_address()
+        }
+    }
+
     pub fn get_region_size(&self) -> u64 {
         self.base_region.get_region_size()
     }
@@ -1,5 +1,7 @@
+pub mod address_expressions;
 pub mod data_types;
 pub mod data_values;
+pub mod dissection;
 pub mod memory;
 pub mod processes;
 pub mod projects;
@@ -9,4 +11,5 @@ pub mod scanning;
 pub mod settings;
 pub mod snapshots;
 pub mod structs;
+pub mod symbols;
 pub mod tasks;
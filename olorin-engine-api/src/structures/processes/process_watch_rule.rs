@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A rule describing a process to attach to automatically whenever it appears, such as a game that is restarted frequently.
+/// The pattern is matched case insensitively against the process name, or against the executable path if the pattern contains
+/// a path separator. Patterns may contain `*` to match any run of characters, and `?` to match any single character.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessWatchRule {
+    process_pattern: String,
+}
+
+impl ProcessWatchRule {
+    pub fn new(process_pattern: String) -> Self {
+        Self { process_pattern }
+    }
+
+    pub fn get_process_pattern(&self) -> &str {
+        &self.process_pattern
+    }
+
+    /// Determines whether this rule matches against executable paths rather than process names.
+    pub fn is_path_pattern(&self) -> bool {
+        self.process_pattern.contains(['/', '\\'])
+    }
+
+    /// Determines whether a process matches this rule. The executable path is only required for path patterns.
+    pub fn matches(
+        &self,
+        process_name: &str,
+        executable_path: Option<&Path>,
+    ) -> bool {
+        if self.is_path_pattern() {
+            match executable_path {
+                // Normalize separators, such that patterns may be written with either separator on any platform.
+                Some(executable_path) => Self::matches_wildcard(
+                    &self.process_pattern.replace('\\', "/"),
+                    &executable_path.to_string_lossy().replace('\\', "/"),
+                ),
+                None => false,
+            }
+        } else {
+            Self::matches_wildcard(&self.process_pattern, process_name)
+        }
+    }
+
+    fn matches_wildcard(
+        pattern: &str,
+        value: &str,
+    ) -> bool {
+        let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+        let value: Vec<char> = value.to_lowercase().chars().collect();
+        let (mut pattern_index, mut value_index) = (0, 0);
+        let mut backtrack_indices: Option<(usize, usize)> = None;
+
+        // Greedy matching, backtracking to the most recent `*` on a mismatch to let it consume one more character.
+        while value_index < value.len() {
+            match pattern.get(pattern_index) {
+                Some('*') => {
+                    backtrack_indices = Some((pattern_index, value_index));
+                    pattern_index += 1;
+                }
+                Some(pattern_char) if *pattern_char == '?' || *pattern_char == value[value_index] => {
+                    pattern_index += 1;
+                    value_index += 1;
+                }
+                _ => match backtrack_indices {
+                    Some((star_index, star_value_index)) => {
+                        backtrack_indices = Some((star_index, star_value_index + 1));
+                        pattern_index = star_index + 1;
+                        value_index = star_value_index + 1;
+                    }
+                    None => return false,
+                },
+            }
+        }
+
+        pattern[pattern_index..]
+            .iter()
+            .all(|pattern_char| *pattern_char == '*')
+    }
+}
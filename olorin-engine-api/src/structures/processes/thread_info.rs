@@ -0,0 +1,64 @@
+use crate::structures::processes::thread_state::ThreadState;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadInfo {
+    thread_id: u32,
+    name: String,
+    state: ThreadState,
+    stack_module_name: String,
+    stack_base_address: u64,
+    stack_size: u64,
+}
+
+impl ThreadInfo {
+    pub fn new(
+        thread_id: u32,
+        name: String,
+        state: ThreadState,
+        stack_module_name: String,
+        stack_base_address: u64,
+        stack_size: u64,
+    ) -> Self {
+        Self {
+            thread_id,
+            name,
+            state,
+            stack_module_name,
+            stack_base_address,
+            stack_size,
+        }
+    }
+
+    pub fn get_thread_id(&self) -> u32 {
+        self.thread_id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_state(&self) -> ThreadState {
+        self.state
+    }
+
+    /// Gets the name of the virtual module spanning the stack of this thread, such as `threadstack0`. Stack addresses can be
+    /// expressed relative to this name, which stays stable across restarts of the process where raw stack addresses do not.
+    pub fn get_stack_module_name(&self) -> &str {
+        &self.stack_module_name
+    }
+
+    /// Gets the lowest address of the stack region of this thread, or zero if the stack could not be located.
+    pub fn get_stack_base_address(&self) -> u64 {
+        self.stack_base_address
+    }
+
+    /// Gets the size of the stack region of this thread, or zero if the stack could not be located.
+    pub fn get_stack_size(&self) -> u64 {
+        self.stack_size
+    }
+
+    pub fn has_stack(&self) -> bool {
+        self.stack_size > 0
+    }
+}
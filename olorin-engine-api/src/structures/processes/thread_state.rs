@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The scheduling state of a thread at the time it was queried.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThreadState {
+    /// Running, or ready to run.
+    Running,
+    /// Waiting on an event, such as a sleep or a lock.
+    Sleeping,
+    /// Waiting on an operation that cannot be interrupted, usually disk IO.
+    UninterruptibleSleep,
+    /// Stopped by a signal, or by a debugger.
+    Stopped,
+    /// Exited, but not yet reaped.
+    Zombie,
+    /// Idle kernel thread, or any state not covered above.
+    Unknown,
+}
+
+impl fmt::Display for ThreadState {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let state_str = match self {
+            ThreadState::Running => "running",
+            ThreadState::Sleeping => "sleeping",
+            ThreadState::UninterruptibleSleep => "uninterruptible",
+            ThreadState::Stopped => "stopped",
+            ThreadState::Zombie => "zombie",
+            ThreadState::Unknown => "unknown",
+        };
+
+        write!(formatter, "{}", state_str)
+    }
+}
@@ -14,7 +14,7 @@ pub struct ProjectInfo {
     /// The process icon associated with this project.
     project_icon_rgba: Option<ProcessIcon>,
 
-    /// The manifest for this project, containing the sort order of project items.
+    /// The manifest for this project, containing the sort order of project items and the process to watch for.
     project_manifest: ProjectManifest,
 
     #[serde(skip)]
@@ -65,6 +65,10 @@ impl ProjectInfo {
         &self.project_manifest
     }
 
+    pub fn get_project_manifest_mut(&mut self) -> &mut ProjectManifest {
+        &mut self.project_manifest
+    }
+
     pub fn get_has_unsaved_changes(&self) -> bool {
         self.has_unsaved_changes
     }
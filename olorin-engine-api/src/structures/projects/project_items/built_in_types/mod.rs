@@ -1,3 +1,5 @@
 pub mod project_item_type_address;
 pub mod project_item_type_directory;
 pub mod project_item_type_pointer;
+pub mod project_item_type_speed;
+pub mod project_item_type_struct;
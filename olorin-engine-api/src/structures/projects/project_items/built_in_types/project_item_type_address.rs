@@ -1,4 +1,5 @@
 use crate::structures::{
+    address_expressions::address_expression::AddressExpression,
     data_types::built_in_types::{string::utf8::data_type_string_utf8::DataTypeStringUtf8, u64::data_type_u64::DataTypeU64},
     data_values::data_value::DataValue,
     projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef},
@@ -18,6 +19,8 @@ impl ProjectItemType for ProjectItemTypeAddress {
 
 impl ProjectItemTypeAddress {
     pub const PROJECT_ITEM_TYPE_ID: &str = "address";
+    pub const PROPERTY_ADDRESS: &str = "address";
+    pub const PROPERTY_ADDRESS_EXPRESSION: &str = "address_expression";
     pub const PROPERTY_DESCRIPTION: &str = "description";
     pub const PROPERTY_FREEZE_VALUE: &str = "freeze_value";
 
@@ -50,6 +53,18 @@ impl ProjectItemTypeAddress {
         }
     }
 
+    pub fn get_field_address(project_item: &ProjectItem) -> u64 {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_ADDRESS)
+            .and_then(|field| field.get_data_value())
+            .and_then(|data_value| data_value.get_value_bytes().as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or_default()
+    }
+
     pub fn set_field_address(
         project_item: &mut ProjectItem,
         address: u64,
@@ -59,7 +74,30 @@ impl ProjectItemTypeAddress {
 
         project_item
             .get_properties_mut()
-            .set_field_node(Self::PROPERTY_DESCRIPTION, field_node, false);
+            .set_field_node(Self::PROPERTY_ADDRESS, field_node, false);
+    }
+
+    /// Gets the expression used to locate this address, such as `"game.exe"+1A2B`, if one was provided.
+    /// Unlike the raw address, this remains valid across process restarts when the address is module-relative.
+    pub fn get_field_address_expression(project_item: &ProjectItem) -> Option<AddressExpression> {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_ADDRESS_EXPRESSION)
+            .and_then(|field| field.get_display_string(true, 0).parse().ok())
+    }
+
+    pub fn set_field_address_expression(
+        project_item: &mut ProjectItem,
+        address_expression: &AddressExpression,
+    ) {
+        let address_expression_data_value = DataTypeStringUtf8::get_value_from_primitive_string(address_expression.get_expression());
+        let field_node = ValuedStructFieldNode::Value(address_expression_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_node(Self::PROPERTY_ADDRESS_EXPRESSION, field_node, false);
     }
 
     pub fn set_field_description(
@@ -74,6 +112,17 @@ impl ProjectItemTypeAddress {
             .set_field_node(Self::PROPERTY_DESCRIPTION, field_node, false);
     }
 
+    /// Gets the value written to this address while the item is activated.
+    pub fn get_field_freeze_value(project_item: &ProjectItem) -> Option<DataValue> {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_FREEZE_VALUE)
+            .and_then(|field| field.get_data_value())
+            .cloned()
+    }
+
     pub fn set_field_freeze_value(
         project_item: &mut ProjectItem,
         freeze_value: DataValue,
@@ -82,6 +131,6 @@ impl ProjectItemTypeAddress {
 
         project_item
             .get_properties_mut()
-            .set_field_node(Self::PROPERTY_DESCRIPTION, field_node, false);
+            .set_field_node(Self::PROPERTY_FREEZE_VALUE, field_node, false);
     }
 }
@@ -0,0 +1,87 @@
+use crate::structures::{
+    data_types::built_in_types::{f64::data_type_f64::DataTypeF64, string::utf8::data_type_string_utf8::DataTypeStringUtf8},
+    projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef},
+    structs::valued_struct_field::ValuedStructFieldNode,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A project item that speeds up or slows down time in the opened process while it is activated.
+#[derive(Serialize, Deserialize)]
+pub struct ProjectItemTypeSpeed {}
+
+impl ProjectItemType for ProjectItemTypeSpeed {
+    fn get_project_item_type_id(&self) -> &str {
+        &Self::PROJECT_ITEM_TYPE_ID
+    }
+}
+
+impl ProjectItemTypeSpeed {
+    pub const PROJECT_ITEM_TYPE_ID: &str = "speed";
+    pub const PROPERTY_DESCRIPTION: &str = "description";
+    pub const PROPERTY_SPEED_FACTOR: &str = "speed_factor";
+
+    pub fn new_project_item(
+        path: &Path,
+        speed_factor: f64,
+        description: &str,
+    ) -> ProjectItem {
+        let speed_type = ProjectItemTypeRef::new(Self::PROJECT_ITEM_TYPE_ID.to_string());
+        let mut project_item = ProjectItem::new(path.to_path_buf(), speed_type, false);
+
+        Self::set_field_speed_factor(&mut project_item, speed_factor);
+        Self::set_field_description(&mut project_item, description);
+
+        project_item
+    }
+
+    pub fn get_field_description(project_item: &ProjectItem) -> String {
+        if let Some(name_field) = project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_DESCRIPTION)
+        {
+            name_field.get_display_string(true, 0)
+        } else {
+            String::new()
+        }
+    }
+
+    pub fn set_field_description(
+        project_item: &mut ProjectItem,
+        description: &str,
+    ) {
+        let description_data_value = DataTypeStringUtf8::get_value_from_primitive_string(&description);
+        let field_node = ValuedStructFieldNode::Value(description_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_node(Self::PROPERTY_DESCRIPTION, field_node, false);
+    }
+
+    /// Gets the speed applied to the opened process while the item is activated, where 1.0 is real time.
+    pub fn get_field_speed_factor(project_item: &ProjectItem) -> f64 {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_SPEED_FACTOR)
+            .and_then(|field| field.get_data_value())
+            .and_then(|data_value| data_value.get_value_bytes().as_slice().try_into().ok())
+            .map(f64::from_le_bytes)
+            .unwrap_or(1.0)
+    }
+
+    pub fn set_field_speed_factor(
+        project_item: &mut ProjectItem,
+        speed_factor: f64,
+    ) {
+        let speed_factor_data_value = DataTypeF64::get_value_from_primitive(speed_factor);
+        let field_node = ValuedStructFieldNode::Value(speed_factor_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_node(Self::PROPERTY_SPEED_FACTOR, field_node, false);
+    }
+}
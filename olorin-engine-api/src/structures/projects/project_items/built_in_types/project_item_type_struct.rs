@@ -0,0 +1,56 @@
+use crate::structures::{
+    data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8,
+    projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef},
+    structs::{symbolic_struct_definition::SymbolicStructDefinition, valued_struct_field::ValuedStructFieldNode},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A named struct layout stored in the project, such that it can be reused by name when viewing memory.
+#[derive(Serialize, Deserialize)]
+pub struct ProjectItemTypeStruct {}
+
+impl ProjectItemType for ProjectItemTypeStruct {
+    fn get_project_item_type_id(&self) -> &str {
+        &Self::PROJECT_ITEM_TYPE_ID
+    }
+}
+
+impl ProjectItemTypeStruct {
+    pub const PROJECT_ITEM_TYPE_ID: &str = "struct";
+    pub const PROPERTY_DEFINITION: &str = "definition";
+
+    pub fn new_project_item(
+        path: &Path,
+        symbolic_struct_definition: &SymbolicStructDefinition,
+    ) -> ProjectItem {
+        let struct_type = ProjectItemTypeRef::new(Self::PROJECT_ITEM_TYPE_ID.to_string());
+        let mut project_item = ProjectItem::new(path.to_path_buf(), struct_type, false);
+
+        Self::set_field_definition(&mut project_item, symbolic_struct_definition);
+
+        project_item
+    }
+
+    /// Gets the struct definition of this item, or `None` if it is missing or no longer parses.
+    pub fn get_field_definition(project_item: &ProjectItem) -> Option<SymbolicStructDefinition> {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_DEFINITION)
+            .and_then(|field| field.get_display_string(true, 0).parse().ok())
+    }
+
+    pub fn set_field_definition(
+        project_item: &mut ProjectItem,
+        symbolic_struct_definition: &SymbolicStructDefinition,
+    ) {
+        let definition_data_value = DataTypeStringUtf8::get_value_from_primitive_string(&symbolic_struct_definition.to_string());
+        let field_node = ValuedStructFieldNode::Value(definition_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_node(Self::PROPERTY_DEFINITION, field_node, false);
+    }
+}
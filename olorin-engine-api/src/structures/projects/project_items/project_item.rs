@@ -1,6 +1,6 @@
 use crate::structures::{projects::project_items::project_item_type_ref::ProjectItemTypeRef, structs::valued_struct::ValuedStruct};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Represents a unique reference to a project item in an opened project.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -47,6 +47,10 @@ impl ProjectItem {
         &self.path
     }
 
+    pub fn get_item_type(&self) -> &ProjectItemTypeRef {
+        &self.item_type
+    }
+
     pub fn get_properties(&self) -> &ValuedStruct {
         &self.properties
     }
@@ -55,6 +59,13 @@ impl ProjectItem {
         &mut self.properties
     }
 
+    pub fn set_properties(
+        &mut self,
+        properties: ValuedStruct,
+    ) {
+        self.properties = properties;
+    }
+
     pub fn get_has_unsaved_changes(&self) -> bool {
         self.has_unsaved_changes
     }
@@ -105,4 +116,22 @@ impl ProjectItem {
 
         &mut self.children
     }
+
+    /// Finds the project item with the given path, which may be this item or any item beneath it.
+    pub fn find_project_item_mut(
+        &mut self,
+        project_item_path: &Path,
+    ) -> Option<&mut ProjectItem> {
+        if self.path == project_item_path {
+            return Some(self);
+        }
+
+        if !self.is_container_type {
+            return None;
+        }
+
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_project_item_mut(project_item_path))
+    }
 }
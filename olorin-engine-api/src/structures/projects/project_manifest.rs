@@ -1,3 +1,4 @@
+use crate::structures::processes::process_watch_rule::ProcessWatchRule;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -5,14 +6,32 @@ use std::path::PathBuf;
 pub struct ProjectManifest {
     #[serde(rename = "sort_order")]
     project_item_sort_order: Vec<PathBuf>,
+
+    /// The process to attach to automatically while this project is open.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    process_watch_rule: Option<ProcessWatchRule>,
 }
 
 impl ProjectManifest {
     pub fn new(project_item_sort_order: Vec<PathBuf>) -> Self {
-        Self { project_item_sort_order }
+        Self {
+            project_item_sort_order,
+            process_watch_rule: None,
+        }
     }
 
     pub fn get_project_item_sort_order(&self) -> &Vec<PathBuf> {
         &self.project_item_sort_order
     }
+
+    pub fn get_process_watch_rule(&self) -> &Option<ProcessWatchRule> {
+        &self.process_watch_rule
+    }
+
+    pub fn set_process_watch_rule(
+        &mut self,
+        process_watch_rule: Option<ProcessWatchRule>,
+    ) {
+        self.process_watch_rule = process_watch_rule;
+    }
 }
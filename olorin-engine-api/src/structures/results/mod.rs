@@ -1,2 +1,4 @@
+pub mod scan_results_watch;
+pub mod scan_results_watch_list;
 pub mod snapshot_region_scan_results;
 pub mod snapshot_scan_result_freeze_list;
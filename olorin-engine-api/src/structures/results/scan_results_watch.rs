@@ -0,0 +1,66 @@
+use crate::structures::scan_results::scan_result::ScanResult;
+use crate::structures::scan_results::scan_result_valued::ScanResultValued;
+use std::time::{Duration, Instant};
+
+/// A subscription to value changes for a set of scan results, along with the values last published to the subscriber.
+pub struct ScanResultsWatch {
+    scan_results: Vec<ScanResultValued>,
+    refresh_interval: Duration,
+    next_refresh: Instant,
+    last_scan_results: Vec<ScanResult>,
+}
+
+impl ScanResultsWatch {
+    pub fn new(
+        scan_results: Vec<ScanResultValued>,
+        refresh_interval: Duration,
+        last_scan_results: Vec<ScanResult>,
+    ) -> Self {
+        Self {
+            scan_results,
+            refresh_interval,
+            next_refresh: Instant::now() + refresh_interval,
+            last_scan_results,
+        }
+    }
+
+    pub fn get_scan_results(&self) -> &Vec<ScanResultValued> {
+        &self.scan_results
+    }
+
+    pub fn get_next_refresh(&self) -> Instant {
+        self.next_refresh
+    }
+
+    pub fn is_refresh_due(
+        &self,
+        now: Instant,
+    ) -> bool {
+        now >= self.next_refresh
+    }
+
+    /// Stores the latest values for this watch, returning only those that differ from the values last published.
+    pub fn update_scan_results(
+        &mut self,
+        now: Instant,
+        scan_results: Vec<ScanResult>,
+    ) -> Vec<ScanResult> {
+        let changed_scan_results = scan_results
+            .iter()
+            .enumerate()
+            .filter(|(index, scan_result)| match self.last_scan_results.get(*index) {
+                Some(last_scan_result) => {
+                    last_scan_result.get_recently_read_value() != scan_result.get_recently_read_value()
+                        || last_scan_result.get_is_frozen() != scan_result.get_is_frozen()
+                }
+                None => true,
+            })
+            .map(|(_, scan_result)| scan_result.clone())
+            .collect();
+
+        self.last_scan_results = scan_results;
+        self.next_refresh = now + self.refresh_interval;
+
+        changed_scan_results
+    }
+}
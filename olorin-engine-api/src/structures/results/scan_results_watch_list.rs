@@ -0,0 +1,39 @@
+use crate::structures::results::scan_results_watch::ScanResultsWatch;
+use std::{collections::HashMap, sync::RwLock};
+
+pub struct ScanResultsWatchList {
+    watches: RwLock<HashMap<String, ScanResultsWatch>>,
+}
+
+/// Contains all active scan result subscriptions, keyed by the id chosen by each subscriber (ie a GUI view or the CLI).
+/// A single read loop services every watch, such that multiple viewers of the same results share the cost of reading them.
+impl ScanResultsWatchList {
+    pub fn new() -> Self {
+        Self {
+            watches: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_watches(&self) -> &RwLock<HashMap<String, ScanResultsWatch>> {
+        &self.watches
+    }
+
+    pub fn set_watch(
+        &self,
+        watch_id: String,
+        scan_results_watch: ScanResultsWatch,
+    ) {
+        if let Ok(mut watches) = self.watches.write() {
+            watches.insert(watch_id, scan_results_watch);
+        }
+    }
+
+    pub fn remove_watch(
+        &self,
+        watch_id: &str,
+    ) {
+        if let Ok(mut watches) = self.watches.write() {
+            watches.remove(watch_id);
+        }
+    }
+}
@@ -14,6 +14,7 @@ pub struct ScanResult {
     valued_result: ScanResultValued,
     module: String,
     module_offset: u64,
+    symbol: Option<String>,
     recently_read_value: Option<DataValue>,
     is_frozen: bool,
 }
@@ -24,11 +25,13 @@ impl ScanResult {
     pub const PROPERTY_NAME_ADDRESS: &str = "address";
     pub const PROPERTY_NAME_MODULE: &str = "module";
     pub const PROPERTY_NAME_MODULE_OFFSET: &str = "module_offset";
+    pub const PROPERTY_NAME_SYMBOL: &str = "symbol";
 
     pub fn new(
         valued_result: ScanResultValued,
         module: String,
         module_offset: u64,
+        symbol: Option<String>,
         recently_read_value: Option<DataValue>,
         is_frozen: bool,
     ) -> Self {
@@ -36,6 +39,7 @@ impl ScanResult {
             valued_result,
             module,
             module_offset,
+            symbol,
             recently_read_value,
             is_frozen,
         }
@@ -57,14 +61,22 @@ impl ScanResult {
             .to_named_valued_struct_field(Self::PROPERTY_NAME_MODULE.to_string(), true);
         let field_module_offset =
             DataTypeU64::get_value_from_primitive(self.module_offset).to_named_valued_struct_field(Self::PROPERTY_NAME_MODULE_OFFSET.to_string(), true);
-
-        ValuedStruct::new_anonymous(vec![
+        let mut fields = vec![
             field_value,
             field_is_frozen,
             field_address,
             field_module,
             field_module_offset,
-        ])
+        ];
+
+        if let Some(symbol) = &self.symbol {
+            fields.push(
+                DataTypeStringUtf8::get_value_from_primitive_array(symbol.as_bytes().to_vec())
+                    .to_named_valued_struct_field(Self::PROPERTY_NAME_SYMBOL.to_string(), true),
+            );
+        }
+
+        ValuedStruct::new_anonymous(fields)
     }
 
     pub fn get_valued_result(&self) -> &ScanResultValued {
@@ -95,6 +107,11 @@ impl ScanResult {
         self.module_offset
     }
 
+    /// Gets the symbol containing this address, formatted as `module!symbol+0x34`, if one is known.
+    pub fn get_symbol(&self) -> &Option<String> {
+        &self.symbol
+    }
+
     pub fn get_recently_read_value(&self) -> &Option<DataValue> {
         &self.recently_read_value
     }
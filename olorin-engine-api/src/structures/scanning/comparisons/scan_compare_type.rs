@@ -29,11 +29,51 @@ impl FromStr for ScanCompareType {
             "-" => Ok(ScanCompareType::Relative(ScanCompareTypeRelative::Decreased)),
             "+x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::IncreasedByX)),
             "-x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::DecreasedByX)),
+            "*x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::MultipliedByX)),
+            "/x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::DividedByX)),
+            "%x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::ModuloByX)),
+            "<<x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::ShiftLeftByX)),
+            ">>x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::ShiftRightByX)),
+            "&x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::LogicalAndByX)),
+            "|x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::LogicalOrByX)),
+            "^x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::LogicalXorByX)),
             _ => Err(ParseScanCompareTypeError),
         }
     }
 }
 
+impl fmt::Display for ScanCompareType {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let symbol = match self {
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal) => "==",
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::NotEqual) => "!=",
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThan) => ">",
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThanOrEqual) => ">=",
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThan) => "<",
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThanOrEqual) => "<=",
+            ScanCompareType::Relative(ScanCompareTypeRelative::Changed) => "c",
+            ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged) => "u",
+            ScanCompareType::Relative(ScanCompareTypeRelative::Increased) => "+",
+            ScanCompareType::Relative(ScanCompareTypeRelative::Decreased) => "-",
+            ScanCompareType::Delta(ScanCompareTypeDelta::IncreasedByX) => "+x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::DecreasedByX) => "-x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::MultipliedByX) => "*x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::DividedByX) => "/x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::ModuloByX) => "%x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::ShiftLeftByX) => "<<x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::ShiftRightByX) => ">>x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::LogicalAndByX) => "&x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::LogicalOrByX) => "|x",
+            ScanCompareType::Delta(ScanCompareTypeDelta::LogicalXorByX) => "^x",
+        };
+
+        write!(formatter, "{}", symbol)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ParseScanCompareTypeError;
 
@@ -3,6 +3,7 @@ use crate::structures::{data_types::data_type_ref::DataTypeRef, scanning::filter
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 /// A custom type that defines a set of filters (scan results) discovered by scanners.
+#[derive(Clone)]
 pub struct SnapshotRegionFilterCollection {
     /// The filters contained in this collection. This is kept as a vector of vectors for better parallelization.
     snapshot_region_filters: Vec<Vec<SnapshotRegionFilter>>,
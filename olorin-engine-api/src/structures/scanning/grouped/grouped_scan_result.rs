@@ -0,0 +1,29 @@
+use crate::structures::scan_results::scan_result_base::ScanResultBase;
+use serde::{Deserialize, Serialize};
+
+/// A set of values discovered near one another by a grouped scan, such as several fields of the same struct.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroupedScanResult {
+    /// The lowest address across all values in the group.
+    anchor_address: u64,
+
+    /// The location of each value in the group, in the same order as the values provided to the scan.
+    scan_results: Vec<ScanResultBase>,
+}
+
+impl GroupedScanResult {
+    pub fn new(
+        anchor_address: u64,
+        scan_results: Vec<ScanResultBase>,
+    ) -> Self {
+        Self { anchor_address, scan_results }
+    }
+
+    pub fn get_anchor_address(&self) -> u64 {
+        self.anchor_address
+    }
+
+    pub fn get_scan_results(&self) -> &Vec<ScanResultBase> {
+        &self.scan_results
+    }
+}
@@ -0,0 +1 @@
+pub mod grouped_scan_result;
@@ -0,0 +1,53 @@
+use crate::structures::data_values::anonymous_value::AnonymousValue;
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use serde::{Deserialize, Serialize};
+
+/// Describes a single step in the scan history, which can be rolled back to restore the results that preceded it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanHistoryEntry {
+    compare_type: ScanCompareType,
+    scan_value: Option<AnonymousValue>,
+    data_type_ids: Vec<String>,
+    result_count: u64,
+    memory_cost: u64,
+}
+
+impl ScanHistoryEntry {
+    pub fn new(
+        compare_type: ScanCompareType,
+        scan_value: Option<AnonymousValue>,
+        data_type_ids: Vec<String>,
+        result_count: u64,
+        memory_cost: u64,
+    ) -> Self {
+        Self {
+            compare_type,
+            scan_value,
+            data_type_ids,
+            result_count,
+            memory_cost,
+        }
+    }
+
+    pub fn get_compare_type(&self) -> &ScanCompareType {
+        &self.compare_type
+    }
+
+    pub fn get_scan_value(&self) -> &Option<AnonymousValue> {
+        &self.scan_value
+    }
+
+    pub fn get_data_type_ids(&self) -> &Vec<String> {
+        &self.data_type_ids
+    }
+
+    /// Gets the number of results produced by this scan step.
+    pub fn get_result_count(&self) -> u64 {
+        self.result_count
+    }
+
+    /// Gets the number of bytes retained in order to be able to roll back this scan step.
+    pub fn get_memory_cost(&self) -> u64 {
+        self.memory_cost
+    }
+}
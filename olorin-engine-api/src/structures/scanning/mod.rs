@@ -1,4 +1,7 @@
 pub mod comparisons;
 pub mod filters;
+pub mod grouped;
+pub mod history;
 pub mod memory_read_mode;
 pub mod parameters;
+pub mod signatures;
@@ -39,17 +39,15 @@ impl ElementScanMappingRule for MapScanType {
         let is_floating_point = mapped_parameters.get_data_type().is_floating_point();
 
         // Decide whether to use a scalar or SIMD scan based on filter region size.
-        if usable_region_size >= 64 {
-            mapped_parameters.set_vectorization_size(VectorizationSize::Vector64);
-        } else if usable_region_size >= 32 {
-            mapped_parameters.set_vectorization_size(VectorizationSize::Vector32);
-        } else if usable_region_size >= 16 {
-            mapped_parameters.set_vectorization_size(VectorizationSize::Vector16);
-        } else {
-            // The filter cannot fit into a vector! Revert to scalar scan.
-            mapped_parameters.set_mapped_scan_type(MappedScanType::Scalar(ScanParametersScalar::ScalarIterative));
+        // Note that this may exceed what the host CPU supports, the scan dispatcher is responsible for clamping this down.
+        match VectorizationSize::from_max_size_in_bytes(usable_region_size) {
+            Some(vectorization_size) => mapped_parameters.set_vectorization_size(vectorization_size),
+            None => {
+                // The filter cannot fit into a vector! Revert to scalar scan.
+                mapped_parameters.set_mapped_scan_type(MappedScanType::Scalar(ScanParametersScalar::ScalarIterative));
 
-            return;
+                return;
+            }
         }
 
         if data_type_size > memory_alignment_size {
@@ -5,6 +5,7 @@ use crate::structures::memory::memory_alignment::MemoryAlignment;
 use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
 use crate::structures::scanning::memory_read_mode::MemoryReadMode;
 use crate::structures::scanning::parameters::element_scan::element_scan_value::ElementScanValue;
+use crate::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
 use crate::structures::snapshots::snapshot_region::SnapshotRegion;
 
 /// Represents the scan arguments for an element-wise scan.
@@ -16,6 +17,12 @@ pub struct ElementScanParameters {
     memory_read_mode: MemoryReadMode,
     is_single_thread_scan: bool,
 
+    /// If provided, forces vectorized scans to use this vector size instead of the largest size supported by the host CPU.
+    vectorization_size_override: Option<VectorizationSize>,
+
+    /// Filters of at least this size are split into chunks that are scanned in parallel. A value of zero disables chunking.
+    chunked_scan_region_size_threshold: u64,
+
     /// If this debug flag is provided, the scan will be performed twice. Once with a specialized scan, and once with the default scan.
     /// An assertion will be made that the default scan produced the exact same result as the specialized scan.
     debug_perform_validation_scan: bool,
@@ -28,6 +35,8 @@ impl ElementScanParameters {
         floating_point_tolerance: FloatingPointTolerance,
         memory_read_mode: MemoryReadMode,
         is_single_thread_scan: bool,
+        vectorization_size_override: Option<VectorizationSize>,
+        chunked_scan_region_size_threshold: u64,
         debug_perform_validation_scan: bool,
     ) -> Self {
         Self {
@@ -36,6 +45,8 @@ impl ElementScanParameters {
             floating_point_tolerance,
             memory_read_mode,
             is_single_thread_scan,
+            vectorization_size_override,
+            chunked_scan_region_size_threshold,
             debug_perform_validation_scan,
         }
     }
@@ -104,6 +115,14 @@ impl ElementScanParameters {
         self.is_single_thread_scan
     }
 
+    pub fn get_vectorization_size_override(&self) -> Option<VectorizationSize> {
+        self.vectorization_size_override
+    }
+
+    pub fn get_chunked_scan_region_size_threshold(&self) -> u64 {
+        self.chunked_scan_region_size_threshold
+    }
+
     pub fn get_debug_perform_validation_scan(&self) -> bool {
         self.debug_perform_validation_scan
     }
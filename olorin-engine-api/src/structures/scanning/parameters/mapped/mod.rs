@@ -1,3 +1,4 @@
 pub mod mapped_scan_parameters;
 pub mod mapped_scan_type;
 pub mod vectorization_size;
+pub mod vectorization_size_override;
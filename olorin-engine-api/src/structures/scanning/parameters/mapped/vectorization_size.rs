@@ -1,12 +1,51 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum VectorizationSize {
     Vector16,
     Vector32,
     Vector64,
 }
 
+impl VectorizationSize {
+    pub fn get_size_in_bytes(&self) -> u64 {
+        match self {
+            VectorizationSize::Vector16 => 16,
+            VectorizationSize::Vector32 => 32,
+            VectorizationSize::Vector64 => 64,
+        }
+    }
+
+    /// Gets the largest vectorization size that fits within the provided number of bytes, if any.
+    pub fn from_max_size_in_bytes(size_in_bytes: u64) -> Option<Self> {
+        if size_in_bytes >= 64 {
+            Some(VectorizationSize::Vector64)
+        } else if size_in_bytes >= 32 {
+            Some(VectorizationSize::Vector32)
+        } else if size_in_bytes >= 16 {
+            Some(VectorizationSize::Vector16)
+        } else {
+            None
+        }
+    }
+}
+
 impl Default for VectorizationSize {
     fn default() -> Self {
         Self::Vector16
     }
 }
+
+impl FromStr for VectorizationSize {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "16" => Ok(VectorizationSize::Vector16),
+            "32" => Ok(VectorizationSize::Vector32),
+            "64" => Ok(VectorizationSize::Vector64),
+            _ => Err("Unknown vectorization size, expected one of: 16, 32, 64.".to_string()),
+        }
+    }
+}
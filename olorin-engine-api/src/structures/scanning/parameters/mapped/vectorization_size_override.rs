@@ -0,0 +1,33 @@
+use crate::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A requested vectorization size for scans. `Auto` clears any override, such that the size is selected from the CPU features detected at runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VectorizationSizeOverride {
+    Auto,
+    Size(VectorizationSize),
+}
+
+impl VectorizationSizeOverride {
+    /// Gets the forced vectorization size, or `None` if the size should be selected automatically.
+    pub fn get_vectorization_size(&self) -> Option<VectorizationSize> {
+        match self {
+            VectorizationSizeOverride::Auto => None,
+            VectorizationSizeOverride::Size(vectorization_size) => Some(*vectorization_size),
+        }
+    }
+}
+
+impl FromStr for VectorizationSizeOverride {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "auto" => Ok(VectorizationSizeOverride::Auto),
+            _ => VectorizationSize::from_str(string)
+                .map(VectorizationSizeOverride::Size)
+                .map_err(|_| "Unknown vectorization size, expected one of: auto, 16, 32, 64.".to_string()),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::conversions::conversions::Conversions;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A byte pattern where each byte carries a mask of the bits that must match. This allows for full byte wildcards (`??`),
+/// as well as nibble wildcards (`4?` or `?8`). Parsed from IDA-style signatures, ie `48 8B ?? ?? 89 05 ?? ?? ?? ??`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ByteSignature {
+    pattern_bytes: Vec<u8>,
+    pattern_masks: Vec<u8>,
+}
+
+impl ByteSignature {
+    pub fn new(
+        pattern_bytes: Vec<u8>,
+        pattern_masks: Vec<u8>,
+    ) -> Self {
+        Self { pattern_bytes, pattern_masks }
+    }
+
+    pub fn get_pattern_bytes(&self) -> &[u8] {
+        &self.pattern_bytes
+    }
+
+    pub fn get_pattern_masks(&self) -> &[u8] {
+        &self.pattern_masks
+    }
+
+    pub fn get_length(&self) -> usize {
+        self.pattern_bytes.len()
+    }
+
+    /// Determines whether the given byte matches the pattern at the specified index, respecting the mask.
+    pub fn matches_byte_at(
+        &self,
+        pattern_index: usize,
+        byte: u8,
+    ) -> bool {
+        (byte & self.pattern_masks[pattern_index]) == self.pattern_bytes[pattern_index]
+    }
+
+    /// Determines whether the provided bytes match this signature. The bytes must be at least as long as the signature.
+    pub fn matches(
+        &self,
+        bytes: &[u8],
+    ) -> bool {
+        if bytes.len() < self.get_length() {
+            return false;
+        }
+
+        (0..self.get_length()).all(|pattern_index| self.matches_byte_at(pattern_index, bytes[pattern_index]))
+    }
+
+    /// Parses a single hex character or wildcard into a (value, mask) nibble pair.
+    fn parse_nibble(nibble: u8) -> Option<(u8, u8)> {
+        if nibble == b'?' {
+            Some((0, 0))
+        } else {
+            Conversions::hex_char_to_byte(nibble)
+                .ok()
+                .map(|value| (value, 0xF))
+        }
+    }
+}
+
+impl FromStr for ByteSignature {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut pattern_bytes = vec![];
+        let mut pattern_masks = vec![];
+
+        for token in string.split_whitespace() {
+            let token_bytes = token.as_bytes();
+
+            // A single '?' is shorthand for a full byte wildcard, as is common in other tools.
+            let (high_nibble, low_nibble) = match token_bytes.len() {
+                1 if token_bytes[0] == b'?' => (b'?', b'?'),
+                2 => (token_bytes[0], token_bytes[1]),
+                _ => return Err(format!("Invalid signature byte: '{}'", token)),
+            };
+
+            let (Some((high_value, high_mask)), Some((low_value, low_mask))) = (Self::parse_nibble(high_nibble), Self::parse_nibble(low_nibble)) else {
+                return Err(format!("Invalid signature byte: '{}'", token));
+            };
+
+            pattern_bytes.push((high_value << 4) | low_value);
+            pattern_masks.push((high_mask << 4) | low_mask);
+        }
+
+        if pattern_bytes.is_empty() {
+            return Err("Signature must contain at least one byte.".to_string());
+        }
+
+        if pattern_masks.iter().all(|mask| *mask == 0) {
+            return Err("Signature must contain at least one non-wildcard byte.".to_string());
+        }
+
+        Ok(Self::new(pattern_bytes, pattern_masks))
+    }
+}
+
+impl fmt::Display for ByteSignature {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        let tokens: Vec<String> = self
+            .pattern_bytes
+            .iter()
+            .zip(self.pattern_masks.iter())
+            .map(|(byte, mask)| {
+                let high = if mask & 0xF0 != 0 { format!("{:X}", byte >> 4) } else { "?".to_string() };
+                let low = if mask & 0x0F != 0 { format!("{:X}", byte & 0xF) } else { "?".to_string() };
+
+                format!("{}{}", high, low)
+            })
+            .collect();
+
+        write!(formatter, "{}", tokens.join(" "))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod byte_signature;
+pub mod signature_scan_result;
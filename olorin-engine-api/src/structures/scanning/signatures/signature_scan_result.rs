@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// A single match from a signature scan, including module-relative information and an optional resolved RIP-relative target.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SignatureScanResult {
+    address: u64,
+    module_name: String,
+    module_offset: u64,
+    resolved_address: Option<u64>,
+    resolved_module_name: String,
+    resolved_module_offset: u64,
+}
+
+impl SignatureScanResult {
+    pub fn new(
+        address: u64,
+        module_name: String,
+        module_offset: u64,
+        resolved_address: Option<u64>,
+        resolved_module_name: String,
+        resolved_module_offset: u64,
+    ) -> Self {
+        Self {
+            address,
+            module_name,
+            module_offset,
+            resolved_address,
+            resolved_module_name,
+            resolved_module_offset,
+        }
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_module_offset(&self) -> u64 {
+        self.module_offset
+    }
+
+    pub fn is_module(&self) -> bool {
+        !self.module_name.is_empty()
+    }
+
+    pub fn get_resolved_address(&self) -> Option<u64> {
+        self.resolved_address
+    }
+
+    pub fn get_resolved_module_name(&self) -> &str {
+        &self.resolved_module_name
+    }
+
+    pub fn get_resolved_module_offset(&self) -> u64 {
+        self.resolved_module_offset
+    }
+}
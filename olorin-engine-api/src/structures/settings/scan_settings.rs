@@ -1,10 +1,12 @@
 use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
 use crate::structures::{data_types::floating_point_tolerance::FloatingPointTolerance, scanning::memory_read_mode::MemoryReadMode};
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 use std::fmt;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
 pub struct ScanSettings {
     pub results_page_size: u32,
     pub results_read_interval: u64,
@@ -14,7 +16,10 @@ pub struct ScanSettings {
     pub memory_read_mode: MemoryReadMode,
     pub floating_point_tolerance: FloatingPointTolerance,
     pub is_single_threaded_scan: bool,
+    pub vectorization_size_override: Option<VectorizationSize>,
+    pub chunked_scan_region_size_threshold: u64,
     pub debug_perform_validation_scan: bool,
+    pub scan_history_depth: u32,
 }
 
 impl fmt::Debug for ScanSettings {
@@ -40,7 +45,10 @@ impl Default for ScanSettings {
             floating_point_tolerance: FloatingPointTolerance::default(),
             memory_read_mode: MemoryReadMode::ReadBeforeScan,
             is_single_threaded_scan: false,
+            vectorization_size_override: None,
+            chunked_scan_region_size_threshold: 64 * 1024 * 1024,
             debug_perform_validation_scan: false,
+            scan_history_depth: 8,
         }
     }
 }
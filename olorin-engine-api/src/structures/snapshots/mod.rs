@@ -1,2 +1,4 @@
 pub mod snapshot;
+pub mod snapshot_checkpoint;
 pub mod snapshot_region;
+pub mod snapshot_region_checkpoint;
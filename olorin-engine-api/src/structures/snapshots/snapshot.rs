@@ -1,9 +1,15 @@
+use crate::structures::data_values::anonymous_value::AnonymousValue;
 use crate::structures::scan_results::scan_result_valued::ScanResultValued;
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use crate::structures::scanning::history::scan_history_entry::ScanHistoryEntry;
+use crate::structures::snapshots::snapshot_checkpoint::SnapshotCheckpoint;
 use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+use crate::structures::snapshots::snapshot_region_checkpoint::SnapshotRegionCheckpoint;
 use std::cmp;
 
 pub struct Snapshot {
     snapshot_regions: Vec<SnapshotRegion>,
+    checkpoints: Vec<SnapshotCheckpoint>,
 }
 
 /// Represents a snapshot of memory in an external process that contains current and previous values of memory pages.
@@ -11,15 +17,19 @@ impl Snapshot {
     /// Creates a new snapshot from the given collection of snapshot regions.
     /// This will automatically sort and remove invalid regions.
     pub fn new() -> Self {
-        Self { snapshot_regions: vec![] }
+        Self {
+            snapshot_regions: vec![],
+            checkpoints: vec![],
+        }
     }
 
-    /// Assigns new snapshot regions to this snapshot.
+    /// Assigns new snapshot regions to this snapshot. This discards the scan history, as it refers to the prior regions.
     pub fn set_snapshot_regions(
         &mut self,
         snapshot_regions: Vec<SnapshotRegion>,
     ) {
         self.snapshot_regions = snapshot_regions;
+        self.checkpoints.clear();
         self.discard_empty_regions();
         self.sort_regions();
     }
@@ -94,4 +104,83 @@ impl Snapshot {
             .map(|snapshot_region| snapshot_region.get_scan_results().get_number_of_results())
             .sum()
     }
+
+    /// Records the values and scan result filters of each snapshot region prior to performing a scan, such that the scan can later be
+    /// rolled back. Each checkpoint holds a copy of the values, which is reported as the memory cost of the scan history.
+    /// The oldest checkpoints are discarded once the maximum depth is exceeded. A maximum depth of zero disables scan history.
+    pub fn create_checkpoint(
+        &mut self,
+        compare_type: ScanCompareType,
+        scan_value: Option<AnonymousValue>,
+        data_type_ids: Vec<String>,
+        max_depth: usize,
+    ) {
+        if max_depth == 0 {
+            self.checkpoints.clear();
+            return;
+        }
+
+        self.checkpoints.push(SnapshotCheckpoint::new(
+            self.snapshot_regions
+                .iter()
+                .map(SnapshotRegionCheckpoint::new)
+                .collect(),
+            compare_type,
+            scan_value,
+            data_type_ids,
+        ));
+
+        if self.checkpoints.len() > max_depth {
+            let excess_count = self.checkpoints.len() - max_depth;
+            self.checkpoints.drain(..excess_count);
+        }
+    }
+
+    /// Rolls back the specified number of scans, restoring the values and scan result filters that preceded them without reading memory.
+    /// Returns the number of scans that were actually rolled back, which is limited by the available history.
+    pub fn rollback(
+        &mut self,
+        scan_count: usize,
+    ) -> usize {
+        let scan_count = scan_count.min(self.checkpoints.len());
+
+        if scan_count == 0 {
+            return 0;
+        }
+
+        let restore_index = self.checkpoints.len() - scan_count;
+
+        if let Some(checkpoint) = self.checkpoints.drain(restore_index..).next() {
+            self.snapshot_regions = checkpoint
+                .take_snapshot_region_checkpoints()
+                .into_iter()
+                .map(SnapshotRegionCheckpoint::restore)
+                .collect();
+        }
+
+        scan_count
+    }
+
+    /// Gets the history of scans that can be rolled back, ordered from oldest to most recent.
+    pub fn get_scan_history(&self) -> Vec<ScanHistoryEntry> {
+        self.checkpoints
+            .iter()
+            .enumerate()
+            .map(|(index, checkpoint)| {
+                // The results produced by a scan are the results captured by the next checkpoint, or the current results for the latest scan.
+                let result_count = match self.checkpoints.get(index + 1) {
+                    Some(next_checkpoint) => next_checkpoint.get_number_of_results(),
+                    None => self.get_number_of_results(),
+                };
+
+                ScanHistoryEntry::new(
+                    checkpoint.get_compare_type().clone(),
+                    checkpoint.get_scan_value().clone(),
+                    checkpoint.get_data_type_ids().clone(),
+                    result_count,
+                    checkpoint.get_memory_cost(),
+                )
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,70 @@
+use crate::structures::data_values::anonymous_value::AnonymousValue;
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use crate::structures::snapshots::snapshot_region_checkpoint::SnapshotRegionCheckpoint;
+
+/// The values and scan result filters of each snapshot region taken before a scan, such that the scan can be rolled back.
+pub struct SnapshotCheckpoint {
+    /// The snapshot regions as they were prior to the scan.
+    snapshot_region_checkpoints: Vec<SnapshotRegionCheckpoint>,
+
+    /// The comparison performed by the scan that followed this checkpoint.
+    compare_type: ScanCompareType,
+
+    /// The value compared against by the scan that followed this checkpoint, if any.
+    scan_value: Option<AnonymousValue>,
+
+    /// The data types scanned by the scan that followed this checkpoint.
+    data_type_ids: Vec<String>,
+}
+
+impl SnapshotCheckpoint {
+    pub fn new(
+        snapshot_region_checkpoints: Vec<SnapshotRegionCheckpoint>,
+        compare_type: ScanCompareType,
+        scan_value: Option<AnonymousValue>,
+        data_type_ids: Vec<String>,
+    ) -> Self {
+        Self {
+            snapshot_region_checkpoints,
+            compare_type,
+            scan_value,
+            data_type_ids,
+        }
+    }
+
+    pub fn get_snapshot_region_checkpoints(&self) -> &Vec<SnapshotRegionCheckpoint> {
+        &self.snapshot_region_checkpoints
+    }
+
+    pub fn take_snapshot_region_checkpoints(self) -> Vec<SnapshotRegionCheckpoint> {
+        self.snapshot_region_checkpoints
+    }
+
+    pub fn get_compare_type(&self) -> &ScanCompareType {
+        &self.compare_type
+    }
+
+    pub fn get_scan_value(&self) -> &Option<AnonymousValue> {
+        &self.scan_value
+    }
+
+    pub fn get_data_type_ids(&self) -> &Vec<String> {
+        &self.data_type_ids
+    }
+
+    /// Gets the number of scan results that existed at the time of this checkpoint.
+    pub fn get_number_of_results(&self) -> u64 {
+        self.snapshot_region_checkpoints
+            .iter()
+            .map(|snapshot_region_checkpoint| snapshot_region_checkpoint.get_number_of_results())
+            .sum()
+    }
+
+    /// Gets the approximate number of bytes held by this checkpoint.
+    pub fn get_memory_cost(&self) -> u64 {
+        self.snapshot_region_checkpoints
+            .iter()
+            .map(|snapshot_region_checkpoint| snapshot_region_checkpoint.get_memory_cost())
+            .sum()
+    }
+}
@@ -139,6 +139,21 @@ impl SnapshotRegion {
         self.normalized_region.get_region_size()
     }
 
+    /// Gets the approximate number of bytes of heap memory held by this snapshot region, including its values and scan results.
+    pub fn get_memory_cost(&self) -> u64 {
+        let filter_count: usize = self
+            .scan_results
+            .get_filter_collections()
+            .iter()
+            .map(|snapshot_region_filter_collection| snapshot_region_filter_collection.iter().count())
+            .sum();
+
+        (self.current_values.len()
+            + self.previous_values.len()
+            + self.page_boundaries.len() * size_of::<u64>()
+            + filter_count * size_of::<SnapshotRegionFilter>()) as u64
+    }
+
     pub fn has_current_values(&self) -> bool {
         !self.current_values.is_empty()
     }
@@ -0,0 +1,84 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+
+/// The bounds, values, and scan result filters of a snapshot region prior to a scan. Values are kept verbatim, such that relative
+/// scans after a rollback compare against the same baseline that the rolled back scan did.
+pub struct SnapshotRegionCheckpoint {
+    /// The bounds of the snapshot region prior to the scan.
+    normalized_region: NormalizedRegion,
+
+    /// Any OS level page boundaries that sub-divided the snapshot region prior to the scan.
+    page_boundaries: Vec<u64>,
+
+    /// The values read from memory by the most recent scan prior to this checkpoint.
+    current_values: Vec<u8>,
+
+    /// The values read from memory by the scan before that, which relative scans compared against.
+    previous_values: Vec<u8>,
+
+    /// The scan result filters of the snapshot region prior to the scan.
+    snapshot_region_filter_collections: Vec<SnapshotRegionFilterCollection>,
+}
+
+impl SnapshotRegionCheckpoint {
+    pub fn new(snapshot_region: &SnapshotRegion) -> Self {
+        Self {
+            normalized_region: NormalizedRegion::new(snapshot_region.get_base_address(), snapshot_region.get_region_size()),
+            page_boundaries: snapshot_region.page_boundaries.clone(),
+            current_values: snapshot_region.current_values.clone(),
+            previous_values: snapshot_region.previous_values.clone(),
+            snapshot_region_filter_collections: snapshot_region
+                .get_scan_results()
+                .get_filter_collections()
+                .clone(),
+        }
+    }
+
+    pub fn get_base_address(&self) -> u64 {
+        self.normalized_region.get_base_address()
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        self.normalized_region.get_region_size()
+    }
+
+    /// Rebuilds the snapshot region as it was prior to the scan, including the values read from memory at that time.
+    pub fn restore(self) -> SnapshotRegion {
+        let mut snapshot_region = SnapshotRegion::new(self.normalized_region, self.page_boundaries);
+
+        snapshot_region.current_values = self.current_values;
+        snapshot_region.previous_values = self.previous_values;
+
+        // Regions are checkpointed without filters before the first scan, and assigning no filters would collapse the region.
+        if !self.snapshot_region_filter_collections.is_empty() {
+            snapshot_region.set_scan_results(SnapshotRegionScanResults::new(self.snapshot_region_filter_collections));
+        }
+
+        snapshot_region
+    }
+
+    /// Gets the number of scan results that existed in this region at the time of this checkpoint.
+    pub fn get_number_of_results(&self) -> u64 {
+        self.snapshot_region_filter_collections
+            .iter()
+            .map(|snapshot_region_filter_collection| snapshot_region_filter_collection.get_number_of_results())
+            .sum()
+    }
+
+    /// Gets the approximate number of bytes of heap memory held by this checkpoint.
+    pub fn get_memory_cost(&self) -> u64 {
+        let filter_count: usize = self
+            .snapshot_region_filter_collections
+            .iter()
+            .map(|snapshot_region_filter_collection| snapshot_region_filter_collection.iter().count())
+            .sum();
+
+        (self.page_boundaries.len() * size_of::<u64>()
+            + self.current_values.len()
+            + self.previous_values.len()
+            + filter_count * size_of::<SnapshotRegionFilter>()) as u64
+    }
+}
@@ -1,5 +1,15 @@
+use crate::registries::symbolic_structs::symbolic_struct_registry::SymbolicStructRegistry;
+use crate::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::structs::container_type::ContainerType;
 use crate::structures::structs::symbolic_struct_field_definition::SymbolicStructFieldDefinition;
+use crate::structures::structs::symbolic_struct_ref::SymbolicStructRef;
+use crate::structures::structs::valued_struct::ValuedStruct;
+use crate::structures::structs::valued_struct_field::{ValuedStructField, ValuedStructFieldNode};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -19,8 +29,169 @@ impl SymbolicStructDefinition {
         self.fields.push(symbolic_struct_field);
     }
 
+    pub fn get_fields(&self) -> &Vec<SymbolicStructFieldDefinition> {
+        &self.fields
+    }
+
     pub fn get_size_in_bytes(&self) -> u64 {
-        self.fields.iter().map(|field| field.get_size_in_bytes()).sum()
+        self.get_size_in_bytes_at_depth(0, SymbolicStructFieldDefinition::POINTER_SIZE)
+    }
+
+    /// Gets the offset of each field. Fields with an explicit offset are placed there, and all other fields directly follow the previous field.
+    pub fn get_field_offsets(&self) -> Vec<u64> {
+        self.get_field_offsets_at_depth(0, SymbolicStructFieldDefinition::POINTER_SIZE)
+    }
+
+    /// Creates a zero-initialized valued struct with this layout, suitable for reading from memory.
+    /// Nested structs are flattened into dotted field names (ie `position.x`), and gaps left by explicit offsets become padding.
+    /// Fields that overlap an earlier field (such as union members) are skipped, as valued structs are laid out contiguously.
+    /// Pointer fields are sized and typed for the given target bitness.
+    pub fn create_valued_struct(
+        &self,
+        symbolic_struct_ref: SymbolicStructRef,
+        bitness: Bitness,
+    ) -> ValuedStruct {
+        let mut valued_fields = vec![];
+        let mut end_offset = 0;
+
+        self.append_valued_fields(0, "", 0, bitness, &mut valued_fields, &mut end_offset);
+
+        ValuedStruct::new(symbolic_struct_ref, valued_fields)
+    }
+
+    pub(crate) fn get_size_in_bytes_at_depth(
+        &self,
+        depth: u32,
+        pointer_size: u64,
+    ) -> u64 {
+        self.get_field_offsets_at_depth(depth, pointer_size)
+            .iter()
+            .zip(self.fields.iter())
+            .map(|(offset, field)| offset + field.get_size_in_bytes_at_depth(depth, pointer_size))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn get_field_offsets_at_depth(
+        &self,
+        depth: u32,
+        pointer_size: u64,
+    ) -> Vec<u64> {
+        let mut next_offset = 0;
+
+        self.fields
+            .iter()
+            .map(|field| {
+                let offset = field.get_offset().unwrap_or(next_offset);
+
+                next_offset = offset + field.get_size_in_bytes_at_depth(depth, pointer_size);
+                offset
+            })
+            .collect()
+    }
+
+    fn append_valued_fields(
+        &self,
+        base_offset: u64,
+        name_prefix: &str,
+        depth: u32,
+        bitness: Bitness,
+        valued_fields: &mut Vec<ValuedStructField>,
+        end_offset: &mut u64,
+    ) {
+        let pointer_size = SymbolicStructFieldDefinition::get_pointer_size(bitness);
+
+        for (offset, field) in self.get_field_offsets_at_depth(depth, pointer_size).into_iter().zip(self.fields.iter()) {
+            let field_offset = base_offset + offset;
+            let field_size = field.get_size_in_bytes_at_depth(depth, pointer_size);
+            let field_name = if field.get_name().is_empty() {
+                format!("{}field_{:X}", name_prefix, offset)
+            } else {
+                format!("{}{}", name_prefix, field.get_name())
+            };
+
+            if field_size == 0 {
+                log::warn!("Skipping field '{}' of unknown type '{}'.", field_name, field.get_value());
+                continue;
+            }
+
+            if field_offset < *end_offset {
+                log::debug!("Skipping field '{}', which overlaps a previous field.", field_name);
+                continue;
+            }
+
+            if field_offset > *end_offset {
+                valued_fields.push(Self::create_padding_field(*end_offset, field_offset - *end_offset));
+            }
+
+            if field.is_padding() {
+                valued_fields.push(Self::create_padding_field(field_offset, field_size));
+            } else if field.get_container_type() == ContainerType::Pointer {
+                let pointer_node = match bitness {
+                    Bitness::Bit32 => ValuedStructFieldNode::Pointer32(0),
+                    Bitness::Bit64 => ValuedStructFieldNode::Pointer64(0),
+                };
+
+                valued_fields.push(ValuedStructField::new(field_name, pointer_node, false));
+            } else if field.is_nested_struct() {
+                let Some(nested_definition) = SymbolicStructRegistry::get_instance().get(field.get_value().get_data_type_id()) else {
+                    continue;
+                };
+
+                if depth >= SymbolicStructFieldDefinition::MAX_NESTING_DEPTH {
+                    continue;
+                }
+
+                let mut nested_end_offset = field_offset;
+
+                match field.get_container_type() {
+                    ContainerType::Array(length) => {
+                        let element_size = nested_definition.get_size_in_bytes_at_depth(depth + 1, pointer_size);
+
+                        for index in 0..length {
+                            let element_prefix = format!("{}[{}].", field_name, index);
+
+                            nested_definition.append_valued_fields(
+                                field_offset + index * element_size,
+                                &element_prefix,
+                                depth + 1,
+                                bitness,
+                                valued_fields,
+                                &mut nested_end_offset,
+                            );
+                        }
+                    }
+                    _ => {
+                        let nested_prefix = format!("{}.", field_name);
+
+                        nested_definition.append_valued_fields(field_offset, &nested_prefix, depth + 1, bitness, valued_fields, &mut nested_end_offset);
+                    }
+                }
+
+                // Trailing padding within the nested struct is not described by any of its fields.
+                if nested_end_offset < field_offset + field_size {
+                    valued_fields.push(Self::create_padding_field(nested_end_offset, field_offset + field_size - nested_end_offset));
+                }
+            } else {
+                let field_node = match field.get_container_type() {
+                    ContainerType::Array(_) => ValuedStructFieldNode::Array(DataValue::new(field.get_value().clone(), vec![0u8; field_size as usize])),
+                    _ => ValuedStructFieldNode::Value(DataValue::new(field.get_value().clone(), vec![0u8; field_size as usize])),
+                };
+
+                valued_fields.push(ValuedStructField::new(field_name, field_node, false));
+            }
+
+            *end_offset = field_offset + field_size;
+        }
+    }
+
+    fn create_padding_field(
+        offset: u64,
+        size_in_bytes: u64,
+    ) -> ValuedStructField {
+        let padding_value = DataValue::new(DataTypeRef::new(DataTypeU8::get_data_type_id()), vec![0u8; size_in_bytes as usize]);
+
+        ValuedStructField::new(format!("pad_{:X}", offset), ValuedStructFieldNode::Array(padding_value), true)
     }
 }
 
@@ -30,10 +201,21 @@ impl FromStr for SymbolicStructDefinition {
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let fields: Result<Vec<SymbolicStructFieldDefinition>, Self::Err> = string
             .split(';')
-            .filter(|&field_string| !field_string.is_empty())
+            .filter(|&field_string| !field_string.trim().is_empty())
             .map(|field_string| SymbolicStructFieldDefinition::from_str(field_string))
             .collect();
 
         Ok(SymbolicStructDefinition::new(fields?))
     }
 }
+
+impl fmt::Display for SymbolicStructDefinition {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let field_strings: Vec<String> = self.fields.iter().map(|field| field.to_string()).collect();
+
+        write!(formatter, "{}", field_strings.join(";"))
+    }
+}
@@ -1,39 +1,193 @@
+use crate::registries::symbolic_structs::symbolic_struct_registry::SymbolicStructRegistry;
+use crate::structures::memory::bitness::Bitness;
 use crate::structures::{data_types::data_type_ref::DataTypeRef, structs::container_type::ContainerType};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
+/// A single field of a symbolic struct, written as `[@offset] type[container] [name]`.
+/// The type is either a registered data type, the name of another symbolic struct, or `pad` for unused bytes.
+/// For example: `u32 health`, `@0x40 f32[3] position`, `Inventory* inventory`, `pad[12]`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SymbolicStructFieldDefinition {
+    #[serde(default)]
+    name: String,
     data_type: DataTypeRef,
     container_type: ContainerType,
+    #[serde(default)]
+    offset: Option<u64>,
 }
 
 impl SymbolicStructFieldDefinition {
+    /// The type id used for padding bytes, which occupy space but hold no meaningful value.
+    pub const PADDING_TYPE_ID: &str = "pad";
+
+    /// The size of pointer fields in 64-bit layouts, which are assumed when the target bitness is not known.
+    pub const POINTER_SIZE: u64 = 8;
+
+    /// The size of pointer fields in 32-bit layouts.
+    pub const POINTER_SIZE_32: u64 = 4;
+
+    /// Limits how deeply nested struct sizes are resolved, which guards against structs that contain themselves by value.
+    pub const MAX_NESTING_DEPTH: u32 = 32;
+
     pub fn new(
         data_type: DataTypeRef,
         container_type: ContainerType,
     ) -> Self {
-        SymbolicStructFieldDefinition { data_type, container_type }
+        SymbolicStructFieldDefinition {
+            name: String::new(),
+            data_type,
+            container_type,
+            offset: None,
+        }
+    }
+
+    pub fn new_named(
+        name: String,
+        data_type: DataTypeRef,
+        container_type: ContainerType,
+        offset: Option<u64>,
+    ) -> Self {
+        SymbolicStructFieldDefinition {
+            name,
+            data_type,
+            container_type,
+            offset,
+        }
+    }
+
+    pub fn new_padding(size_in_bytes: u64) -> Self {
+        SymbolicStructFieldDefinition::new(DataTypeRef::new(Self::PADDING_TYPE_ID), ContainerType::Array(size_in_bytes))
     }
 
     pub fn get_size_in_bytes(&self) -> u64 {
-        self.data_type.get_unit_size_in_bytes()
+        self.get_size_in_bytes_at_depth(0, Self::POINTER_SIZE)
+    }
+
+    /// Gets the size of pointer fields in a layout for a target of the given bitness.
+    pub fn get_pointer_size(bitness: Bitness) -> u64 {
+        match bitness {
+            Bitness::Bit32 => Self::POINTER_SIZE_32,
+            Bitness::Bit64 => Self::POINTER_SIZE,
+        }
     }
 
     pub fn get_value(&self) -> &DataTypeRef {
         &self.data_type
     }
+
+    pub fn get_container_type(&self) -> ContainerType {
+        self.container_type
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the explicit offset of this field within its struct, if one was provided.
+    pub fn get_offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    pub fn is_padding(&self) -> bool {
+        self.data_type.get_data_type_id() == Self::PADDING_TYPE_ID
+    }
+
+    /// Determines whether this field refers to another symbolic struct rather than to a data type.
+    pub fn is_nested_struct(&self) -> bool {
+        !self.is_padding() && !self.data_type.is_valid()
+    }
+
+    pub(crate) fn get_size_in_bytes_at_depth(
+        &self,
+        depth: u32,
+        pointer_size: u64,
+    ) -> u64 {
+        match self.container_type {
+            ContainerType::Pointer => pointer_size,
+            ContainerType::Array(length) => self.get_unit_size_in_bytes(depth, pointer_size) * length,
+            ContainerType::None => self.get_unit_size_in_bytes(depth, pointer_size),
+        }
+    }
+
+    fn get_unit_size_in_bytes(
+        &self,
+        depth: u32,
+        pointer_size: u64,
+    ) -> u64 {
+        if self.is_padding() {
+            1
+        } else if self.data_type.is_valid() {
+            self.data_type.get_unit_size_in_bytes()
+        } else if depth < Self::MAX_NESTING_DEPTH {
+            match SymbolicStructRegistry::get_instance().get(self.data_type.get_data_type_id()) {
+                Some(nested_definition) => nested_definition.get_size_in_bytes_at_depth(depth + 1, pointer_size),
+                None => 0,
+            }
+        } else {
+            0
+        }
+    }
+}
+
+impl fmt::Display for SymbolicStructFieldDefinition {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if let Some(offset) = self.offset {
+            write!(formatter, "@0x{:X} ", offset)?;
+        }
+
+        write!(formatter, "{}{}", self.data_type, self.container_type)?;
+
+        if !self.name.is_empty() {
+            write!(formatter, " {}", self.name)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl FromStr for SymbolicStructFieldDefinition {
     type Err = String;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut string = string.trim();
+
+        // Parse an optional explicit offset, ie `@0x10` or `@16`.
+        let offset = match string.strip_prefix('@') {
+            Some(offset_and_remainder) => {
+                let (offset_str, remainder) = offset_and_remainder
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(|| format!("Missing field type after offset in '{}'", string))?;
+                let offset = match offset_str
+                    .strip_prefix("0x")
+                    .or_else(|| offset_str.strip_prefix("0X"))
+                {
+                    Some(hex_str) => u64::from_str_radix(hex_str, 16),
+                    None => offset_str.parse::<u64>(),
+                }
+                .map_err(|error| format!("Invalid field offset '{}': {}", offset_str, error))?;
+
+                string = remainder.trim_start();
+                Some(offset)
+            }
+            None => None,
+        };
+
+        // Split off the optional field name. Container suffixes never contain whitespace, so the name follows the first space.
+        let (type_string, name) = match string.split_once(char::is_whitespace) {
+            Some((type_string, name)) => (type_string, name.trim()),
+            None => (string, ""),
+        };
+
         // Determine container type based on string suffix.
-        let (type_str, container_type) = if let Some(open_idx) = string.find('[') {
-            if let Some(close_idx) = string.strip_suffix(']').map(|_| string.len() - 1) {
-                let type_part = string[..open_idx].trim();
-                let len_part = string[open_idx + 1..close_idx].trim();
+        let (type_str, container_type) = if let Some(open_idx) = type_string.find('[') {
+            if let Some(close_idx) = type_string.strip_suffix(']').map(|_| type_string.len() - 1) {
+                let type_part = type_string[..open_idx].trim();
+                let len_part = type_string[open_idx + 1..close_idx].trim();
 
                 let len = len_part
                     .parse::<u64>()
@@ -43,14 +197,18 @@ impl FromStr for SymbolicStructFieldDefinition {
             } else {
                 return Err("Missing closing ']' in array type".into());
             }
-        } else if let Some(stripped) = string.strip_suffix('*') {
+        } else if let Some(stripped) = type_string.strip_suffix('*') {
             (stripped, ContainerType::Pointer)
         } else {
-            (string, ContainerType::None)
+            (type_string, ContainerType::None)
         };
 
+        if type_str.is_empty() {
+            return Err(format!("Missing field type in '{}'", string));
+        }
+
         let data_type = DataTypeRef::from_str(type_str.trim())?;
 
-        Ok(SymbolicStructFieldDefinition::new(data_type, container_type))
+        Ok(SymbolicStructFieldDefinition::new_named(name.to_string(), data_type, container_type, offset))
     }
 }
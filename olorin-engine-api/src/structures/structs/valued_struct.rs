@@ -54,6 +54,24 @@ impl ValuedStruct {
         self.fields.iter().find(|field| field.get_name() == field_name)
     }
 
+    /// Gets the byte offset of the named field, as fields are laid out contiguously.
+    pub fn get_field_offset(
+        &self,
+        field_name: &str,
+    ) -> Option<u64> {
+        let mut offset = 0;
+
+        for field in &self.fields {
+            if field.get_name() == field_name {
+                return Some(offset);
+            }
+
+            offset += field.get_size_in_bytes();
+        }
+
+        None
+    }
+
     pub fn get_field_mut(
         &mut self,
         field_name: &str,
@@ -0,0 +1,3 @@
+pub mod module_section;
+pub mod module_symbol;
+pub mod module_symbol_table;
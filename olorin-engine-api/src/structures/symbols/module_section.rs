@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// A section from the on-disk image of a module, such as `.text` or `.data`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleSection {
+    name: String,
+    /// The offset of this section from the base address of the module.
+    module_offset: u64,
+    size: u64,
+}
+
+impl ModuleSection {
+    pub fn new(
+        name: String,
+        module_offset: u64,
+        size: u64,
+    ) -> Self {
+        Self { name, module_offset, size }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_module_offset(&self) -> u64 {
+        self.module_offset
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn contains_module_offset(
+        &self,
+        module_offset: u64,
+    ) -> bool {
+        module_offset >= self.module_offset && module_offset - self.module_offset < self.size
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// A named location within a module, such as an export or an entry from `.symtab` or `.dynsym`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleSymbol {
+    name: String,
+    /// The offset of this symbol from the base address of the module.
+    module_offset: u64,
+    /// The size of this symbol in bytes, or zero if unknown (ie for PE exports).
+    size: u64,
+}
+
+impl ModuleSymbol {
+    pub fn new(
+        name: String,
+        module_offset: u64,
+        size: u64,
+    ) -> Self {
+        Self { name, module_offset, size }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_module_offset(&self) -> u64 {
+        self.module_offset
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+}
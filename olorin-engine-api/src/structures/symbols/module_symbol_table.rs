@@ -0,0 +1,89 @@
+use crate::structures::symbols::module_section::ModuleSection;
+use crate::structures::symbols::module_symbol::ModuleSymbol;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The sections and symbols parsed from the on-disk image of a module, supporting lookups in either direction.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ModuleSymbolTable {
+    sections: Vec<ModuleSection>,
+    /// All symbols, sorted by module offset.
+    symbols: Vec<ModuleSymbol>,
+    /// Maps symbol names to their index in the symbol list.
+    symbol_indicies_by_name: HashMap<String, usize>,
+}
+
+impl ModuleSymbolTable {
+    pub fn new(
+        sections: Vec<ModuleSection>,
+        mut symbols: Vec<ModuleSymbol>,
+    ) -> Self {
+        symbols.sort_by(|left, right| {
+            left.get_module_offset()
+                .cmp(&right.get_module_offset())
+                .then_with(|| left.get_name().cmp(right.get_name()))
+        });
+
+        // The same symbol frequently appears in both `.symtab` and `.dynsym`, so keep only one copy.
+        symbols.dedup_by(|left, right| left.get_module_offset() == right.get_module_offset() && left.get_name() == right.get_name());
+
+        let mut symbol_indicies_by_name = HashMap::with_capacity(symbols.len());
+
+        for (index, symbol) in symbols.iter().enumerate() {
+            symbol_indicies_by_name
+                .entry(symbol.get_name().to_string())
+                .or_insert(index);
+        }
+
+        Self {
+            sections,
+            symbols,
+            symbol_indicies_by_name,
+        }
+    }
+
+    pub fn get_sections(&self) -> &Vec<ModuleSection> {
+        &self.sections
+    }
+
+    pub fn get_symbols(&self) -> &Vec<ModuleSymbol> {
+        &self.symbols
+    }
+
+    /// Finds the symbol containing the given module offset, returning the symbol and the offset into that symbol.
+    /// Symbols of unknown size are considered to extend to the next symbol, but never beyond the containing section.
+    pub fn find_symbol(
+        &self,
+        module_offset: u64,
+    ) -> Option<(&ModuleSymbol, u64)> {
+        let index = self
+            .symbols
+            .partition_point(|symbol| symbol.get_module_offset() <= module_offset)
+            .checked_sub(1)?;
+        let symbol = &self.symbols[index];
+        let offset_into_symbol = module_offset - symbol.get_module_offset();
+
+        if symbol.get_size() > 0 {
+            return (offset_into_symbol < symbol.get_size()).then_some((symbol, offset_into_symbol));
+        }
+
+        let is_same_section = self
+            .sections
+            .iter()
+            .find(|section| section.contains_module_offset(symbol.get_module_offset()))
+            .map(|section| section.contains_module_offset(module_offset))
+            .unwrap_or(false);
+
+        is_same_section.then_some((symbol, offset_into_symbol))
+    }
+
+    /// Finds the module offset of the symbol with the given name.
+    pub fn find_symbol_by_name(
+        &self,
+        symbol_name: &str,
+    ) -> Option<&ModuleSymbol> {
+        self.symbol_indicies_by_name
+            .get(symbol_name)
+            .map(|index| &self.symbols[*index])
+    }
+}
@@ -0,0 +1,19 @@
+use olorin_engine_api::structures::address_expressions::address_expression::AddressExpression;
+use std::str::FromStr;
+
+#[test]
+fn command_line_addresses_keep_bare_numbers_decimal() {
+    let parse = |string: &str| {
+        AddressExpression::parse_command_line_address(string)
+            .expect("Failed to parse the address.")
+            .get_constant_value()
+    };
+
+    assert_eq!(parse("1000"), Some(1000));
+    assert_eq!(parse("0x1000"), Some(0x1000));
+    assert_eq!(parse("1A2B"), Some(0x1A2B));
+    assert_eq!(parse("1000+10"), Some(0x1010));
+
+    // Expressions stored in projects are written in hex, and must continue to round trip.
+    assert_eq!(AddressExpression::from_str("1000").unwrap().get_constant_value(), Some(0x1000));
+}
@@ -0,0 +1,13 @@
+use olorin_engine_api::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
+use olorin_engine_api::structures::scanning::parameters::mapped::vectorization_size_override::VectorizationSizeOverride;
+use std::str::FromStr;
+
+#[test]
+fn auto_clears_the_vectorization_size_override() {
+    let auto = VectorizationSizeOverride::from_str("auto").unwrap();
+    let forced = VectorizationSizeOverride::from_str("32").unwrap();
+
+    assert_eq!(auto.get_vectorization_size(), None);
+    assert_eq!(forced.get_vectorization_size(), Some(VectorizationSize::Vector32));
+    assert!(VectorizationSizeOverride::from_str("8").is_err());
+}
@@ -6,6 +6,9 @@ pub static HARDWARE_VECTOR_NAME: OnceLock<String> = OnceLock::new();
 
 pub struct Vectors {}
 
+/// Detects the SIMD capabilities of the host CPU at runtime. Scanners operate on byte-wise comparisons and masks, so
+/// the reported vector size is the widest register for which the host supports integer byte operations, rather than the
+/// widest register the host supports at all. For example, AVX without AVX2 only offers 256-bit floating point operations.
 impl Vectors {
     pub fn has_vector_support() -> bool {
         *HAS_VECTOR_SUPPORT.get_or_init(|| {
@@ -58,14 +61,18 @@ impl Vectors {
         })
     }
 
+    /// Returns whether the host can natively operate on vectors of the given size in bytes.
+    pub fn supports_vector_size(vector_size_in_bytes: u64) -> bool {
+        Self::has_vector_support() && vector_size_in_bytes <= Self::get_hardware_vector_size()
+    }
+
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     fn detect_x86_features() -> (bool, u64, String) {
-        if is_x86_feature_detected!("avx512f") {
-            (true, 64, "avx512f".to_string())
+        // Byte-wise compares on 512-bit registers require the BW extension in addition to the foundation instructions.
+        if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+            (true, 64, "avx512bw".to_string())
         } else if is_x86_feature_detected!("avx2") {
             (true, 32, "avx2".to_string())
-        } else if is_x86_feature_detected!("avx") {
-            (true, 32, "avx".to_string())
         } else if is_x86_feature_detected!("sse4.2") {
             (true, 16, "sse4.2".to_string())
         } else if is_x86_feature_detected!("sse4.1") {
@@ -76,8 +83,6 @@ impl Vectors {
             (true, 16, "sse3".to_string())
         } else if is_x86_feature_detected!("sse2") {
             (true, 16, "sse2".to_string())
-        } else if is_x86_feature_detected!("sse") {
-            (true, 16, "sse".to_string())
         } else {
             (false, 0, "(none)".to_string())
         }
@@ -85,9 +90,9 @@ impl Vectors {
 
     #[cfg(target_arch = "aarch64")]
     fn detect_arm_features() -> (bool, u64, String) {
-        // NEON is required for AArch64, so we can safely assume it's available
-        // SVE and SVE2 detection would go here if needed
-        if cfg!(target_feature = "neon") {
+        // NEON is mandatory on AArch64, but we still query it at runtime in case the binary was built for a generic target.
+        // SVE and SVE2 detection would go here if needed, although their register width is implementation defined.
+        if std::arch::is_aarch64_feature_detected!("neon") {
             (true, 16, "neon".to_string()) // NEON uses 128-bit vectors
         } else {
             (false, 0, "(none)".to_string())
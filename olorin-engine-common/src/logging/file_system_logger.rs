@@ -11,6 +11,7 @@ use std::{path::PathBuf, sync::RwLock};
 
 pub struct FileSystemLogger {
     log_event_senders: Arc<RwLock<Vec<Sender<String>>>>,
+    log_dispatcher_sender: Sender<String>,
     log_dispatcher_receiver: Receiver<String>,
 }
 
@@ -19,6 +20,7 @@ impl FileSystemLogger {
         let (log_dispatcher_sender, log_dispatcher_receiver) = crossbeam_channel::unbounded();
         let file_system_logger = FileSystemLogger {
             log_event_senders: Arc::new(RwLock::new(Vec::new())),
+            log_dispatcher_sender: log_dispatcher_sender.clone(),
             log_dispatcher_receiver,
         };
 
@@ -39,6 +41,15 @@ impl FileSystemLogger {
         Ok(receiver)
     }
 
+    /// Sends an already formatted log message to subscribers without writing it to the log file, such as messages logged by
+    /// a privileged shell, which keeps its own log file.
+    pub fn forward_log_message(
+        &self,
+        log_message: String,
+    ) {
+        let _ = self.log_dispatcher_sender.send(log_message);
+    }
+
     /// Starts sending events for log messages to subscribers. This should be called after everything that needs logs is initialized.
     /// For example, after the engine and GUI are initialized, that would be a good time to call this.
     pub fn start_log_event_sender(&self) {
@@ -1 +1,3 @@
-
+pub mod library_injection;
+pub mod memory_allocation;
+pub mod speedhack;
@@ -0,0 +1,43 @@
+use std::path::{Path, PathBuf};
+
+/// A shared library loaded into a process at runtime by the library injector.
+#[derive(Clone, Debug)]
+pub struct InjectedLibrary {
+    process_id: u32,
+    library_path: PathBuf,
+    /// The handle returned by the loader of the target, required to unload the library again.
+    library_handle: u64,
+    module_base_address: u64,
+}
+
+impl InjectedLibrary {
+    pub fn new(
+        process_id: u32,
+        library_path: PathBuf,
+        library_handle: u64,
+        module_base_address: u64,
+    ) -> Self {
+        Self {
+            process_id,
+            library_path,
+            library_handle,
+            module_base_address,
+        }
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_library_path(&self) -> &Path {
+        &self.library_path
+    }
+
+    pub fn get_library_handle(&self) -> u64 {
+        self.library_handle
+    }
+
+    pub fn get_module_base_address(&self) -> u64 {
+        self.module_base_address
+    }
+}
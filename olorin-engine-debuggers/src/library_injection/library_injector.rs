@@ -0,0 +1,121 @@
+use crate::library_injection::injected_library::InjectedLibrary;
+use once_cell::sync::Lazy;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub(crate) trait PlatformLibraryInjector {
+    /// Loads the library into a running process, returning the loaded library once its module is mapped.
+    fn inject_library(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Result<InjectedLibrary, String>;
+
+    fn unload_library(injected_library: &InjectedLibrary) -> Result<(), String>;
+
+    /// Gets the environment variable that makes the loader of a newly launched process load the given libraries before any other.
+    fn get_preload_environment_variable(library_paths: &[PathBuf]) -> Result<(String, String), String>;
+}
+
+#[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+use crate::library_injection::linux::linux_library_injector::LinuxLibraryInjector as LibraryInjectorImpl;
+
+#[cfg(not(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+use crate::library_injection::unsupported::unsupported_library_injector::UnsupportedLibraryInjector as LibraryInjectorImpl;
+
+static INJECTED_LIBRARIES: Lazy<Mutex<Vec<InjectedLibrary>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// Loads shared libraries into target processes, such that hooks and other tooling can run from within the target.
+pub struct LibraryInjector;
+
+impl LibraryInjector {
+    pub fn inject_library(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Result<InjectedLibrary, String> {
+        // The loader of the target resolves relative paths against its own working directory and search paths, not ours.
+        let library_path = library_path
+            .canonicalize()
+            .map_err(|error| format!("Failed to resolve library {}: {}", library_path.display(), error))?;
+
+        if Self::find_injected_library(process_id, &library_path).is_some() {
+            return Err(format!("{} is already injected into process {}.", library_path.display(), process_id));
+        }
+
+        let injected_library = LibraryInjectorImpl::inject_library(process_id, &library_path)?;
+
+        log::info!(
+            "Injected {} into process {} at 0x{:X}.",
+            library_path.display(),
+            process_id,
+            injected_library.get_module_base_address()
+        );
+
+        if let Ok(mut injected_libraries) = INJECTED_LIBRARIES.lock() {
+            injected_libraries.push(injected_library.clone());
+        }
+
+        Ok(injected_library)
+    }
+
+    /// Unloads a library that was previously injected into the process at runtime.
+    pub fn unload_library(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Result<(), String> {
+        let library_path = library_path.canonicalize().unwrap_or_else(|_| library_path.to_path_buf());
+        let injected_library = match Self::find_injected_library(process_id, &library_path) {
+            Some(injected_library) => injected_library,
+            None => return Err(format!("{} was not injected into process {} at runtime.", library_path.display(), process_id)),
+        };
+
+        LibraryInjectorImpl::unload_library(&injected_library)?;
+
+        log::info!("Unloaded {} from process {}.", library_path.display(), process_id);
+
+        if let Ok(mut injected_libraries) = INJECTED_LIBRARIES.lock() {
+            injected_libraries.retain(|injected_library| {
+                injected_library.get_process_id() != process_id || injected_library.get_library_path() != library_path
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Gets the libraries injected into the given process at runtime that have not since been unloaded.
+    pub fn get_injected_libraries(process_id: u32) -> Vec<InjectedLibrary> {
+        match INJECTED_LIBRARIES.lock() {
+            Ok(injected_libraries) => injected_libraries
+                .iter()
+                .filter(|injected_library| injected_library.get_process_id() == process_id)
+                .cloned()
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    /// Gets the environment variable that preloads the given libraries into a process when it is launched with it.
+    pub fn get_preload_environment_variable(library_paths: &[PathBuf]) -> Result<(String, String), String> {
+        let library_paths = library_paths
+            .iter()
+            .map(|library_path| {
+                library_path
+                    .canonicalize()
+                    .map_err(|error| format!("Failed to resolve library {}: {}", library_path.display(), error))
+            })
+            .collect::<Result<Vec<PathBuf>, String>>()?;
+
+        LibraryInjectorImpl::get_preload_environment_variable(&library_paths)
+    }
+
+    fn find_injected_library(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Option<InjectedLibrary> {
+        INJECTED_LIBRARIES.lock().ok().and_then(|injected_libraries| {
+            injected_libraries
+                .iter()
+                .find(|injected_library| injected_library.get_process_id() == process_id && injected_library.get_library_path() == library_path)
+                .cloned()
+        })
+    }
+}
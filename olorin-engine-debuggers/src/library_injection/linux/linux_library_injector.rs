@@ -0,0 +1,124 @@
+use crate::library_injection::injected_library::InjectedLibrary;
+use crate::library_injection::library_injector::PlatformLibraryInjector;
+use crate::library_injection::linux::linux_module_maps::LinuxModuleMaps;
+use crate::library_injection::linux::linux_remote_call_session::LinuxRemoteCallSession;
+use crate::library_injection::linux::linux_symbol_resolver::LinuxSymbolResolver;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+pub struct LinuxLibraryInjector;
+
+impl LinuxLibraryInjector {
+    /// Determines whether a module is one that may export the dynamic loading functions, being glibc, its legacy libdl, or musl.
+    fn is_dynamic_loading_module(module_file_name: &str) -> bool {
+        ["libc.so", "libc-", "libdl.so", "libdl-", "ld-musl-"]
+            .iter()
+            .any(|prefix| module_file_name.starts_with(prefix))
+    }
+
+    fn resolve_dynamic_loading_function(
+        process_id: u32,
+        symbol_names: &[&str],
+    ) -> Result<u64, String> {
+        // Older versions of glibc only export these from libdl, which is often not loaded, but have private equivalents in libc itself.
+        match LinuxSymbolResolver::resolve_symbol(process_id, Self::is_dynamic_loading_module, symbol_names)? {
+            Some(function_address) => Ok(function_address),
+            None => Err(format!(
+                "Failed to find {} in process {}. The C library may not be loaded yet, such as when the process was launched suspended, in which case the library can be preloaded at launch instead.",
+                symbol_names[0], process_id
+            )),
+        }
+    }
+
+    /// Gets the reason the last dynamic loading call failed, as reported by `dlerror` in the target.
+    fn get_dynamic_loading_error(
+        process_id: u32,
+        remote_call_session: &mut LinuxRemoteCallSession,
+    ) -> String {
+        let dlerror_address = match Self::resolve_dynamic_loading_function(process_id, &["dlerror"]) {
+            Ok(dlerror_address) => dlerror_address,
+            Err(_) => return "Unknown error.".to_string(),
+        };
+
+        match remote_call_session.call_function(dlerror_address, &[]) {
+            Ok(0) => "Unknown error.".to_string(),
+            Ok(error_message_address) => remote_call_session
+                .read_string(error_message_address, 1024)
+                .unwrap_or_else(|error| error),
+            Err(error) => error,
+        }
+    }
+}
+
+impl PlatformLibraryInjector for LinuxLibraryInjector {
+    fn inject_library(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Result<InjectedLibrary, String> {
+        let dlopen_address = Self::resolve_dynamic_loading_function(process_id, &["dlopen", "__libc_dlopen_mode"])?;
+        let mut library_path_bytes = library_path.as_os_str().as_bytes().to_vec();
+
+        library_path_bytes.push(0);
+
+        let mut remote_call_session = LinuxRemoteCallSession::attach(process_id)?;
+        let library_path_address = remote_call_session.get_scratch_address(library_path_bytes.len() as u64)?;
+
+        remote_call_session.write_bytes(library_path_address, &library_path_bytes)?;
+
+        let library_handle = remote_call_session.call_function(dlopen_address, &[library_path_address, libc::RTLD_NOW as u64])?;
+
+        if library_handle == 0 {
+            return Err(format!(
+                "Failed to load {}: {}",
+                library_path.display(),
+                Self::get_dynamic_loading_error(process_id, &mut remote_call_session)
+            ));
+        }
+
+        // Release the process before reading its maps, such that it is not held stopped any longer than needed.
+        drop(remote_call_session);
+
+        let module_base_address = LinuxModuleMaps::get_module_base_address(process_id, &library_path.to_string_lossy())?
+            .ok_or_else(|| format!("{} was loaded, but is not mapped into process {}.", library_path.display(), process_id))?;
+
+        Ok(InjectedLibrary::new(process_id, library_path.to_path_buf(), library_handle, module_base_address))
+    }
+
+    fn unload_library(injected_library: &InjectedLibrary) -> Result<(), String> {
+        let process_id = injected_library.get_process_id();
+        let dlclose_address = Self::resolve_dynamic_loading_function(process_id, &["dlclose", "__libc_dlclose"])?;
+        let mut remote_call_session = LinuxRemoteCallSession::attach(process_id)?;
+        let result = remote_call_session.call_function(dlclose_address, &[injected_library.get_library_handle()])?;
+
+        if result as libc::c_int != 0 {
+            return Err(format!(
+                "Failed to unload {}: {}",
+                injected_library.get_library_path().display(),
+                Self::get_dynamic_loading_error(process_id, &mut remote_call_session)
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn get_preload_environment_variable(library_paths: &[PathBuf]) -> Result<(String, String), String> {
+        let mut preload_value = String::new();
+
+        for library_path in library_paths {
+            let library_path = library_path.to_string_lossy();
+
+            // The loader splits the list on both colons and spaces, with no way to escape them.
+            if library_path.contains([':', ' ']) {
+                return Err(format!("Cannot preload {}, as its path contains a colon or space.", library_path));
+            }
+
+            if !preload_value.is_empty() {
+                preload_value.push(':');
+            }
+
+            preload_value.push_str(&library_path);
+        }
+
+        Ok(("LD_PRELOAD".to_string(), preload_value))
+    }
+}
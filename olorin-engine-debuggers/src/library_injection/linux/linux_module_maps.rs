@@ -0,0 +1,53 @@
+use std::fs;
+
+/// A file backed mapping listed in `/proc/<pid>/maps`.
+pub struct LinuxModuleMapping {
+    pub start_address: u64,
+    pub file_offset: u64,
+    pub file_path: String,
+}
+
+pub struct LinuxModuleMaps;
+
+impl LinuxModuleMaps {
+    /// Gets the file backed mappings of a process, skipping anonymous and pseudo mappings such as `[stack]`.
+    pub fn get_file_mappings(process_id: u32) -> Result<Vec<LinuxModuleMapping>, String> {
+        let maps = fs::read_to_string(format!("/proc/{}/maps", process_id))
+            .map_err(|error| format!("Failed to read the memory maps of process {}: {}", process_id, error))?;
+
+        Ok(maps.lines().filter_map(Self::parse_mapping).collect())
+    }
+
+    /// Gets the base address of the module loaded from the given file, which is the lowest address at which it is mapped.
+    pub fn get_module_base_address(
+        process_id: u32,
+        file_path: &str,
+    ) -> Result<Option<u64>, String> {
+        Ok(Self::get_file_mappings(process_id)?
+            .iter()
+            .filter(|mapping| mapping.file_path == file_path)
+            .map(|mapping| mapping.start_address)
+            .min())
+    }
+
+    /// Parses a line of the form `start-end perms offset dev inode path`, where the path may contain spaces.
+    fn parse_mapping(line: &str) -> Option<LinuxModuleMapping> {
+        let mut fields = line.splitn(6, char::is_whitespace);
+        let (start_address, _end_address) = fields.next()?.split_once('-')?;
+        let _permissions = fields.next()?;
+        let file_offset = fields.next()?;
+        let _device = fields.next()?;
+        let _inode = fields.next()?;
+        let file_path = fields.next()?.trim_start();
+
+        if !file_path.starts_with('/') {
+            return None;
+        }
+
+        Some(LinuxModuleMapping {
+            start_address: u64::from_str_radix(start_address, 16).ok()?,
+            file_offset: u64::from_str_radix(file_offset, 16).ok()?,
+            file_path: file_path.to_string(),
+        })
+    }
+}
@@ -0,0 +1,496 @@
+use std::fs;
+use std::io;
+
+/// Space below the stack pointer of the interrupted function that it may still be using without having adjusted the stack pointer.
+const RED_ZONE_SIZE: u64 = 0x80;
+
+/// Space reserved below the stack pointer of the hijacked thread for scratch data, below which called functions get their stack.
+/// This is large enough to hold any path.
+const STACK_RESERVE_SIZE: u64 = 0x2000;
+
+/// The encoding of `syscall`. Stepping over it advances the instruction pointer by its size.
+#[cfg(target_arch = "x86_64")]
+const SYSCALL_INSTRUCTION: [u8; 2] = [0x0F, 0x05];
+
+/// The encoding of `svc #0`. Stepping over it advances the instruction pointer by its size.
+#[cfg(target_arch = "aarch64")]
+const SYSCALL_INSTRUCTION: [u8; 4] = 0xD400_0001u32.to_le_bytes();
+
+/// Hijacks the main thread of a process with ptrace to call functions within it. The thread is attached for the lifetime of the
+/// session, and its registers are restored when the session is dropped, such that it resumes exactly where it was interrupted.
+/// Functions are called with a null return address, such that returning faults at address zero and hands control back to us.
+pub struct LinuxRemoteCallSession {
+    process_id: libc::pid_t,
+    saved_registers: libc::user_regs_struct,
+    /// A stop signal that arrived while attached, which is delivered once we detach rather than swallowed.
+    deferred_signal: Option<libc::c_int>,
+    /// The address of a system call instruction in the target, found on the first system call of the session.
+    syscall_instruction_address: Option<u64>,
+}
+
+impl LinuxRemoteCallSession {
+    pub fn attach(process_id: u32) -> Result<Self, String> {
+        let process_id = process_id as libc::pid_t;
+
+        if unsafe { libc::ptrace(libc::PTRACE_ATTACH, process_id, std::ptr::null_mut::<libc::c_void>(), std::ptr::null_mut::<libc::c_void>()) } == -1 {
+            return Err(format!(
+                "Failed to attach to process {}: {}. Check that no other debugger is attached, and that ptrace is permitted (kernel.yama.ptrace_scope).",
+                process_id,
+                io::Error::last_os_error()
+            ));
+        }
+
+        let deferred_signal = match Self::wait_for_stop(process_id) {
+            Ok(libc::SIGSTOP) => None,
+            Ok(signal) => Some(signal),
+            Err(error) => return Err(error),
+        };
+        let saved_registers = match Self::get_registers(process_id) {
+            Ok(saved_registers) => saved_registers,
+            Err(error) => {
+                Self::detach(process_id, deferred_signal);
+                return Err(error);
+            }
+        };
+
+        Ok(Self {
+            process_id,
+            saved_registers,
+            deferred_signal,
+            syscall_instruction_address: None,
+        })
+    }
+
+    /// Calls a function in the target with up to six integer arguments, returning its integer return value.
+    pub fn call_function(
+        &mut self,
+        function_address: u64,
+        arguments: &[u64],
+    ) -> Result<u64, String> {
+        if arguments.len() > 6 {
+            return Err("Remote calls support at most six arguments.".to_string());
+        }
+
+        let mut registers = self.saved_registers;
+        let stack_pointer = (Self::get_stack_pointer(&registers) - STACK_RESERVE_SIZE) & !0xF;
+
+        self.prepare_call(&mut registers, function_address, arguments, stack_pointer)?;
+        Self::set_registers(self.process_id, &registers)?;
+
+        let mut signal = 0;
+
+        loop {
+            if unsafe { libc::ptrace(libc::PTRACE_CONT, self.process_id, std::ptr::null_mut::<libc::c_void>(), signal as usize as *mut libc::c_void) } == -1 {
+                return Err(format!("Failed to resume process {}: {}", self.process_id, io::Error::last_os_error()));
+            }
+
+            let stop_signal = Self::wait_for_stop(self.process_id)?;
+            let registers = Self::get_registers(self.process_id)?;
+
+            match stop_signal {
+                libc::SIGSEGV if Self::get_instruction_pointer(&registers) == 0 => return Ok(Self::get_return_value(&registers)),
+                libc::SIGSEGV | libc::SIGBUS | libc::SIGILL | libc::SIGABRT => {
+                    return Err(format!(
+                        "Process {} raised signal {} at 0x{:X} during the remote call.",
+                        self.process_id,
+                        stop_signal,
+                        Self::get_instruction_pointer(&registers)
+                    ));
+                }
+                // Stopping mid call would leave the thread hijacked, so stop signals are held until we detach.
+                libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU => {
+                    self.deferred_signal = Some(stop_signal);
+                    signal = 0;
+                }
+                // Other signals are delivered as normal, as the handlers of the target may rely on them.
+                _ => signal = stop_signal,
+            }
+        }
+    }
+
+    /// Makes a system call from the hijacked thread with up to six arguments, returning its raw result, which is a negated error number
+    /// on failure. The thread is stepped over an existing system call instruction of the target, such that no code is modified and
+    /// no library needs to be loaded, which also allows system calls in processes launched suspended.
+    pub fn call_syscall(
+        &mut self,
+        syscall_number: u64,
+        arguments: &[u64],
+    ) -> Result<i64, String> {
+        if arguments.len() > 6 {
+            return Err("System calls support at most six arguments.".to_string());
+        }
+
+        let syscall_instruction_address = match self.syscall_instruction_address {
+            Some(syscall_instruction_address) => syscall_instruction_address,
+            None => {
+                let syscall_instruction_address = self.find_syscall_instruction()?;
+
+                self.syscall_instruction_address = Some(syscall_instruction_address);
+                syscall_instruction_address
+            }
+        };
+        let return_address = syscall_instruction_address + SYSCALL_INSTRUCTION.len() as u64;
+        let mut registers = self.saved_registers;
+
+        Self::prepare_syscall(&mut registers, syscall_instruction_address, syscall_number, arguments);
+        Self::set_registers(self.process_id, &registers)?;
+
+        let mut signal = 0;
+
+        loop {
+            if unsafe { libc::ptrace(libc::PTRACE_SINGLESTEP, self.process_id, std::ptr::null_mut::<libc::c_void>(), signal as usize as *mut libc::c_void) } == -1 {
+                return Err(format!("Failed to step process {}: {}", self.process_id, io::Error::last_os_error()));
+            }
+
+            let stop_signal = Self::wait_for_stop(self.process_id)?;
+            let registers = Self::get_registers(self.process_id)?;
+
+            match stop_signal {
+                libc::SIGTRAP if Self::get_instruction_pointer(&registers) == return_address => return Ok(Self::get_return_value(&registers) as i64),
+                // A signal delivered before the system call ran diverts the thread into its handler, which is stepped through until it
+                // returns to the system call instruction.
+                libc::SIGTRAP => signal = 0,
+                libc::SIGSEGV | libc::SIGBUS | libc::SIGILL | libc::SIGABRT => {
+                    return Err(format!(
+                        "Process {} raised signal {} at 0x{:X} during the system call.",
+                        self.process_id,
+                        stop_signal,
+                        Self::get_instruction_pointer(&registers)
+                    ));
+                }
+                libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU => {
+                    self.deferred_signal = Some(stop_signal);
+                    signal = 0;
+                }
+                _ => signal = stop_signal,
+            }
+        }
+    }
+
+    /// Writes bytes to the memory of the target.
+    pub fn write_bytes(
+        &self,
+        address: u64,
+        bytes: &[u8],
+    ) -> Result<(), String> {
+        let local_iovec = libc::iovec {
+            iov_base: bytes.as_ptr() as *mut libc::c_void,
+            iov_len: bytes.len(),
+        };
+        let remote_iovec = libc::iovec {
+            iov_base: address as *mut libc::c_void,
+            iov_len: bytes.len(),
+        };
+
+        match unsafe { libc::process_vm_writev(self.process_id, &local_iovec, 1, &remote_iovec, 1, 0) } {
+            written if written == bytes.len() as isize => Ok(()),
+            _ => Err(format!("Failed to write to process {} at 0x{:X}: {}", self.process_id, address, io::Error::last_os_error())),
+        }
+    }
+
+    /// Reads a null terminated string from the memory of the target, up to the given length.
+    pub fn read_string(
+        &self,
+        address: u64,
+        max_length: usize,
+    ) -> Result<String, String> {
+        let mut bytes = vec![0u8; max_length];
+        let local_iovec = libc::iovec {
+            iov_base: bytes.as_mut_ptr() as *mut libc::c_void,
+            iov_len: bytes.len(),
+        };
+        let remote_iovec = libc::iovec {
+            iov_base: address as *mut libc::c_void,
+            iov_len: bytes.len(),
+        };
+
+        // The read may be cut short by the end of the mapping, which is fine as long as the string terminates before it.
+        let read_length = unsafe { libc::process_vm_readv(self.process_id, &local_iovec, 1, &remote_iovec, 1, 0) };
+
+        if read_length <= 0 {
+            return Err(format!("Failed to read from process {} at 0x{:X}: {}", self.process_id, address, io::Error::last_os_error()));
+        }
+
+        bytes.truncate(read_length as usize);
+
+        let string_length = bytes.iter().position(|byte| *byte == 0).unwrap_or(bytes.len());
+
+        Ok(String::from_utf8_lossy(&bytes[..string_length]).into_owned())
+    }
+
+    /// Gets an address below the stack of the interrupted function at which scratch data, such as strings to pass to remote calls,
+    /// can be written without being clobbered by the calls themselves.
+    pub fn get_scratch_address(
+        &self,
+        size: u64,
+    ) -> Result<u64, String> {
+        if size > STACK_RESERVE_SIZE - RED_ZONE_SIZE - 0x10 {
+            return Err(format!("Scratch data of {} bytes exceeds the reserved stack space.", size));
+        }
+
+        Ok((Self::get_stack_pointer(&self.saved_registers) - RED_ZONE_SIZE - size) & !0xF)
+    }
+
+    /// Finds a system call instruction in the executable mappings of the target, starting with the vDSO, which the kernel maps into
+    /// every process before any of its code runs.
+    fn find_syscall_instruction(&self) -> Result<u64, String> {
+        let maps = fs::read_to_string(format!("/proc/{}/maps", self.process_id))
+            .map_err(|error| format!("Failed to read the memory maps of process {}: {}", self.process_id, error))?;
+        let mut executable_ranges: Vec<(u64, u64, bool)> = maps
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let (start_address, end_address) = fields.next()?.split_once('-')?;
+                let permissions = fields.next()?;
+                let is_vdso = line.ends_with("[vdso]");
+
+                if !permissions.contains('x') {
+                    return None;
+                }
+
+                Some((u64::from_str_radix(start_address, 16).ok()?, u64::from_str_radix(end_address, 16).ok()?, is_vdso))
+            })
+            .collect();
+
+        executable_ranges.sort_by_key(|(_, _, is_vdso)| !is_vdso);
+
+        for (start_address, end_address, _) in executable_ranges {
+            // Mappings that are execute only cannot be read, and are skipped.
+            let Ok(bytes) = self.read_bytes(start_address, (end_address - start_address) as usize) else {
+                continue;
+            };
+
+            // Instructions are aligned to their size on aarch64, whereas x86_64 instructions may start at any byte.
+            let alignment = if cfg!(target_arch = "aarch64") { SYSCALL_INSTRUCTION.len() } else { 1 };
+
+            if let Some(offset) = bytes
+                .windows(SYSCALL_INSTRUCTION.len())
+                .enumerate()
+                .step_by(alignment)
+                .find(|(_, window)| *window == SYSCALL_INSTRUCTION)
+                .map(|(offset, _)| offset)
+            {
+                return Ok(start_address + offset as u64);
+            }
+        }
+
+        Err(format!("Failed to find a system call instruction in process {}.", self.process_id))
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        length: usize,
+    ) -> Result<Vec<u8>, String> {
+        let mut bytes = vec![0u8; length];
+        let local_iovec = libc::iovec {
+            iov_base: bytes.as_mut_ptr() as *mut libc::c_void,
+            iov_len: bytes.len(),
+        };
+        let remote_iovec = libc::iovec {
+            iov_base: address as *mut libc::c_void,
+            iov_len: bytes.len(),
+        };
+
+        match unsafe { libc::process_vm_readv(self.process_id, &local_iovec, 1, &remote_iovec, 1, 0) } {
+            read_length if read_length == length as isize => Ok(bytes),
+            _ => Err(format!("Failed to read from process {} at 0x{:X}: {}", self.process_id, address, io::Error::last_os_error())),
+        }
+    }
+
+    fn detach(
+        process_id: libc::pid_t,
+        deferred_signal: Option<libc::c_int>,
+    ) {
+        let signal = deferred_signal.unwrap_or(0);
+
+        if unsafe { libc::ptrace(libc::PTRACE_DETACH, process_id, std::ptr::null_mut::<libc::c_void>(), signal as usize as *mut libc::c_void) } == -1 {
+            log::error!("Failed to detach from process {}: {}", process_id, io::Error::last_os_error());
+        }
+    }
+
+    fn wait_for_stop(process_id: libc::pid_t) -> Result<libc::c_int, String> {
+        let mut status = 0;
+
+        if unsafe { libc::waitpid(process_id, &mut status, libc::__WALL) } == -1 {
+            return Err(format!("Failed to wait for process {}: {}", process_id, io::Error::last_os_error()));
+        }
+
+        if libc::WIFEXITED(status) || libc::WIFSIGNALED(status) {
+            return Err(format!("Process {} exited.", process_id));
+        }
+
+        if !libc::WIFSTOPPED(status) {
+            return Err(format!("Process {} did not stop, wait status: {}", process_id, status));
+        }
+
+        Ok(libc::WSTOPSIG(status))
+    }
+
+    fn get_registers(process_id: libc::pid_t) -> Result<libc::user_regs_struct, String> {
+        let mut registers: libc::user_regs_struct = unsafe { std::mem::zeroed() };
+        let mut iovec = libc::iovec {
+            iov_base: &mut registers as *mut libc::user_regs_struct as *mut libc::c_void,
+            iov_len: std::mem::size_of::<libc::user_regs_struct>(),
+        };
+
+        if unsafe { libc::ptrace(libc::PTRACE_GETREGSET, process_id, libc::NT_PRSTATUS as usize as *mut libc::c_void, &mut iovec) } == -1 {
+            return Err(format!("Failed to get the registers of process {}: {}", process_id, io::Error::last_os_error()));
+        }
+
+        Ok(registers)
+    }
+
+    fn set_registers(
+        process_id: libc::pid_t,
+        registers: &libc::user_regs_struct,
+    ) -> Result<(), String> {
+        let mut registers = *registers;
+        let mut iovec = libc::iovec {
+            iov_base: &mut registers as *mut libc::user_regs_struct as *mut libc::c_void,
+            iov_len: std::mem::size_of::<libc::user_regs_struct>(),
+        };
+
+        if unsafe { libc::ptrace(libc::PTRACE_SETREGSET, process_id, libc::NT_PRSTATUS as usize as *mut libc::c_void, &mut iovec) } == -1 {
+            return Err(format!("Failed to set the registers of process {}: {}", process_id, io::Error::last_os_error()));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn prepare_call(
+        &self,
+        registers: &mut libc::user_regs_struct,
+        function_address: u64,
+        arguments: &[u64],
+        stack_pointer: u64,
+    ) -> Result<(), String> {
+        // Push a null return address, leaving the stack misaligned by 8 as though the function had been entered with a call.
+        let return_address_pointer = stack_pointer - 8;
+
+        self.write_bytes(return_address_pointer, &0u64.to_le_bytes())?;
+
+        let argument_registers = [
+            &mut registers.rdi,
+            &mut registers.rsi,
+            &mut registers.rdx,
+            &mut registers.rcx,
+            &mut registers.r8,
+            &mut registers.r9,
+        ];
+
+        for (argument_register, argument) in argument_registers.into_iter().zip(arguments) {
+            *argument_register = *argument;
+        }
+
+        registers.rsp = return_address_pointer;
+        registers.rip = function_address;
+        registers.rax = 0;
+        // Prevent the kernel from treating the hijacked state as an interrupted system call to be restarted.
+        registers.orig_rax = u64::MAX;
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn prepare_call(
+        &self,
+        registers: &mut libc::user_regs_struct,
+        function_address: u64,
+        arguments: &[u64],
+        stack_pointer: u64,
+    ) -> Result<(), String> {
+        for (argument_index, argument) in arguments.iter().enumerate() {
+            registers.regs[argument_index] = *argument;
+        }
+
+        // The link register holds the return address.
+        registers.regs[30] = 0;
+        registers.sp = stack_pointer;
+        registers.pc = function_address;
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn prepare_syscall(
+        registers: &mut libc::user_regs_struct,
+        syscall_instruction_address: u64,
+        syscall_number: u64,
+        arguments: &[u64],
+    ) {
+        let argument_registers = [
+            &mut registers.rdi,
+            &mut registers.rsi,
+            &mut registers.rdx,
+            &mut registers.r10,
+            &mut registers.r8,
+            &mut registers.r9,
+        ];
+
+        for (argument_register, argument) in argument_registers.into_iter().zip(arguments) {
+            *argument_register = *argument;
+        }
+
+        registers.rax = syscall_number;
+        registers.rip = syscall_instruction_address;
+        // Prevent the kernel from treating the hijacked state as an interrupted system call to be restarted.
+        registers.orig_rax = u64::MAX;
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn prepare_syscall(
+        registers: &mut libc::user_regs_struct,
+        syscall_instruction_address: u64,
+        syscall_number: u64,
+        arguments: &[u64],
+    ) {
+        for (argument_index, argument) in arguments.iter().enumerate() {
+            registers.regs[argument_index] = *argument;
+        }
+
+        registers.regs[8] = syscall_number;
+        registers.pc = syscall_instruction_address;
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn get_stack_pointer(registers: &libc::user_regs_struct) -> u64 {
+        registers.rsp
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn get_stack_pointer(registers: &libc::user_regs_struct) -> u64 {
+        registers.sp
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn get_instruction_pointer(registers: &libc::user_regs_struct) -> u64 {
+        registers.rip
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn get_instruction_pointer(registers: &libc::user_regs_struct) -> u64 {
+        registers.pc
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn get_return_value(registers: &libc::user_regs_struct) -> u64 {
+        registers.rax
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn get_return_value(registers: &libc::user_regs_struct) -> u64 {
+        registers.regs[0]
+    }
+}
+
+impl Drop for LinuxRemoteCallSession {
+    fn drop(&mut self) {
+        if let Err(error) = Self::set_registers(self.process_id, &self.saved_registers) {
+            log::error!("{}", error);
+        }
+
+        Self::detach(self.process_id, self.deferred_signal);
+    }
+}
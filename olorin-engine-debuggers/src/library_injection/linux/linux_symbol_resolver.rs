@@ -0,0 +1,65 @@
+use crate::library_injection::linux::linux_module_maps::LinuxModuleMaps;
+use object::{Object, ObjectSegment, ObjectSymbol};
+use std::fs;
+use std::path::Path;
+
+pub struct LinuxSymbolResolver;
+
+impl LinuxSymbolResolver {
+    /// Resolves the address of the first of the given symbols exported by a module loaded in the target, searching modules whose
+    /// file name satisfies the filter in the order they are mapped.
+    pub fn resolve_symbol<F>(
+        process_id: u32,
+        module_filter: F,
+        symbol_names: &[&str],
+    ) -> Result<Option<u64>, String>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mappings = LinuxModuleMaps::get_file_mappings(process_id)?;
+        let mut searched_module_paths: Vec<&str> = vec![];
+
+        for mapping in &mappings {
+            let module_file_name = Path::new(&mapping.file_path)
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy())
+                .unwrap_or_default();
+
+            if mapping.file_offset != 0 || !module_filter(&module_file_name) || searched_module_paths.contains(&mapping.file_path.as_str()) {
+                continue;
+            }
+
+            searched_module_paths.push(&mapping.file_path);
+
+            // Read the module through the root of the target, such that modules of a target in another mount namespace still resolve.
+            let module_bytes = fs::read(format!("/proc/{}/root{}", process_id, mapping.file_path))
+                .map_err(|error| format!("Failed to read module {}: {}", mapping.file_path, error))?;
+            let module_file = object::File::parse(&*module_bytes).map_err(|error| format!("Failed to parse module {}: {}", mapping.file_path, error))?;
+
+            if !module_file.is_64() {
+                return Err("Only 64 bit processes are supported.".to_string());
+            }
+
+            // The module is mapped at its lowest segment address plus the load bias, which is what symbol addresses are relative to.
+            let lowest_segment_address = module_file
+                .segments()
+                .map(|segment| segment.address())
+                .min()
+                .unwrap_or(0)
+                & !0xFFF;
+            let load_bias = mapping.start_address.wrapping_sub(lowest_segment_address);
+
+            for symbol_name in symbol_names {
+                let symbol = module_file
+                    .dynamic_symbols()
+                    .find(|symbol| symbol.is_definition() && symbol.name() == Ok(symbol_name));
+
+                if let Some(symbol) = symbol {
+                    return Ok(Some(load_bias.wrapping_add(symbol.address())));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
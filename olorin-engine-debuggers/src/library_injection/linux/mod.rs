@@ -0,0 +1,4 @@
+pub mod linux_library_injector;
+pub mod linux_module_maps;
+pub mod linux_remote_call_session;
+pub mod linux_symbol_resolver;
@@ -0,0 +1,22 @@
+use crate::library_injection::injected_library::InjectedLibrary;
+use crate::library_injection::library_injector::PlatformLibraryInjector;
+use std::path::{Path, PathBuf};
+
+pub struct UnsupportedLibraryInjector;
+
+impl PlatformLibraryInjector for UnsupportedLibraryInjector {
+    fn inject_library(
+        _process_id: u32,
+        _library_path: &Path,
+    ) -> Result<InjectedLibrary, String> {
+        Err("Library injection is not supported on this platform.".to_string())
+    }
+
+    fn unload_library(_injected_library: &InjectedLibrary) -> Result<(), String> {
+        Err("Library injection is not supported on this platform.".to_string())
+    }
+
+    fn get_preload_environment_variable(_library_paths: &[PathBuf]) -> Result<(String, String), String> {
+        Err("Preloading libraries is not supported on this platform.".to_string())
+    }
+}
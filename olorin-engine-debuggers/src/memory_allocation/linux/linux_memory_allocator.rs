@@ -0,0 +1,97 @@
+use crate::library_injection::linux::linux_remote_call_session::LinuxRemoteCallSession;
+use crate::memory_allocation::memory_allocator::PlatformMemoryAllocator;
+use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::io;
+
+/// Manages memory within a process by making `mmap`, `munmap`, and `mprotect` system calls from one of its threads.
+pub struct LinuxMemoryAllocator;
+
+impl LinuxMemoryAllocator {
+    fn get_protection_flags(protection: MemoryProtectionEnum) -> u64 {
+        let mut protection_flags = libc::PROT_NONE;
+
+        if protection.contains(MemoryProtectionEnum::READ) {
+            protection_flags |= libc::PROT_READ;
+        }
+
+        // Private mappings are always copy on write, so both are requested as writable.
+        if protection.intersects(MemoryProtectionEnum::WRITE | MemoryProtectionEnum::COPY_ON_WRITE) {
+            protection_flags |= libc::PROT_WRITE;
+        }
+
+        if protection.contains(MemoryProtectionEnum::EXECUTE) {
+            protection_flags |= libc::PROT_EXEC;
+        }
+
+        protection_flags as u64
+    }
+
+    fn get_page_size() -> u64 {
+        unsafe { libc::sysconf(libc::_SC_PAGESIZE) as u64 }
+    }
+
+    fn call_syscall(
+        process_info: &OpenedProcessInfo,
+        syscall_number: libc::c_long,
+        arguments: &[u64],
+    ) -> Result<u64, String> {
+        let mut remote_call_session = LinuxRemoteCallSession::attach(process_info.get_process_id_raw())?;
+        let result = remote_call_session.call_syscall(syscall_number as u64, arguments)?;
+
+        // The kernel reports errors as negated error numbers, which occupy the last page of the address space.
+        if (-4095..0).contains(&result) {
+            return Err(io::Error::from_raw_os_error(-result as i32).to_string());
+        }
+
+        Ok(result as u64)
+    }
+}
+
+impl PlatformMemoryAllocator for LinuxMemoryAllocator {
+    fn allocate(
+        process_info: &OpenedProcessInfo,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<u64, String> {
+        let arguments = [
+            0,
+            size,
+            Self::get_protection_flags(protection),
+            (libc::MAP_PRIVATE | libc::MAP_ANONYMOUS) as u64,
+            // No file descriptor, as the mapping is anonymous.
+            u64::MAX,
+            0,
+        ];
+
+        Self::call_syscall(process_info, libc::SYS_mmap, &arguments)
+            .map_err(|error| format!("Failed to allocate 0x{:X} bytes in process {}: {}", size, process_info.get_process_id_raw(), error))
+    }
+
+    fn free(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<(), String> {
+        Self::call_syscall(process_info, libc::SYS_munmap, &[address, size])
+            .map(|_| ())
+            .map_err(|error| format!("Failed to free 0x{:X} in process {}: {}", address, process_info.get_process_id_raw(), error))
+    }
+
+    fn protect(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<(), String> {
+        // The kernel requires a page aligned start, so widen the range to the pages it overlaps.
+        let page_size = Self::get_page_size();
+        let start_address = address & !(page_size - 1);
+        let end_address = address.saturating_add(size).saturating_add(page_size - 1) & !(page_size - 1);
+        let arguments = [start_address, end_address - start_address, Self::get_protection_flags(protection)];
+
+        Self::call_syscall(process_info, libc::SYS_mprotect, &arguments)
+            .map(|_| ())
+            .map_err(|error| format!("Failed to change the protection of 0x{:X} in process {}: {}", address, process_info.get_process_id_raw(), error))
+    }
+}
@@ -0,0 +1,42 @@
+use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+
+/// Memory allocated within a process by the memory allocator, such as a code cave.
+#[derive(Clone, Debug)]
+pub struct MemoryAllocation {
+    process_id: u32,
+    address: u64,
+    size: u64,
+    protection: MemoryProtectionEnum,
+}
+
+impl MemoryAllocation {
+    pub fn new(
+        process_id: u32,
+        address: u64,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Self {
+        Self {
+            process_id,
+            address,
+            size,
+            protection,
+        }
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn get_protection(&self) -> MemoryProtectionEnum {
+        self.protection
+    }
+}
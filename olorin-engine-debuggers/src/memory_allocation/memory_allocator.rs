@@ -0,0 +1,139 @@
+use crate::memory_allocation::memory_allocation::MemoryAllocation;
+use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+
+pub(crate) trait PlatformMemoryAllocator {
+    /// Allocates committed memory anywhere in the process, returning its address.
+    fn allocate(
+        process_info: &OpenedProcessInfo,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<u64, String>;
+
+    fn free(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+    ) -> Result<(), String>;
+
+    /// Changes the protection of every page overlapping the given range.
+    fn protect(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<(), String>;
+}
+
+#[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+use crate::memory_allocation::linux::linux_memory_allocator::LinuxMemoryAllocator as MemoryAllocatorImpl;
+
+#[cfg(target_os = "windows")]
+use crate::memory_allocation::windows::windows_memory_allocator::WindowsMemoryAllocator as MemoryAllocatorImpl;
+
+#[cfg(not(any(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")), target_os = "windows")))]
+use crate::memory_allocation::unsupported::unsupported_memory_allocator::UnsupportedMemoryAllocator as MemoryAllocatorImpl;
+
+static MEMORY_ALLOCATIONS: Lazy<Mutex<Vec<MemoryAllocation>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// Allocates, frees, and changes the protection of memory within target processes, such as for code caves or to unlock read only pages.
+pub struct MemoryAllocator;
+
+impl MemoryAllocator {
+    pub fn allocate(
+        process_info: &OpenedProcessInfo,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<MemoryAllocation, String> {
+        if size == 0 {
+            return Err("Cannot allocate zero bytes.".to_string());
+        }
+
+        let process_id = process_info.get_process_id_raw();
+        let address = MemoryAllocatorImpl::allocate(process_info, size, protection)?;
+        let memory_allocation = MemoryAllocation::new(process_id, address, size, protection);
+
+        log::info!("Allocated 0x{:X} bytes ({}) in process {} at 0x{:X}.", size, protection, process_id, address);
+
+        if let Ok(mut memory_allocations) = MEMORY_ALLOCATIONS.lock() {
+            memory_allocations.push(memory_allocation.clone());
+        }
+
+        Ok(memory_allocation)
+    }
+
+    /// Frees memory within the process. The size may be omitted for memory allocated through the allocator, in which case the whole
+    /// allocation is freed.
+    pub fn free(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: Option<u64>,
+    ) -> Result<(), String> {
+        let process_id = process_info.get_process_id_raw();
+        let size = match size {
+            Some(size) => size,
+            None => Self::find_allocation(process_id, address)
+                .map(|memory_allocation| memory_allocation.get_size())
+                .ok_or_else(|| format!("0x{:X} was not allocated in process {} by the engine, so the size to free must be given.", address, process_id))?,
+        };
+
+        MemoryAllocatorImpl::free(process_info, address, size)?;
+
+        log::info!("Freed 0x{:X} bytes in process {} at 0x{:X}.", size, process_id, address);
+
+        if let Ok(mut memory_allocations) = MEMORY_ALLOCATIONS.lock() {
+            memory_allocations.retain(|memory_allocation| memory_allocation.get_process_id() != process_id || memory_allocation.get_address() != address);
+        }
+
+        Ok(())
+    }
+
+    pub fn protect(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<(), String> {
+        if size == 0 {
+            return Err("Cannot change the protection of zero bytes.".to_string());
+        }
+
+        MemoryAllocatorImpl::protect(process_info, address, size, protection)?;
+
+        log::info!(
+            "Changed the protection of 0x{:X} bytes in process {} at 0x{:X} to {}.",
+            size,
+            process_info.get_process_id_raw(),
+            address,
+            protection
+        );
+
+        Ok(())
+    }
+
+    /// Gets the memory allocated in the given process through the allocator that has not since been freed.
+    pub fn get_allocations(process_id: u32) -> Vec<MemoryAllocation> {
+        match MEMORY_ALLOCATIONS.lock() {
+            Ok(memory_allocations) => memory_allocations
+                .iter()
+                .filter(|memory_allocation| memory_allocation.get_process_id() == process_id)
+                .cloned()
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    fn find_allocation(
+        process_id: u32,
+        address: u64,
+    ) -> Option<MemoryAllocation> {
+        MEMORY_ALLOCATIONS.lock().ok().and_then(|memory_allocations| {
+            memory_allocations
+                .iter()
+                .find(|memory_allocation| memory_allocation.get_process_id() == process_id && memory_allocation.get_address() == address)
+                .cloned()
+        })
+    }
+}
@@ -0,0 +1,11 @@
+pub mod memory_allocation;
+pub mod memory_allocator;
+
+#[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod linux;
+
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(not(any(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")), target_os = "windows")))]
+mod unsupported;
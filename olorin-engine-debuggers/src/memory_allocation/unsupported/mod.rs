@@ -0,0 +1 @@
+pub mod unsupported_memory_allocator;
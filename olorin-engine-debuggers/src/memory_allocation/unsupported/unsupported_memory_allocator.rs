@@ -0,0 +1,33 @@
+use crate::memory_allocation::memory_allocator::PlatformMemoryAllocator;
+use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Rejects remote memory management, for platforms that do not yet implement it.
+pub struct UnsupportedMemoryAllocator;
+
+impl PlatformMemoryAllocator for UnsupportedMemoryAllocator {
+    fn allocate(
+        _process_info: &OpenedProcessInfo,
+        _size: u64,
+        _protection: MemoryProtectionEnum,
+    ) -> Result<u64, String> {
+        Err("Memory allocation is not supported on this platform.".to_string())
+    }
+
+    fn free(
+        _process_info: &OpenedProcessInfo,
+        _address: u64,
+        _size: u64,
+    ) -> Result<(), String> {
+        Err("Memory allocation is not supported on this platform.".to_string())
+    }
+
+    fn protect(
+        _process_info: &OpenedProcessInfo,
+        _address: u64,
+        _size: u64,
+        _protection: MemoryProtectionEnum,
+    ) -> Result<(), String> {
+        Err("Changing memory protection is not supported on this platform.".to_string())
+    }
+}
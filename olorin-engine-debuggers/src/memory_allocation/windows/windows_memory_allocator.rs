@@ -0,0 +1,113 @@
+use crate::memory_allocation::memory_allocator::PlatformMemoryAllocator;
+use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::ffi::c_void;
+use std::io;
+use windows_sys::Win32::System::Memory::{
+    MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_NOACCESS,
+    PAGE_PROTECTION_FLAGS, PAGE_READONLY, PAGE_READWRITE, PAGE_WRITECOPY, VirtualAllocEx, VirtualFreeEx, VirtualProtectEx,
+};
+
+/// Manages memory within a process through the virtual memory functions, using the handle of the opened process.
+pub struct WindowsMemoryAllocator;
+
+impl WindowsMemoryAllocator {
+    fn get_protection_flags(protection: MemoryProtectionEnum) -> PAGE_PROTECTION_FLAGS {
+        let is_readable = protection.contains(MemoryProtectionEnum::READ);
+        let is_writable = protection.contains(MemoryProtectionEnum::WRITE);
+        let is_copy_on_write = protection.contains(MemoryProtectionEnum::COPY_ON_WRITE);
+
+        // Pages cannot be writable without being readable, so writable protections imply read access.
+        if protection.contains(MemoryProtectionEnum::EXECUTE) {
+            match (is_readable, is_writable, is_copy_on_write) {
+                (_, true, _) => PAGE_EXECUTE_READWRITE,
+                (_, false, true) => PAGE_EXECUTE_WRITECOPY,
+                (true, false, false) => PAGE_EXECUTE_READ,
+                (false, false, false) => PAGE_EXECUTE,
+            }
+        } else {
+            match (is_readable, is_writable, is_copy_on_write) {
+                (_, true, _) => PAGE_READWRITE,
+                (_, false, true) => PAGE_WRITECOPY,
+                (true, false, false) => PAGE_READONLY,
+                (false, false, false) => PAGE_NOACCESS,
+            }
+        }
+    }
+}
+
+impl PlatformMemoryAllocator for WindowsMemoryAllocator {
+    fn allocate(
+        process_info: &OpenedProcessInfo,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<u64, String> {
+        let address = unsafe {
+            VirtualAllocEx(
+                process_info.get_handle() as *mut c_void,
+                std::ptr::null(),
+                size as usize,
+                MEM_COMMIT | MEM_RESERVE,
+                Self::get_protection_flags(protection),
+            )
+        };
+
+        if address.is_null() {
+            return Err(format!(
+                "Failed to allocate 0x{:X} bytes in process {}: {}",
+                size,
+                process_info.get_process_id_raw(),
+                io::Error::last_os_error()
+            ));
+        }
+
+        Ok(address as u64)
+    }
+
+    fn free(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        _size: u64,
+    ) -> Result<(), String> {
+        // Releasing always frees the entire allocation, which requires a size of zero.
+        if unsafe { VirtualFreeEx(process_info.get_handle() as *mut c_void, address as *mut c_void, 0, MEM_RELEASE) } == 0 {
+            return Err(format!(
+                "Failed to free 0x{:X} in process {}: {}",
+                address,
+                process_info.get_process_id_raw(),
+                io::Error::last_os_error()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn protect(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        protection: MemoryProtectionEnum,
+    ) -> Result<(), String> {
+        let mut old_protection_flags: PAGE_PROTECTION_FLAGS = 0;
+
+        if unsafe {
+            VirtualProtectEx(
+                process_info.get_handle() as *mut c_void,
+                address as *const c_void,
+                size as usize,
+                Self::get_protection_flags(protection),
+                &mut old_protection_flags,
+            )
+        } == 0
+        {
+            return Err(format!(
+                "Failed to change the protection of 0x{:X} in process {}: {}",
+                address,
+                process_info.get_process_id_raw(),
+                io::Error::last_os_error()
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::library_injection::linux::linux_module_maps::LinuxModuleMaps;
+use crate::speedhack::speedhack_controller::PlatformSpeedhackController;
+use crate::speedhack::speedhack_shared_state::SpeedhackSharedState;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::os::fd::AsRawFd;
+use std::os::unix::fs::{MetadataExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// How often processes with a shared memory file are checked for having exited.
+const EXITED_PROCESS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The processes for which a thread is already waiting to remove the shared memory file once they exit.
+static WATCHED_PROCESS_IDS: Mutex<Vec<u32>> = Mutex::new(vec![]);
+
+pub struct LinuxSpeedhackController;
+
+impl LinuxSpeedhackController {
+    /// Opens the shared memory file for the given process. The file lives in a world writable directory, so symlinks are never
+    /// followed, and an existing file is only reused if it is a private regular file owned by the engine or the target.
+    fn open_shared_memory_file(
+        process_id: u32,
+        shared_memory_path: &Path,
+    ) -> Result<File, String> {
+        let process_owner = fs::metadata(format!("/proc/{}", process_id))
+            .map(|process_metadata| (process_metadata.uid(), process_metadata.gid()))
+            .ok();
+        let created_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .custom_flags(libc::O_NOFOLLOW)
+            .open(shared_memory_path);
+
+        match created_file {
+            Ok(shared_memory_file) => {
+                // The target must be able to open the file itself, so it is handed to the owner of the target when they differ.
+                if let Some((uid, gid)) = process_owner {
+                    let _ = std::os::unix::fs::fchown(&shared_memory_file, Some(uid), Some(gid));
+                }
+
+                Ok(shared_memory_file)
+            }
+            // The file exists if the library was preloaded, or if the speed was previously set for this process.
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+                let shared_memory_file = OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .custom_flags(libc::O_NOFOLLOW)
+                    .open(shared_memory_path)
+                    .map_err(|error| format!("Failed to open {}: {}", shared_memory_path.display(), error))?;
+                let metadata = shared_memory_file
+                    .metadata()
+                    .map_err(|error| format!("Failed to inspect {}: {}", shared_memory_path.display(), error))?;
+                let engine_uid = unsafe { libc::geteuid() };
+                let is_trusted_owner = metadata.uid() == engine_uid || process_owner.is_some_and(|(uid, _)| metadata.uid() == uid);
+
+                if !metadata.file_type().is_file() || metadata.nlink() != 1 || metadata.mode() & 0o077 != 0 || !is_trusted_owner {
+                    return Err(format!(
+                        "Refusing to use {}, as it is not a private file owned by the engine or the target process.",
+                        shared_memory_path.display()
+                    ));
+                }
+
+                Ok(shared_memory_file)
+            }
+            Err(error) => Err(format!("Failed to create {}: {}", shared_memory_path.display(), error)),
+        }
+    }
+
+    /// Removes the shared memory file once the process exits, as the library cannot clean up after a process that is killed.
+    fn remove_shared_memory_file_on_exit(process_id: u32) {
+        match WATCHED_PROCESS_IDS.lock() {
+            Ok(mut watched_process_ids) if !watched_process_ids.contains(&process_id) => watched_process_ids.push(process_id),
+            _ => return,
+        }
+
+        thread::spawn(move || {
+            let process_path = PathBuf::from(format!("/proc/{}", process_id));
+
+            while process_path.exists() {
+                thread::sleep(EXITED_PROCESS_POLL_INTERVAL);
+            }
+
+            let _ = fs::remove_file(SpeedhackSharedState::get_shared_memory_path(process_id));
+
+            if let Ok(mut watched_process_ids) = WATCHED_PROCESS_IDS.lock() {
+                watched_process_ids.retain(|watched_process_id| *watched_process_id != process_id);
+            }
+        });
+    }
+}
+
+impl PlatformSpeedhackController for LinuxSpeedhackController {
+    fn get_library_file_name() -> Result<&'static str, String> {
+        Ok("libolorin_speedhack.so")
+    }
+
+    fn write_speed_factor(
+        process_id: u32,
+        speed_factor: f64,
+    ) -> Result<(), String> {
+        let shared_memory_path = SpeedhackSharedState::get_shared_memory_path(process_id);
+        let shared_memory_size = SpeedhackSharedState::get_size();
+        let shared_memory_file = Self::open_shared_memory_file(process_id, &shared_memory_path)?;
+
+        if shared_memory_file.metadata().map(|metadata| metadata.len()).unwrap_or(0) < shared_memory_size as u64 {
+            shared_memory_file
+                .set_len(shared_memory_size as u64)
+                .map_err(|error| format!("Failed to resize {}: {}", shared_memory_path.display(), error))?;
+        }
+
+        Self::remove_shared_memory_file_on_exit(process_id);
+
+        let mapping = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                shared_memory_size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                shared_memory_file.as_raw_fd(),
+                0,
+            )
+        };
+
+        if mapping == libc::MAP_FAILED {
+            return Err(format!("Failed to map {}: {}", shared_memory_path.display(), io::Error::last_os_error()));
+        }
+
+        unsafe {
+            (*(mapping as *const SpeedhackSharedState)).set_speed_factor(speed_factor);
+            libc::munmap(mapping, shared_memory_size);
+        }
+
+        Ok(())
+    }
+
+    fn is_library_loaded(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Result<bool, String> {
+        Ok(LinuxModuleMaps::get_module_base_address(process_id, &library_path.to_string_lossy())?.is_some())
+    }
+}
@@ -0,0 +1 @@
+pub mod linux_speedhack_controller;
@@ -0,0 +1,77 @@
+use crate::library_injection::library_injector::LibraryInjector;
+use std::path::{Path, PathBuf};
+
+pub(crate) trait PlatformSpeedhackController {
+    /// Gets the file name of the speedhack library, which is built alongside the engine.
+    fn get_library_file_name() -> Result<&'static str, String>;
+
+    /// Writes the speed factor to the shared state of the given process, creating it if the process has none yet.
+    fn write_speed_factor(
+        process_id: u32,
+        speed_factor: f64,
+    ) -> Result<(), String>;
+
+    /// Determines whether the library is loaded in the process, whether injected at runtime or preloaded at launch.
+    fn is_library_loaded(
+        process_id: u32,
+        library_path: &Path,
+    ) -> Result<bool, String>;
+}
+
+#[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+use crate::speedhack::linux::linux_speedhack_controller::LinuxSpeedhackController as SpeedhackControllerImpl;
+
+#[cfg(not(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+use crate::speedhack::unsupported::unsupported_speedhack_controller::UnsupportedSpeedhackController as SpeedhackControllerImpl;
+
+/// Speeds up or slows down time within a target, by loading the speedhack library into it and controlling its speed factor.
+pub struct SpeedhackController;
+
+impl SpeedhackController {
+    /// Scales the passage of time in the given process by the speed factor, where 1.0 is real time.
+    pub fn set_speed(
+        process_id: u32,
+        speed_factor: f64,
+    ) -> Result<(), String> {
+        if !speed_factor.is_finite() || speed_factor <= 0.0 {
+            return Err(format!("Invalid speed factor {}, the speed factor must be greater than zero.", speed_factor));
+        }
+
+        // Write the factor before loading the library, such that time is scaled from the first hooked call onwards.
+        SpeedhackControllerImpl::write_speed_factor(process_id, speed_factor)?;
+
+        // Real time needs no hooks, so there is no need to load the library just to reset the speed.
+        if speed_factor != 1.0 {
+            let library_path = Self::get_speedhack_library_path()?;
+
+            if !SpeedhackControllerImpl::is_library_loaded(process_id, &library_path)? {
+                LibraryInjector::inject_library(process_id, &library_path)?;
+            }
+        }
+
+        log::info!("Set the speed of process {} to {}x.", process_id, speed_factor);
+
+        Ok(())
+    }
+
+    /// Gets the path of the speedhack library, which is expected next to the running executable or in the directory above it.
+    pub fn get_speedhack_library_path() -> Result<PathBuf, String> {
+        let library_file_name = SpeedhackControllerImpl::get_library_file_name()?;
+        let executable_path = std::env::current_exe().map_err(|error| format!("Failed to get the path of the running executable: {}", error))?;
+
+        executable_path
+            .ancestors()
+            .skip(1)
+            .take(2)
+            .map(|directory| directory.join(library_file_name))
+            .find(|library_path| library_path.is_file())
+            .and_then(|library_path| library_path.canonicalize().ok())
+            .ok_or_else(|| {
+                format!(
+                    "Failed to find the speedhack library {} next to {}. Build the olorin-speedhack crate to produce it.",
+                    library_file_name,
+                    executable_path.display()
+                )
+            })
+    }
+}
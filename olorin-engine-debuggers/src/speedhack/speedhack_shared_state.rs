@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+/// Marks a shared state page as initialized by the engine, as a freshly created page is all zeroes.
+const SPEEDHACK_SHARED_STATE_MAGIC: u32 = 0x4F4C_5348;
+
+/// Incremented whenever the layout changes, such that a stale speedhack library ignores a page it does not understand.
+const SPEEDHACK_SHARED_STATE_VERSION: u32 = 1;
+
+/// The state shared between the engine and the speedhack library loaded in a target, through a small shared memory file.
+/// The engine is the only writer, and the library polls it whenever a hooked clock function is called.
+#[repr(C)]
+pub struct SpeedhackSharedState {
+    magic: AtomicU32,
+    version: AtomicU32,
+    speed_factor_bits: AtomicU64,
+}
+
+impl SpeedhackSharedState {
+    /// Gets the path of the shared memory file for the given process, which both sides derive independently from its process id.
+    pub fn get_shared_memory_path(process_id: u32) -> PathBuf {
+        PathBuf::from(format!("/dev/shm/olorin_speedhack_{}", process_id))
+    }
+
+    pub fn get_size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+
+    /// Gets the speed factor, falling back to real time until the engine has written a valid factor.
+    pub fn get_speed_factor(&self) -> f64 {
+        if self.magic.load(Ordering::Acquire) != SPEEDHACK_SHARED_STATE_MAGIC || self.version.load(Ordering::Relaxed) != SPEEDHACK_SHARED_STATE_VERSION {
+            return 1.0;
+        }
+
+        let speed_factor = f64::from_bits(self.speed_factor_bits.load(Ordering::Relaxed));
+
+        if speed_factor.is_finite() && speed_factor > 0.0 { speed_factor } else { 1.0 }
+    }
+
+    pub fn set_speed_factor(
+        &self,
+        speed_factor: f64,
+    ) {
+        self.speed_factor_bits
+            .store(speed_factor.to_bits(), Ordering::Relaxed);
+        self.version
+            .store(SPEEDHACK_SHARED_STATE_VERSION, Ordering::Relaxed);
+        // Publish the magic last, such that a reader that sees it also sees the factor.
+        self.magic
+            .store(SPEEDHACK_SHARED_STATE_MAGIC, Ordering::Release);
+    }
+}
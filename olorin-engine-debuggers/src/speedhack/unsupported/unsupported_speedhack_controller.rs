@@ -0,0 +1,24 @@
+use crate::speedhack::speedhack_controller::PlatformSpeedhackController;
+use std::path::Path;
+
+pub struct UnsupportedSpeedhackController;
+
+impl PlatformSpeedhackController for UnsupportedSpeedhackController {
+    fn get_library_file_name() -> Result<&'static str, String> {
+        Err("Speedhack is not supported on this platform.".to_string())
+    }
+
+    fn write_speed_factor(
+        _process_id: u32,
+        _speed_factor: f64,
+    ) -> Result<(), String> {
+        Err("Speedhack is not supported on this platform.".to_string())
+    }
+
+    fn is_library_loaded(
+        _process_id: u32,
+        _library_path: &Path,
+    ) -> Result<bool, String> {
+        Err("Speedhack is not supported on this platform.".to_string())
+    }
+}
@@ -0,0 +1,134 @@
+#![cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+
+use olorin_engine_debuggers::library_injection::library_injector::LibraryInjector;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn get_mapped_library_paths(process_id: u32) -> HashSet<String> {
+    fs::read_to_string(format!("/proc/{}/maps", process_id))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(5))
+        .filter(|file_path| file_path.starts_with('/') && file_path.contains(".so"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn get_library_base_address(
+    process_id: u32,
+    library_path: &str,
+) -> Option<u64> {
+    fs::read_to_string(format!("/proc/{}/maps", process_id))
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| line.split_whitespace().nth(5) == Some(library_path))
+        .filter_map(|line| u64::from_str_radix(line.split('-').next()?, 16).ok())
+        .min()
+}
+
+fn wait_until<F>(condition: F) -> bool
+where
+    F: Fn() -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    false
+}
+
+/// Spawns a fixture process that sleeps in a system call for the given duration, then exits successfully. Returns once the C library
+/// of the fixture is loaded, such that libraries can be injected into it.
+fn spawn_fixture(seconds: u32) -> Child {
+    let fixture = Command::new("sleep")
+        .arg(seconds.to_string())
+        .spawn()
+        .expect("Failed to spawn the fixture process.");
+    let process_id = fixture.id();
+
+    assert!(wait_until(|| {
+        get_mapped_library_paths(process_id)
+            .iter()
+            .any(|library_path| library_path.contains("libc"))
+    }));
+
+    fixture
+}
+
+/// Picks a shared library loaded by this test process but not by the fixture, such that loading it into the fixture is observable.
+fn pick_library_to_inject(fixture_process_id: u32) -> PathBuf {
+    let fixture_library_paths = get_mapped_library_paths(fixture_process_id);
+    let mut candidate_library_paths: Vec<String> = get_mapped_library_paths(std::process::id())
+        .into_iter()
+        .filter(|library_path| !fixture_library_paths.contains(library_path))
+        .collect();
+
+    candidate_library_paths.sort();
+
+    PathBuf::from(
+        candidate_library_paths
+            .first()
+            .expect("No shared library is available to inject into the fixture."),
+    )
+}
+
+#[test]
+fn runtime_injected_library_is_mapped_then_unloaded() {
+    let mut fixture = spawn_fixture(2);
+    let process_id = fixture.id();
+    let library_path = pick_library_to_inject(process_id);
+    let library_path_string = library_path.to_string_lossy().into_owned();
+
+    let injected_library = LibraryInjector::inject_library(process_id, &library_path).expect("Failed to inject the library.");
+
+    assert_eq!(
+        Some(injected_library.get_module_base_address()),
+        get_library_base_address(process_id, &library_path_string)
+    );
+    assert_ne!(injected_library.get_library_handle(), 0);
+    assert_eq!(LibraryInjector::get_injected_libraries(process_id).len(), 1);
+    assert!(LibraryInjector::inject_library(process_id, &library_path).is_err());
+
+    LibraryInjector::unload_library(process_id, &library_path).expect("Failed to unload the library.");
+
+    assert!(!get_mapped_library_paths(process_id).contains(&library_path_string));
+    assert!(LibraryInjector::get_injected_libraries(process_id).is_empty());
+    assert!(LibraryInjector::unload_library(process_id, &library_path).is_err());
+
+    // The hijacked thread should have resumed its interrupted sleep, and exited cleanly once it elapsed.
+    assert!(fixture.wait().unwrap().success());
+}
+
+#[test]
+fn preloaded_library_is_mapped_at_launch() {
+    let mut reference_fixture = spawn_fixture(30);
+    let library_path = pick_library_to_inject(reference_fixture.id());
+
+    reference_fixture.kill().unwrap();
+    reference_fixture.wait().unwrap();
+
+    let (preload_key, preload_value) =
+        LibraryInjector::get_preload_environment_variable(std::slice::from_ref(&library_path)).expect("Failed to build the preload environment variable.");
+    let mut fixture = Command::new("sleep")
+        .arg("30")
+        .env(preload_key, preload_value)
+        .spawn()
+        .expect("Failed to spawn the fixture process.");
+    let process_id = fixture.id();
+    let library_path_string = library_path.to_string_lossy().into_owned();
+
+    assert!(wait_until(|| get_mapped_library_paths(process_id).contains(&library_path_string)));
+
+    fixture.kill().unwrap();
+    fixture.wait().unwrap();
+}
@@ -0,0 +1,65 @@
+#![cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_debuggers::memory_allocation::memory_allocator::MemoryAllocator;
+use std::fs;
+use std::process::{Child, Command};
+use std::thread;
+use std::time::Duration;
+
+/// Gets the permissions column of the mapping starting at the given address, ie `rw-p`.
+fn get_mapping_permissions(
+    process_id: u32,
+    address: u64,
+) -> Option<String> {
+    fs::read_to_string(format!("/proc/{}/maps", process_id))
+        .unwrap_or_default()
+        .lines()
+        .find(|line| line.split('-').next().and_then(|start| u64::from_str_radix(start, 16).ok()) == Some(address))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .map(str::to_string)
+}
+
+/// Spawns a fixture process that sleeps in a system call for the given duration, then exits successfully.
+fn spawn_fixture(seconds: u32) -> Child {
+    let fixture = Command::new("sleep")
+        .arg(seconds.to_string())
+        .spawn()
+        .expect("Failed to spawn the fixture process.");
+
+    // Give the fixture time to exec and enter its sleep, such that the vDSO and C library are mapped.
+    thread::sleep(Duration::from_millis(200));
+
+    fixture
+}
+
+#[test]
+fn memory_is_allocated_protected_then_freed() {
+    let mut fixture = spawn_fixture(2);
+    let process_id = fixture.id();
+    let process_info = OpenedProcessInfo::new(process_id, "sleep".to_string(), 0, Bitness::Bit64, None);
+
+    let memory_allocation =
+        MemoryAllocator::allocate(&process_info, 0x2000, MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE).expect("Failed to allocate memory.");
+    let address = memory_allocation.get_address();
+
+    assert_eq!(get_mapping_permissions(process_id, address).as_deref(), Some("rw-p"));
+    assert_eq!(MemoryAllocator::get_allocations(process_id).len(), 1);
+
+    MemoryAllocator::protect(&process_info, address, 0x2000, MemoryProtectionEnum::READ | MemoryProtectionEnum::EXECUTE)
+        .expect("Failed to change the memory protection.");
+
+    assert_eq!(get_mapping_permissions(process_id, address).as_deref(), Some("r-xp"));
+
+    // Memory allocated through the allocator is freed whole without a size.
+    MemoryAllocator::free(&process_info, address, None).expect("Failed to free memory.");
+
+    assert_eq!(get_mapping_permissions(process_id, address), None);
+    assert!(MemoryAllocator::get_allocations(process_id).is_empty());
+    assert!(MemoryAllocator::free(&process_info, address, None).is_err());
+
+    // The hijacked thread should have resumed its interrupted sleep, and exited cleanly once it elapsed.
+    assert!(fixture.wait().unwrap().success());
+}
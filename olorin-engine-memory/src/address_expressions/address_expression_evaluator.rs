@@ -0,0 +1,100 @@
+use crate::memory_queryer::memory_queryer::MemoryQueryer;
+use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use crate::memory_reader::MemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use crate::symbols::symbol_service::SymbolService;
+use olorin_engine_api::structures::address_expressions::address_expression::AddressExpression;
+use olorin_engine_api::structures::address_expressions::address_expression_node::AddressExpressionNode;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Resolves address expressions against a process, looking up module bases and following pointers as required.
+pub struct AddressExpressionEvaluator<'a> {
+    process_info: &'a OpenedProcessInfo,
+    modules: Option<Vec<NormalizedModule>>,
+}
+
+impl<'a> AddressExpressionEvaluator<'a> {
+    /// Evaluates the given address expression, returning an error that names the failing sub-expression if it cannot be resolved.
+    pub fn evaluate(
+        process_info: &'a OpenedProcessInfo,
+        address_expression: &AddressExpression,
+    ) -> Result<u64, String> {
+        // Plain addresses are by far the most common, and require no access to the process.
+        if let Some(address) = address_expression.get_constant_value() {
+            return Ok(address);
+        }
+
+        let mut evaluator = AddressExpressionEvaluator { process_info, modules: None };
+
+        evaluator
+            .evaluate_node(address_expression.get_root_node())
+            .map_err(|error| format!("{} (in address expression '{}')", error, address_expression))
+    }
+
+    fn evaluate_node(
+        &mut self,
+        node: &AddressExpressionNode,
+    ) -> Result<u64, String> {
+        match node {
+            AddressExpressionNode::Constant(value) => Ok(*value),
+            AddressExpressionNode::Module(module_name) => match MemoryQueryer::get_instance().resolve_module(self.get_modules(), module_name) {
+                0 => Err(format!("Module not found for '{}'", node)),
+                module_base_address => Ok(module_base_address),
+            },
+            AddressExpressionNode::Symbol { module_name, symbol_name } => SymbolService::get_instance()
+                .resolve_symbol(self.get_modules(), module_name, symbol_name)
+                .ok_or_else(|| format!("Symbol not found for '{}'", node)),
+            AddressExpressionNode::Dereference(inner_node) => {
+                let pointer_address = self.evaluate_node(inner_node)?;
+
+                self.read_pointer(pointer_address)
+                    .ok_or_else(|| format!("Unable to read pointer at 0x{:X} for '{}'", pointer_address, node))
+            }
+            AddressExpressionNode::Add(left_node, right_node) => self
+                .evaluate_node(left_node)?
+                .checked_add(self.evaluate_node(right_node)?)
+                .ok_or_else(|| format!("Arithmetic overflow in '{}'", node)),
+            AddressExpressionNode::Subtract(left_node, right_node) => self
+                .evaluate_node(left_node)?
+                .checked_sub(self.evaluate_node(right_node)?)
+                .ok_or_else(|| format!("Arithmetic underflow in '{}'", node)),
+            AddressExpressionNode::Multiply(left_node, right_node) => self
+                .evaluate_node(left_node)?
+                .checked_mul(self.evaluate_node(right_node)?)
+                .ok_or_else(|| format!("Arithmetic overflow in '{}'", node)),
+        }
+    }
+
+    /// Gets the modules of the process. These are only queried once per evaluation, and only if the expression references a module.
+    fn get_modules(&mut self) -> &Vec<NormalizedModule> {
+        let process_info = self.process_info;
+
+        self.modules
+            .get_or_insert_with(|| MemoryQueryer::get_instance().get_modules(process_info))
+    }
+
+    /// Reads a pointer sized to the bitness of the opened process.
+    fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Option<u64> {
+        match self.process_info.get_bitness() {
+            Bitness::Bit32 => {
+                let mut bytes = [0u8; 4];
+
+                MemoryReader::get_instance()
+                    .read_bytes(self.process_info, address, &mut bytes)
+                    .then(|| u32::from_le_bytes(bytes) as u64)
+            }
+            Bitness::Bit64 => {
+                let mut bytes = [0u8; 8];
+
+                MemoryReader::get_instance()
+                    .read_bytes(self.process_info, address, &mut bytes)
+                    .then(|| u64::from_le_bytes(bytes))
+            }
+        }
+    }
+}
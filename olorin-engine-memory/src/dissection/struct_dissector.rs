@@ -0,0 +1,350 @@
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_queryer::MemoryQueryer;
+use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use crate::memory_reader::MemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use crate::symbols::symbol_service::SymbolService;
+use olorin_engine_api::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+use olorin_engine_api::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+use olorin_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use olorin_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use olorin_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use olorin_engine_api::structures::data_types::built_in_types::u16::data_type_u16::DataTypeU16;
+use olorin_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use olorin_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use olorin_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use olorin_engine_api::structures::dissection::dissected_field::DissectedField;
+use olorin_engine_api::structures::dissection::dissected_field_kind::DissectedFieldKind;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+
+/// Infers the layout of a block of memory by guessing the type of each slot, using the regions and modules of the process
+/// to recognize pointers. The heuristics are checked from most to least specific: pointers, strings, floats, then integers.
+pub struct StructDissector<'a> {
+    process_info: &'a OpenedProcessInfo,
+    regions: Vec<NormalizedRegion>,
+    executable_regions: Vec<NormalizedRegion>,
+    modules: Vec<NormalizedModule>,
+}
+
+impl<'a> StructDissector<'a> {
+    /// The largest block that can be dissected at once.
+    pub const MAX_DISSECT_LENGTH: u64 = 0x10000;
+
+    /// The minimum number of printable characters for a run of bytes to be considered a string.
+    const MIN_STRING_LENGTH: usize = 4;
+
+    /// Floats outside of this range are more likely to be some other type that happens to have a valid float bit pattern.
+    const MIN_PLAUSIBLE_FLOAT: f64 = 1.0e-4;
+    const MAX_PLAUSIBLE_FLOAT: f64 = 1.0e7;
+
+    /// Integers within this magnitude are considered plausible counts, ids, flags and the like.
+    const MAX_PLAUSIBLE_INTEGER: i64 = 0x10000;
+
+    /// Reads a block of memory and infers the type of each field within it.
+    pub fn dissect(
+        process_info: &'a OpenedProcessInfo,
+        address: u64,
+        length: u64,
+    ) -> Result<Vec<DissectedField>, String> {
+        if length == 0 || length > Self::MAX_DISSECT_LENGTH {
+            return Err(format!(
+                "Dissect length must be between 1 and 0x{:X} bytes, got 0x{:X}.",
+                Self::MAX_DISSECT_LENGTH,
+                length
+            ));
+        }
+
+        let mut bytes = vec![0u8; length as usize];
+
+        if !MemoryReader::get_instance().read_bytes(process_info, address, &mut bytes) {
+            return Err(format!("Unable to read 0x{:X} bytes at 0x{:X}.", length, address));
+        }
+
+        let memory_queryer = MemoryQueryer::get_instance();
+        let mut regions = memory_queryer.get_all_virtual_pages(process_info);
+        let mut executable_regions = memory_queryer.get_virtual_pages(
+            process_info,
+            MemoryProtectionEnum::EXECUTE,
+            MemoryProtectionEnum::empty(),
+            MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+            memory_queryer.get_min_usermode_address(process_info),
+            memory_queryer.get_max_usermode_address(process_info),
+            RegionBoundsHandling::Exclude,
+        );
+
+        // Regions are sorted such that pointer targets can be found by binary search.
+        regions.sort_by_key(|region| region.get_base_address());
+        executable_regions.sort_by_key(|region| region.get_base_address());
+
+        let struct_dissector = StructDissector {
+            process_info,
+            regions,
+            executable_regions,
+            modules: memory_queryer.get_modules(process_info),
+        };
+
+        Ok(struct_dissector.dissect_bytes(&bytes))
+    }
+
+    fn dissect_bytes(
+        &self,
+        bytes: &[u8],
+    ) -> Vec<DissectedField> {
+        let pointer_size = self.get_pointer_size();
+        let mut dissected_fields = vec![];
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            let remaining_bytes = &bytes[offset..];
+            let dissected_field = (if offset % pointer_size == 0 {
+                self.try_dissect_pointer(offset, remaining_bytes)
+            } else {
+                None
+            })
+            .or_else(|| Self::try_dissect_string_utf16(offset, remaining_bytes))
+            .or_else(|| Self::try_dissect_string_utf8(offset, remaining_bytes))
+            .or_else(|| if offset % 8 == 0 { Self::try_dissect_double(offset, remaining_bytes) } else { None })
+            .or_else(|| if offset % 4 == 0 { Self::try_dissect_dword(offset, remaining_bytes) } else { None })
+            .unwrap_or_else(|| Self::dissect_byte(offset, remaining_bytes[0]));
+
+            offset += dissected_field.get_size_in_bytes() as usize;
+            dissected_fields.push(dissected_field);
+        }
+
+        dissected_fields
+    }
+
+    fn try_dissect_pointer(
+        &self,
+        offset: usize,
+        bytes: &[u8],
+    ) -> Option<DissectedField> {
+        let pointer_size = self.get_pointer_size();
+        let pointer_value = Self::read_pointer_value(bytes, pointer_size)?;
+        Self::find_region(&self.regions, pointer_value)?;
+
+        let data_type = match pointer_size {
+            4 => DataTypeRef::new(DataTypeU32::get_data_type_id()),
+            _ => DataTypeRef::new(DataTypeU64::get_data_type_id()),
+        };
+        let is_in_module = self
+            .modules
+            .iter()
+            .any(|module| module.contains_address(pointer_value));
+
+        // A pointer into a module that itself points at executable code is most likely a virtual function table.
+        let kind = if is_in_module && self.is_executable_pointer_at(pointer_value) {
+            DissectedFieldKind::VTablePointer
+        } else {
+            DissectedFieldKind::Pointer
+        };
+        let preview = match SymbolService::get_instance().address_to_symbol(pointer_value, &self.modules) {
+            Some(symbol) => format!("-> {}", symbol),
+            None => match MemoryQueryer::get_instance().address_to_module_expression(pointer_value, &self.modules) {
+                Some(address_expression_node) => format!("-> {}", address_expression_node),
+                None => format!("-> {:X}", pointer_value),
+            },
+        };
+
+        Some(DissectedField::new(
+            offset as u64,
+            pointer_size as u64,
+            kind,
+            data_type,
+            ContainerType::None,
+            preview,
+        ))
+    }
+
+    fn try_dissect_string_utf8(
+        offset: usize,
+        bytes: &[u8],
+    ) -> Option<DissectedField> {
+        let character_count = bytes
+            .iter()
+            .take_while(|&&byte| Self::is_printable_character(byte))
+            .count();
+
+        if character_count < Self::MIN_STRING_LENGTH || bytes.get(character_count).is_some_and(|&byte| byte != 0) {
+            return None;
+        }
+
+        // Include the null terminator, along with any zero padding up to the next 4 byte boundary.
+        let mut length = (character_count + 1).min(bytes.len());
+
+        while (offset + length) % 4 != 0 && bytes.get(length) == Some(&0) {
+            length += 1;
+        }
+
+        let preview = format!("\"{}\"", String::from_utf8_lossy(&bytes[..character_count]));
+
+        Some(DissectedField::new(
+            offset as u64,
+            length as u64,
+            DissectedFieldKind::StringUtf8,
+            DataTypeRef::new(DataTypeStringUtf8::get_data_type_id()),
+            ContainerType::Array(length as u64),
+            preview,
+        ))
+    }
+
+    fn try_dissect_string_utf16(
+        offset: usize,
+        bytes: &[u8],
+    ) -> Option<DissectedField> {
+        if offset % 2 != 0 {
+            return None;
+        }
+
+        let code_units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect();
+        let character_count = code_units
+            .iter()
+            .take_while(|&&code_unit| code_unit <= 0xFF && Self::is_printable_character(code_unit as u8))
+            .count();
+
+        if character_count < Self::MIN_STRING_LENGTH || code_units.get(character_count).is_some_and(|&code_unit| code_unit != 0) {
+            return None;
+        }
+
+        let unit_count = (character_count + 1).min(code_units.len());
+        let preview = format!("L\"{}\"", String::from_utf16_lossy(&code_units[..character_count]));
+
+        Some(DissectedField::new(
+            offset as u64,
+            unit_count as u64 * 2,
+            DissectedFieldKind::StringUtf16,
+            DataTypeRef::new(DataTypeU16::get_data_type_id()),
+            ContainerType::Array(unit_count as u64),
+            preview,
+        ))
+    }
+
+    fn try_dissect_double(
+        offset: usize,
+        bytes: &[u8],
+    ) -> Option<DissectedField> {
+        let value = f64::from_le_bytes(bytes.get(..8)?.try_into().ok()?);
+
+        // Two adjacent plausible floats are more likely than a double whose halves both happen to look like floats.
+        let low_value = f32::from_le_bytes(bytes[..4].try_into().ok()?);
+        let high_value = f32::from_le_bytes(bytes[4..8].try_into().ok()?);
+
+        if !Self::is_plausible_float(value) || (Self::is_plausible_float(low_value as f64) && Self::is_plausible_float(high_value as f64)) {
+            return None;
+        }
+
+        Some(DissectedField::new(
+            offset as u64,
+            8,
+            DissectedFieldKind::Double,
+            DataTypeRef::new(DataTypeF64::get_data_type_id()),
+            ContainerType::None,
+            value.to_string(),
+        ))
+    }
+
+    fn try_dissect_dword(
+        offset: usize,
+        bytes: &[u8],
+    ) -> Option<DissectedField> {
+        let dword_bytes: [u8; 4] = bytes.get(..4)?.try_into().ok()?;
+        let float_value = f32::from_le_bytes(dword_bytes);
+        let integer_value = i32::from_le_bytes(dword_bytes);
+
+        let (kind, data_type, preview) = if Self::is_plausible_float(float_value as f64) {
+            (DissectedFieldKind::Float, DataTypeF32::get_data_type_id(), float_value.to_string())
+        } else if (integer_value as i64).abs() <= Self::MAX_PLAUSIBLE_INTEGER {
+            (DissectedFieldKind::Integer, DataTypeI32::get_data_type_id(), integer_value.to_string())
+        } else {
+            (DissectedFieldKind::Unknown, DataTypeU32::get_data_type_id(), format!("{:X}", integer_value as u32))
+        };
+
+        Some(DissectedField::new(
+            offset as u64,
+            4,
+            kind,
+            DataTypeRef::new(data_type),
+            ContainerType::None,
+            preview,
+        ))
+    }
+
+    fn dissect_byte(
+        offset: usize,
+        byte: u8,
+    ) -> DissectedField {
+        DissectedField::new(
+            offset as u64,
+            1,
+            DissectedFieldKind::Unknown,
+            DataTypeRef::new(DataTypeU8::get_data_type_id()),
+            ContainerType::None,
+            format!("{:02X}", byte),
+        )
+    }
+
+    /// Determines whether the given address holds a pointer into executable memory.
+    fn is_executable_pointer_at(
+        &self,
+        address: u64,
+    ) -> bool {
+        let pointer_size = self.get_pointer_size();
+        let mut bytes = [0u8; 8];
+
+        if !MemoryReader::get_instance().read_bytes(self.process_info, address, &mut bytes[..pointer_size]) {
+            return false;
+        }
+
+        match Self::read_pointer_value(&bytes, pointer_size) {
+            Some(target_address) => Self::find_region(&self.executable_regions, target_address).is_some(),
+            None => false,
+        }
+    }
+
+    fn find_region(
+        sorted_regions: &[NormalizedRegion],
+        address: u64,
+    ) -> Option<&NormalizedRegion> {
+        let region_index = sorted_regions
+            .partition_point(|region| region.get_base_address() <= address)
+            .checked_sub(1)?;
+        let region = &sorted_regions[region_index];
+
+        (address < region.get_end_address()).then_some(region)
+    }
+
+    fn read_pointer_value(
+        bytes: &[u8],
+        pointer_size: usize,
+    ) -> Option<u64> {
+        match pointer_size {
+            4 => Some(u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?) as u64),
+            _ => Some(u64::from_le_bytes(bytes.get(..8)?.try_into().ok()?)),
+        }
+        .filter(|&pointer_value| pointer_value != 0)
+    }
+
+    fn get_pointer_size(&self) -> usize {
+        match self.process_info.get_bitness() {
+            Bitness::Bit32 => 4,
+            Bitness::Bit64 => 8,
+        }
+    }
+
+    fn is_printable_character(byte: u8) -> bool {
+        (0x20..=0x7E).contains(&byte) || byte == b'\t' || byte == b'\n' || byte == b'\r'
+    }
+
+    fn is_plausible_float(value: f64) -> bool {
+        value.is_normal() && (Self::MIN_PLAUSIBLE_FLOAT..=Self::MAX_PLAUSIBLE_FLOAT).contains(&value.abs())
+    }
+}
@@ -1,4 +1,9 @@
+pub mod address_expressions;
 pub mod config;
+pub mod dissection;
+pub mod memory_dumps;
 pub mod memory_queryer;
 pub mod memory_reader;
 pub mod memory_writer;
+pub mod symbols;
+pub mod virtual_targets;
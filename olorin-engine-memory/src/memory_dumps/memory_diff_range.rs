@@ -0,0 +1,27 @@
+/// A contiguous range of bytes that differs between two memory sources.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryDiffRange {
+    base_address: u64,
+    size: u64,
+}
+
+impl MemoryDiffRange {
+    pub fn new(
+        base_address: u64,
+        size: u64,
+    ) -> Self {
+        Self { base_address, size }
+    }
+
+    pub fn get_base_address(&self) -> u64 {
+        self.base_address
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn get_end_address(&self) -> u64 {
+        self.base_address.saturating_add(self.size)
+    }
+}
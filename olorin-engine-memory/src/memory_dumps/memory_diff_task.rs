@@ -0,0 +1,218 @@
+use crate::memory_dumps::memory_diff_range::MemoryDiffRange;
+use crate::memory_dumps::memory_dump::MemoryDump;
+use crate::memory_reader::MemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use olorin_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use olorin_engine_api::structures::data_values::data_value::DataValue;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Instant;
+
+const TASK_NAME: &'static str = "Memory Diff";
+
+/// The number of bytes compared at a time when diffing against live memory.
+const DIFF_CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// The granularity at which live memory is read when a chunk cannot be read in its entirety.
+const DIFF_PAGE_SIZE: u64 = 0x1000;
+
+/// The maximum number of changed ranges written to the log. All ranges are written to the output file, if provided.
+const MAX_LOGGED_RANGES: usize = 64;
+
+/// The memory that a dump is compared against.
+pub enum MemoryDiffTarget {
+    Dump(MemoryDump),
+    LiveMemory(OpenedProcessInfo),
+}
+
+impl MemoryDiffTarget {
+    /// Reads the given range from this target. Returns `None` for any bytes that are unavailable.
+    fn read_bytes(
+        &self,
+        address: u64,
+        size: u64,
+    ) -> Vec<Option<u8>> {
+        match self {
+            MemoryDiffTarget::Dump(memory_dump) => memory_dump.read_bytes(address, size),
+            MemoryDiffTarget::LiveMemory(process_info) => {
+                let mut buffer = vec![0u8; size as usize];
+
+                if MemoryReader::get_instance().read_bytes(process_info, address, &mut buffer) {
+                    return buffer.into_iter().map(Some).collect();
+                }
+
+                // The range may only be partially unreadable (ie a guard page), so fall back to reading each page individually.
+                let mut bytes = vec![None; size as usize];
+                let end_address = address.saturating_add(size);
+                let mut page_address = address;
+
+                while page_address < end_address {
+                    let page_end_address = ((page_address / DIFF_PAGE_SIZE) + 1)
+                        .saturating_mul(DIFF_PAGE_SIZE)
+                        .min(end_address);
+                    let page_offset = (page_address - address) as usize;
+                    let page_buffer = &mut buffer[page_offset..(page_end_address - address) as usize];
+
+                    if MemoryReader::get_instance().read_bytes(process_info, page_address, page_buffer) {
+                        for (byte_offset, byte) in page_buffer.iter().enumerate() {
+                            bytes[page_offset + byte_offset] = Some(*byte);
+                        }
+                    }
+
+                    page_address = page_end_address;
+                }
+
+                bytes
+            }
+        }
+    }
+}
+
+pub struct MemoryDiffTask {}
+
+/// Implementation of a task that compares a memory dump against another dump or live memory, reporting changed byte ranges.
+impl MemoryDiffTask {
+    pub fn start_task(
+        base_dump: MemoryDump,
+        diff_target: MemoryDiffTarget,
+        data_type_ref: Option<DataTypeRef>,
+        output_path: Option<PathBuf>,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            Self::diff_task(&task_clone, base_dump, diff_target, data_type_ref, output_path);
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn diff_task(
+        trackable_task: &Arc<TrackableTask>,
+        base_dump: MemoryDump,
+        diff_target: MemoryDiffTarget,
+        data_type_ref: Option<DataTypeRef>,
+        output_path: Option<PathBuf>,
+    ) {
+        let start_time = Instant::now();
+        let cancellation_token = trackable_task.get_cancellation_token();
+        let header = base_dump.get_header();
+        let total_size: u64 = header.get_regions().iter().map(|region| region.get_region_size()).sum();
+        let mut processed_size = 0u64;
+        let mut diff_ranges: Vec<MemoryDiffRange> = vec![];
+
+        // Only the readable regions of the base dump are compared. Bytes unavailable in the target are skipped.
+        for region in header.get_regions() {
+            let mut chunk_address = region.get_base_address();
+
+            while chunk_address < region.get_end_address() {
+                if cancellation_token.load(Ordering::SeqCst) {
+                    log::info!("Memory diff canceled.");
+                    return;
+                }
+
+                let chunk_size = (region.get_end_address() - chunk_address).min(DIFF_CHUNK_SIZE);
+                let base_bytes = base_dump.read_bytes(chunk_address, chunk_size);
+                let target_bytes = diff_target.read_bytes(chunk_address, chunk_size);
+
+                for (offset, (base_byte, target_byte)) in base_bytes.into_iter().zip(target_bytes).enumerate() {
+                    let address = chunk_address + offset as u64;
+
+                    if target_byte.is_none() || target_byte == base_byte {
+                        continue;
+                    }
+
+                    match diff_ranges.last_mut() {
+                        Some(last_range) if last_range.get_end_address() == address => {
+                            *last_range = MemoryDiffRange::new(last_range.get_base_address(), last_range.get_size() + 1);
+                        }
+                        _ => diff_ranges.push(MemoryDiffRange::new(address, 1)),
+                    }
+                }
+
+                chunk_address += chunk_size;
+                processed_size += chunk_size;
+                trackable_task.set_progress((processed_size as f32 / total_size.max(1) as f32) * 100.0);
+            }
+        }
+
+        let changed_byte_count: u64 = diff_ranges.iter().map(|range| range.get_size()).sum();
+        let report_lines: Vec<String> = diff_ranges
+            .iter()
+            .map(|diff_range| Self::format_diff_range(diff_range, &base_dump, &diff_target, &data_type_ref))
+            .collect();
+
+        for report_line in report_lines.iter().take(MAX_LOGGED_RANGES) {
+            log::info!("{}", report_line);
+        }
+
+        if report_lines.len() > MAX_LOGGED_RANGES {
+            log::info!("... and {} more changed ranges.", report_lines.len() - MAX_LOGGED_RANGES);
+        }
+
+        log::info!(
+            "Diff complete in {:?}: {} changed ranges, {} changed bytes.",
+            start_time.elapsed(),
+            diff_ranges.len(),
+            changed_byte_count
+        );
+
+        if let Some(output_path) = output_path {
+            match fs::write(&output_path, report_lines.join("\n")) {
+                Ok(()) => log::info!("Diff report written to {:?}.", output_path),
+                Err(error) => log::error!("Failed to write diff report to {:?}: {}", output_path, error),
+            }
+        }
+    }
+
+    /// Formats a changed range, optionally interpreting the old and new bytes as the given data type. When interpreting,
+    /// the range is widened to whole elements, aligned relative to the base address of the dump.
+    fn format_diff_range(
+        diff_range: &MemoryDiffRange,
+        base_dump: &MemoryDump,
+        diff_target: &MemoryDiffTarget,
+        data_type_ref: &Option<DataTypeRef>,
+    ) -> String {
+        let range_string = format!(
+            "{:X}-{:X} ({} bytes)",
+            diff_range.get_base_address(),
+            diff_range.get_end_address(),
+            diff_range.get_size()
+        );
+
+        let Some(data_type_ref) = data_type_ref else {
+            return range_string;
+        };
+
+        let unit_size = data_type_ref.get_unit_size_in_bytes().max(1);
+        let dump_base_address = base_dump.get_header().get_base_address();
+        let aligned_start_address = diff_range.get_base_address() - (diff_range.get_base_address() - dump_base_address) % unit_size;
+        let aligned_size = (diff_range.get_end_address() - aligned_start_address).div_ceil(unit_size) * unit_size;
+        let old_bytes: Option<Vec<u8>> = base_dump
+            .read_bytes(aligned_start_address, aligned_size)
+            .into_iter()
+            .collect();
+        let new_bytes: Option<Vec<u8>> = diff_target
+            .read_bytes(aligned_start_address, aligned_size)
+            .into_iter()
+            .collect();
+
+        match (old_bytes, new_bytes) {
+            (Some(old_bytes), Some(new_bytes)) => format!(
+                "{}: {} -> {}",
+                range_string,
+                DataValue::new(data_type_ref.clone(), old_bytes).get_default_display_value_string(),
+                DataValue::new(data_type_ref.clone(), new_bytes).get_default_display_value_string()
+            ),
+            _ => range_string,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::memory_dumps::memory_dump_header::MemoryDumpHeader;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// A memory dump file, consisting of a magic value, a length prefixed JSON header, and the raw dumped bytes.
+/// Dumped bytes are read from disk on demand, as dumps may be far larger than available memory.
+pub struct MemoryDump {
+    header: MemoryDumpHeader,
+    bytes_offset: u64,
+    reader: Mutex<BufReader<File>>,
+}
+
+impl MemoryDump {
+    const MAGIC: &'static [u8; 8] = b"OLORDUMP";
+
+    /// The largest number of bytes that may be dumped at once.
+    pub const MAX_DUMP_SIZE: u64 = 16 * 1024 * 1024 * 1024;
+
+    /// The largest header accepted when loading a dump. Headers only describe regions, so anything larger indicates a corrupt file.
+    const MAX_HEADER_SIZE: u32 = 64 * 1024 * 1024;
+
+    pub fn get_header(&self) -> &MemoryDumpHeader {
+        &self.header
+    }
+
+    /// Reads the dumped bytes in the given range. Returns `None` for any bytes outside of the dump, or that were unreadable.
+    pub fn read_bytes(
+        &self,
+        address: u64,
+        size: u64,
+    ) -> Vec<Option<u8>> {
+        let mut bytes = vec![None; size as usize];
+        let end_address = address.saturating_add(size);
+        let read_start_address = address.max(self.header.get_base_address());
+        let read_end_address = end_address.min(self.header.get_end_address());
+
+        if read_start_address >= read_end_address {
+            return bytes;
+        }
+
+        let mut buffer = vec![0u8; (read_end_address - read_start_address) as usize];
+        let file_offset = self.bytes_offset + (read_start_address - self.header.get_base_address());
+        let read_result = match self.reader.lock() {
+            Ok(mut reader) => reader
+                .seek(SeekFrom::Start(file_offset))
+                .and_then(|_| reader.read_exact(&mut buffer)),
+            Err(error) => {
+                log::error!("Failed to acquire dump reader lock: {}", error);
+                return bytes;
+            }
+        };
+
+        if let Err(error) = read_result {
+            log::error!("Failed to read dumped bytes at {:X}: {}", read_start_address, error);
+            return bytes;
+        }
+
+        // Only bytes within the captured regions are exposed, as the remainder are zeros standing in for unreadable memory.
+        for region in self.header.get_regions() {
+            let overlap_start = region.get_base_address().max(read_start_address);
+            let overlap_end = region.get_end_address().min(read_end_address);
+
+            for overlap_address in overlap_start..overlap_end {
+                bytes[(overlap_address - address) as usize] = Some(buffer[(overlap_address - read_start_address) as usize]);
+            }
+        }
+
+        bytes
+    }
+
+    /// Writes a dump file, streaming the dumped bytes from the provided reader.
+    pub fn save_to_file(
+        file_path: &Path,
+        header: &MemoryDumpHeader,
+        dumped_bytes: &mut impl Read,
+    ) -> Result<(), String> {
+        let header_json = serde_json::to_vec(header).map_err(|error| format!("Failed to serialize dump header: {}", error))?;
+        let file = File::create(file_path).map_err(|error| format!("Failed to create dump file {:?}: {}", file_path, error))?;
+        let mut writer = BufWriter::new(file);
+
+        writer
+            .write_all(Self::MAGIC)
+            .and_then(|_| writer.write_all(&(header_json.len() as u32).to_le_bytes()))
+            .and_then(|_| writer.write_all(&header_json))
+            .and_then(|_| io::copy(&mut dumped_bytes.take(header.get_size()), &mut writer))
+            .and_then(|written_size| {
+                if written_size == header.get_size() {
+                    writer.flush()
+                } else {
+                    Err(io::Error::new(io::ErrorKind::UnexpectedEof, "dumped bytes ended early"))
+                }
+            })
+            .map_err(|error| format!("Failed to write dump file {:?}: {}", file_path, error))
+    }
+
+    pub fn load_from_file(file_path: &Path) -> Result<Self, String> {
+        let file = File::open(file_path).map_err(|error| format!("Failed to open dump file {:?}: {}", file_path, error))?;
+        let file_size = file
+            .metadata()
+            .map_err(|error| format!("Failed to read dump file {:?}: {}", file_path, error))?
+            .len();
+        let mut reader = BufReader::new(file);
+        let mut magic = [0u8; 8];
+        let mut header_length = [0u8; 4];
+
+        reader
+            .read_exact(&mut magic)
+            .and_then(|_| reader.read_exact(&mut header_length))
+            .map_err(|error| format!("Failed to read dump file {:?}: {}", file_path, error))?;
+
+        if &magic != Self::MAGIC {
+            return Err(format!("File {:?} is not a memory dump.", file_path));
+        }
+
+        let header_length = u32::from_le_bytes(header_length);
+
+        if header_length > Self::MAX_HEADER_SIZE {
+            return Err(format!("Dump header in {:?} is too large ({} bytes).", file_path, header_length));
+        }
+
+        let mut header_json = Vec::new();
+
+        header_json
+            .try_reserve_exact(header_length as usize)
+            .map_err(|error| format!("Failed to allocate dump header for {:?}: {}", file_path, error))?;
+        header_json.resize(header_length as usize, 0);
+
+        reader
+            .read_exact(&mut header_json)
+            .map_err(|error| format!("Failed to read dump header from {:?}: {}", file_path, error))?;
+
+        let header: MemoryDumpHeader = serde_json::from_slice(&header_json).map_err(|error| format!("Invalid dump header in {:?}: {}", file_path, error))?;
+
+        if header.get_version() > MemoryDumpHeader::VERSION {
+            return Err(format!("Dump file {:?} uses unsupported version {}.", file_path, header.get_version()));
+        }
+
+        // The header size is untrusted, so it is checked against the bytes actually present rather than used to size any allocation.
+        let bytes_offset = (Self::MAGIC.len() + size_of::<u32>()) as u64 + header_length as u64;
+
+        if file_size.saturating_sub(bytes_offset) != header.get_size() {
+            return Err(format!(
+                "Dump file {:?} is truncated or corrupt, expected {} dumped bytes.",
+                file_path,
+                header.get_size()
+            ));
+        }
+
+        Ok(Self {
+            header,
+            bytes_offset,
+            reader: Mutex::new(reader),
+        })
+    }
+}
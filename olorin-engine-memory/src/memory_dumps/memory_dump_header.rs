@@ -0,0 +1,67 @@
+use crate::memory_dumps::memory_dump_region::MemoryDumpRegion;
+use serde::{Deserialize, Serialize};
+
+/// Metadata stored at the start of a memory dump file, describing where the dumped bytes came from.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryDumpHeader {
+    version: u32,
+    base_address: u64,
+    size: u64,
+    module_name: Option<String>,
+
+    /// The readable regions captured within the dump. Bytes outside of these regions could not be read, and are stored as zeros.
+    regions: Vec<MemoryDumpRegion>,
+}
+
+impl MemoryDumpHeader {
+    pub const VERSION: u32 = 1;
+
+    pub fn new(
+        base_address: u64,
+        size: u64,
+        module_name: Option<String>,
+        regions: Vec<MemoryDumpRegion>,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            base_address,
+            size,
+            module_name,
+            regions,
+        }
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn get_base_address(&self) -> u64 {
+        self.base_address
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn get_end_address(&self) -> u64 {
+        self.base_address.saturating_add(self.size)
+    }
+
+    pub fn get_module_name(&self) -> &Option<String> {
+        &self.module_name
+    }
+
+    pub fn get_regions(&self) -> &Vec<MemoryDumpRegion> {
+        &self.regions
+    }
+
+    /// Gets whether the byte at the given address was readable when the dump was taken.
+    pub fn is_address_readable(
+        &self,
+        address: u64,
+    ) -> bool {
+        self.regions
+            .iter()
+            .any(|region| address >= region.get_base_address() && address < region.get_end_address())
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// A readable region captured in a memory dump.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryDumpRegion {
+    base_address: u64,
+    region_size: u64,
+
+    /// The protection of this region, stored as `MemoryProtectionEnum` bits.
+    protection: u32,
+}
+
+impl MemoryDumpRegion {
+    pub fn new(
+        base_address: u64,
+        region_size: u64,
+        protection: u32,
+    ) -> Self {
+        Self {
+            base_address,
+            region_size,
+            protection,
+        }
+    }
+
+    pub fn get_base_address(&self) -> u64 {
+        self.base_address
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        self.region_size
+    }
+
+    pub fn get_end_address(&self) -> u64 {
+        self.base_address.saturating_add(self.region_size)
+    }
+
+    pub fn get_protection(&self) -> u32 {
+        self.protection
+    }
+}
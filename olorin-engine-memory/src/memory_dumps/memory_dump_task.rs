@@ -0,0 +1,210 @@
+use crate::memory_dumps::memory_dump::MemoryDump;
+use crate::memory_dumps::memory_dump_header::MemoryDumpHeader;
+use crate::memory_dumps::memory_dump_region::MemoryDumpRegion;
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_queryer::MemoryQueryer;
+use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use crate::memory_reader::MemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use olorin_engine_api::conversions::conversions::Conversions;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Instant;
+
+const TASK_NAME: &'static str = "Memory Dump";
+
+/// The number of bytes read at a time. Smaller chunks allow for more granular handling of unreadable pages within a region.
+const DUMP_CHUNK_SIZE: u64 = 1024 * 1024;
+
+pub struct MemoryDumpTask {}
+
+/// Implementation of a task that dumps a range of process memory to a file.
+impl MemoryDumpTask {
+    pub fn start_task(
+        process_info: OpenedProcessInfo,
+        base_address: u64,
+        size: u64,
+        module_name: Option<String>,
+        file_path: PathBuf,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            Self::dump_task(&task_clone, process_info, base_address, size, module_name, file_path);
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn dump_task(
+        trackable_task: &Arc<TrackableTask>,
+        process_info: OpenedProcessInfo,
+        base_address: u64,
+        size: u64,
+        module_name: Option<String>,
+        file_path: PathBuf,
+    ) {
+        if size > MemoryDump::MAX_DUMP_SIZE {
+            log::error!("Cannot dump {} bytes, the maximum dump size is {} bytes.", size, MemoryDump::MAX_DUMP_SIZE);
+            return;
+        }
+
+        let start_time = Instant::now();
+        let end_address = base_address.saturating_add(size);
+        let cancellation_token = trackable_task.get_cancellation_token();
+        let regions = MemoryQueryer::query_pages_by_address_range(&process_info, base_address, end_address);
+        let writable_region_addresses = Self::query_region_addresses_with_protection(&process_info, MemoryProtectionEnum::WRITE, base_address, end_address);
+        let executable_region_addresses = Self::query_region_addresses_with_protection(&process_info, MemoryProtectionEnum::EXECUTE, base_address, end_address);
+        let total_region_size: u64 = regions.iter().map(|region| region.get_region_size()).sum();
+        let mut processed_size = 0u64;
+        let mut dump_regions: Vec<MemoryDumpRegion> = vec![];
+
+        // Dumped bytes are streamed to a partial file, as the header (which precedes them) is only known once every region is read.
+        let mut partial_file_path = file_path.clone().into_os_string();
+        partial_file_path.push(".partial");
+        let partial_file_path = PathBuf::from(partial_file_path);
+        let mut partial_file = match Self::create_partial_file(&partial_file_path, size) {
+            Ok(partial_file) => partial_file,
+            Err(error) => {
+                log::error!("{}", error);
+                return;
+            }
+        };
+        let mut buffer = Vec::new();
+
+        if let Err(error) = buffer.try_reserve_exact(DUMP_CHUNK_SIZE as usize) {
+            log::error!("Failed to allocate dump buffer: {}", error);
+            let _ = fs::remove_file(&partial_file_path);
+            return;
+        }
+
+        log::info!("Dumping {} bytes from address {:X}...", size, base_address);
+
+        for region in regions {
+            let mut protection = MemoryProtectionEnum::READ;
+
+            if writable_region_addresses.contains(&region.get_base_address()) {
+                protection |= MemoryProtectionEnum::WRITE;
+            }
+
+            if executable_region_addresses.contains(&region.get_base_address()) {
+                protection |= MemoryProtectionEnum::EXECUTE;
+            }
+
+            let region_end_address = region.get_end_address().min(end_address);
+            let mut chunk_address = region.get_base_address().max(base_address);
+
+            while chunk_address < region_end_address {
+                if cancellation_token.load(Ordering::SeqCst) {
+                    log::info!("Memory dump canceled.");
+                    let _ = fs::remove_file(&partial_file_path);
+                    return;
+                }
+
+                let chunk_size = (region_end_address - chunk_address).min(DUMP_CHUNK_SIZE);
+
+                buffer.resize(chunk_size as usize, 0);
+
+                // Unreadable chunks are never written, leaving them zeroed, and are omitted from the captured regions.
+                if MemoryReader::get_instance().read_bytes(&process_info, chunk_address, &mut buffer) {
+                    if let Err(error) = partial_file
+                        .seek(SeekFrom::Start(chunk_address - base_address))
+                        .and_then(|_| partial_file.write_all(&buffer))
+                    {
+                        log::error!("Failed to write dumped bytes to {:?}: {}", partial_file_path, error);
+                        let _ = fs::remove_file(&partial_file_path);
+                        return;
+                    }
+
+                    match dump_regions.last_mut() {
+                        Some(last_region) if last_region.get_end_address() == chunk_address && last_region.get_protection() == protection.bits() => {
+                            *last_region = MemoryDumpRegion::new(last_region.get_base_address(), last_region.get_region_size() + chunk_size, protection.bits());
+                        }
+                        _ => dump_regions.push(MemoryDumpRegion::new(chunk_address, chunk_size, protection.bits())),
+                    }
+                }
+
+                chunk_address += chunk_size;
+                processed_size += chunk_size;
+                trackable_task.set_progress((processed_size as f32 / total_region_size.max(1) as f32) * 100.0);
+            }
+        }
+
+        let captured_size: u64 = dump_regions.iter().map(|region| region.get_region_size()).sum();
+        let header = MemoryDumpHeader::new(base_address, size, module_name, dump_regions);
+        let save_result = partial_file
+            .seek(SeekFrom::Start(0))
+            .map_err(|error| format!("Failed to read dumped bytes from {:?}: {}", partial_file_path, error))
+            .and_then(|_| MemoryDump::save_to_file(&file_path, &header, &mut BufReader::new(&partial_file)));
+
+        drop(partial_file);
+        let _ = fs::remove_file(&partial_file_path);
+
+        match save_result {
+            Ok(()) => {
+                log::info!("Memory dumped to {:?} in: {:?}", file_path, start_time.elapsed());
+                log::info!(
+                    "{} of {} bytes were readable ({})",
+                    captured_size,
+                    size,
+                    Conversions::value_to_metric_size(captured_size)
+                );
+            }
+            Err(error) => log::error!("{}", error),
+        }
+    }
+
+    /// Creates the file that dumped bytes are streamed into, sized up front so that unreadable ranges read back as zeros.
+    fn create_partial_file(
+        partial_file_path: &Path,
+        size: u64,
+    ) -> Result<File, String> {
+        let partial_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(partial_file_path)
+            .map_err(|error| format!("Failed to create {:?}: {}", partial_file_path, error))?;
+
+        partial_file
+            .set_len(size)
+            .map_err(|error| format!("Failed to size {:?}: {}", partial_file_path, error))?;
+
+        Ok(partial_file)
+    }
+
+    /// Gets the base addresses of regions within the given range that have the required protection.
+    fn query_region_addresses_with_protection(
+        process_info: &OpenedProcessInfo,
+        required_protection: MemoryProtectionEnum,
+        start_address: u64,
+        end_address: u64,
+    ) -> HashSet<u64> {
+        MemoryQueryer::get_instance()
+            .get_virtual_pages(
+                process_info,
+                required_protection,
+                MemoryProtectionEnum::empty(),
+                MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+                start_address,
+                end_address,
+                RegionBoundsHandling::Resize,
+            )
+            .iter()
+            .map(|region| region.get_base_address())
+            .collect()
+    }
+}
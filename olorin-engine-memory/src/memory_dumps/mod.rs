@@ -0,0 +1,6 @@
+pub mod memory_diff_range;
+pub mod memory_diff_task;
+pub mod memory_dump;
+pub mod memory_dump_header;
+pub mod memory_dump_region;
+pub mod memory_dump_task;
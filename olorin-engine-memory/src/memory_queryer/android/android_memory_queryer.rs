@@ -279,6 +279,22 @@ impl IMemoryQueryer for AndroidMemoryQueryer {
         false
     }
 
+    fn get_address_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> MemoryProtectionEnum {
+        if let Ok(regions) = Self::parse_proc_maps(process_info.process_id as i32) {
+            for reg in regions {
+                if address >= reg.start && address < reg.end {
+                    return Self::to_memory_protection(&reg.perms);
+                }
+            }
+        }
+
+        MemoryProtectionEnum::NONE
+    }
+
     fn get_maximum_address(
         &self,
         process_info: &OpenedProcessInfo,
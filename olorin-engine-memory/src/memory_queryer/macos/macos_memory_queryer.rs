@@ -53,6 +53,14 @@ impl IMemoryQueryer for MacosMemoryQueryer {
         false
     }
 
+    fn get_address_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> MemoryProtectionEnum {
+        MemoryProtectionEnum::NONE
+    }
+
     fn get_maximum_address(
         &self,
         process_info: &OpenedProcessInfo,
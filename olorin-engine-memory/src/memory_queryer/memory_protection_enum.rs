@@ -1,9 +1,2 @@
-bitflags::bitflags! {
-    pub struct MemoryProtectionEnum: u32 {
-        const NONE = 0x0;
-        const READ = 0x1;
-        const WRITE = 0x2;
-        const EXECUTE = 0x4;
-        const COPY_ON_WRITE = 0x8;
-    }
-}
+// Protection is part of the engine API, such that it can be reported in command responses.
+pub use olorin_engine_api::structures::memory::memory_protection_enum::MemoryProtectionEnum;
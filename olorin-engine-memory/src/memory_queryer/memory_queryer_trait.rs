@@ -28,6 +28,13 @@ pub trait IMemoryQueryer {
         address: u64,
     ) -> bool;
 
+    /// Gets the protection of the page containing the given address, or no protection if the address is not committed.
+    fn get_address_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> MemoryProtectionEnum;
+
     fn get_maximum_address(
         &self,
         process_info: &OpenedProcessInfo,
@@ -4,6 +4,9 @@ pub mod memory_queryer_trait;
 pub mod memory_type_enum;
 pub mod page_retrieval_mode;
 pub mod region_bounds_handling;
+pub mod routed_memory_queryer;
+pub mod virtual_module_provider_trait;
+pub mod virtual_module_registry;
 
 #[cfg(any(target_os = "android"))]
 mod android;
@@ -18,13 +21,15 @@ mod macos;
 mod windows;
 
 #[cfg(target_os = "android")]
-pub use crate::memory_queryer::android::android_memory_queryer::AndroidMemoryQueryer as MemoryQueryerImpl;
+pub use crate::memory_queryer::android::android_memory_queryer::AndroidMemoryQueryer as PlatformMemoryQueryer;
 
 #[cfg(target_os = "linux")]
-pub use crate::memory_queryer::linux::linux_memory_queryer::LinuxMemoryQueryer as MemoryQueryerImpl;
+pub use crate::memory_queryer::linux::linux_memory_queryer::LinuxMemoryQueryer as PlatformMemoryQueryer;
 
 #[cfg(target_os = "macos")]
-pub use crate::memory_queryer::macos::macos_memory_queryer::MacOsMemoryQueryer as MemoryQueryerImpl;
+pub use crate::memory_queryer::macos::macos_memory_queryer::MacOsMemoryQueryer as PlatformMemoryQueryer;
 
 #[cfg(target_os = "windows")]
-pub use crate::memory_queryer::windows::windows_memory_queryer::WindowsMemoryQueryer as MemoryQueryerImpl;
+pub use crate::memory_queryer::windows::windows_memory_queryer::WindowsMemoryQueryer as PlatformMemoryQueryer;
+
+pub use crate::memory_queryer::routed_memory_queryer::RoutedMemoryQueryer as MemoryQueryerImpl;
@@ -0,0 +1,216 @@
+use crate::memory_queryer::PlatformMemoryQueryer;
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use crate::memory_queryer::virtual_module_registry::VirtualModuleRegistry;
+use crate::virtual_targets::virtual_memory_queryer::VirtualMemoryQueryer;
+use crate::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use olorin_engine_api::structures::address_expressions::address_expression_node::AddressExpressionNode;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// How long a listing of modules is reused. Modules are listed several times for each refresh of scan results, project items,
+/// and the memory viewer, and listing them reads the memory maps and every thread of the process.
+const MODULE_CACHE_DURATION: Duration = Duration::from_millis(500);
+
+/// Queries memory with the platform queryer, or from a virtual target if the opened process is backed by one.
+pub struct RoutedMemoryQueryer {
+    platform_memory_queryer: PlatformMemoryQueryer,
+    virtual_memory_queryer: VirtualMemoryQueryer,
+
+    /// The most recent listing of modules, along with the process it was listed for and when.
+    module_cache: RwLock<Option<(u32, Instant, Vec<NormalizedModule>)>>,
+}
+
+impl RoutedMemoryQueryer {
+    pub fn new() -> Self {
+        Self {
+            platform_memory_queryer: PlatformMemoryQueryer::new(),
+            virtual_memory_queryer: VirtualMemoryQueryer::new(),
+            module_cache: RwLock::new(None),
+        }
+    }
+
+    /// Builds an expression for the given address relative to the module containing it, if any, ie `game.exe+1A2B`.
+    /// Addresses within modules anchored at their end, such as thread stacks, are written as `threadstack0-120`.
+    pub fn address_to_module_expression(
+        &self,
+        address: u64,
+        modules: &Vec<NormalizedModule>,
+    ) -> Option<AddressExpressionNode> {
+        if let Some(module) = modules
+            .iter()
+            .find(|module| module.is_anchored_at_end() && module.contains_address(address))
+        {
+            return Some(AddressExpressionNode::Subtract(
+                Box::new(AddressExpressionNode::Module(module.get_module_name().to_string())),
+                Box::new(AddressExpressionNode::Constant(module.get_anchor_address() - address)),
+            ));
+        }
+
+        self.address_to_module(address, modules)
+            .map(|(module_name, module_offset)| {
+                AddressExpressionNode::Add(
+                    Box::new(AddressExpressionNode::Module(module_name)),
+                    Box::new(AddressExpressionNode::Constant(module_offset)),
+                )
+            })
+    }
+
+    fn get_memory_queryer(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> &dyn IMemoryQueryer {
+        if VirtualTargetRegistry::get_instance().is_virtual_process(process_info.get_process_id_raw()) {
+            &self.virtual_memory_queryer
+        } else {
+            &self.platform_memory_queryer
+        }
+    }
+}
+
+impl IMemoryQueryer for RoutedMemoryQueryer {
+    fn get_virtual_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+        required_protection: MemoryProtectionEnum,
+        excluded_protection: MemoryProtectionEnum,
+        allowed_types: MemoryTypeEnum,
+        start_address: u64,
+        end_address: u64,
+        region_bounds_handling: RegionBoundsHandling,
+    ) -> Vec<NormalizedRegion> {
+        self.get_memory_queryer(process_info).get_virtual_pages(
+            process_info,
+            required_protection,
+            excluded_protection,
+            allowed_types,
+            start_address,
+            end_address,
+            region_bounds_handling,
+        )
+    }
+
+    fn get_all_virtual_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedRegion> {
+        self.get_memory_queryer(process_info)
+            .get_all_virtual_pages(process_info)
+    }
+
+    fn is_address_writable(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> bool {
+        self.get_memory_queryer(process_info)
+            .is_address_writable(process_info, address)
+    }
+
+    fn get_address_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> MemoryProtectionEnum {
+        self.get_memory_queryer(process_info)
+            .get_address_protection(process_info, address)
+    }
+
+    fn get_maximum_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> u64 {
+        self.get_memory_queryer(process_info)
+            .get_maximum_address(process_info)
+    }
+
+    fn get_min_usermode_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> u64 {
+        self.get_memory_queryer(process_info)
+            .get_min_usermode_address(process_info)
+    }
+
+    fn get_max_usermode_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> u64 {
+        self.get_memory_queryer(process_info)
+            .get_max_usermode_address(process_info)
+    }
+
+    fn get_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        let process_id = process_info.get_process_id_raw();
+
+        let cached_modules = match self.module_cache.read() {
+            Ok(module_cache) => match module_cache.as_ref() {
+                Some((cached_process_id, cached_time, cached_modules)) if *cached_process_id == process_id && cached_time.elapsed() < MODULE_CACHE_DURATION => {
+                    Some(cached_modules.clone())
+                }
+                _ => None,
+            },
+            Err(_) => None,
+        };
+
+        if let Some(cached_modules) = cached_modules {
+            return cached_modules;
+        }
+
+        let mut modules = self
+            .get_memory_queryer(process_info)
+            .get_modules(process_info);
+
+        // Virtual targets have no threads or other live state from which virtual modules could be derived.
+        if !VirtualTargetRegistry::get_instance().is_virtual_process(process_id) {
+            modules.extend(VirtualModuleRegistry::get_instance().get_virtual_modules(process_info));
+        }
+
+        if let Ok(mut module_cache) = self.module_cache.write() {
+            *module_cache = Some((process_id, Instant::now(), modules.clone()));
+        }
+
+        modules
+    }
+
+    fn address_to_module(
+        &self,
+        address: u64,
+        modules: &Vec<NormalizedModule>,
+    ) -> Option<(String, u64)> {
+        // Modules anchored at their end only have negative offsets, which are expressed by `address_to_module_expression`.
+        if modules
+            .iter()
+            .any(|module| module.is_anchored_at_end() && module.contains_address(address))
+        {
+            return None;
+        }
+
+        self.platform_memory_queryer
+            .address_to_module(address, modules)
+    }
+
+    fn resolve_module(
+        &self,
+        modules: &Vec<NormalizedModule>,
+        identifier: &str,
+    ) -> u64 {
+        if let Some(module) = modules
+            .iter()
+            .find(|module| module.is_anchored_at_end() && module.get_module_name().eq_ignore_ascii_case(identifier))
+        {
+            return module.get_anchor_address();
+        }
+
+        self.platform_memory_queryer
+            .resolve_module(modules, identifier)
+    }
+}
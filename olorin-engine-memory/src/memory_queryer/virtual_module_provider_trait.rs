@@ -0,0 +1,11 @@
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// A source of named regions that are not loaded images, such as thread stacks, but which addresses can be expressed relative to
+/// in the same way as modules.
+pub trait IVirtualModuleProvider: Send + Sync {
+    fn get_virtual_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule>;
+}
@@ -0,0 +1,70 @@
+use crate::memory_queryer::virtual_module_provider_trait::IVirtualModuleProvider;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::{Arc, Once, RwLock};
+
+/// Tracks the providers of virtual modules, which are listed alongside the modules of a process. Providers are registered by
+/// crates that sit above the memory crate, such as the thread stacks provided by the process crate.
+pub struct VirtualModuleRegistry {
+    providers: RwLock<Vec<(String, Arc<dyn IVirtualModuleProvider>)>>,
+}
+
+impl VirtualModuleRegistry {
+    fn new() -> Self {
+        Self {
+            providers: RwLock::new(vec![]),
+        }
+    }
+
+    pub fn get_instance() -> &'static VirtualModuleRegistry {
+        static mut INSTANCE: Option<VirtualModuleRegistry> = None;
+        static INIT: Once = Once::new();
+
+        unsafe {
+            INIT.call_once(|| {
+                let instance = VirtualModuleRegistry::new();
+                INSTANCE = Some(instance);
+            });
+
+            #[allow(static_mut_refs)]
+            INSTANCE.as_ref().unwrap_unchecked()
+        }
+    }
+
+    /// Registers a provider under the given name, replacing any provider previously registered under the same name.
+    pub fn register_provider(
+        &self,
+        provider_name: &str,
+        provider: Arc<dyn IVirtualModuleProvider>,
+    ) {
+        match self.providers.write() {
+            Ok(mut providers) => {
+                providers.retain(|(existing_provider_name, _)| existing_provider_name != provider_name);
+                providers.push((provider_name.to_string(), provider));
+            }
+            Err(error) => {
+                log::error!("Failed to acquire virtual module registry write lock: {}", error);
+            }
+        }
+    }
+
+    /// Gets the virtual modules of the given process from all registered providers.
+    pub fn get_virtual_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        // Clone the providers out of the lock, such that a slow provider does not block registration.
+        let providers: Vec<Arc<dyn IVirtualModuleProvider>> = match self.providers.read() {
+            Ok(providers) => providers.iter().map(|(_, provider)| provider.clone()).collect(),
+            Err(error) => {
+                log::error!("Failed to acquire virtual module registry read lock: {}", error);
+                return vec![];
+            }
+        };
+
+        providers
+            .iter()
+            .flat_map(|provider| provider.get_virtual_modules(process_info))
+            .collect()
+    }
+}
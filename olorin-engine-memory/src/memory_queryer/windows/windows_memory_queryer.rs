@@ -13,7 +13,8 @@ use std::ffi::OsStr;
 use std::path::Path;
 use windows_sys::Win32::Foundation::HMODULE;
 use windows_sys::Win32::System::Memory::{
-    MEMORY_BASIC_INFORMATION64, PAGE_EXECUTE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_READWRITE, PAGE_WRITECOPY, VirtualQueryEx,
+    MEM_COMMIT, MEMORY_BASIC_INFORMATION64, PAGE_EXECUTE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_READONLY, PAGE_READWRITE,
+    PAGE_WRITECOPY, VirtualQueryEx,
 };
 use windows_sys::Win32::System::ProcessStatus::{K32EnumProcessModulesEx, K32GetModuleFileNameExA, K32GetModuleInformation, LIST_MODULES_ALL, MODULEINFO};
 
@@ -44,6 +45,23 @@ impl WindowsMemoryQueryer {
 
         flags
     }
+
+    /// Converts page protection flags to a protection, ignoring modifiers such as guard pages.
+    fn get_memory_protection(
+        &self,
+        protection_flags: u32,
+    ) -> MemoryProtectionEnum {
+        match protection_flags & 0xFF {
+            PAGE_READONLY => MemoryProtectionEnum::READ,
+            PAGE_READWRITE => MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+            PAGE_WRITECOPY => MemoryProtectionEnum::READ | MemoryProtectionEnum::COPY_ON_WRITE,
+            PAGE_EXECUTE => MemoryProtectionEnum::EXECUTE,
+            PAGE_EXECUTE_READ => MemoryProtectionEnum::READ | MemoryProtectionEnum::EXECUTE,
+            PAGE_EXECUTE_READWRITE => MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE | MemoryProtectionEnum::EXECUTE,
+            PAGE_EXECUTE_WRITECOPY => MemoryProtectionEnum::READ | MemoryProtectionEnum::COPY_ON_WRITE | MemoryProtectionEnum::EXECUTE,
+            _ => MemoryProtectionEnum::NONE,
+        }
+    }
 }
 
 impl IMemoryQueryer for WindowsMemoryQueryer {
@@ -186,6 +204,28 @@ impl IMemoryQueryer for WindowsMemoryQueryer {
         virtual_pages_in_bounds.len() > 0
     }
 
+    fn get_address_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> MemoryProtectionEnum {
+        let mut mbi: MEMORY_BASIC_INFORMATION64 = unsafe { std::mem::zeroed() };
+        let result = unsafe {
+            VirtualQueryEx(
+                process_info.get_handle() as *mut c_void,
+                address as *const c_void,
+                &mut mbi as *mut _ as *mut _,
+                size_of::<MEMORY_BASIC_INFORMATION64>(),
+            )
+        };
+
+        if result == 0 || mbi.State != MEM_COMMIT {
+            return MemoryProtectionEnum::NONE;
+        }
+
+        self.get_memory_protection(mbi.Protect)
+    }
+
     fn get_maximum_address(
         &self,
         process_info: &OpenedProcessInfo,
@@ -282,11 +322,10 @@ impl IMemoryQueryer for WindowsMemoryQueryer {
                 continue;
             }
 
-            modules.push(NormalizedModule::new(
-                &module_name,
-                module_info.lpBaseOfDll as u64,
-                module_info.SizeOfImage as u64,
-            ));
+            let mut module = NormalizedModule::new(&module_name, module_info.lpBaseOfDll as u64, module_info.SizeOfImage as u64);
+
+            module.set_module_path(&module_path);
+            modules.push(module);
         }
 
         modules
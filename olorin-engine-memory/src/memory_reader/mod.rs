@@ -1,4 +1,5 @@
 pub mod memory_reader_trait;
+pub mod routed_memory_reader;
 
 use std::sync::Once;
 
@@ -15,17 +16,19 @@ mod macos;
 mod windows;
 
 #[cfg(target_os = "android")]
-pub use crate::memory_reader::android::android_memory_reader::AndroidMemoryReader as MemoryReaderImpl;
+pub use crate::memory_reader::android::android_memory_reader::AndroidMemoryReader as PlatformMemoryReader;
 
 #[cfg(target_os = "linux")]
-pub use crate::memory_reader::linux::linux_memory_reader::LinuxMemoryReader as MemoryReaderImpl;
+pub use crate::memory_reader::linux::linux_memory_reader::LinuxMemoryReader as PlatformMemoryReader;
 
 #[cfg(target_os = "macos")]
-pub use crate::memory_reader::macos::macos_memory_reader::MacOsMemoryReader as MemoryReaderImpl;
+pub use crate::memory_reader::macos::macos_memory_reader::MacOsMemoryReader as PlatformMemoryReader;
 
 #[cfg(target_os = "windows")]
-// pub use crate::memory_reader::windows::windows_memory_reader::WindowsMemoryReader as MemoryReaderImpl;
-pub use crate::memory_reader::windows::windows_memory_reader_nt::WindowsMemoryReaderNt as MemoryReaderImpl;
+// pub use crate::memory_reader::windows::windows_memory_reader::WindowsMemoryReader as PlatformMemoryReader;
+pub use crate::memory_reader::windows::windows_memory_reader_nt::WindowsMemoryReaderNt as PlatformMemoryReader;
+
+pub use crate::memory_reader::routed_memory_reader::RoutedMemoryReader as MemoryReaderImpl;
 
 pub struct MemoryReader;
 
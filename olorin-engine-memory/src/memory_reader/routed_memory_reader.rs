@@ -0,0 +1,65 @@
+use crate::memory_reader::PlatformMemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use crate::virtual_targets::virtual_memory_reader::VirtualMemoryReader;
+use crate::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use olorin_engine_api::structures::data_values::data_value::DataValue;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
+
+/// Reads memory from the platform reader, or from a virtual target if the opened process is backed by one.
+pub struct RoutedMemoryReader {
+    platform_memory_reader: PlatformMemoryReader,
+    virtual_memory_reader: VirtualMemoryReader,
+}
+
+impl RoutedMemoryReader {
+    pub fn new() -> Self {
+        Self {
+            platform_memory_reader: PlatformMemoryReader::new(),
+            virtual_memory_reader: VirtualMemoryReader::new(),
+        }
+    }
+
+    fn get_memory_reader(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> &dyn IMemoryReader {
+        if VirtualTargetRegistry::get_instance().is_virtual_process(process_info.get_process_id_raw()) {
+            &self.virtual_memory_reader
+        } else {
+            &self.platform_memory_reader
+        }
+    }
+}
+
+impl IMemoryReader for RoutedMemoryReader {
+    fn read(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        data_value: &mut DataValue,
+    ) -> bool {
+        self.get_memory_reader(process_info)
+            .read(process_info, address, data_value)
+    }
+
+    fn read_struct(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        valued_struct: &mut ValuedStruct,
+    ) -> bool {
+        self.get_memory_reader(process_info)
+            .read_struct(process_info, address, valued_struct)
+    }
+
+    fn read_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        self.get_memory_reader(process_info)
+            .read_bytes(process_info, address, values)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod memory_writer_trait;
+pub mod routed_memory_writer;
 
 use std::sync::Once;
 
@@ -15,16 +16,18 @@ mod macos;
 mod windows;
 
 #[cfg(target_os = "android")]
-pub use crate::memory_writer::android::android_memory_writer::AndroidMemoryWriter as MemoryWriterImpl;
+pub use crate::memory_writer::android::android_memory_writer::AndroidMemoryWriter as PlatformMemoryWriter;
 
 #[cfg(target_os = "linux")]
-pub use crate::memory_writer::linux::linux_memory_writer::LinuxMemoryWriter as MemoryWriterImpl;
+pub use crate::memory_writer::linux::linux_memory_writer::LinuxMemoryWriter as PlatformMemoryWriter;
 
 #[cfg(target_os = "macos")]
-pub use crate::memory_writer::macos::macos_memory_writer::MacOsMemoryWriter as MemoryWriterImpl;
+pub use crate::memory_writer::macos::macos_memory_writer::MacOsMemoryWriter as PlatformMemoryWriter;
 
 #[cfg(target_os = "windows")]
-pub use crate::memory_writer::windows::windows_memory_writer::WindowsMemoryWriter as MemoryWriterImpl;
+pub use crate::memory_writer::windows::windows_memory_writer::WindowsMemoryWriter as PlatformMemoryWriter;
+
+pub use crate::memory_writer::routed_memory_writer::RoutedMemoryWriter as MemoryWriterImpl;
 
 pub struct MemoryWriter;
 
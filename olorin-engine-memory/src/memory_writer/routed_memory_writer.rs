@@ -0,0 +1,43 @@
+use crate::memory_writer::PlatformMemoryWriter;
+use crate::memory_writer::memory_writer_trait::IMemoryWriter;
+use crate::virtual_targets::virtual_memory_writer::VirtualMemoryWriter;
+use crate::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Writes memory with the platform writer, or to a virtual target if the opened process is backed by one.
+pub struct RoutedMemoryWriter {
+    platform_memory_writer: PlatformMemoryWriter,
+    virtual_memory_writer: VirtualMemoryWriter,
+}
+
+impl RoutedMemoryWriter {
+    pub fn new() -> Self {
+        Self {
+            platform_memory_writer: PlatformMemoryWriter::new(),
+            virtual_memory_writer: VirtualMemoryWriter::new(),
+        }
+    }
+
+    fn get_memory_writer(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> &dyn IMemoryWriter {
+        if VirtualTargetRegistry::get_instance().is_virtual_process(process_info.get_process_id_raw()) {
+            &self.virtual_memory_writer
+        } else {
+            &self.platform_memory_writer
+        }
+    }
+}
+
+impl IMemoryWriter for RoutedMemoryWriter {
+    fn write_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        self.get_memory_writer(process_info)
+            .write_bytes(process_info, address, values)
+    }
+}
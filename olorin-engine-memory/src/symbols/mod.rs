@@ -0,0 +1,2 @@
+pub mod module_symbol_parser;
+pub mod symbol_service;
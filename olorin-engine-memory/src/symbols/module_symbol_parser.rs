@@ -0,0 +1,100 @@
+use object::{Object, ObjectSection, ObjectSegment, ObjectSymbol, SymbolKind};
+use olorin_engine_api::structures::symbols::module_section::ModuleSection;
+use olorin_engine_api::structures::symbols::module_symbol::ModuleSymbol;
+use olorin_engine_api::structures::symbols::module_symbol_table::ModuleSymbolTable;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The page size to which ELF load segments are aligned when mapped.
+const ELF_PAGE_SIZE: u64 = 0x1000;
+
+pub struct ModuleSymbolParser;
+
+/// Parses the on-disk ELF and PE images of modules. Both formats are parsed on every platform, allowing
+/// remote or dumped targets to be inspected regardless of the host operating system.
+impl ModuleSymbolParser {
+    /// Gets a key that uniquely identifies the build of a module image. This is the GNU build-id for ELF images,
+    /// the CodeView GUID and age for PE images, and otherwise a hash of the image contents.
+    pub fn get_build_key(image_bytes: &[u8]) -> String {
+        if let Ok(object_file) = object::File::parse(image_bytes) {
+            if let Ok(Some(build_id)) = object_file.build_id() {
+                return format!("build-id:{}", Self::to_hex(build_id));
+            }
+
+            if let Ok(Some(code_view)) = object_file.pdb_info() {
+                return format!("pdb:{}:{:X}", Self::to_hex(&code_view.guid()), code_view.age());
+            }
+        }
+
+        let mut hasher = DefaultHasher::new();
+
+        image_bytes.hash(&mut hasher);
+
+        format!("hash:{:016X}", hasher.finish())
+    }
+
+    /// Parses the exports, sections, and `.symtab`/`.dynsym` symbols of a module image.
+    /// All offsets are relative to the address at which the image is loaded.
+    pub fn parse(image_bytes: &[u8]) -> Result<ModuleSymbolTable, String> {
+        let object_file = object::File::parse(image_bytes).map_err(|error| format!("Failed to parse module image: {}", error))?;
+        let image_base = Self::get_image_base(&object_file);
+        let to_module_offset = |address: u64| address.checked_sub(image_base);
+
+        let sections = object_file
+            .sections()
+            .filter(|section| section.size() > 0 && section.address() > 0)
+            .filter_map(|section| {
+                let name = section.name().ok()?.to_string();
+
+                Some(ModuleSection::new(name, to_module_offset(section.address())?, section.size()))
+            })
+            .collect();
+
+        let mut symbols: Vec<ModuleSymbol> = object_file
+            .symbols()
+            .chain(object_file.dynamic_symbols())
+            .filter(|symbol| symbol.is_definition() && matches!(symbol.kind(), SymbolKind::Text | SymbolKind::Data) && symbol.address() > 0)
+            .filter_map(|symbol| {
+                let name = symbol.name().ok().filter(|name| !name.is_empty())?.to_string();
+
+                Some(ModuleSymbol::new(name, to_module_offset(symbol.address())?, symbol.size()))
+            })
+            .collect();
+
+        // Exports cover PE images, which rarely ship a COFF symbol table. For ELF images these duplicate `.dynsym`.
+        if let Ok(exports) = object_file.exports() {
+            let symbol_names: HashSet<String> = symbols.iter().map(|symbol| symbol.get_name().to_string()).collect();
+
+            for export in exports {
+                let Some(module_offset) = to_module_offset(export.address()) else {
+                    continue;
+                };
+                let name = String::from_utf8_lossy(export.name()).to_string();
+
+                if !name.is_empty() && !symbol_names.contains(&name) {
+                    symbols.push(ModuleSymbol::new(name, module_offset, 0));
+                }
+            }
+        }
+
+        Ok(ModuleSymbolTable::new(sections, symbols))
+    }
+
+    /// Gets the address that the image expects to be loaded at, such that symbol addresses can be made module-relative.
+    fn get_image_base(object_file: &object::File) -> u64 {
+        match object_file.format() {
+            object::BinaryFormat::Elf => object_file
+                .segments()
+                .map(|segment| segment.address())
+                .min()
+                .map(|address| address & !(ELF_PAGE_SIZE - 1))
+                .unwrap_or(0),
+            _ => object_file.relative_address_base(),
+        }
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+}
@@ -0,0 +1,140 @@
+use crate::symbols::module_symbol_parser::ModuleSymbolParser;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::symbols::module_symbol_table::ModuleSymbolTable;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Once, RwLock};
+use std::time::SystemTime;
+
+/// Identifies a specific version of a file on disk, allowing the build key to be reused without rereading the file.
+type ModuleFileIdentity = (String, u64, Option<SystemTime>);
+
+/// Resolves addresses to symbols and symbols to addresses for the modules of a process.
+/// Parsed symbol tables are cached by the build key of each module image, such that the same image loaded by
+/// multiple processes (or reattached to) is only parsed once.
+pub struct SymbolService {
+    symbol_tables: RwLock<HashMap<String, Arc<ModuleSymbolTable>>>,
+    build_keys: RwLock<HashMap<ModuleFileIdentity, String>>,
+}
+
+impl SymbolService {
+    fn new() -> Self {
+        Self {
+            symbol_tables: RwLock::new(HashMap::new()),
+            build_keys: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_instance() -> &'static SymbolService {
+        static mut INSTANCE: Option<SymbolService> = None;
+        static INIT: Once = Once::new();
+
+        unsafe {
+            INIT.call_once(|| {
+                let instance = SymbolService::new();
+                INSTANCE = Some(instance);
+            });
+
+            #[allow(static_mut_refs)]
+            INSTANCE.as_ref().unwrap_unchecked()
+        }
+    }
+
+    /// Gets the symbol table for the given module, parsing its on-disk image if it has not yet been parsed.
+    pub fn get_symbol_table(
+        &self,
+        module: &NormalizedModule,
+    ) -> Option<Arc<ModuleSymbolTable>> {
+        let module_path = module.get_module_path();
+
+        if module_path.is_empty() {
+            return None;
+        }
+
+        let metadata = fs::metadata(module_path).ok()?;
+        let file_identity: ModuleFileIdentity = (module_path.to_string(), metadata.len(), metadata.modified().ok());
+        let known_build_key = self
+            .build_keys
+            .read()
+            .ok()
+            .and_then(|build_keys| build_keys.get(&file_identity).cloned());
+
+        if let Some(build_key) = &known_build_key {
+            if let Some(symbol_table) = self.get_cached_symbol_table(build_key) {
+                return Some(symbol_table);
+            }
+        }
+
+        let image_bytes = match fs::read(module_path) {
+            Ok(image_bytes) => image_bytes,
+            Err(error) => {
+                log::warn!("Unable to read module image {}: {}", module_path, error);
+                return None;
+            }
+        };
+        let build_key = known_build_key.unwrap_or_else(|| ModuleSymbolParser::get_build_key(&image_bytes));
+
+        if let Ok(mut build_keys) = self.build_keys.write() {
+            build_keys.insert(file_identity, build_key.clone());
+        }
+
+        if let Some(symbol_table) = self.get_cached_symbol_table(&build_key) {
+            return Some(symbol_table);
+        }
+
+        // Images that fail to parse are cached as empty tables, such that they are not reparsed on every lookup.
+        let symbol_table = Arc::new(ModuleSymbolParser::parse(&image_bytes).unwrap_or_else(|error| {
+            log::warn!("{} ({})", error, module_path);
+            ModuleSymbolTable::default()
+        }));
+
+        if let Ok(mut symbol_tables) = self.symbol_tables.write() {
+            symbol_tables.insert(build_key, symbol_table.clone());
+        }
+
+        Some(symbol_table)
+    }
+
+    /// Formats an address as `module!symbol+0x34`, or returns `None` if the address is not within a known symbol.
+    pub fn address_to_symbol(
+        &self,
+        address: u64,
+        modules: &Vec<NormalizedModule>,
+    ) -> Option<String> {
+        let module = modules.iter().find(|module| module.contains_address(address))?;
+        let symbol_table = self.get_symbol_table(module)?;
+        let (symbol, offset_into_symbol) = symbol_table.find_symbol(address - module.get_base_address())?;
+
+        if offset_into_symbol == 0 {
+            Some(format!("{}!{}", module.get_module_name(), symbol.get_name()))
+        } else {
+            Some(format!("{}!{}+0x{:X}", module.get_module_name(), symbol.get_name(), offset_into_symbol))
+        }
+    }
+
+    /// Resolves the address of a symbol within the named module.
+    pub fn resolve_symbol(
+        &self,
+        modules: &Vec<NormalizedModule>,
+        module_name: &str,
+        symbol_name: &str,
+    ) -> Option<u64> {
+        let module = modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(module_name))?;
+        let symbol_table = self.get_symbol_table(module)?;
+        let symbol = symbol_table.find_symbol_by_name(symbol_name)?;
+
+        module.get_base_address().checked_add(symbol.get_module_offset())
+    }
+
+    fn get_cached_symbol_table(
+        &self,
+        build_key: &str,
+    ) -> Option<Arc<ModuleSymbolTable>> {
+        self.symbol_tables
+            .read()
+            .ok()
+            .and_then(|symbol_tables| symbol_tables.get(build_key).cloned())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::virtual_targets::virtual_memory_region::VirtualMemoryRegion;
+
+/// A `PT_LOAD` segment of an ELF core, mapping a region of the dumped process to its contents in the core file.
+#[derive(Clone)]
+pub struct ElfCoreSegment {
+    virtual_memory_region: VirtualMemoryRegion,
+    file_offset: u64,
+    file_size: u64,
+}
+
+impl ElfCoreSegment {
+    pub fn new(
+        virtual_memory_region: VirtualMemoryRegion,
+        file_offset: u64,
+        file_size: u64,
+    ) -> Self {
+        Self {
+            virtual_memory_region,
+            file_offset,
+            file_size,
+        }
+    }
+
+    pub fn get_virtual_memory_region(&self) -> &VirtualMemoryRegion {
+        &self.virtual_memory_region
+    }
+
+    /// Gets the offset of the segment contents within the core file.
+    pub fn get_file_offset(&self) -> u64 {
+        self.file_offset
+    }
+
+    /// Gets the number of bytes stored in the core file. Any remaining bytes of the segment are zero filled.
+    pub fn get_file_size(&self) -> u64 {
+        self.file_size
+    }
+}
@@ -0,0 +1,324 @@
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::virtual_targets::elf_core::elf_core_segment::ElfCoreSegment;
+use crate::virtual_targets::virtual_memory_region::VirtualMemoryRegion;
+use crate::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use object::elf::{ET_CORE, FileHeader32, FileHeader64, NT_FILE, PF_R, PF_W, PF_X, PT_LOAD};
+use object::read::elf::{FileHeader, ProgramHeader};
+use object::{Endian, Endianness, FileKind, ReadCache};
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A file mapping listed by the `NT_FILE` note of an ELF core.
+struct ElfCoreFileMapping {
+    start_address: u64,
+    end_address: u64,
+    file_path: String,
+}
+
+/// A read only virtual target backed by an ELF core dump, such as those produced by the Linux kernel or `gcore`.
+/// The `PT_LOAD` segments of the core become the regions of the target, and the `NT_FILE` note provides its modules.
+/// Segment contents are read from the core file on demand, rather than loading the entire dump into memory.
+pub struct ElfCoreTarget {
+    name: String,
+    core_file_path: PathBuf,
+    bitness: Bitness,
+    segments: Vec<ElfCoreSegment>,
+    modules: Vec<NormalizedModule>,
+    core_file: Mutex<File>,
+}
+
+impl ElfCoreTarget {
+    pub fn open(core_file_path: &Path) -> Result<Self, String> {
+        let core_file = File::open(core_file_path).map_err(|error| format!("Failed to open core file {}: {}", core_file_path.display(), error))?;
+        let read_cache = ReadCache::new(core_file);
+
+        let (bitness, segments, modules) = match FileKind::parse(&read_cache) {
+            Ok(FileKind::Elf32) => {
+                let (segments, modules) = Self::parse_core::<FileHeader32<Endianness>>(&read_cache)?;
+                (Bitness::Bit32, segments, modules)
+            }
+            Ok(FileKind::Elf64) => {
+                let (segments, modules) = Self::parse_core::<FileHeader64<Endianness>>(&read_cache)?;
+                (Bitness::Bit64, segments, modules)
+            }
+            Ok(_) => return Err(format!("{} is not an ELF file.", core_file_path.display())),
+            Err(error) => return Err(format!("Failed to parse core file {}: {}", core_file_path.display(), error)),
+        };
+
+        let name = core_file_path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| core_file_path.display().to_string());
+
+        log::info!("Opened core file {} with {} segments and {} modules.", name, segments.len(), modules.len());
+
+        Ok(Self {
+            name,
+            core_file_path: core_file_path.to_path_buf(),
+            bitness,
+            segments,
+            modules,
+            core_file: Mutex::new(read_cache.into_inner()),
+        })
+    }
+
+    fn parse_core<Elf: FileHeader<Endian = Endianness>>(read_cache: &ReadCache<File>) -> Result<(Vec<ElfCoreSegment>, Vec<NormalizedModule>), String> {
+        let file_header = Elf::parse(read_cache).map_err(|error| format!("Invalid ELF header: {}", error))?;
+        let endian = file_header
+            .endian()
+            .map_err(|error| format!("Invalid ELF header: {}", error))?;
+
+        if file_header.e_type(endian) != ET_CORE {
+            return Err("The ELF file is not a core dump.".to_string());
+        }
+
+        let program_headers = file_header
+            .program_headers(endian, read_cache)
+            .map_err(|error| format!("Invalid ELF program headers: {}", error))?;
+        let word_size = if file_header.is_type_64() { 8 } else { 4 };
+        let mut file_mappings = vec![];
+
+        for program_header in program_headers {
+            let mut notes = match program_header.notes(endian, read_cache) {
+                Ok(Some(notes)) => notes,
+                Ok(None) => continue,
+                Err(error) => {
+                    log::warn!("Skipping invalid core note segment: {}", error);
+                    continue;
+                }
+            };
+
+            while let Ok(Some(note)) = notes.next() {
+                if note.n_type(endian) == NT_FILE && note.name() == b"CORE" {
+                    file_mappings.extend(Self::parse_file_mappings(note.desc(), word_size, endian));
+                }
+            }
+        }
+
+        let mut segments = vec![];
+
+        for program_header in program_headers {
+            if program_header.p_type(endian) != PT_LOAD {
+                continue;
+            }
+
+            let base_address: u64 = program_header.p_vaddr(endian).into();
+            let region_size: u64 = program_header.p_memsz(endian).into();
+            let file_size: u64 = program_header.p_filesz(endian).into();
+
+            // Segments without file contents were not captured by the dump (typically read only file mappings), so there is nothing to read.
+            if region_size == 0 || file_size == 0 {
+                continue;
+            }
+
+            let is_file_backed = file_mappings
+                .iter()
+                .any(|file_mapping| file_mapping.start_address < base_address + region_size && base_address < file_mapping.end_address);
+            let memory_type = if is_file_backed { MemoryTypeEnum::IMAGE } else { MemoryTypeEnum::PRIVATE };
+            let virtual_memory_region = VirtualMemoryRegion::new(
+                NormalizedRegion::new(base_address, region_size),
+                Self::get_protection(program_header.p_flags(endian)),
+                memory_type,
+            );
+
+            segments.push(ElfCoreSegment::new(
+                virtual_memory_region,
+                program_header.p_offset(endian).into(),
+                file_size.min(region_size),
+            ));
+        }
+
+        segments.sort_by_key(|segment| segment.get_virtual_memory_region().get_region().get_base_address());
+
+        Ok((segments, Self::get_modules_from_file_mappings(&file_mappings)))
+    }
+
+    /// Parses the `NT_FILE` note, which holds a count and page size, followed by a start, end, and page offset for each mapping,
+    /// followed by the null terminated path of each mapping.
+    fn parse_file_mappings(
+        desc: &[u8],
+        word_size: usize,
+        endian: Endianness,
+    ) -> Vec<ElfCoreFileMapping> {
+        let read_word = |index: usize| -> Option<u64> {
+            let bytes = desc.get(index * word_size..(index + 1) * word_size)?;
+
+            if word_size == 8 {
+                Some(endian.read_u64_bytes(bytes.try_into().ok()?))
+            } else {
+                Some(endian.read_u32_bytes(bytes.try_into().ok()?) as u64)
+            }
+        };
+        let mut file_mappings = vec![];
+        let count = match read_word(0) {
+            Some(count) => count as usize,
+            None => return file_mappings,
+        };
+        let file_paths_offset = count.saturating_mul(3).saturating_add(2).saturating_mul(word_size);
+        let mut file_paths = desc
+            .get(file_paths_offset..)
+            .unwrap_or_default()
+            .split(|byte| *byte == 0);
+
+        for index in 0..count {
+            let (Some(start_address), Some(end_address), Some(file_path)) = (read_word(2 + index * 3), read_word(3 + index * 3), file_paths.next()) else {
+                log::warn!("Core file mapping note is truncated, only {} of {} mappings were read.", index, count);
+                break;
+            };
+
+            file_mappings.push(ElfCoreFileMapping {
+                start_address,
+                end_address,
+                file_path: String::from_utf8_lossy(file_path).into_owned(),
+            });
+        }
+
+        file_mappings
+    }
+
+    /// Collapses the file mappings into one module per mapped file, spanning all mappings of that file.
+    fn get_modules_from_file_mappings(file_mappings: &[ElfCoreFileMapping]) -> Vec<NormalizedModule> {
+        let mut module_bounds: Vec<(&str, u64, u64)> = vec![];
+
+        for file_mapping in file_mappings {
+            match module_bounds
+                .iter_mut()
+                .find(|(file_path, _, _)| *file_path == file_mapping.file_path)
+            {
+                Some((_, start_address, end_address)) => {
+                    *start_address = (*start_address).min(file_mapping.start_address);
+                    *end_address = (*end_address).max(file_mapping.end_address);
+                }
+                None => module_bounds.push((&file_mapping.file_path, file_mapping.start_address, file_mapping.end_address)),
+            }
+        }
+
+        module_bounds
+            .into_iter()
+            .map(|(file_path, start_address, end_address)| {
+                let module_name = Path::new(file_path)
+                    .file_name()
+                    .map(|file_name| file_name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| file_path.to_string());
+                let mut module = NormalizedModule::new(&module_name, start_address, end_address.saturating_sub(start_address));
+
+                module.set_module_path(file_path);
+                module
+            })
+            .collect()
+    }
+
+    fn get_protection(segment_flags: u32) -> MemoryProtectionEnum {
+        let mut protection = MemoryProtectionEnum::NONE;
+
+        if segment_flags & PF_R != 0 {
+            protection |= MemoryProtectionEnum::READ;
+        }
+
+        if segment_flags & PF_W != 0 {
+            protection |= MemoryProtectionEnum::WRITE;
+        }
+
+        if segment_flags & PF_X != 0 {
+            protection |= MemoryProtectionEnum::EXECUTE;
+        }
+
+        protection
+    }
+
+    fn read_file_bytes(
+        &self,
+        file_offset: u64,
+        values: &mut [u8],
+    ) -> bool {
+        let mut core_file = match self.core_file.lock() {
+            Ok(core_file) => core_file,
+            Err(error) => {
+                log::error!("Failed to acquire core file lock: {}", error);
+                return false;
+            }
+        };
+
+        core_file.seek(SeekFrom::Start(file_offset)).is_ok() && core_file.read_exact(values).is_ok()
+    }
+}
+
+impl IVirtualMemoryTarget for ElfCoreTarget {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_bitness(&self) -> Bitness {
+        self.bitness
+    }
+
+    fn get_source_path(&self) -> Option<&Path> {
+        Some(&self.core_file_path)
+    }
+
+    fn get_regions(&self) -> Vec<VirtualMemoryRegion> {
+        self.segments
+            .iter()
+            .map(|segment| segment.get_virtual_memory_region().clone())
+            .collect()
+    }
+
+    fn get_modules(&self) -> Vec<NormalizedModule> {
+        self.modules.clone()
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        let mut bytes_read = 0;
+
+        // Reads may span several adjacent segments, but fail if any byte falls outside of the dumped memory.
+        while bytes_read < values.len() {
+            let current_address = address.saturating_add(bytes_read as u64);
+            let segment_index = self
+                .segments
+                .partition_point(|segment| segment.get_virtual_memory_region().get_region().get_base_address() <= current_address);
+
+            let segment = match segment_index.checked_sub(1).map(|index| &self.segments[index]) {
+                Some(segment) if current_address < segment.get_virtual_memory_region().get_region().get_end_address() => segment,
+                _ => return false,
+            };
+
+            let region = segment.get_virtual_memory_region().get_region();
+            let segment_offset = current_address - region.get_base_address();
+            let chunk_size = ((region.get_end_address() - current_address) as usize).min(values.len() - bytes_read);
+            let chunk = &mut values[bytes_read..bytes_read + chunk_size];
+
+            // Any part of the chunk beyond the stored file contents is zero filled, per the ELF specification.
+            let stored_size = (segment.get_file_size().saturating_sub(segment_offset) as usize).min(chunk_size);
+            let (stored_chunk, zero_filled_chunk) = chunk.split_at_mut(stored_size);
+
+            if !stored_chunk.is_empty() && !self.read_file_bytes(segment.get_file_offset() + segment_offset, stored_chunk) {
+                return false;
+            }
+
+            zero_filled_chunk.fill(0);
+            bytes_read += chunk_size;
+        }
+
+        true
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        _values: &[u8],
+    ) -> bool {
+        log::warn!("Cannot write to 0x{:X}, core file {} is read only.", address, self.name);
+
+        false
+    }
+}
@@ -0,0 +1,2 @@
+pub mod elf_core_segment;
+pub mod elf_core_target;
@@ -0,0 +1,239 @@
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::virtual_targets::virtual_memory_region::VirtualMemoryRegion;
+use crate::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A writable virtual target backed by an arbitrary file, such as a save game, exposed as a single region starting at address 0.
+/// Addresses are therefore file offsets. Writes are applied to an in-memory copy of the file, and are only written back to disk
+/// when changes are explicitly saved, at which point the original file is first backed up.
+pub struct FileTarget {
+    name: String,
+    file_path: PathBuf,
+    file_bytes: RwLock<Vec<u8>>,
+    has_unsaved_changes: AtomicBool,
+    session_backup_path: RwLock<Option<PathBuf>>,
+}
+
+impl FileTarget {
+    const BACKUP_EXTENSION: &'static str = "bak";
+
+    pub fn open(file_path: &Path) -> Result<Self, String> {
+        let file_bytes = fs::read(file_path).map_err(|error| format!("Failed to read file {}: {}", file_path.display(), error))?;
+        let name = file_path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file_path.display().to_string());
+
+        log::info!("Opened file {} as a target ({} bytes).", name, file_bytes.len());
+
+        Ok(Self {
+            name,
+            file_path: file_path.to_path_buf(),
+            file_bytes: RwLock::new(file_bytes),
+            has_unsaved_changes: AtomicBool::new(false),
+            session_backup_path: RwLock::new(None),
+        })
+    }
+
+    pub fn get_file_path(&self) -> &Path {
+        &self.file_path
+    }
+
+    /// Gets the path to which the original file is copied before changes are first saved, ie `save.dat` is backed up to `save.dat.bak`.
+    /// An existing backup is never overwritten, such that it keeps the file as it was before it was first edited.
+    pub fn get_backup_path(&self) -> PathBuf {
+        self.get_backup_path_with_suffix(None)
+    }
+
+    /// Gets the backup taken by the first save of this session, if any. This is the original backup, unless it already existed and
+    /// no longer matched the file on disk, in which case the file is backed up to a timestamped path, ie `save.dat.1700000000000.bak`.
+    pub fn get_session_backup_path(&self) -> Option<PathBuf> {
+        match self.session_backup_path.read() {
+            Ok(session_backup_path) => session_backup_path.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire file target backup lock: {}", error);
+                None
+            }
+        }
+    }
+
+    fn get_backup_path_with_suffix(
+        &self,
+        suffix: Option<&str>,
+    ) -> PathBuf {
+        let mut backup_path = self.file_path.clone().into_os_string();
+
+        if let Some(suffix) = suffix {
+            backup_path.push(".");
+            backup_path.push(suffix);
+        }
+
+        backup_path.push(".");
+        backup_path.push(Self::BACKUP_EXTENSION);
+
+        PathBuf::from(backup_path)
+    }
+
+    /// Backs up the file as it currently is on disk. The original backup is kept, and a timestamped backup is only written when the
+    /// file on disk has changed since, such that each session that saves over a different file can be rolled back independently.
+    fn back_up_file(&self) -> Result<PathBuf, String> {
+        let backup_path = self.get_backup_path();
+
+        if !backup_path.exists() {
+            fs::copy(&self.file_path, &backup_path).map_err(|error| format!("Failed to back up file to {}: {}", backup_path.display(), error))?;
+            log::info!("Backed up original file to {}.", backup_path.display());
+
+            return Ok(backup_path);
+        }
+
+        let file_bytes = fs::read(&self.file_path).map_err(|error| format!("Failed to read file {}: {}", self.file_path.display(), error))?;
+        let backup_bytes = fs::read(&backup_path).map_err(|error| format!("Failed to read backup {}: {}", backup_path.display(), error))?;
+
+        if file_bytes == backup_bytes {
+            log::info!("Existing backup {} already matches the file on disk.", backup_path.display());
+
+            return Ok(backup_path);
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+        let session_backup_path = self.get_backup_path_with_suffix(Some(&timestamp.to_string()));
+
+        fs::write(&session_backup_path, &file_bytes).map_err(|error| format!("Failed to back up file to {}: {}", session_backup_path.display(), error))?;
+        log::info!("Backed up file to {}, keeping the original backup {}.", session_backup_path.display(), backup_path.display());
+
+        Ok(session_backup_path)
+    }
+
+    fn get_file_size(&self) -> u64 {
+        match self.file_bytes.read() {
+            Ok(file_bytes) => file_bytes.len() as u64,
+            Err(error) => {
+                log::error!("Failed to acquire file target read lock: {}", error);
+                0
+            }
+        }
+    }
+}
+
+impl IVirtualMemoryTarget for FileTarget {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_bitness(&self) -> Bitness {
+        Bitness::Bit64
+    }
+
+    fn get_regions(&self) -> Vec<VirtualMemoryRegion> {
+        let file_size = self.get_file_size();
+
+        if file_size == 0 {
+            return vec![];
+        }
+
+        vec![VirtualMemoryRegion::new(
+            NormalizedRegion::new(0, file_size),
+            MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+            MemoryTypeEnum::PRIVATE,
+        )]
+    }
+
+    fn get_modules(&self) -> Vec<NormalizedModule> {
+        vec![]
+    }
+
+    fn get_source_path(&self) -> Option<&Path> {
+        Some(&self.file_path)
+    }
+
+    fn has_unsaved_changes(&self) -> bool {
+        self.has_unsaved_changes.load(Ordering::SeqCst)
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        let file_bytes = match self.file_bytes.read() {
+            Ok(file_bytes) => file_bytes,
+            Err(error) => {
+                log::error!("Failed to acquire file target read lock: {}", error);
+                return false;
+            }
+        };
+
+        match usize::try_from(address)
+            .ok()
+            .and_then(|offset| file_bytes.get(offset..offset.checked_add(values.len())?))
+        {
+            Some(bytes) => {
+                values.copy_from_slice(bytes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        let mut file_bytes = match self.file_bytes.write() {
+            Ok(file_bytes) => file_bytes,
+            Err(error) => {
+                log::error!("Failed to acquire file target write lock: {}", error);
+                return false;
+            }
+        };
+
+        // Writes never grow the file, as the target is exposed as a fixed size region.
+        match usize::try_from(address)
+            .ok()
+            .and_then(|offset| file_bytes.get_mut(offset..offset.checked_add(values.len())?))
+        {
+            Some(bytes) => {
+                bytes.copy_from_slice(values);
+                self.has_unsaved_changes.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn save_changes(&self) -> Result<(), String> {
+        let file_bytes = self
+            .file_bytes
+            .read()
+            .map_err(|error| format!("Failed to acquire file target read lock: {}", error))?;
+
+        // The file on disk is backed up on the first save of the session, such that the backup holds the file as it was before this session's edits.
+        let mut session_backup_path = self
+            .session_backup_path
+            .write()
+            .map_err(|error| format!("Failed to acquire file target backup lock: {}", error))?;
+
+        if session_backup_path.is_none() {
+            *session_backup_path = Some(self.back_up_file()?);
+        }
+
+        fs::write(&self.file_path, file_bytes.as_slice()).map_err(|error| format!("Failed to save file {}: {}", self.file_path.display(), error))?;
+        self.has_unsaved_changes.store(false, Ordering::SeqCst);
+
+        log::info!("Saved changes to {}.", self.file_path.display());
+
+        Ok(())
+    }
+}
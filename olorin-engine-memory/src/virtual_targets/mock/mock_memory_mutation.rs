@@ -0,0 +1,30 @@
+/// A scripted write to a mock target, applied once the target has served the given number of reads.
+#[derive(Clone)]
+pub struct MockMemoryMutation {
+    read_count: u64,
+    address: u64,
+    bytes: Vec<u8>,
+}
+
+impl MockMemoryMutation {
+    pub fn new(
+        read_count: u64,
+        address: u64,
+        bytes: Vec<u8>,
+    ) -> Self {
+        Self { read_count, address, bytes }
+    }
+
+    /// Gets the number of reads after which this mutation is applied.
+    pub fn get_read_count(&self) -> u64 {
+        self.read_count
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
@@ -0,0 +1,47 @@
+use crate::virtual_targets::virtual_memory_region::VirtualMemoryRegion;
+
+/// A region of a mock target, holding its contents directly.
+/// Unreadable regions are still reported by the queryer, but fail all reads and writes, simulating guard or decommitted pages.
+#[derive(Clone)]
+pub struct MockMemoryRegion {
+    virtual_memory_region: VirtualMemoryRegion,
+    bytes: Vec<u8>,
+    is_readable: bool,
+}
+
+impl MockMemoryRegion {
+    pub fn new(
+        virtual_memory_region: VirtualMemoryRegion,
+        bytes: Vec<u8>,
+        is_readable: bool,
+    ) -> Self {
+        Self {
+            virtual_memory_region,
+            bytes,
+            is_readable,
+        }
+    }
+
+    pub fn get_virtual_memory_region(&self) -> &VirtualMemoryRegion {
+        &self.virtual_memory_region
+    }
+
+    pub fn get_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn get_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.is_readable
+    }
+
+    pub fn set_is_readable(
+        &mut self,
+        is_readable: bool,
+    ) {
+        self.is_readable = is_readable;
+    }
+}
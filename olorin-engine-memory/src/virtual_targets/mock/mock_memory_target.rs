@@ -0,0 +1,292 @@
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::virtual_targets::mock::mock_memory_mutation::MockMemoryMutation;
+use crate::virtual_targets::mock::mock_memory_region::MockMemoryRegion;
+use crate::virtual_targets::virtual_memory_region::VirtualMemoryRegion;
+use crate::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+
+/// An in-memory virtual target with scripted contents, allowing the engine to be exercised deterministically without a live process.
+/// Regions, modules, and unreadable pages are all set up by the caller. Values can be changed directly between engine operations,
+/// or scripted as mutations that are applied once the target has served a given number of reads.
+pub struct MockMemoryTarget {
+    name: String,
+    bitness: Bitness,
+    regions: RwLock<Vec<MockMemoryRegion>>,
+    modules: RwLock<Vec<NormalizedModule>>,
+    pending_mutations: Mutex<Vec<MockMemoryMutation>>,
+    read_count: AtomicU64,
+    write_count: AtomicU64,
+}
+
+impl MockMemoryTarget {
+    pub fn new(
+        name: &str,
+        bitness: Bitness,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            bitness,
+            regions: RwLock::new(vec![]),
+            modules: RwLock::new(vec![]),
+            pending_mutations: Mutex::new(vec![]),
+            read_count: AtomicU64::new(0),
+            write_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds a readable region with the given contents. Regions are expected not to overlap.
+    pub fn add_region(
+        &self,
+        base_address: u64,
+        bytes: Vec<u8>,
+        protection: MemoryProtectionEnum,
+        memory_type: MemoryTypeEnum,
+    ) {
+        let virtual_memory_region = VirtualMemoryRegion::new(NormalizedRegion::new(base_address, bytes.len() as u64), protection, memory_type);
+
+        self.insert_region(MockMemoryRegion::new(virtual_memory_region, bytes, true));
+    }
+
+    /// Adds a region that is reported by the queryer, but which fails all reads and writes.
+    pub fn add_unreadable_region(
+        &self,
+        base_address: u64,
+        region_size: u64,
+    ) {
+        let virtual_memory_region = VirtualMemoryRegion::new(
+            NormalizedRegion::new(base_address, region_size),
+            MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+            MemoryTypeEnum::PRIVATE,
+        );
+
+        self.insert_region(MockMemoryRegion::new(virtual_memory_region, vec![0u8; region_size as usize], false));
+    }
+
+    /// Marks the region starting at the given address as readable or unreadable, returning whether such a region exists.
+    pub fn set_region_readable(
+        &self,
+        base_address: u64,
+        is_readable: bool,
+    ) -> bool {
+        let Ok(mut regions) = self.regions.write() else {
+            return false;
+        };
+
+        match regions
+            .iter_mut()
+            .find(|region| region.get_virtual_memory_region().get_region().get_base_address() == base_address)
+        {
+            Some(region) => {
+                region.set_is_readable(is_readable);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn add_module(
+        &self,
+        module_name: &str,
+        base_address: u64,
+        size: u64,
+    ) {
+        if let Ok(mut modules) = self.modules.write() {
+            modules.push(NormalizedModule::new(module_name, base_address, size));
+        }
+    }
+
+    /// Queues a write to be applied once the target has served the mutation's read count.
+    pub fn queue_mutation(
+        &self,
+        mock_memory_mutation: MockMemoryMutation,
+    ) {
+        if let Ok(mut pending_mutations) = self.pending_mutations.lock() {
+            pending_mutations.push(mock_memory_mutation);
+        }
+    }
+
+    /// Sets bytes directly, as the mocked process would, without counting as a write by the engine.
+    pub fn set_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        let Ok(mut regions) = self.regions.write() else {
+            return false;
+        };
+
+        Self::access_bytes(&mut regions, address, values.len(), |chunk, values_offset| {
+            chunk.copy_from_slice(&values[values_offset..values_offset + chunk.len()]);
+        })
+    }
+
+    /// Gets bytes directly, without counting as a read by the engine. Returns `None` if any byte is unreadable.
+    pub fn get_bytes(
+        &self,
+        address: u64,
+        length: usize,
+    ) -> Option<Vec<u8>> {
+        let mut values = vec![0u8; length];
+        let mut regions = self.regions.write().ok()?;
+
+        Self::access_bytes(&mut regions, address, length, |chunk, values_offset| {
+            values[values_offset..values_offset + chunk.len()].copy_from_slice(chunk);
+        })
+        .then_some(values)
+    }
+
+    /// Gets the number of reads served to the engine so far, including failed reads.
+    pub fn get_read_count(&self) -> u64 {
+        self.read_count.load(Ordering::SeqCst)
+    }
+
+    /// Gets the number of writes made by the engine so far, including failed writes.
+    pub fn get_write_count(&self) -> u64 {
+        self.write_count.load(Ordering::SeqCst)
+    }
+
+    fn insert_region(
+        &self,
+        mock_memory_region: MockMemoryRegion,
+    ) {
+        if let Ok(mut regions) = self.regions.write() {
+            regions.push(mock_memory_region);
+            regions.sort_by_key(|region| region.get_virtual_memory_region().get_region().get_base_address());
+        }
+    }
+
+    /// Applies any scripted mutations that are due. This is done while holding the region lock of the read that triggers them,
+    /// such that concurrent reads are serialized in read count order and always observe the mutations that precede them.
+    fn apply_pending_mutations(
+        &self,
+        regions: &mut [MockMemoryRegion],
+        served_read_count: u64,
+    ) {
+        let Ok(mut pending_mutations) = self.pending_mutations.lock() else {
+            return;
+        };
+        let (due_mutations, remaining_mutations): (Vec<MockMemoryMutation>, Vec<MockMemoryMutation>) = pending_mutations
+            .drain(..)
+            .partition(|mutation| mutation.get_read_count() <= served_read_count);
+
+        *pending_mutations = remaining_mutations;
+
+        for mutation in due_mutations {
+            let values = mutation.get_bytes();
+            let is_applied = Self::access_bytes(regions, mutation.get_address(), values.len(), |chunk, values_offset| {
+                chunk.copy_from_slice(&values[values_offset..values_offset + chunk.len()]);
+            });
+
+            if !is_applied {
+                log::warn!("Scripted mutation at 0x{:X} does not land in a readable region.", mutation.get_address());
+            }
+        }
+    }
+
+    /// Invokes the callback over each region chunk spanned by the given range, along with the offset of that chunk within the range.
+    /// Nothing is accessed unless the entire range is readable.
+    fn access_bytes<F>(
+        regions: &mut [MockMemoryRegion],
+        address: u64,
+        length: usize,
+        mut callback: F,
+    ) -> bool
+    where
+        F: FnMut(&mut [u8], usize),
+    {
+        let mut chunks = vec![];
+        let mut processed_length = 0;
+
+        while processed_length < length {
+            let Some(current_address) = address.checked_add(processed_length as u64) else {
+                return false;
+            };
+            let Some(region_index) = regions.iter().position(|region| {
+                let region = region.get_virtual_memory_region().get_region();
+
+                region.get_base_address() <= current_address && current_address < region.get_end_address()
+            }) else {
+                return false;
+            };
+
+            if !regions[region_index].is_readable() {
+                return false;
+            }
+
+            let region_offset = (current_address - regions[region_index].get_virtual_memory_region().get_region().get_base_address()) as usize;
+            let chunk_length = (regions[region_index].get_bytes().len() - region_offset).min(length - processed_length);
+
+            chunks.push((region_index, region_offset, chunk_length, processed_length));
+            processed_length += chunk_length;
+        }
+
+        for (region_index, region_offset, chunk_length, values_offset) in chunks {
+            callback(
+                &mut regions[region_index].get_bytes_mut()[region_offset..region_offset + chunk_length],
+                values_offset,
+            );
+        }
+
+        true
+    }
+}
+
+impl IVirtualMemoryTarget for MockMemoryTarget {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_bitness(&self) -> Bitness {
+        self.bitness
+    }
+
+    fn get_regions(&self) -> Vec<VirtualMemoryRegion> {
+        match self.regions.read() {
+            Ok(regions) => regions
+                .iter()
+                .map(|region| region.get_virtual_memory_region().clone())
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    fn get_modules(&self) -> Vec<NormalizedModule> {
+        match self.modules.read() {
+            Ok(modules) => modules.clone(),
+            Err(_) => vec![],
+        }
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        let Ok(mut regions) = self.regions.write() else {
+            self.read_count.fetch_add(1, Ordering::SeqCst);
+            return false;
+        };
+        let served_read_count = self.read_count.fetch_add(1, Ordering::SeqCst);
+
+        self.apply_pending_mutations(&mut regions, served_read_count);
+
+        Self::access_bytes(&mut regions, address, values.len(), |chunk, values_offset| {
+            values[values_offset..values_offset + chunk.len()].copy_from_slice(chunk);
+        })
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        self.write_count.fetch_add(1, Ordering::SeqCst);
+
+        self.set_bytes(address, values)
+    }
+}
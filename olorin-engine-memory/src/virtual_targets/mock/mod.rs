@@ -0,0 +1,3 @@
+pub mod mock_memory_mutation;
+pub mod mock_memory_region;
+pub mod mock_memory_target;
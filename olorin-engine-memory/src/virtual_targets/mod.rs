@@ -0,0 +1,9 @@
+pub mod elf_core;
+pub mod file;
+pub mod mock;
+pub mod virtual_memory_queryer;
+pub mod virtual_memory_reader;
+pub mod virtual_memory_region;
+pub mod virtual_memory_target_trait;
+pub mod virtual_memory_writer;
+pub mod virtual_target_registry;
@@ -0,0 +1,199 @@
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use crate::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Serves memory queries for processes that are backed by a registered virtual target.
+pub struct VirtualMemoryQueryer;
+
+impl VirtualMemoryQueryer {
+    pub fn new() -> Self {
+        VirtualMemoryQueryer
+    }
+}
+
+impl IMemoryQueryer for VirtualMemoryQueryer {
+    fn get_virtual_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+        required_protection: MemoryProtectionEnum,
+        excluded_protection: MemoryProtectionEnum,
+        allowed_types: MemoryTypeEnum,
+        start_address: u64,
+        end_address: u64,
+        region_bounds_handling: RegionBoundsHandling,
+    ) -> Vec<NormalizedRegion> {
+        let mut regions = vec![];
+
+        let target = match VirtualTargetRegistry::get_instance().get_target(process_info.get_process_id_raw()) {
+            Some(target) => target,
+            None => return regions,
+        };
+
+        // Return empty regions if the start or end address is invalid.
+        if start_address >= end_address {
+            return regions;
+        }
+
+        for virtual_region in target.get_regions() {
+            let mut region = virtual_region.get_region().clone();
+
+            // Regions are included if they overlap the bounds at all, subject to the bounds handling below.
+            if region.get_end_address() <= start_address || region.get_base_address() >= end_address {
+                continue;
+            }
+
+            if !allowed_types.intersects(virtual_region.get_memory_type()) {
+                continue;
+            }
+
+            // Ensure at least one required protection flag is set.
+            if !required_protection.is_empty() && !virtual_region.get_protection().intersects(required_protection) {
+                continue;
+            }
+
+            // Ensure no ignored protection flags are set.
+            if virtual_region.get_protection().intersects(excluded_protection) {
+                continue;
+            }
+
+            // Handle regions that are partially in the provided bounds based on given bounds handling method.
+            if region.get_base_address() < start_address || region.get_end_address() > end_address {
+                match region_bounds_handling {
+                    RegionBoundsHandling::Exclude => continue,
+                    RegionBoundsHandling::Include => {}
+                    RegionBoundsHandling::Resize => {
+                        region.set_end_address(end_address.min(region.get_end_address()));
+                        region.set_base_address_retain_end_address(start_address.max(region.get_base_address()));
+                    }
+                }
+            }
+
+            regions.push(region);
+        }
+
+        regions
+    }
+
+    fn get_all_virtual_pages(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedRegion> {
+        let start_address = 0;
+        let end_address = self.get_maximum_address(process_info);
+        self.get_virtual_pages(
+            process_info,
+            MemoryProtectionEnum::NONE,
+            MemoryProtectionEnum::NONE,
+            MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+            start_address,
+            end_address,
+            RegionBoundsHandling::Exclude,
+        )
+    }
+
+    fn is_address_writable(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> bool {
+        let virtual_pages_in_bounds = self.get_virtual_pages(
+            process_info,
+            MemoryProtectionEnum::WRITE,
+            MemoryProtectionEnum::NONE,
+            MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+            address,
+            address.saturating_add(1),
+            RegionBoundsHandling::Include,
+        );
+
+        virtual_pages_in_bounds.len() > 0
+    }
+
+    fn get_address_protection(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> MemoryProtectionEnum {
+        let target = match VirtualTargetRegistry::get_instance().get_target(process_info.get_process_id_raw()) {
+            Some(target) => target,
+            None => return MemoryProtectionEnum::NONE,
+        };
+
+        target
+            .get_regions()
+            .iter()
+            .find(|virtual_region| virtual_region.get_region().contains_address(address))
+            .map(|virtual_region| virtual_region.get_protection())
+            .unwrap_or(MemoryProtectionEnum::NONE)
+    }
+
+    fn get_maximum_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> u64 {
+        if process_info.get_bitness() == Bitness::Bit32 {
+            u32::MAX as u64
+        } else {
+            u64::MAX
+        }
+    }
+
+    fn get_min_usermode_address(
+        &self,
+        _: &OpenedProcessInfo,
+    ) -> u64 {
+        0
+    }
+
+    fn get_max_usermode_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> u64 {
+        // Virtual targets only expose the memory that was captured, so there is no kernel range to exclude.
+        self.get_maximum_address(process_info)
+    }
+
+    fn get_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        match VirtualTargetRegistry::get_instance().get_target(process_info.get_process_id_raw()) {
+            Some(target) => target.get_modules(),
+            None => vec![],
+        }
+    }
+
+    fn address_to_module(
+        &self,
+        address: u64,
+        modules: &Vec<NormalizedModule>,
+    ) -> Option<(String, u64)> {
+        for module in modules {
+            if module.contains_address(address) {
+                return Some((module.get_module_name().to_string(), address - module.get_base_address()));
+            }
+        }
+
+        None
+    }
+
+    fn resolve_module(
+        &self,
+        modules: &Vec<NormalizedModule>,
+        identifier: &str,
+    ) -> u64 {
+        for module in modules {
+            if module.get_module_name().eq_ignore_ascii_case(identifier) {
+                return module.get_base_address();
+            }
+        }
+
+        0
+    }
+}
@@ -0,0 +1,60 @@
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use crate::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use olorin_engine_api::structures::data_values::data_value::DataValue;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
+
+/// Serves memory reads for processes that are backed by a registered virtual target.
+pub struct VirtualMemoryReader;
+
+impl VirtualMemoryReader {
+    pub fn new() -> Self {
+        VirtualMemoryReader
+    }
+}
+
+impl IMemoryReader for VirtualMemoryReader {
+    fn read(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        data_value: &mut DataValue,
+    ) -> bool {
+        let mut buffer = vec![0u8; data_value.get_size_in_bytes() as usize];
+
+        let success = self.read_bytes(process_info, address, &mut buffer);
+        if success {
+            data_value.copy_from_bytes(&buffer);
+        }
+
+        return success;
+    }
+
+    fn read_struct(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        valued_struct: &mut ValuedStruct,
+    ) -> bool {
+        let mut buffer = vec![0u8; valued_struct.get_size_in_bytes() as usize];
+
+        let success = self.read_bytes(process_info, address, &mut buffer);
+        if success {
+            valued_struct.copy_from_bytes(&buffer);
+        }
+
+        return success;
+    }
+
+    fn read_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        match VirtualTargetRegistry::get_instance().get_target(process_info.get_process_id_raw()) {
+            Some(target) => target.read_bytes(address, values),
+            None => false,
+        }
+    }
+}
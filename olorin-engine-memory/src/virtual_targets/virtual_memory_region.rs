@@ -0,0 +1,37 @@
+use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+
+/// A region of memory exposed by a virtual target, along with the page attributes that the queryer filters on.
+#[derive(Clone)]
+pub struct VirtualMemoryRegion {
+    region: NormalizedRegion,
+    protection: MemoryProtectionEnum,
+    memory_type: MemoryTypeEnum,
+}
+
+impl VirtualMemoryRegion {
+    pub fn new(
+        region: NormalizedRegion,
+        protection: MemoryProtectionEnum,
+        memory_type: MemoryTypeEnum,
+    ) -> Self {
+        Self {
+            region,
+            protection,
+            memory_type,
+        }
+    }
+
+    pub fn get_region(&self) -> &NormalizedRegion {
+        &self.region
+    }
+
+    pub fn get_protection(&self) -> MemoryProtectionEnum {
+        self.protection
+    }
+
+    pub fn get_memory_type(&self) -> MemoryTypeEnum {
+        self.memory_type
+    }
+}
@@ -0,0 +1,44 @@
+use crate::virtual_targets::virtual_memory_region::VirtualMemoryRegion;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use std::path::Path;
+
+/// A memory source that is presented to the engine as a process, without being backed by a live process.
+pub trait IVirtualMemoryTarget: Send + Sync {
+    fn get_name(&self) -> &str;
+
+    fn get_bitness(&self) -> Bitness;
+
+    fn get_regions(&self) -> Vec<VirtualMemoryRegion>;
+
+    fn get_modules(&self) -> Vec<NormalizedModule>;
+
+    /// Gets the file from which the target was opened, if any, such that opening the same file again can reuse the target.
+    fn get_source_path(&self) -> Option<&Path> {
+        None
+    }
+
+    /// Reads the given bytes, failing if any part of the range is not backed by a region.
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool;
+
+    /// Writes the given bytes, failing if any part of the range is not backed by a region or the target is read only.
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> bool;
+
+    /// Gets whether writes have been made to the target that have not yet been persisted with `save_changes`.
+    fn has_unsaved_changes(&self) -> bool {
+        false
+    }
+
+    /// Persists any writes made to the target back to its source, for targets that support it.
+    fn save_changes(&self) -> Result<(), String> {
+        Err(format!("{} does not support saving changes.", self.get_name()))
+    }
+}
@@ -0,0 +1,26 @@
+use crate::memory_writer::memory_writer_trait::IMemoryWriter;
+use crate::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Serves memory writes for processes that are backed by a registered virtual target.
+pub struct VirtualMemoryWriter;
+
+impl VirtualMemoryWriter {
+    pub fn new() -> Self {
+        VirtualMemoryWriter
+    }
+}
+
+impl IMemoryWriter for VirtualMemoryWriter {
+    fn write_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        match VirtualTargetRegistry::get_instance().get_target(process_info.get_process_id_raw()) {
+            Some(target) => target.write_bytes(address, values),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::processes::process_info::ProcessInfo;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Once, RwLock};
+
+/// Tracks the virtual targets that can be listed and opened as though they were processes.
+/// Virtual targets are assigned process ids from a range that is not used by real processes, and the process id doubles
+/// as the handle of the opened target, such that memory operations can be routed to the target by either value.
+pub struct VirtualTargetRegistry {
+    targets: RwLock<HashMap<u32, Arc<dyn IVirtualMemoryTarget>>>,
+    next_process_id: AtomicU32,
+}
+
+impl VirtualTargetRegistry {
+    const FIRST_PROCESS_ID: u32 = 0xF000_0000;
+
+    fn new() -> Self {
+        Self {
+            targets: RwLock::new(HashMap::new()),
+            next_process_id: AtomicU32::new(Self::FIRST_PROCESS_ID),
+        }
+    }
+
+    pub fn get_instance() -> &'static VirtualTargetRegistry {
+        static mut INSTANCE: Option<VirtualTargetRegistry> = None;
+        static INIT: Once = Once::new();
+
+        unsafe {
+            INIT.call_once(|| {
+                let instance = VirtualTargetRegistry::new();
+                INSTANCE = Some(instance);
+            });
+
+            #[allow(static_mut_refs)]
+            INSTANCE.as_ref().unwrap_unchecked()
+        }
+    }
+
+    /// Registers a virtual target, returning the process id under which it can be listed and opened.
+    pub fn register_target(
+        &self,
+        target: Arc<dyn IVirtualMemoryTarget>,
+    ) -> u32 {
+        let process_id = self.next_process_id.fetch_add(1, Ordering::SeqCst);
+
+        match self.targets.write() {
+            Ok(mut targets) => {
+                targets.insert(process_id, target);
+            }
+            Err(error) => {
+                log::error!("Failed to acquire virtual target registry write lock: {}", error);
+            }
+        }
+
+        process_id
+    }
+
+    /// Removes a virtual target, returning whether a target was registered under the given process id.
+    pub fn unregister_target(
+        &self,
+        process_id: u32,
+    ) -> bool {
+        match self.targets.write() {
+            Ok(mut targets) => targets.remove(&process_id).is_some(),
+            Err(error) => {
+                log::error!("Failed to acquire virtual target registry write lock: {}", error);
+                false
+            }
+        }
+    }
+
+    pub fn get_target(
+        &self,
+        process_id: u32,
+    ) -> Option<Arc<dyn IVirtualMemoryTarget>> {
+        // Avoid taking the lock for real processes, as this is checked for every memory operation.
+        if process_id < Self::FIRST_PROCESS_ID {
+            return None;
+        }
+
+        match self.targets.read() {
+            Ok(targets) => targets.get(&process_id).cloned(),
+            Err(error) => {
+                log::error!("Failed to acquire virtual target registry read lock: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Finds the process id of the registered target opened from the given file, if any. Paths are compared as given,
+    /// so callers should canonicalize them before both opening and finding targets.
+    pub fn find_target_by_source_path(
+        &self,
+        source_path: &Path,
+    ) -> Option<u32> {
+        match self.targets.read() {
+            Ok(targets) => targets
+                .iter()
+                .filter(|(_, target)| target.get_source_path() == Some(source_path))
+                .map(|(process_id, _)| *process_id)
+                .min(),
+            Err(error) => {
+                log::error!("Failed to acquire virtual target registry read lock: {}", error);
+                None
+            }
+        }
+    }
+
+    pub fn is_virtual_process(
+        &self,
+        process_id: u32,
+    ) -> bool {
+        self.get_target(process_id).is_some()
+    }
+
+    /// Gets whether the virtual target registered under the given process id has writes that have not been saved back to its source.
+    pub fn has_unsaved_changes(
+        &self,
+        process_id: u32,
+    ) -> bool {
+        self.get_target(process_id)
+            .is_some_and(|target| target.has_unsaved_changes())
+    }
+
+    /// Gets whether the given handle belongs to an opened virtual target.
+    pub fn is_virtual_handle(
+        &self,
+        handle: u64,
+    ) -> bool {
+        u32::try_from(handle).map_or(false, |process_id| self.is_virtual_process(process_id))
+    }
+
+    /// Gets the process info of all registered virtual targets, ordered by process id.
+    pub fn get_process_infos(&self) -> Vec<ProcessInfo> {
+        let mut process_infos: Vec<ProcessInfo> = match self.targets.read() {
+            Ok(targets) => targets
+                .iter()
+                .map(|(process_id, target)| ProcessInfo::new(*process_id, target.get_name().to_string(), false, None))
+                .collect(),
+            Err(error) => {
+                log::error!("Failed to acquire virtual target registry read lock: {}", error);
+                vec![]
+            }
+        };
+
+        process_infos.sort_by_key(|process_info| process_info.get_process_id_raw());
+
+        process_infos
+    }
+
+    /// Opens a registered virtual target, if one exists for the given process id.
+    pub fn open_process(
+        &self,
+        process_info: &ProcessInfo,
+    ) -> Option<OpenedProcessInfo> {
+        let process_id = process_info.get_process_id_raw();
+        let target = self.get_target(process_id)?;
+
+        Some(OpenedProcessInfo::new(
+            process_id,
+            target.get_name().to_string(),
+            process_id as u64,
+            target.get_bitness(),
+            process_info.get_icon().clone(),
+        ))
+    }
+}
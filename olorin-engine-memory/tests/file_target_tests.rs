@@ -0,0 +1,53 @@
+use olorin_engine_memory::virtual_targets::file::file_target::FileTarget;
+use olorin_engine_memory::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates a uniquely named file in the temporary directory holding the given bytes.
+fn create_fixture_file(
+    name: &str,
+    file_bytes: &[u8],
+) -> PathBuf {
+    let file_path = std::env::temp_dir().join(format!("olorin_{}_{}.dat", name, std::process::id()));
+
+    fs::write(&file_path, file_bytes).expect("Failed to write the fixture file.");
+
+    file_path
+}
+
+#[test]
+fn backups_keep_the_original_file_and_back_up_later_sessions() {
+    let file_path = create_fixture_file("file_target_backup", &[1, 2, 3, 4]);
+
+    // First session, which backs up the original file before saving.
+    let file_target = FileTarget::open(&file_path).expect("Failed to open the fixture file.");
+    let backup_path = file_target.get_backup_path();
+
+    let _ = fs::remove_file(&backup_path);
+
+    assert!(file_target.write_bytes(0, &[5]));
+    file_target.save_changes().expect("Failed to save the first session.");
+
+    assert_eq!(file_target.get_session_backup_path(), Some(backup_path.clone()));
+
+    // Second session, which must not replace the original backup with the already edited file, but backs the edited file up separately.
+    let file_target = FileTarget::open(&file_path).expect("Failed to reopen the fixture file.");
+
+    assert!(file_target.write_bytes(1, &[6]));
+    assert!(file_target.has_unsaved_changes());
+    file_target.save_changes().expect("Failed to save the second session.");
+    assert!(!file_target.has_unsaved_changes());
+
+    let session_backup_path = file_target
+        .get_session_backup_path()
+        .expect("The second session was not backed up.");
+
+    assert_ne!(session_backup_path, backup_path);
+    assert_eq!(fs::read(&file_path).unwrap(), vec![5, 6, 3, 4]);
+    assert_eq!(fs::read(&backup_path).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(fs::read(&session_backup_path).unwrap(), vec![5, 2, 3, 4]);
+
+    let _ = fs::remove_file(&file_path);
+    let _ = fs::remove_file(&backup_path);
+    let _ = fs::remove_file(&session_backup_path);
+}
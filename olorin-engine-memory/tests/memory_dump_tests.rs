@@ -0,0 +1,33 @@
+use olorin_engine_memory::memory_dumps::memory_dump::MemoryDump;
+use olorin_engine_memory::memory_dumps::memory_dump_header::MemoryDumpHeader;
+use olorin_engine_memory::memory_dumps::memory_dump_region::MemoryDumpRegion;
+use std::fs;
+use std::fs::OpenOptions;
+
+#[test]
+fn dumps_only_expose_captured_regions_and_reject_truncated_files() {
+    let file_path = std::env::temp_dir().join(format!("olorin_memory_dump_{}.dmp", std::process::id()));
+    let dumped_bytes: Vec<u8> = (0..16).collect();
+
+    // Only the middle of the dump was readable, the remainder are zeros standing in for unreadable memory.
+    let header = MemoryDumpHeader::new(0x1000, 16, None, vec![MemoryDumpRegion::new(0x1004, 8, 0)]);
+
+    MemoryDump::save_to_file(&file_path, &header, &mut dumped_bytes.as_slice()).expect("Failed to save the dump.");
+
+    let memory_dump = MemoryDump::load_from_file(&file_path).expect("Failed to load the dump.");
+    let bytes = memory_dump.read_bytes(0xFFE, 8);
+
+    assert_eq!(bytes, vec![None, None, None, None, None, None, Some(4), Some(5)]);
+
+    // A header claiming more bytes than the file holds must not be trusted.
+    drop(memory_dump);
+    OpenOptions::new()
+        .write(true)
+        .open(&file_path)
+        .and_then(|file| file.set_len(file.metadata()?.len() - 1))
+        .expect("Failed to truncate the dump.");
+
+    assert!(MemoryDump::load_from_file(&file_path).is_err());
+
+    let _ = fs::remove_file(&file_path);
+}
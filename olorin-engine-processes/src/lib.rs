@@ -1,2 +1,4 @@
 pub mod process;
+pub mod process_launch;
 pub mod process_query;
+pub mod thread_query;
@@ -2,18 +2,28 @@ use crate::process_query::{process_query_options::ProcessQueryOptions, process_q
 use olorin_engine_api::{
     events::{
         engine_event::{EngineEvent, EngineEventRequest},
-        process::changed::process_changed_event::ProcessChangedEvent,
+        process::changed::{process_change_kind::ProcessChangeKind, process_changed_event::ProcessChangedEvent},
     },
-    structures::processes::opened_process_info::OpenedProcessInfo,
+    structures::processes::{opened_process_info::OpenedProcessInfo, process_watch_rule::ProcessWatchRule},
 };
 use std::{
+    collections::HashSet,
+    path::PathBuf,
     sync::{Arc, RwLock},
     thread,
     time::Duration,
 };
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
 
 pub struct ProcessManager {
     opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+
+    /// The process to attach to automatically whenever no process is opened, if any.
+    process_watch_rule: Arc<RwLock<Option<ProcessWatchRule>>>,
+
+    /// The name of the most recently opened process that died, used to tell re-attaching to a restarted process apart from a new attach.
+    lost_process_name: Arc<RwLock<Option<String>>>,
+
     event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
 }
 
@@ -21,10 +31,18 @@ impl ProcessManager {
     pub fn new(event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>) -> Self {
         let instance = Self {
             opened_process: Arc::new(RwLock::new(None)),
+            process_watch_rule: Arc::new(RwLock::new(None)),
+            lost_process_name: Arc::new(RwLock::new(None)),
             event_emitter: event_emitter.clone(),
         };
 
-        Self::listen_for_open_process_death(event_emitter, instance.opened_process.clone());
+        Self::listen_for_open_process_death(event_emitter.clone(), instance.opened_process.clone(), instance.lost_process_name.clone());
+        Self::listen_for_watched_process(
+            event_emitter,
+            instance.opened_process.clone(),
+            instance.process_watch_rule.clone(),
+            instance.lost_process_name.clone(),
+        );
 
         instance
     }
@@ -34,17 +52,7 @@ impl ProcessManager {
         &self,
         process_info: OpenedProcessInfo,
     ) {
-        if let Ok(mut process) = self.opened_process.write() {
-            log::info!("Opened process: {}, pid: {}", process_info.get_name(), process_info.get_process_id());
-            *process = Some(process_info.clone());
-
-            (self.event_emitter)(
-                ProcessChangedEvent {
-                    process_info: Some(process_info),
-                }
-                .to_engine_event(),
-            );
-        }
+        Self::attach_to_process(&self.event_emitter, &self.opened_process, &self.lost_process_name, process_info);
     }
 
     /// Clears the process to which we are currently attached.
@@ -52,9 +60,20 @@ impl ProcessManager {
         if let Ok(mut process) = self.opened_process.write() {
             *process = None;
 
+            // An explicit detach is not a crash, so reopening the same process later is treated as a new attach.
+            if let Ok(mut lost_process_name) = self.lost_process_name.write() {
+                *lost_process_name = None;
+            }
+
             log::info!("Process closed.");
 
-            (self.event_emitter)(ProcessChangedEvent { process_info: None }.to_engine_event());
+            (self.event_emitter)(
+                ProcessChangedEvent {
+                    process_info: None,
+                    change_kind: ProcessChangeKind::Detached,
+                }
+                .to_engine_event(),
+            );
         }
     }
 
@@ -74,10 +93,73 @@ impl ProcessManager {
         self.opened_process.clone()
     }
 
+    /// Gets the rule describing the process to attach to automatically, if any.
+    pub fn get_process_watch_rule(&self) -> Option<ProcessWatchRule> {
+        match self.process_watch_rule.read() {
+            Ok(process_watch_rule) => process_watch_rule.clone(),
+            Err(error) => {
+                log::error!("Failed to access process watch rule: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Sets the rule describing the process to attach to automatically whenever no process is opened, or clears it if `None`.
+    pub fn set_process_watch_rule(
+        &self,
+        process_watch_rule: Option<ProcessWatchRule>,
+    ) {
+        if let Ok(mut current_process_watch_rule) = self.process_watch_rule.write() {
+            match &process_watch_rule {
+                Some(process_watch_rule) => log::info!("Watching for process: {}", process_watch_rule.get_process_pattern()),
+                None if current_process_watch_rule.is_some() => log::info!("Stopped watching for process."),
+                None => {}
+            }
+
+            *current_process_watch_rule = process_watch_rule;
+        }
+    }
+
+    fn attach_to_process(
+        event_emitter: &Arc<dyn Fn(EngineEvent) + Send + Sync>,
+        opened_process: &Arc<RwLock<Option<OpenedProcessInfo>>>,
+        lost_process_name: &Arc<RwLock<Option<String>>>,
+        process_info: OpenedProcessInfo,
+    ) {
+        let change_kind = match opened_process.write() {
+            Ok(mut process) => {
+                log::info!("Opened process: {}, pid: {}", process_info.get_name(), process_info.get_process_id());
+                *process = Some(process_info.clone());
+
+                match lost_process_name.write() {
+                    Ok(mut lost_process_name) => match lost_process_name.take() {
+                        Some(lost_process_name) if lost_process_name.eq_ignore_ascii_case(process_info.get_name()) => ProcessChangeKind::Reattached,
+                        _ => ProcessChangeKind::Attached,
+                    },
+                    Err(_) => ProcessChangeKind::Attached,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to access opened process: {}", error);
+                return;
+            }
+        };
+
+        // The event is emitted after releasing the lock, as re-attach handling may take a while to re-resolve addresses.
+        (event_emitter)(
+            ProcessChangedEvent {
+                process_info: Some(process_info),
+                change_kind,
+            }
+            .to_engine_event(),
+        );
+    }
+
     /// Listens for the death of the currently opened process by polling for it repeatedly.
     fn listen_for_open_process_death(
         event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
         opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        lost_process_name: Arc<RwLock<Option<String>>>,
     ) {
         std::thread::spawn(move || {
             loop {
@@ -109,12 +191,133 @@ impl ProcessManager {
 
                 if processes.len() <= 0 {
                     if let Ok(mut opened_process) = opened_process.write() {
-                        *opened_process = None;
+                        // Remember the process that died, such that attaching to its next instance is reported as a re-attach.
+                        if let (Some(opened_process_info), Ok(mut lost_process_name)) = (opened_process.take(), lost_process_name.write()) {
+                            *lost_process_name = Some(opened_process_info.get_name().to_string());
+                        }
+
                         log::info!("Process no longer open, detaching.");
-                        (event_emitter)(ProcessChangedEvent { process_info: None }.to_engine_event());
+                        (event_emitter)(
+                            ProcessChangedEvent {
+                                process_info: None,
+                                change_kind: ProcessChangeKind::Detached,
+                            }
+                            .to_engine_event(),
+                        );
                     }
                 }
             }
         });
     }
+
+    /// Listens for a process matching the watch rule to appear while no process is opened, and attaches to it automatically.
+    fn listen_for_watched_process(
+        event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
+        opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        process_watch_rule: Arc<RwLock<Option<ProcessWatchRule>>>,
+        lost_process_name: Arc<RwLock<Option<String>>>,
+    ) {
+        std::thread::spawn(move || {
+            // Processes that could not be opened are only attempted once, rather than failing (and logging) on every poll.
+            let mut failed_process_ids = HashSet::new();
+
+            loop {
+                thread::sleep(Duration::from_millis(500));
+
+                let is_process_opened = match opened_process.read() {
+                    Ok(opened_process) => opened_process.is_some(),
+                    Err(_) => continue,
+                };
+                let process_watch_rule = match process_watch_rule.read() {
+                    Ok(process_watch_rule) => process_watch_rule.clone(),
+                    Err(_) => continue,
+                };
+                let process_watch_rule = match process_watch_rule {
+                    Some(process_watch_rule) if !is_process_opened => process_watch_rule,
+                    _ => continue,
+                };
+
+                // Icons are not needed to match the watch rule, and are expensive to fetch for every process on every poll.
+                let process_query_options = ProcessQueryOptions {
+                    required_process_id: None,
+                    search_name: None,
+                    require_windowed: false,
+                    match_case: false,
+                    fetch_icons: false,
+                    limit: None,
+                };
+                let processes = ProcessQuery::get_processes(process_query_options);
+
+                // Forget failures for processes that have exited, as their ids may be reused by a later process.
+                failed_process_ids.retain(|process_id| {
+                    processes
+                        .iter()
+                        .any(|process_info| process_info.get_process_id_raw() == *process_id)
+                });
+
+                let matching_process = processes
+                    .into_iter()
+                    .filter(|process_info| !failed_process_ids.contains(&process_info.get_process_id_raw()))
+                    .find(|process_info| {
+                        let executable_path = if process_watch_rule.is_path_pattern() {
+                            Self::get_executable_path(process_info.get_process_id())
+                        } else {
+                            None
+                        };
+
+                        process_watch_rule.matches(process_info.get_name(), executable_path.as_deref())
+                    });
+
+                let Some(process_info) = matching_process else {
+                    continue;
+                };
+
+                // Fetch the icon of only the matched process, falling back to the process without an icon if it exits in the meantime.
+                let process_query_options = ProcessQueryOptions {
+                    required_process_id: Some(process_info.get_process_id()),
+                    search_name: None,
+                    require_windowed: false,
+                    match_case: false,
+                    fetch_icons: true,
+                    limit: Some(1),
+                };
+                let process_info = ProcessQuery::get_processes(process_query_options)
+                    .into_iter()
+                    .next()
+                    .unwrap_or(process_info);
+
+                match ProcessQuery::open_process(&process_info) {
+                    Ok(opened_process_info) => {
+                        // A process may have been opened manually while this one was being opened, in which case that one takes priority.
+                        if opened_process.read().map(|opened_process| opened_process.is_some()).unwrap_or(true) {
+                            let _ = ProcessQuery::close_process(opened_process_info.get_handle());
+                            continue;
+                        }
+
+                        log::info!("Watched process {} appeared, attaching.", process_info.get_name());
+                        Self::attach_to_process(&event_emitter, &opened_process, &lost_process_name, opened_process_info);
+                    }
+                    Err(error) => {
+                        log::error!("Failed to attach to watched process {}: {}", process_info.get_name(), error);
+                        failed_process_ids.insert(process_info.get_process_id_raw());
+                    }
+                }
+            }
+        });
+    }
+
+    fn get_executable_path(process_id: Pid) -> Option<PathBuf> {
+        let mut system = System::new();
+
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[process_id]),
+            true,
+            ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+        );
+
+        system
+            .process(process_id)
+            .and_then(|process| process.exe())
+            .map(|executable_path| executable_path.to_path_buf())
+    }
 }
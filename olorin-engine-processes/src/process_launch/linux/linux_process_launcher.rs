@@ -0,0 +1,142 @@
+use crate::process_launch::process_launcher::PlatformProcessLauncher;
+use std::env;
+use std::ffi::{CStr, CString};
+use std::io;
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+
+pub struct LinuxProcessLauncher;
+
+impl LinuxProcessLauncher {
+    /// The environment variables through which pkexec and sudo report the user that invoked them, in order of preference.
+    const INVOKING_USER_ID_VARIABLES: [&'static str; 2] = ["PKEXEC_UID", "SUDO_UID"];
+
+    fn get_invoking_user_id() -> Option<libc::uid_t> {
+        Self::INVOKING_USER_ID_VARIABLES
+            .iter()
+            .find_map(|variable_name| env::var(variable_name).ok()?.parse::<libc::uid_t>().ok())
+    }
+
+    /// Looks up the name, home directory, and primary group of a user.
+    fn get_user(user_id: libc::uid_t) -> Result<(String, String, libc::gid_t), String> {
+        let mut passwd = unsafe { std::mem::zeroed::<libc::passwd>() };
+        let mut buffer = vec![0 as libc::c_char; 16384];
+        let mut result = std::ptr::null_mut::<libc::passwd>();
+        let error_code = unsafe { libc::getpwuid_r(user_id, &mut passwd, buffer.as_mut_ptr(), buffer.len(), &mut result) };
+
+        if error_code != 0 || result.is_null() {
+            return Err(format!("Failed to find user {}: {}", user_id, io::Error::from_raw_os_error(error_code)));
+        }
+
+        let user_name = unsafe { CStr::from_ptr(passwd.pw_name) }.to_string_lossy().into_owned();
+        let home_directory = unsafe { CStr::from_ptr(passwd.pw_dir) }.to_string_lossy().into_owned();
+
+        Ok((user_name, home_directory, passwd.pw_gid))
+    }
+
+    /// Gets the primary and supplementary groups of a user.
+    fn get_group_ids(
+        user_name: &str,
+        group_id: libc::gid_t,
+    ) -> Result<Vec<libc::gid_t>, String> {
+        let user_name = CString::new(user_name).map_err(|error| format!("Invalid user name {}: {}", user_name, error))?;
+        let mut group_ids = vec![0 as libc::gid_t; 64];
+
+        loop {
+            let mut group_count = group_ids.len() as libc::c_int;
+
+            // The group count is updated to the number of groups the user has when the buffer is too small.
+            if unsafe { libc::getgrouplist(user_name.as_ptr(), group_id, group_ids.as_mut_ptr(), &mut group_count) } != -1 {
+                group_ids.truncate(group_count as usize);
+                return Ok(group_ids);
+            }
+
+            if group_count as usize <= group_ids.len() {
+                return Err(format!("Failed to get the groups of user {}.", user_name.to_string_lossy()));
+            }
+
+            group_ids.resize(group_count as usize, 0);
+        }
+    }
+}
+
+impl PlatformProcessLauncher for LinuxProcessLauncher {
+    fn prepare_unprivileged_start(command: &mut Command) -> Result<(), String> {
+        // An engine elevated through pkexec or sudo runs as root, but the launched process should run as the user that elevated it.
+        // An engine that was started as root directly has no such user, and launches processes as root.
+        if unsafe { libc::geteuid() } != 0 {
+            return Ok(());
+        }
+
+        let user_id = match Self::get_invoking_user_id() {
+            Some(user_id) if user_id != 0 => user_id,
+            _ => return Ok(()),
+        };
+        let (user_name, home_directory, group_id) = Self::get_user(user_id)?;
+        let group_ids = Self::get_group_ids(&user_name, group_id)?;
+
+        command
+            .env("HOME", home_directory)
+            .env("USER", &user_name)
+            .env("LOGNAME", &user_name);
+
+        // The groups are looked up before forking, as the child may only make async-signal-safe calls before exec.
+        // Groups must be dropped first, as changing them requires the privileges that dropping the user id gives up.
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setgroups(group_ids.len(), group_ids.as_ptr()) == -1
+                    || libc::setgid(group_id) == -1
+                    || libc::setuid(user_id) == -1
+                {
+                    return Err(io::Error::last_os_error());
+                }
+
+                Ok(())
+            });
+        }
+
+        Ok(())
+    }
+
+    fn prepare_suspended_start(command: &mut Command) {
+        // Asking to be traced makes the kernel stop the child with SIGTRAP once exec completes, before the loader or main have run.
+        unsafe {
+            command.pre_exec(|| {
+                if libc::ptrace(libc::PTRACE_TRACEME, 0, std::ptr::null_mut::<libc::c_void>(), std::ptr::null_mut::<libc::c_void>()) == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                Ok(())
+            });
+        }
+    }
+
+    fn complete_suspended_start(process_id: u32) -> Result<(), String> {
+        let pid = process_id as libc::pid_t;
+        let mut status = 0;
+
+        if unsafe { libc::waitpid(pid, &mut status, 0) } == -1 {
+            return Err(format!("Failed to wait for the process to stop: {}", io::Error::last_os_error()));
+        }
+
+        if !libc::WIFSTOPPED(status) || libc::WSTOPSIG(status) != libc::SIGTRAP {
+            return Err(format!("The process did not stop after exec, wait status: {}", status));
+        }
+
+        // Only the spawning thread may resume a ptrace stop, so detach with SIGSTOP instead. This leaves the process in a regular stop,
+        // which SIGCONT resumes from any thread, and frees it up for a debugger to attach.
+        if unsafe { libc::ptrace(libc::PTRACE_DETACH, pid, std::ptr::null_mut::<libc::c_void>(), libc::SIGSTOP as usize as *mut libc::c_void) } == -1 {
+            return Err(format!("Failed to detach from the process: {}", io::Error::last_os_error()));
+        }
+
+        Ok(())
+    }
+
+    fn resume_process(process_id: u32) -> Result<(), String> {
+        if unsafe { libc::kill(process_id as libc::pid_t, libc::SIGCONT) } == -1 {
+            return Err(format!("Failed to resume process {}: {}", process_id, io::Error::last_os_error()));
+        }
+
+        Ok(())
+    }
+}
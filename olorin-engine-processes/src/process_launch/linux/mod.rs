@@ -0,0 +1 @@
+pub mod linux_process_launcher;
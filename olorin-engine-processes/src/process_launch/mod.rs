@@ -0,0 +1,11 @@
+pub mod process_launch_options;
+pub mod process_launcher;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux;
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "windows")))]
+mod unsupported;
+
+#[cfg(target_os = "windows")]
+mod windows;
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+pub struct ProcessLaunchOptions {
+    pub executable_path: PathBuf,
+    pub arguments: Vec<String>,
+    pub working_directory: Option<PathBuf>,
+    /// Environment variables to set for the launched process, on top of those inherited from the engine.
+    pub environment_variables: Vec<(String, String)>,
+    /// Whether to stop the process before any of its code runs, such that values initialized at startup can be caught.
+    pub start_suspended: bool,
+}
@@ -0,0 +1,156 @@
+use crate::process_launch::process_launch_options::ProcessLaunchOptions;
+use once_cell::sync::Lazy;
+use olorin_engine_api::structures::processes::process_info::ProcessInfo;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+
+pub(crate) trait PlatformProcessLauncher {
+    /// Prepares the command such that the spawned process runs as the user that started the engine, rather than with the elevated
+    /// privileges of the engine.
+    fn prepare_unprivileged_start(command: &mut Command) -> Result<(), String>;
+
+    /// Prepares the command such that the spawned process stops before running any of its own code.
+    fn prepare_suspended_start(command: &mut Command);
+
+    /// Completes a suspended start once the process has spawned, leaving it stopped until it is resumed.
+    fn complete_suspended_start(process_id: u32) -> Result<(), String>;
+
+    fn resume_process(process_id: u32) -> Result<(), String>;
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::process_launch::linux::linux_process_launcher::LinuxProcessLauncher as ProcessLauncherImpl;
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "windows")))]
+use crate::process_launch::unsupported::unsupported_process_launcher::UnsupportedProcessLauncher as ProcessLauncherImpl;
+
+#[cfg(target_os = "windows")]
+use crate::process_launch::windows::windows_process_launcher::WindowsProcessLauncher as ProcessLauncherImpl;
+
+/// The processes that were launched suspended and have not yet been resumed.
+static SUSPENDED_PROCESS_IDS: Lazy<Mutex<HashSet<u32>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+pub struct ProcessLauncher;
+
+impl ProcessLauncher {
+    /// Launches an executable, forwarding its standard output and error to the log until it exits.
+    pub fn launch_process(process_launch_options: &ProcessLaunchOptions) -> Result<ProcessInfo, String> {
+        let executable_path = &process_launch_options.executable_path;
+        let process_name = match executable_path.file_name() {
+            Some(file_name) => file_name.to_string_lossy().into_owned(),
+            None => return Err(format!("Invalid executable path: {}", executable_path.display())),
+        };
+        let mut command = Command::new(executable_path);
+
+        command
+            .args(&process_launch_options.arguments)
+            .envs(process_launch_options.environment_variables.iter().cloned())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(working_directory) = &process_launch_options.working_directory {
+            command.current_dir(working_directory);
+        }
+
+        ProcessLauncherImpl::prepare_unprivileged_start(&mut command).map_err(|error| format!("Failed to launch {}: {}", process_name, error))?;
+
+        if process_launch_options.start_suspended {
+            ProcessLauncherImpl::prepare_suspended_start(&mut command);
+        }
+
+        let mut child = command
+            .spawn()
+            .map_err(|error| format!("Failed to launch {}: {}", executable_path.display(), error))?;
+        let process_id = child.id();
+
+        if process_launch_options.start_suspended {
+            if let Err(error) = ProcessLauncherImpl::complete_suspended_start(process_id) {
+                let _ = child.kill();
+                let _ = child.wait();
+
+                return Err(format!("Failed to suspend {}: {}", process_name, error));
+            }
+
+            if let Ok(mut suspended_process_ids) = SUSPENDED_PROCESS_IDS.lock() {
+                suspended_process_ids.insert(process_id);
+            }
+
+            log::info!("Launched {} suspended, pid: {}", process_name, process_id);
+        } else {
+            log::info!("Launched {}, pid: {}", process_name, process_id);
+        }
+
+        Self::forward_output(child.stdout.take(), process_name.clone(), process_id, false);
+        Self::forward_output(child.stderr.take(), process_name.clone(), process_id, true);
+        Self::wait_for_exit(child, process_name.clone(), process_id);
+
+        Ok(ProcessInfo::new(process_id, process_name, false, None))
+    }
+
+    /// Resumes a process that was launched suspended. Other processes are rejected, as resuming them could release threads that the
+    /// process deliberately suspended itself.
+    pub fn resume_process(process_id: u32) -> Result<(), String> {
+        let was_suspended = SUSPENDED_PROCESS_IDS
+            .lock()
+            .map(|mut suspended_process_ids| suspended_process_ids.remove(&process_id))
+            .map_err(|error| format!("Failed to acquire suspended process lock: {}", error))?;
+
+        if !was_suspended {
+            return Err(format!("Process {} was not launched suspended, or has already been resumed.", process_id));
+        }
+
+        ProcessLauncherImpl::resume_process(process_id)
+    }
+
+    fn forward_output<R>(
+        stream: Option<R>,
+        process_name: String,
+        process_id: u32,
+        is_error_stream: bool,
+    ) where
+        R: Read + Send + 'static,
+    {
+        let Some(stream) = stream else {
+            return;
+        };
+
+        thread::spawn(move || {
+            // Lines are split on raw bytes, as programs are free to write output that is not valid UTF-8.
+            for line in BufReader::new(stream).split(b'\n') {
+                let Ok(line) = line else {
+                    break;
+                };
+                let line = String::from_utf8_lossy(&line);
+                let line = line.trim_end_matches('\r');
+
+                if is_error_stream {
+                    log::warn!("[{}:{}] {}", process_name, process_id, line);
+                } else {
+                    log::info!("[{}:{}] {}", process_name, process_id, line);
+                }
+            }
+        });
+    }
+
+    /// Reaps the process once it exits, such that it does not linger as a zombie.
+    fn wait_for_exit(
+        mut child: Child,
+        process_name: String,
+        process_id: u32,
+    ) {
+        thread::spawn(move || {
+            match child.wait() {
+                Ok(exit_status) => log::info!("Launched process {} (pid: {}) exited with {}.", process_name, process_id, exit_status),
+                Err(error) => log::error!("Failed to wait for launched process {} (pid: {}): {}", process_name, process_id, error),
+            }
+
+            if let Ok(mut suspended_process_ids) = SUSPENDED_PROCESS_IDS.lock() {
+                suspended_process_ids.remove(&process_id);
+            }
+        });
+    }
+}
@@ -0,0 +1 @@
+pub mod unsupported_process_launcher;
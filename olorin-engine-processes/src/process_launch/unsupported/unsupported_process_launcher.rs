@@ -0,0 +1,21 @@
+use crate::process_launch::process_launcher::PlatformProcessLauncher;
+use std::process::Command;
+
+/// Launches processes without support for a suspended start, for platforms that do not yet implement one.
+pub struct UnsupportedProcessLauncher;
+
+impl PlatformProcessLauncher for UnsupportedProcessLauncher {
+    fn prepare_unprivileged_start(_command: &mut Command) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn prepare_suspended_start(_command: &mut Command) {}
+
+    fn complete_suspended_start(_process_id: u32) -> Result<(), String> {
+        Err("Suspended launches are not supported on this platform.".to_string())
+    }
+
+    fn resume_process(_process_id: u32) -> Result<(), String> {
+        Err("Suspended launches are not supported on this platform.".to_string())
+    }
+}
@@ -0,0 +1 @@
+pub mod windows_process_launcher;
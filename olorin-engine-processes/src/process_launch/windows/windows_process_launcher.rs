@@ -0,0 +1,67 @@
+use crate::process_launch::process_launcher::PlatformProcessLauncher;
+use std::os::windows::process::CommandExt;
+use std::process::Command;
+use windows_sys::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+use windows_sys::Win32::System::Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, TH32CS_SNAPTHREAD, THREADENTRY32, Thread32First, Thread32Next};
+use windows_sys::Win32::System::Threading::{CREATE_SUSPENDED, OpenThread, ResumeThread, THREAD_SUSPEND_RESUME};
+
+pub struct WindowsProcessLauncher;
+
+impl PlatformProcessLauncher for WindowsProcessLauncher {
+    fn prepare_unprivileged_start(_command: &mut Command) -> Result<(), String> {
+        // Elevation is granted per process on Windows, and children of an elevated engine are launched with the same token.
+        Ok(())
+    }
+
+    fn prepare_suspended_start(command: &mut Command) {
+        // The primary thread is created suspended, before the loader has run or any modules besides ntdll are mapped.
+        command.creation_flags(CREATE_SUSPENDED);
+    }
+
+    fn complete_suspended_start(_process_id: u32) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn resume_process(process_id: u32) -> Result<(), String> {
+        let mut resumed_thread_count = 0;
+
+        unsafe {
+            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0);
+
+            if snapshot == INVALID_HANDLE_VALUE {
+                return Err(format!("Failed to enumerate the threads of process {}.", process_id));
+            }
+
+            let mut thread_entry = std::mem::zeroed::<THREADENTRY32>();
+            thread_entry.dwSize = std::mem::size_of::<THREADENTRY32>() as u32;
+
+            if Thread32First(snapshot, &mut thread_entry) != 0 {
+                loop {
+                    if thread_entry.th32OwnerProcessID == process_id {
+                        let thread_handle = OpenThread(THREAD_SUSPEND_RESUME, 0, thread_entry.th32ThreadID);
+
+                        if !thread_handle.is_null() {
+                            if ResumeThread(thread_handle) != u32::MAX {
+                                resumed_thread_count += 1;
+                            }
+
+                            CloseHandle(thread_handle);
+                        }
+                    }
+
+                    if Thread32Next(snapshot, &mut thread_entry) == 0 {
+                        break;
+                    }
+                }
+            }
+
+            CloseHandle(snapshot);
+        }
+
+        if resumed_thread_count == 0 {
+            return Err(format!("Failed to resume any threads of process {}.", process_id));
+        }
+
+        Ok(())
+    }
+}
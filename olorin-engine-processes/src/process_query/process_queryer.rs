@@ -1,5 +1,6 @@
 use crate::process_query::process_query_options::ProcessQueryOptions;
 use olorin_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo};
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
 
 pub(crate) trait ProcessQueryer {
     fn start_monitoring() -> Result<(), String>;
@@ -33,14 +34,62 @@ impl ProcessQuery {
     }
 
     pub fn open_process(process_info: &ProcessInfo) -> Result<OpenedProcessInfo, String> {
+        if let Some(opened_process_info) = VirtualTargetRegistry::get_instance().open_process(process_info) {
+            return Ok(opened_process_info);
+        }
+
         ProcessQueryImpl::open_process(process_info)
     }
 
     pub fn close_process(handle: u64) -> Result<(), String> {
+        // Virtual targets hold no OS handle, and stay registered such that they can be reopened.
+        if VirtualTargetRegistry::get_instance().is_virtual_handle(handle) {
+            return Ok(());
+        }
+
         ProcessQueryImpl::close_process(handle)
     }
 
+    /// Gets the running processes matching the given options, followed by any matching virtual targets.
     pub fn get_processes(process_query_options: ProcessQueryOptions) -> Vec<ProcessInfo> {
-        ProcessQueryImpl::get_processes(process_query_options)
+        let limit = process_query_options.limit.unwrap_or(usize::MAX as u64) as usize;
+        let virtual_processes = Self::get_virtual_processes(&process_query_options);
+        let mut processes = ProcessQueryImpl::get_processes(process_query_options);
+
+        processes.extend(virtual_processes);
+        processes.truncate(limit);
+
+        processes
+    }
+
+    fn get_virtual_processes(process_query_options: &ProcessQueryOptions) -> Vec<ProcessInfo> {
+        VirtualTargetRegistry::get_instance()
+            .get_process_infos()
+            .into_iter()
+            .filter(|process_info| {
+                let mut matches = true;
+
+                if process_query_options.require_windowed {
+                    matches &= process_info.get_is_windowed();
+                }
+
+                if let Some(ref term) = process_query_options.search_name {
+                    if process_query_options.match_case {
+                        matches &= process_info.get_name().contains(term);
+                    } else {
+                        matches &= process_info
+                            .get_name()
+                            .to_lowercase()
+                            .contains(&term.to_lowercase());
+                    }
+                }
+
+                if let Some(required_process_id) = process_query_options.required_process_id {
+                    matches &= process_info.get_process_id_raw() == required_process_id.as_u32();
+                }
+
+                matches
+            })
+            .collect()
     }
 }
@@ -264,6 +264,14 @@ impl ProcessQueryer for WindowsProcessQuery {
             .processes()
             .iter()
             .filter_map(|(process_id, process)| {
+                // Skip other processes before resolving them, such that querying a single process does not fetch the icon of every process.
+                if process_query_options
+                    .required_process_id
+                    .is_some_and(|required_process_id| required_process_id != *process_id)
+                {
+                    return None;
+                }
+
                 // Try to get from cache first
                 let process_info = if let Some(cached_info) = Self::get_from_cache(process_id) {
                     // If icons are required but not in cache, update the icon
@@ -321,10 +329,6 @@ impl ProcessQueryer for WindowsProcessQuery {
                     }
                 }
 
-                if let Some(required_process_id) = process_query_options.required_process_id {
-                    matches &= process_info.get_process_id_raw() == required_process_id.as_u32();
-                }
-
                 matches.then_some(process_info)
             })
             .take(process_query_options.limit.unwrap_or(usize::MAX as u64) as usize)
@@ -0,0 +1,135 @@
+use crate::thread_query::thread_queryer::{ThreadQuery, ThreadQueryer};
+use olorin_engine_api::structures::processes::thread_info::ThreadInfo;
+use olorin_engine_api::structures::processes::thread_state::ThreadState;
+use std::fs;
+
+/// A mapping from `/proc/<pid>/maps`, reduced to the fields needed to locate stacks.
+struct MappedRegion {
+    start_address: u64,
+    end_address: u64,
+    pathname: String,
+}
+
+pub struct LinuxThreadQuery;
+
+impl ThreadQueryer for LinuxThreadQuery {
+    fn get_threads(process_id: u32) -> Result<Vec<ThreadInfo>, String> {
+        let task_directory = format!("/proc/{}/task", process_id);
+        let task_entries = fs::read_dir(&task_directory).map_err(|error| format!("Failed to list threads of process {}: {}", process_id, error))?;
+        let mut thread_ids: Vec<u32> = task_entries
+            .filter_map(|task_entry| task_entry.ok()?.file_name().to_str()?.parse().ok())
+            .collect();
+
+        // The main thread shares its id with the process. Listing it first keeps it as threadstack0 while other threads come and go.
+        thread_ids.sort_by_key(|thread_id| (*thread_id != process_id, *thread_id));
+
+        let mapped_regions = Self::read_mapped_regions(process_id)?;
+        let mut threads = vec![];
+
+        for thread_id in thread_ids {
+            let thread_directory = format!("{}/{}", task_directory, thread_id);
+
+            // Threads that exit while being listed no longer have a status, and are left out.
+            let Some(state) = Self::read_state(&thread_directory) else {
+                continue;
+            };
+            let name = fs::read_to_string(format!("{}/comm", thread_directory))
+                .map(|name| name.trim_end().to_string())
+                .unwrap_or_default();
+            let stack_module_name = ThreadQuery::get_stack_module_name(threads.len());
+            let (stack_base_address, stack_size) = Self::find_stack_region(process_id, thread_id, &thread_directory, &mapped_regions)
+                .map(|mapped_region| (mapped_region.start_address, mapped_region.end_address - mapped_region.start_address))
+                .unwrap_or((0, 0));
+
+            threads.push(ThreadInfo::new(thread_id, name, state, stack_module_name, stack_base_address, stack_size));
+        }
+
+        Ok(threads)
+    }
+}
+
+impl LinuxThreadQuery {
+    fn read_mapped_regions(process_id: u32) -> Result<Vec<MappedRegion>, String> {
+        let maps = fs::read_to_string(format!("/proc/{}/maps", process_id))
+            .map_err(|error| format!("Failed to read memory maps of process {}: {}", process_id, error))?;
+        let mut mapped_regions = vec![];
+
+        // Each line is formatted as `start-end perms offset dev inode [pathname]`.
+        for line in maps.lines() {
+            let mut fields = line.split_whitespace();
+            let Some((start_address, end_address)) = fields.next().and_then(|range| range.split_once('-')) else {
+                continue;
+            };
+            let (Ok(start_address), Ok(end_address)) = (u64::from_str_radix(start_address, 16), u64::from_str_radix(end_address, 16)) else {
+                continue;
+            };
+            let pathname = fields.nth(4).unwrap_or("").to_string();
+
+            mapped_regions.push(MappedRegion {
+                start_address,
+                end_address,
+                pathname,
+            });
+        }
+
+        Ok(mapped_regions)
+    }
+
+    fn read_state(thread_directory: &str) -> Option<ThreadState> {
+        let stat = fs::read_to_string(format!("{}/stat", thread_directory)).ok()?;
+
+        // The thread name is parenthesized and may itself contain spaces or parentheses, so the state is found after the last ')'.
+        let state_char = stat[stat.rfind(')')? + 1..].trim_start().chars().next()?;
+
+        Some(match state_char {
+            'R' => ThreadState::Running,
+            'S' => ThreadState::Sleeping,
+            'D' => ThreadState::UninterruptibleSleep,
+            'T' | 't' => ThreadState::Stopped,
+            'Z' => ThreadState::Zombie,
+            _ => ThreadState::Unknown,
+        })
+    }
+
+    fn find_stack_region<'a>(
+        process_id: u32,
+        thread_id: u32,
+        thread_directory: &str,
+        mapped_regions: &'a [MappedRegion],
+    ) -> Option<&'a MappedRegion> {
+        // Only the main thread stack is labelled on current kernels. Kernels before 4.5 also labelled other stacks as `[stack:<tid>]`.
+        let stack_label = if thread_id == process_id {
+            "[stack]".to_string()
+        } else {
+            format!("[stack:{}]", thread_id)
+        };
+
+        if let Some(mapped_region) = mapped_regions
+            .iter()
+            .find(|mapped_region| mapped_region.pathname == stack_label)
+        {
+            return Some(mapped_region);
+        }
+
+        // Otherwise the stack is the mapping containing the stack pointer, which is only reported while the thread is blocked.
+        let stack_pointer = Self::read_stack_pointer(thread_directory)?;
+
+        mapped_regions
+            .iter()
+            .find(|mapped_region| stack_pointer >= mapped_region.start_address && stack_pointer < mapped_region.end_address)
+    }
+
+    fn read_stack_pointer(thread_directory: &str) -> Option<u64> {
+        let syscall = fs::read_to_string(format!("{}/syscall", thread_directory)).ok()?;
+        let fields: Vec<&str> = syscall.split_whitespace().collect();
+
+        // Blocked threads report `<number> <args...> <sp> <pc>`, or `-1 <sp> <pc>` outside of a syscall. Running threads report `running`.
+        if fields.len() < 3 {
+            return None;
+        }
+
+        let stack_pointer = fields[fields.len() - 2];
+
+        u64::from_str_radix(stack_pointer.trim_start_matches("0x"), 16).ok()
+    }
+}
@@ -0,0 +1,8 @@
+pub mod thread_queryer;
+pub mod thread_stack_module_provider;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux;
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+mod unsupported;
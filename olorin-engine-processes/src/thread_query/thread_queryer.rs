@@ -0,0 +1,32 @@
+use olorin_engine_api::structures::processes::thread_info::ThreadInfo;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+
+pub(crate) trait ThreadQueryer {
+    /// Gets the threads of a process, with the main thread listed first.
+    fn get_threads(process_id: u32) -> Result<Vec<ThreadInfo>, String>;
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::thread_query::linux::linux_thread_query::LinuxThreadQuery as ThreadQueryImpl;
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+use crate::thread_query::unsupported::unsupported_thread_query::UnsupportedThreadQuery as ThreadQueryImpl;
+
+pub struct ThreadQuery;
+
+impl ThreadQuery {
+    const STACK_MODULE_PREFIX: &'static str = "threadstack";
+
+    pub fn get_threads(process_id: u32) -> Result<Vec<ThreadInfo>, String> {
+        if VirtualTargetRegistry::get_instance().is_virtual_process(process_id) {
+            return Err(format!("Process {} is a virtual target, which has no threads.", process_id));
+        }
+
+        ThreadQueryImpl::get_threads(process_id)
+    }
+
+    /// Gets the name of the virtual module for the stack of the thread at the given index in the thread list.
+    pub fn get_stack_module_name(thread_index: usize) -> String {
+        format!("{}{}", Self::STACK_MODULE_PREFIX, thread_index)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::thread_query::thread_queryer::ThreadQuery;
+use olorin_engine_api::structures::memory::normalized_module::NormalizedModule;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_memory::memory_queryer::virtual_module_provider_trait::IVirtualModuleProvider;
+use olorin_engine_memory::memory_queryer::virtual_module_registry::VirtualModuleRegistry;
+use std::sync::Arc;
+
+/// Lists the stacks of the threads of a process as virtual modules named `threadstack0`, `threadstack1`, and so on, such that
+/// pointer paths rooted on a stack can be stored relative to the stack rather than as raw addresses. Stacks grow down, so each
+/// is anchored at its top, where frames sit at stable (negative) offsets regardless of how far the mapping has grown.
+pub struct ThreadStackModuleProvider;
+
+impl ThreadStackModuleProvider {
+    const PROVIDER_NAME: &'static str = "thread_stacks";
+
+    /// Registers thread stacks with the memory queryer, such that they are listed alongside the modules of a process.
+    pub fn register() {
+        VirtualModuleRegistry::get_instance().register_provider(Self::PROVIDER_NAME, Arc::new(ThreadStackModuleProvider));
+    }
+}
+
+impl IVirtualModuleProvider for ThreadStackModuleProvider {
+    fn get_virtual_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        // Failures are expected on platforms without thread queries, and modules are listed too often to log each one.
+        let Ok(threads) = ThreadQuery::get_threads(process_info.get_process_id_raw()) else {
+            return vec![];
+        };
+
+        threads
+            .iter()
+            .filter(|thread_info| thread_info.has_stack())
+            .map(|thread_info| {
+                NormalizedModule::new_anchored_at_end(
+                    thread_info.get_stack_module_name(),
+                    thread_info.get_stack_base_address(),
+                    thread_info.get_stack_size(),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1 @@
+pub mod unsupported_thread_query;
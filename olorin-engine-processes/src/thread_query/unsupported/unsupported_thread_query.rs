@@ -0,0 +1,11 @@
+use crate::thread_query::thread_queryer::ThreadQueryer;
+use olorin_engine_api::structures::processes::thread_info::ThreadInfo;
+
+/// Rejects thread queries, for platforms that do not yet implement them.
+pub struct UnsupportedThreadQuery;
+
+impl ThreadQueryer for UnsupportedThreadQuery {
+    fn get_threads(_process_id: u32) -> Result<Vec<ThreadInfo>, String> {
+        Err("Thread queries are not supported on this platform.".to_string())
+    }
+}
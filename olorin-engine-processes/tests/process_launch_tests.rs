@@ -0,0 +1,60 @@
+#![cfg(target_os = "linux")]
+
+use olorin_engine_processes::process_launch::process_launch_options::ProcessLaunchOptions;
+use olorin_engine_processes::process_launch::process_launcher::ProcessLauncher;
+use std::fs;
+use std::path::PathBuf;
+
+/// The user id of `nobody`, which stands in for the user that elevated the engine.
+const INVOKING_USER_ID: u32 = 65534;
+
+/// Gets the effective user id of a process, which is the second of the user ids on the `Uid:` line of its status.
+fn get_effective_user_id(process_id: u32) -> Option<u32> {
+    fs::read_to_string(format!("/proc/{}/status", process_id))
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))
+        .and_then(|user_ids| user_ids.split_whitespace().nth(1))
+        .and_then(|user_id| user_id.parse().ok())
+}
+
+fn get_environment_variable(
+    process_id: u32,
+    variable_name: &str,
+) -> Option<String> {
+    let environment = fs::read(format!("/proc/{}/environ", process_id)).ok()?;
+    let prefix = format!("{}=", variable_name);
+
+    environment
+        .split(|byte| *byte == 0)
+        .map(String::from_utf8_lossy)
+        .find_map(|variable| variable.strip_prefix(&prefix).map(str::to_string))
+}
+
+#[test]
+fn processes_launched_by_an_elevated_engine_run_as_the_invoking_user() {
+    // Only a root engine can switch users, so there is nothing to verify when the tests are not run as root.
+    if unsafe { libc::geteuid() } != 0 {
+        eprintln!("Skipping, as dropping privileges requires the tests to run as root.");
+        return;
+    }
+
+    // Elevating through sudo records the invoking user, which the launcher should switch to.
+    unsafe {
+        std::env::remove_var("PKEXEC_UID");
+        std::env::set_var("SUDO_UID", INVOKING_USER_ID.to_string());
+    }
+
+    let process_info = ProcessLauncher::launch_process(&ProcessLaunchOptions {
+        executable_path: PathBuf::from("/bin/sleep"),
+        arguments: vec!["2".to_string()],
+        working_directory: None,
+        environment_variables: vec![],
+        start_suspended: false,
+    })
+    .expect("Failed to launch the fixture process.");
+    let process_id = process_info.get_process_id_raw();
+
+    assert_eq!(get_effective_user_id(process_id), Some(INVOKING_USER_ID));
+    assert_eq!(get_environment_variable(process_id, "USER").as_deref(), Some("nobody"));
+}
@@ -0,0 +1,128 @@
+#![cfg(any(target_os = "linux", target_os = "android"))]
+
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::processes::thread_info::ThreadInfo;
+use olorin_engine_api::structures::processes::thread_state::ThreadState;
+use olorin_engine_memory::memory_queryer::virtual_module_provider_trait::IVirtualModuleProvider;
+use olorin_engine_processes::thread_query::thread_queryer::ThreadQuery;
+use olorin_engine_processes::thread_query::thread_stack_module_provider::ThreadStackModuleProvider;
+use std::process;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// A named thread that reports the address of one of its stack variables, then blocks until it is dropped.
+struct BlockedThread {
+    stack_variable_address: u64,
+    release_sender: mpsc::Sender<()>,
+    join_handle: JoinHandle<()>,
+}
+
+impl BlockedThread {
+    fn spawn(thread_name: &str) -> Self {
+        let (address_sender, address_receiver) = mpsc::channel();
+        let (release_sender, release_receiver) = mpsc::channel::<()>();
+        let join_handle = thread::Builder::new()
+            .name(thread_name.to_string())
+            .spawn(move || {
+                let stack_variable = 0u64;
+
+                address_sender
+                    .send(&stack_variable as *const u64 as u64)
+                    .expect("Failed to send the stack variable address.");
+                let _ = release_receiver.recv();
+            })
+            .expect("Failed to spawn the blocked thread.");
+        let stack_variable_address = address_receiver
+            .recv()
+            .expect("Failed to receive the stack variable address.");
+
+        Self {
+            stack_variable_address,
+            release_sender,
+            join_handle,
+        }
+    }
+
+    fn release(self) {
+        drop(self.release_sender);
+        self.join_handle
+            .join()
+            .expect("The blocked thread panicked.");
+    }
+}
+
+/// Queries the threads of this process until the named thread has blocked, as its stack is only located once it has.
+fn wait_for_blocked_thread(thread_name: &str) -> (Vec<ThreadInfo>, ThreadInfo) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    loop {
+        let threads = ThreadQuery::get_threads(process::id()).expect("Failed to query threads.");
+
+        if let Some(thread_info) = threads.iter().find(|thread_info| {
+            thread_info.get_name() == thread_name && thread_info.get_state() == ThreadState::Sleeping && thread_info.has_stack()
+        }) {
+            let thread_info = thread_info.clone();
+
+            return (threads, thread_info);
+        }
+
+        assert!(Instant::now() < deadline, "Thread {} never blocked with a located stack.", thread_name);
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn stack_contains(
+    thread_info: &ThreadInfo,
+    address: u64,
+) -> bool {
+    address >= thread_info.get_stack_base_address() && address < thread_info.get_stack_base_address() + thread_info.get_stack_size()
+}
+
+#[test]
+fn threads_are_listed_with_names_states_and_stacks() {
+    let blocked_thread = BlockedThread::spawn("olorin-blocked");
+    let (threads, blocked_thread_info) = wait_for_blocked_thread("olorin-blocked");
+
+    // The main thread always comes first, and its stack is the labelled `[stack]` mapping.
+    let main_thread_info = &threads[0];
+
+    assert_eq!(main_thread_info.get_thread_id(), process::id());
+    assert_eq!(main_thread_info.get_stack_module_name(), "threadstack0");
+    assert!(main_thread_info.has_stack());
+
+    assert_ne!(blocked_thread_info.get_thread_id(), process::id());
+    assert!(stack_contains(&blocked_thread_info, blocked_thread.stack_variable_address));
+    assert!(!stack_contains(main_thread_info, blocked_thread.stack_variable_address));
+
+    let stack_module_names: Vec<&str> = threads
+        .iter()
+        .map(|thread_info| thread_info.get_stack_module_name())
+        .collect();
+
+    for (thread_index, stack_module_name) in stack_module_names.iter().enumerate() {
+        assert_eq!(*stack_module_name, format!("threadstack{}", thread_index));
+    }
+
+    blocked_thread.release();
+}
+
+#[test]
+fn thread_stacks_are_listed_as_virtual_modules() {
+    let blocked_thread = BlockedThread::spawn("olorin-module");
+    let (_, blocked_thread_info) = wait_for_blocked_thread("olorin-module");
+    let process_info = OpenedProcessInfo::new(process::id(), "thread_query_tests".to_string(), 0, Bitness::Bit64, None);
+    let modules = ThreadStackModuleProvider.get_virtual_modules(&process_info);
+    let stack_module = modules
+        .iter()
+        .find(|module| module.contains_address(blocked_thread.stack_variable_address))
+        .expect("No virtual module contains the stack variable.");
+
+    // Indices shift as threads of concurrently running tests come and go, so only the naming scheme is compared.
+    assert!(stack_module.get_module_name().starts_with("threadstack"));
+    assert_eq!(stack_module.get_base_address(), blocked_thread_info.get_stack_base_address());
+    assert!(modules.iter().any(|module| module.get_module_name() == "threadstack0"));
+
+    blocked_thread.release();
+}
@@ -1,2 +1,3 @@
 pub mod project;
 pub mod settings;
+pub mod structs;
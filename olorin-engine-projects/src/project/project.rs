@@ -1,15 +1,21 @@
+use olorin_engine_api::registries::symbolic_structs::symbolic_struct_registry::SymbolicStructRegistry;
 use olorin_engine_api::structures::{
     processes::process_icon::ProcessIcon,
     projects::{
         project_info::ProjectInfo,
-        project_items::{built_in_types::project_item_type_directory::ProjectItemTypeDirectory, project_item::ProjectItem},
+        project_items::{
+            built_in_types::{project_item_type_directory::ProjectItemTypeDirectory, project_item_type_struct::ProjectItemTypeStruct},
+            project_item::ProjectItem,
+        },
         project_manifest::ProjectManifest,
     },
+    structs::symbolic_struct_definition::SymbolicStructDefinition,
 };
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     fs::{self},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use super::serialization::serializable_project_file::SerializableProjectFile;
@@ -90,4 +96,61 @@ impl Project {
     pub fn get_project_root_mut(&mut self) -> &mut ProjectItem {
         &mut self.project_root
     }
+
+    /// Gets the library of named struct definitions stored in this project, keyed by the file stem of each struct item.
+    /// Struct items in different directories may share a name, in which case the first in project order is used and the others are reported.
+    pub fn get_symbolic_struct_definitions(&self) -> Vec<(String, SymbolicStructDefinition)> {
+        let mut symbolic_struct_definitions = vec![];
+        let mut symbolic_struct_paths: HashMap<String, PathBuf> = HashMap::new();
+
+        Self::collect_symbolic_struct_definitions(&self.project_root, &mut symbolic_struct_definitions, &mut symbolic_struct_paths);
+
+        symbolic_struct_definitions
+    }
+
+    /// Gets the name under which the given struct item is registered, which is the file stem of its path.
+    pub fn get_symbolic_struct_name(struct_item_path: &Path) -> String {
+        struct_item_path
+            .file_stem()
+            .map(|file_stem| file_stem.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Loads the struct library of this project into the symbolic struct registry, replacing any previously registered structs.
+    /// This should be called whenever the struct items of the opened project change.
+    pub fn register_symbolic_structs(&self) {
+        SymbolicStructRegistry::get_instance().set_definitions(self.get_symbolic_struct_definitions());
+    }
+
+    fn collect_symbolic_struct_definitions(
+        project_item: &ProjectItem,
+        symbolic_struct_definitions: &mut Vec<(String, SymbolicStructDefinition)>,
+        symbolic_struct_paths: &mut HashMap<String, PathBuf>,
+    ) {
+        if project_item.get_is_container_type() {
+            for child in project_item.get_children() {
+                Self::collect_symbolic_struct_definitions(child, symbolic_struct_definitions, symbolic_struct_paths);
+            }
+        } else if project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeStruct::PROJECT_ITEM_TYPE_ID {
+            let struct_name = Self::get_symbolic_struct_name(project_item.get_path());
+
+            if let Some(existing_path) = symbolic_struct_paths.get(&struct_name) {
+                log::warn!(
+                    "Skipping struct {:?}, as the struct name {} is already defined by {:?}. Rename one of these items to use both.",
+                    project_item.get_path(),
+                    struct_name,
+                    existing_path
+                );
+                return;
+            }
+
+            match ProjectItemTypeStruct::get_field_definition(project_item) {
+                Some(symbolic_struct_definition) if !struct_name.is_empty() => {
+                    symbolic_struct_paths.insert(struct_name.clone(), project_item.get_path().clone());
+                    symbolic_struct_definitions.push((struct_name, symbolic_struct_definition));
+                }
+                _ => log::warn!("Skipping invalid struct definition in project item {:?}.", project_item.get_path()),
+            }
+        }
+    }
 }
@@ -11,6 +11,7 @@ use olorin_engine_api::{
             deleted::project_deleted_event::ProjectDeletedEvent,
         },
     },
+    registries::symbolic_structs::symbolic_struct_registry::SymbolicStructRegistry,
     structures::projects::{project_info::ProjectInfo, project_manifest::ProjectManifest},
 };
 use std::{
@@ -50,6 +51,7 @@ impl ProjectManager {
     ) {
         if let Ok(mut project) = self.opened_project.write() {
             log::info!("Opened project: {}", project_info.get_name());
+            project_info.register_symbolic_structs();
             *project = Some(project_info);
         }
     }
@@ -59,6 +61,8 @@ impl ProjectManager {
         if let Ok(mut project) = self.opened_project.write() {
             *project = None;
 
+            SymbolicStructRegistry::get_instance().set_definitions(vec![]);
+
             log::info!("Project closed.");
 
             (self.event_emitter)(ProjectClosedEvent {}.to_engine_event());
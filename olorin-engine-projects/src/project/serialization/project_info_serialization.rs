@@ -18,7 +18,7 @@ struct ProjectInfoStub {
     #[serde(rename = "icon")]
     project_icon_rgba: Option<ProcessIcon>,
 
-    /// The manifest for this project, containing the sort order of project items.
+    /// The manifest for this project, containing the sort order of project items and the process to watch for.
     #[serde(rename = "manifest")]
     project_manifest: ProjectManifest,
 }
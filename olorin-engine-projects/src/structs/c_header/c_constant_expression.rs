@@ -0,0 +1,168 @@
+use crate::structs::c_header::c_header_token::CHeaderToken;
+use std::collections::HashMap;
+
+/// Evaluates integer constant expressions, such as array lengths, bitfield widths, and enum values.
+/// Supports integer literals, named constants, parentheses, unary operators, and the arithmetic, shift, and bitwise operators.
+pub struct CConstantExpression<'a> {
+    tokens: &'a [CHeaderToken],
+    constants: &'a HashMap<String, i64>,
+    position: usize,
+}
+
+impl<'a> CConstantExpression<'a> {
+    pub fn evaluate(
+        tokens: &'a [CHeaderToken],
+        constants: &'a HashMap<String, i64>,
+    ) -> Result<i64, String> {
+        let mut expression = CConstantExpression {
+            tokens,
+            constants,
+            position: 0,
+        };
+        let value = expression.parse_bitwise_or()?;
+
+        if expression.position < tokens.len() {
+            return Err(format!("Unexpected token {:?} in constant expression.", tokens[expression.position]));
+        }
+
+        Ok(value)
+    }
+
+    fn parse_bitwise_or(&mut self) -> Result<i64, String> {
+        let mut value = self.parse_bitwise_xor()?;
+
+        while self.consume_operator("|") {
+            value |= self.parse_bitwise_xor()?;
+        }
+
+        Ok(value)
+    }
+
+    fn parse_bitwise_xor(&mut self) -> Result<i64, String> {
+        let mut value = self.parse_bitwise_and()?;
+
+        while self.consume_operator("^") {
+            value ^= self.parse_bitwise_and()?;
+        }
+
+        Ok(value)
+    }
+
+    fn parse_bitwise_and(&mut self) -> Result<i64, String> {
+        let mut value = self.parse_shift()?;
+
+        while self.consume_operator("&") {
+            value &= self.parse_shift()?;
+        }
+
+        Ok(value)
+    }
+
+    fn parse_shift(&mut self) -> Result<i64, String> {
+        let mut value = self.parse_additive()?;
+
+        loop {
+            if self.consume_operator("<<") {
+                value = value.wrapping_shl(self.parse_additive()? as u32);
+            } else if self.consume_operator(">>") {
+                value = value.wrapping_shr(self.parse_additive()? as u32);
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn parse_additive(&mut self) -> Result<i64, String> {
+        let mut value = self.parse_multiplicative()?;
+
+        loop {
+            if self.consume_operator("+") {
+                value = value.wrapping_add(self.parse_multiplicative()?);
+            } else if self.consume_operator("-") {
+                value = value.wrapping_sub(self.parse_multiplicative()?);
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<i64, String> {
+        let mut value = self.parse_unary()?;
+
+        loop {
+            if self.consume_operator("*") {
+                value = value.wrapping_mul(self.parse_unary()?);
+            } else if self.consume_operator("/") {
+                value = value
+                    .checked_div(self.parse_unary()?)
+                    .ok_or("Division by zero in constant expression.")?;
+            } else if self.consume_operator("%") {
+                value = value
+                    .checked_rem(self.parse_unary()?)
+                    .ok_or("Division by zero in constant expression.")?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<i64, String> {
+        if self.consume_operator("-") {
+            Ok(self.parse_unary()?.wrapping_neg())
+        } else if self.consume_operator("+") {
+            self.parse_unary()
+        } else if self.consume_operator("~") {
+            Ok(!self.parse_unary()?)
+        } else if self.consume_operator("!") {
+            Ok((self.parse_unary()? == 0) as i64)
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<i64, String> {
+        match self.tokens.get(self.position) {
+            Some(CHeaderToken::Number(number)) => {
+                self.position += 1;
+                Ok(*number as i64)
+            }
+            Some(CHeaderToken::Identifier(identifier)) => {
+                self.position += 1;
+                self.constants
+                    .get(identifier)
+                    .copied()
+                    .ok_or_else(|| format!("Unknown constant '{}'.", identifier))
+            }
+            Some(CHeaderToken::Punctuation('(')) => {
+                self.position += 1;
+
+                let value = self.parse_bitwise_or()?;
+
+                if !self.consume_operator(")") {
+                    return Err("Missing closing ')' in constant expression.".into());
+                }
+
+                Ok(value)
+            }
+            Some(token) => Err(format!("Unexpected token {:?} in constant expression.", token)),
+            None => Err("Unexpected end of constant expression.".into()),
+        }
+    }
+
+    /// Consumes the given operator, which the tokenizer splits into individual punctuation characters.
+    fn consume_operator(
+        &mut self,
+        operator: &str,
+    ) -> bool {
+        let matches_operator = operator
+            .chars()
+            .enumerate()
+            .all(|(index, character)| self.tokens.get(self.position + index) == Some(&CHeaderToken::Punctuation(character)));
+
+        if matches_operator {
+            self.position += operator.len();
+        }
+
+        matches_operator
+    }
+}
@@ -0,0 +1,220 @@
+use olorin_engine_api::structures::data_types::built_in_types::bool8::data_type_bool8::DataTypeBool8;
+use olorin_engine_api::structures::data_types::built_in_types::bool32::data_type_bool32::DataTypeBool32;
+use olorin_engine_api::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+use olorin_engine_api::structures::data_types::built_in_types::f32be::data_type_f32be::DataTypeF32be;
+use olorin_engine_api::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+use olorin_engine_api::structures::data_types::built_in_types::f64be::data_type_f64be::DataTypeF64be;
+use olorin_engine_api::structures::data_types::built_in_types::i8::data_type_i8::DataTypeI8;
+use olorin_engine_api::structures::data_types::built_in_types::i16::data_type_i16::DataTypeI16;
+use olorin_engine_api::structures::data_types::built_in_types::i16be::data_type_i16be::DataTypeI16be;
+use olorin_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use olorin_engine_api::structures::data_types::built_in_types::i32be::data_type_i32be::DataTypeI32be;
+use olorin_engine_api::structures::data_types::built_in_types::i64::data_type_i64::DataTypeI64;
+use olorin_engine_api::structures::data_types::built_in_types::i64be::data_type_i64be::DataTypeI64be;
+use olorin_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use olorin_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use olorin_engine_api::structures::data_types::built_in_types::u16::data_type_u16::DataTypeU16;
+use olorin_engine_api::structures::data_types::built_in_types::u16be::data_type_u16be::DataTypeU16be;
+use olorin_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use olorin_engine_api::structures::data_types::built_in_types::u32be::data_type_u32be::DataTypeU32be;
+use olorin_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use olorin_engine_api::structures::data_types::built_in_types::u64be::data_type_u64be::DataTypeU64be;
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+use olorin_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use olorin_engine_api::structures::structs::symbolic_struct_field_definition::SymbolicStructFieldDefinition;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Exports symbolic struct definitions as a C header.
+/// Structs are written under `#pragma pack(push, 1)` with explicit padding, such that each field lands at its original offset
+/// regardless of the compiler's alignment rules. Nested struct sizes are resolved through the symbolic struct registry.
+pub struct CHeaderStructExporter {}
+
+impl CHeaderStructExporter {
+    pub fn export(symbolic_struct_definitions: &[(String, SymbolicStructDefinition)]) -> String {
+        let struct_names: HashSet<&str> = symbolic_struct_definitions
+            .iter()
+            .map(|(struct_name, _)| struct_name.as_str())
+            .collect();
+        let mut header = String::new();
+
+        header.push_str("#pragma once\n\n#include <stdbool.h>\n#include <stdint.h>\n\n#pragma pack(push, 1)\n\n");
+
+        for (struct_name, _) in symbolic_struct_definitions {
+            let _ = writeln!(header, "struct {};", Self::sanitize_identifier(struct_name));
+        }
+
+        for index in Self::get_dependency_order(symbolic_struct_definitions) {
+            let (struct_name, symbolic_struct_definition) = &symbolic_struct_definitions[index];
+
+            header.push('\n');
+            Self::write_struct(&mut header, struct_name, symbolic_struct_definition, &struct_names);
+        }
+
+        header.push_str("\n#pragma pack(pop)\n");
+
+        header
+    }
+
+    fn write_struct(
+        header: &mut String,
+        struct_name: &str,
+        symbolic_struct_definition: &SymbolicStructDefinition,
+        struct_names: &HashSet<&str>,
+    ) {
+        let mut end_offset = 0;
+
+        let _ = writeln!(
+            header,
+            "struct {} // 0x{:X} bytes\n{{",
+            Self::sanitize_identifier(struct_name),
+            symbolic_struct_definition.get_size_in_bytes()
+        );
+
+        for (offset, field) in symbolic_struct_definition
+            .get_field_offsets()
+            .into_iter()
+            .zip(symbolic_struct_definition.get_fields().iter())
+        {
+            let field_size = field.get_size_in_bytes();
+            let field_name = if field.get_name().is_empty() {
+                format!("{}_{:X}", if field.is_padding() { "pad" } else { "field" }, offset)
+            } else {
+                Self::sanitize_identifier(field.get_name())
+            };
+
+            // Overlapping fields, such as union members, are kept for reference but cannot be expressed in a packed struct.
+            if offset < end_offset {
+                let _ = writeln!(
+                    header,
+                    "    // {}; // 0x{:X}, overlaps a previous field",
+                    Self::get_field_declaration(field, &field_name, struct_names),
+                    offset
+                );
+                continue;
+            }
+
+            if offset > end_offset {
+                let _ = writeln!(header, "    uint8_t pad_{:X}[{}]; // 0x{:X}", end_offset, offset - end_offset, end_offset);
+            }
+
+            let _ = writeln!(
+                header,
+                "    {}; // 0x{:X}",
+                Self::get_field_declaration(field, &field_name, struct_names),
+                offset
+            );
+
+            end_offset = offset + field_size;
+        }
+
+        header.push_str("};\n");
+    }
+
+    fn get_field_declaration(
+        field: &SymbolicStructFieldDefinition,
+        field_name: &str,
+        struct_names: &HashSet<&str>,
+    ) -> String {
+        let data_type_id = field.get_value().get_data_type_id();
+        let (type_name, byte_order_comment) = if field.is_padding() {
+            ("uint8_t".to_string(), "")
+        } else if struct_names.contains(data_type_id) || field.is_nested_struct() {
+            (format!("struct {}", Self::sanitize_identifier(data_type_id)), "")
+        } else {
+            Self::get_c_type_name(data_type_id)
+        };
+
+        let declaration = match field.get_container_type() {
+            ContainerType::None => format!("{} {}", type_name, field_name),
+            ContainerType::Array(length) => format!("{} {}[{}]", type_name, field_name, length),
+            ContainerType::Pointer => {
+                if field.is_nested_struct() && !struct_names.contains(data_type_id) {
+                    format!("void* {}", field_name)
+                } else {
+                    format!("{}* {}", type_name, field_name)
+                }
+            }
+        };
+
+        format!("{}{}", declaration, byte_order_comment)
+    }
+
+    /// Gets the C type name for a data type, along with a comment noting when the value is big endian.
+    fn get_c_type_name(data_type_id: &str) -> (String, &'static str) {
+        let big_endian_comment = " /* big endian */";
+        let (type_name, comment) = match data_type_id {
+            id if id == DataTypeU8::get_data_type_id() => ("uint8_t", ""),
+            id if id == DataTypeI8::get_data_type_id() => ("int8_t", ""),
+            id if id == DataTypeU16::get_data_type_id() => ("uint16_t", ""),
+            id if id == DataTypeI16::get_data_type_id() => ("int16_t", ""),
+            id if id == DataTypeU32::get_data_type_id() => ("uint32_t", ""),
+            id if id == DataTypeI32::get_data_type_id() => ("int32_t", ""),
+            id if id == DataTypeU64::get_data_type_id() => ("uint64_t", ""),
+            id if id == DataTypeI64::get_data_type_id() => ("int64_t", ""),
+            id if id == DataTypeF32::get_data_type_id() => ("float", ""),
+            id if id == DataTypeF64::get_data_type_id() => ("double", ""),
+            id if id == DataTypeBool8::get_data_type_id() => ("bool", ""),
+            id if id == DataTypeBool32::get_data_type_id() => ("int32_t", ""),
+            id if id == DataTypeStringUtf8::get_data_type_id() => ("char", ""),
+            id if id == DataTypeU16be::get_data_type_id() => ("uint16_t", big_endian_comment),
+            id if id == DataTypeI16be::get_data_type_id() => ("int16_t", big_endian_comment),
+            id if id == DataTypeU32be::get_data_type_id() => ("uint32_t", big_endian_comment),
+            id if id == DataTypeI32be::get_data_type_id() => ("int32_t", big_endian_comment),
+            id if id == DataTypeU64be::get_data_type_id() => ("uint64_t", big_endian_comment),
+            id if id == DataTypeI64be::get_data_type_id() => ("int64_t", big_endian_comment),
+            id if id == DataTypeF32be::get_data_type_id() => ("float", big_endian_comment),
+            id if id == DataTypeF64be::get_data_type_id() => ("double", big_endian_comment),
+            _ => ("uint8_t", ""),
+        };
+
+        (type_name.to_string(), comment)
+    }
+
+    /// Orders structs such that each struct follows the structs that it contains by value, as C requires complete types for these.
+    fn get_dependency_order(symbolic_struct_definitions: &[(String, SymbolicStructDefinition)]) -> Vec<usize> {
+        let mut ordered_indices = vec![];
+        let mut visited_indices = HashSet::new();
+
+        for index in 0..symbolic_struct_definitions.len() {
+            Self::visit_dependencies(index, symbolic_struct_definitions, &mut visited_indices, &mut ordered_indices);
+        }
+
+        ordered_indices
+    }
+
+    fn visit_dependencies(
+        index: usize,
+        symbolic_struct_definitions: &[(String, SymbolicStructDefinition)],
+        visited_indices: &mut HashSet<usize>,
+        ordered_indices: &mut Vec<usize>,
+    ) {
+        // Structs are marked visited before their dependencies, such that cycles terminate rather than recurse indefinitely.
+        if !visited_indices.insert(index) {
+            return;
+        }
+
+        for field in symbolic_struct_definitions[index].1.get_fields() {
+            if field.get_container_type() == ContainerType::Pointer {
+                continue;
+            }
+
+            let data_type_id = field.get_value().get_data_type_id();
+
+            if let Some(dependency_index) = symbolic_struct_definitions
+                .iter()
+                .position(|(struct_name, _)| struct_name == data_type_id)
+            {
+                Self::visit_dependencies(dependency_index, symbolic_struct_definitions, visited_indices, ordered_indices);
+            }
+        }
+
+        ordered_indices.push(index);
+    }
+
+    fn sanitize_identifier(name: &str) -> String {
+        name.chars()
+            .map(|character| if character.is_alphanumeric() || character == '_' { character } else { '_' })
+            .collect()
+    }
+}
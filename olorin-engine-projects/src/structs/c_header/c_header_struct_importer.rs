@@ -0,0 +1,1039 @@
+use crate::structs::c_header::c_constant_expression::CConstantExpression;
+use crate::structs::c_header::c_header_token::CHeaderToken;
+use crate::structs::c_header::c_header_tokenizer::CHeaderTokenizer;
+use crate::structs::c_header::c_struct_member::CStructMember;
+use crate::structs::c_header::c_type::CType;
+use crate::structs::struct_import_result::StructImportResult;
+use olorin_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use olorin_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use olorin_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+use olorin_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use olorin_engine_api::structures::structs::symbolic_struct_field_definition::SymbolicStructFieldDefinition;
+use std::collections::HashMap;
+
+/// Imports symbolic struct definitions from C and C++ headers.
+/// Supports struct, union, enum, and typedef declarations, including fixed-width integers, arrays, pointers, bitfields,
+/// nested structs, and `#pragma pack`. Layouts follow the 64-bit Windows ABI, and every field is given an explicit offset.
+/// Functions, templates, and other declarations that do not affect layout are skipped.
+pub struct CHeaderStructImporter {
+    tokens: Vec<CHeaderToken>,
+    position: usize,
+    pack: Option<u64>,
+    pack_stack: Vec<Option<u64>>,
+    types: HashMap<String, CType>,
+    constants: HashMap<String, i64>,
+    struct_definitions: Vec<(String, SymbolicStructDefinition)>,
+    struct_name_stack: Vec<String>,
+    anonymous_struct_count: u64,
+    last_anonymous_struct_name: Option<String>,
+    warnings: Vec<String>,
+}
+
+impl CHeaderStructImporter {
+    const QUALIFIERS: [&str; 20] = [
+        "const",
+        "volatile",
+        "mutable",
+        "static",
+        "inline",
+        "register",
+        "extern",
+        "thread_local",
+        "constexpr",
+        "typename",
+        "restrict",
+        "__restrict",
+        "__restrict__",
+        "__unaligned",
+        "__ptr32",
+        "__ptr64",
+        "__inline",
+        "__forceinline",
+        "unaligned",
+        "UNALIGNED",
+    ];
+
+    const CALLING_CONVENTIONS: [&str; 10] = [
+        "__cdecl",
+        "__stdcall",
+        "__fastcall",
+        "__thiscall",
+        "__vectorcall",
+        "WINAPI",
+        "CALLBACK",
+        "APIENTRY",
+        "NTAPI",
+        "WINAPIV",
+    ];
+
+    const ATTRIBUTES: [&str; 4] = ["__attribute__", "__declspec", "alignas", "_Alignas"];
+
+    /// Parses the given header source, returning each struct and union definition by name in declaration order.
+    pub fn import(source: &str) -> Result<StructImportResult, String> {
+        let mut importer = CHeaderStructImporter {
+            tokens: CHeaderTokenizer::tokenize(source),
+            position: 0,
+            pack: None,
+            pack_stack: vec![],
+            types: HashMap::new(),
+            constants: HashMap::new(),
+            struct_definitions: vec![],
+            struct_name_stack: vec![],
+            anonymous_struct_count: 0,
+            last_anonymous_struct_name: None,
+            warnings: vec![],
+        };
+
+        importer.parse_top_level()?;
+
+        Ok(StructImportResult::new(importer.struct_definitions, importer.warnings))
+    }
+
+    fn parse_top_level(&mut self) -> Result<(), String> {
+        while let Some(token) = self.peek().cloned() {
+            let CHeaderToken::Identifier(identifier) = token else {
+                // Stray punctuation, such as the closing braces of namespaces and `extern "C"` blocks.
+                self.advance();
+                continue;
+            };
+
+            match identifier.as_str() {
+                "typedef" => self.parse_typedef()?,
+                "using" => self.parse_using()?,
+                "namespace" => {
+                    // Namespace contents are parsed as though they were declared at the top level.
+                    while matches!(self.peek(), Some(CHeaderToken::Identifier(_))) {
+                        self.advance();
+                    }
+
+                    self.consume_punctuation('{');
+                }
+                "extern" if self.peek_at(1) == Some(&CHeaderToken::Punctuation('{')) => self.position += 2,
+                "template" | "static_assert" | "_Static_assert" => self.skip_declaration(),
+                _ => {
+                    self.parse_type_specifier()?;
+                    self.skip_declaration();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_typedef(&mut self) -> Result<(), String> {
+        self.advance();
+
+        let mut base_type = self.parse_type_specifier()?;
+
+        loop {
+            match self.parse_declarator(base_type.clone())? {
+                Some((c_type, name, _)) if !name.is_empty() => {
+                    // Anonymous structs take the name of their typedef, ie `typedef struct { ... } Player;`.
+                    let anonymous_struct_name = match &c_type {
+                        CType::Struct { name: struct_name, .. } if self.last_anonymous_struct_name.as_ref() == Some(struct_name) => Some(struct_name.clone()),
+                        _ => None,
+                    };
+
+                    match anonymous_struct_name {
+                        Some(anonymous_struct_name) => {
+                            base_type = self.rename_struct(&anonymous_struct_name, &name);
+                            self.last_anonymous_struct_name = None;
+                        }
+                        None => {
+                            self.types.insert(name, c_type);
+                        }
+                    }
+                }
+                Some(_) => {}
+                None => {
+                    self.skip_declaration();
+                    return Ok(());
+                }
+            }
+
+            if !self.consume_punctuation(',') {
+                break;
+            }
+        }
+
+        if !self.consume_punctuation(';') {
+            self.skip_declaration();
+        }
+
+        Ok(())
+    }
+
+    /// Parses a C++ type alias, ie `using Health = int32_t;`. Using directives and declarations are skipped.
+    fn parse_using(&mut self) -> Result<(), String> {
+        self.advance();
+
+        let Some(name) = self.take_identifier() else {
+            self.skip_declaration();
+            return Ok(());
+        };
+
+        if !self.consume_punctuation('=') {
+            self.skip_declaration();
+            return Ok(());
+        }
+
+        let base_type = self.parse_type_specifier()?;
+
+        if let Some((c_type, _, _)) = self.parse_declarator(base_type)? {
+            self.types.insert(name, c_type);
+        }
+
+        if !self.consume_punctuation(';') {
+            self.skip_declaration();
+        }
+
+        Ok(())
+    }
+
+    /// Parses the type portion of a declaration, registering any struct, union, or enum that it defines.
+    fn parse_type_specifier(&mut self) -> Result<CType, String> {
+        let mut specifiers: Vec<String> = vec![];
+
+        while let Some(identifier) = self.peek_identifier() {
+            match identifier.as_str() {
+                "struct" | "class" | "union" if specifiers.is_empty() => return self.parse_struct_specifier(),
+                "enum" if specifiers.is_empty() => return self.parse_enum_specifier(),
+                _ if Self::ATTRIBUTES.contains(&identifier.as_str()) => {
+                    self.skip_attributes();
+                }
+                _ if Self::QUALIFIERS.contains(&identifier.as_str()) => self.advance(),
+                _ if CType::SPECIFIER_WORDS.contains(&identifier.as_str()) => {
+                    specifiers.push(identifier);
+                    self.advance();
+                }
+                _ if specifiers.is_empty() => {
+                    self.advance();
+                    self.skip_template_arguments();
+
+                    return Ok(self.resolve_type_name(&identifier));
+                }
+                _ => break,
+            }
+        }
+
+        if specifiers.is_empty() {
+            return Err(format!("Expected a type, but found {:?}.", self.peek()));
+        }
+
+        CType::from_specifiers(&specifiers).ok_or_else(|| format!("Unsupported type '{}'.", specifiers.join(" ")))
+    }
+
+    fn parse_struct_specifier(&mut self) -> Result<CType, String> {
+        let is_union = self.peek_identifier().as_deref() == Some("union");
+
+        self.advance();
+
+        let mut is_packed = self.skip_attributes();
+        let name = self.take_identifier();
+
+        if self.peek_identifier().as_deref() == Some("final") {
+            self.advance();
+        }
+
+        // Base classes are laid out at the start of the derived class.
+        let mut base_class_names = vec![];
+
+        if self.consume_punctuation(':') {
+            while let Some(token) = self.peek().cloned() {
+                match token {
+                    CHeaderToken::Punctuation('{') | CHeaderToken::Punctuation(';') => break,
+                    CHeaderToken::Identifier(identifier) if !matches!(identifier.as_str(), "public" | "private" | "protected" | "virtual") => {
+                        self.advance();
+                        self.skip_template_arguments();
+                        base_class_names.push(identifier);
+                    }
+                    _ => self.advance(),
+                }
+            }
+        }
+
+        if !self.is_punctuation('{') {
+            // An elaborated or forward declared type, ie `struct Inventory* inventory;`.
+            return Ok(match name {
+                Some(name) => self.resolve_type_name(&name),
+                None => CType::Unknown(String::new()),
+            });
+        }
+
+        let is_anonymous = name.is_none();
+        let struct_name = match name {
+            Some(name) => name,
+            None => {
+                self.anonymous_struct_count += 1;
+
+                match self.struct_name_stack.last() {
+                    Some(outer_struct_name) => format!("{}_anonymous_{}", outer_struct_name, self.anonymous_struct_count),
+                    None => format!("anonymous_{}", self.anonymous_struct_count),
+                }
+            }
+        };
+
+        // Packing is determined by the `#pragma pack` in effect where the struct is defined.
+        let pack = self.pack;
+        let mut members: Vec<CStructMember> = base_class_names
+            .iter()
+            .map(|base_class_name| CStructMember::new(format!("base_{}", base_class_name), self.resolve_type_name(base_class_name), None))
+            .collect();
+
+        self.struct_name_stack.push(struct_name.clone());
+
+        let has_virtual_methods = self.parse_struct_body(&mut members);
+
+        self.struct_name_stack.pop();
+
+        if has_virtual_methods? && base_class_names.is_empty() {
+            members.insert(0, CStructMember::new("vtable".to_string(), CType::Pointer(Box::new(CType::Void)), None));
+        }
+
+        is_packed |= self.skip_attributes();
+
+        let pack = if is_packed { Some(1) } else { pack };
+        let c_type = self.register_struct(&struct_name, &members, is_union, pack);
+
+        if is_anonymous {
+            self.last_anonymous_struct_name = Some(struct_name);
+        }
+
+        Ok(c_type)
+    }
+
+    /// Parses the members of a struct body, returning whether any virtual methods were declared.
+    fn parse_struct_body(
+        &mut self,
+        members: &mut Vec<CStructMember>,
+    ) -> Result<bool, String> {
+        let mut has_virtual_methods = false;
+
+        self.advance();
+
+        loop {
+            match self.peek().cloned() {
+                None => return Err("Unterminated struct body.".to_string()),
+                Some(CHeaderToken::Punctuation('}')) => {
+                    self.advance();
+                    return Ok(has_virtual_methods);
+                }
+                Some(CHeaderToken::Punctuation(';')) => self.advance(),
+                Some(CHeaderToken::Identifier(identifier)) => match identifier.as_str() {
+                    "public" | "private" | "protected" if self.peek_at(1) == Some(&CHeaderToken::Punctuation(':')) => self.position += 2,
+                    "virtual" => {
+                        has_virtual_methods = true;
+                        self.advance();
+                    }
+                    "typedef" => self.parse_typedef()?,
+                    "using" => self.parse_using()?,
+                    "static" | "friend" | "template" | "operator" | "explicit" | "static_assert" | "_Static_assert" => self.skip_declaration(),
+                    _ => self.parse_member_declaration(members)?,
+                },
+                // Destructors and other declarations that do not begin with a type.
+                Some(_) => self.skip_declaration(),
+            }
+        }
+    }
+
+    fn parse_member_declaration(
+        &mut self,
+        members: &mut Vec<CStructMember>,
+    ) -> Result<(), String> {
+        let base_type = self.parse_type_specifier()?;
+
+        if self.consume_punctuation(';') {
+            // An anonymous struct or union member, whose fields occupy storage within the enclosing struct.
+            if let CType::Struct { name, .. } = &base_type {
+                if self.last_anonymous_struct_name.as_ref() == Some(name) {
+                    members.push(CStructMember::new(name.clone(), base_type.clone(), None));
+                    self.last_anonymous_struct_name = None;
+                }
+            }
+
+            return Ok(());
+        }
+
+        loop {
+            let Some((c_type, name, bit_width)) = self.parse_declarator(base_type.clone())? else {
+                // A method or constructor declaration.
+                self.skip_declaration();
+                return Ok(());
+            };
+
+            members.push(CStructMember::new(name, c_type, bit_width));
+
+            if !self.consume_punctuation(',') {
+                break;
+            }
+        }
+
+        if !self.consume_punctuation(';') {
+            self.skip_declaration();
+        }
+
+        Ok(())
+    }
+
+    /// Parses a declarator, applying its pointers and array dimensions to the base type.
+    /// Returns the declared type, name, and bitfield width, or `None` if the declarator declares a function.
+    fn parse_declarator(
+        &mut self,
+        base_type: CType,
+    ) -> Result<Option<(CType, String, Option<u64>)>, String> {
+        let mut c_type = base_type;
+
+        self.skip_declarator_qualifiers();
+
+        while self.consume_punctuation('*') || self.consume_punctuation('&') {
+            c_type = CType::Pointer(Box::new(c_type));
+            self.skip_declarator_qualifiers();
+        }
+
+        let name = if self.is_punctuation('(') {
+            if !self.is_function_pointer_declarator() {
+                return Ok(None);
+            }
+
+            // A function pointer, ie `void (__stdcall* callback)(int)`.
+            self.advance();
+            self.skip_declarator_qualifiers();
+
+            c_type = CType::Void;
+
+            while self.consume_punctuation('*') || self.consume_punctuation('&') {
+                c_type = CType::Pointer(Box::new(c_type));
+                self.skip_declarator_qualifiers();
+            }
+
+            let name = self.take_identifier().unwrap_or_default();
+            let array_dimensions = self.parse_array_dimensions()?;
+
+            self.expect_punctuation(')')?;
+
+            if self.is_punctuation('(') {
+                self.skip_balanced('(', ')');
+            }
+
+            c_type = Self::apply_array_dimensions(c_type, &array_dimensions);
+
+            name
+        } else {
+            let name = self.take_identifier().unwrap_or_default();
+
+            if name == "operator" || self.is_punctuation('(') {
+                return Ok(None);
+            }
+
+            let array_dimensions = self.parse_array_dimensions()?;
+
+            c_type = Self::apply_array_dimensions(c_type, &array_dimensions);
+
+            name
+        };
+
+        let bit_width = if self.consume_punctuation(':') {
+            Some(self.parse_constant_expression()? as u64)
+        } else {
+            None
+        };
+
+        self.skip_attributes();
+
+        // Skip default member initializers, ie `int health = 100;` or `int health{ 100 };`.
+        if self.is_punctuation('=') || self.is_punctuation('{') {
+            self.skip_until_declarator_end();
+        }
+
+        Ok(Some((c_type, name, bit_width)))
+    }
+
+    fn parse_enum_specifier(&mut self) -> Result<CType, String> {
+        self.advance();
+
+        if matches!(self.peek_identifier().as_deref(), Some("class") | Some("struct")) {
+            self.advance();
+        }
+
+        self.skip_attributes();
+
+        let name = self.take_identifier();
+        let underlying_type = if self.consume_punctuation(':') {
+            self.parse_type_specifier()?
+        } else if let Some(enum_type) = name
+            .as_ref()
+            .filter(|_| !self.is_punctuation('{'))
+            .and_then(|name| self.types.get(name))
+        {
+            // An elaborated reference to an earlier enum, ie `enum Team team;`.
+            return Ok(enum_type.clone());
+        } else {
+            CType::from_specifiers(&["int".to_string()]).unwrap_or(CType::Void)
+        };
+
+        if self.consume_punctuation('{') {
+            let mut next_value: i64 = 0;
+
+            loop {
+                match self.peek().cloned() {
+                    None => return Err("Unterminated enum body.".to_string()),
+                    Some(CHeaderToken::Punctuation('}')) => {
+                        self.advance();
+                        break;
+                    }
+                    Some(CHeaderToken::Identifier(enumerator)) => {
+                        self.advance();
+                        self.skip_attributes();
+
+                        if self.consume_punctuation('=') {
+                            next_value = self.parse_constant_expression()?;
+                        }
+
+                        self.constants.insert(enumerator, next_value);
+                        next_value = next_value.wrapping_add(1);
+                    }
+                    Some(_) => self.advance(),
+                }
+            }
+        }
+
+        if let Some(name) = name {
+            self.types.insert(name, underlying_type.clone());
+        }
+
+        Ok(underlying_type)
+    }
+
+    fn parse_array_dimensions(&mut self) -> Result<Vec<u64>, String> {
+        let mut array_dimensions = vec![];
+
+        while self.consume_punctuation('[') {
+            // Flexible array members, ie `uint8_t data[];`, occupy no space.
+            if self.consume_punctuation(']') {
+                array_dimensions.push(0);
+                continue;
+            }
+
+            array_dimensions.push(self.parse_constant_expression()? as u64);
+            self.expect_punctuation(']')?;
+        }
+
+        Ok(array_dimensions)
+    }
+
+    /// Parses a constant expression, ending at the first unbalanced closing bracket, comma, semicolon, or assignment.
+    fn parse_constant_expression(&mut self) -> Result<i64, String> {
+        let mut expression_tokens = vec![];
+        let mut depth = 0;
+
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                CHeaderToken::Punctuation('(') => depth += 1,
+                CHeaderToken::Punctuation(')') if depth > 0 => depth -= 1,
+                CHeaderToken::Punctuation(')' | ']' | '}' | ',' | ';' | '=' | '{') => break,
+                _ => {}
+            }
+
+            expression_tokens.push(token);
+            self.advance();
+        }
+
+        CConstantExpression::evaluate(&expression_tokens, &self.constants)
+    }
+
+    fn apply_array_dimensions(
+        element_type: CType,
+        array_dimensions: &[u64],
+    ) -> CType {
+        // The rightmost dimension is the innermost, ie `int grid[2][3]` is an array of two arrays of three integers.
+        array_dimensions
+            .iter()
+            .rev()
+            .fold(element_type, |c_type, length| CType::Array(Box::new(c_type), *length))
+    }
+
+    fn resolve_type_name(
+        &self,
+        type_name: &str,
+    ) -> CType {
+        self.types
+            .get(type_name)
+            .cloned()
+            .or_else(|| CType::from_type_name(type_name))
+            .unwrap_or_else(|| CType::Unknown(type_name.to_string()))
+    }
+
+    /// Lays out the given members and registers the resulting struct, replacing any earlier definition with the same name.
+    fn register_struct(
+        &mut self,
+        struct_name: &str,
+        members: &[CStructMember],
+        is_union: bool,
+        pack: Option<u64>,
+    ) -> CType {
+        let (symbolic_struct_definition, size_in_bytes, alignment) = Self::layout_struct(struct_name, members, is_union, pack, &mut self.warnings);
+        let c_type = CType::Struct {
+            name: struct_name.to_string(),
+            size_in_bytes,
+            alignment,
+        };
+
+        self.types.insert(struct_name.to_string(), c_type.clone());
+
+        match self
+            .struct_definitions
+            .iter_mut()
+            .find(|(name, _)| name == struct_name)
+        {
+            Some((_, existing_definition)) => *existing_definition = symbolic_struct_definition,
+            None => self
+                .struct_definitions
+                .push((struct_name.to_string(), symbolic_struct_definition)),
+        }
+
+        c_type
+    }
+
+    /// Renames a struct, along with the anonymous structs nested within it and all fields that refer to them.
+    fn rename_struct(
+        &mut self,
+        struct_name: &str,
+        new_struct_name: &str,
+    ) -> CType {
+        let nested_struct_prefix = format!("{}_anonymous_", struct_name);
+        let nested_struct_names: Vec<String> = self
+            .struct_definitions
+            .iter()
+            .map(|(name, _)| name.clone())
+            .filter(|name| name.starts_with(&nested_struct_prefix))
+            .collect();
+
+        for nested_struct_name in nested_struct_names {
+            let new_nested_struct_name = format!("{}{}", new_struct_name, &nested_struct_name[struct_name.len()..]);
+
+            self.rename_struct(&nested_struct_name, &new_nested_struct_name);
+        }
+
+        let c_type = match self.types.remove(struct_name) {
+            Some(CType::Struct { size_in_bytes, alignment, .. }) => CType::Struct {
+                name: new_struct_name.to_string(),
+                size_in_bytes,
+                alignment,
+            },
+            _ => CType::Unknown(new_struct_name.to_string()),
+        };
+
+        self.types.insert(new_struct_name.to_string(), c_type.clone());
+
+        for (name, symbolic_struct_definition) in self.struct_definitions.iter_mut() {
+            if name == struct_name {
+                *name = new_struct_name.to_string();
+            }
+
+            if symbolic_struct_definition
+                .get_fields()
+                .iter()
+                .any(|field| field.get_value().get_data_type_id() == struct_name)
+            {
+                let fields = symbolic_struct_definition
+                    .get_fields()
+                    .iter()
+                    .map(|field| match field.get_value().get_data_type_id() == struct_name {
+                        // Anonymous struct members are named after their struct, and are renamed alongside it.
+                        true => SymbolicStructFieldDefinition::new_named(
+                            match field.get_name() == struct_name {
+                                true => new_struct_name.to_string(),
+                                false => field.get_name().to_string(),
+                            },
+                            DataTypeRef::new(new_struct_name),
+                            field.get_container_type(),
+                            field.get_offset(),
+                        ),
+                        false => field.clone(),
+                    })
+                    .collect();
+
+                *symbolic_struct_definition = SymbolicStructDefinition::new(fields);
+            }
+        }
+
+        c_type
+    }
+
+    /// Computes the offset of each member, returning the struct definition along with its size and alignment.
+    fn layout_struct(
+        struct_name: &str,
+        members: &[CStructMember],
+        is_union: bool,
+        pack: Option<u64>,
+        warnings: &mut Vec<String>,
+    ) -> (SymbolicStructDefinition, u64, u64) {
+        let mut fields = vec![];
+        let mut next_offset = 0;
+        let mut end_offset = 0;
+        let mut alignment = 1;
+
+        // Consecutive bitfields share a storage unit while they fit, tracked as the unit size and the bits used so far.
+        let mut bitfield_unit: Option<(u64, u64)> = None;
+
+        for member in members {
+            let member_size = member.get_c_type().get_size_in_bytes();
+            let member_alignment = match pack {
+                Some(pack) => member.get_c_type().get_alignment().min(pack.max(1)),
+                None => member.get_c_type().get_alignment(),
+            };
+
+            match member.get_bit_width() {
+                Some(0) => {
+                    bitfield_unit = None;
+                    continue;
+                }
+                Some(bit_width) => {
+                    if let Some((unit_size, bits_used)) = bitfield_unit.as_mut() {
+                        if !is_union && *unit_size == member_size && *bits_used + bit_width <= member_size * 8 {
+                            *bits_used += bit_width;
+                            continue;
+                        }
+                    }
+
+                    bitfield_unit = Some((member_size, bit_width));
+                }
+                None => bitfield_unit = None,
+            }
+
+            let field_offset = if is_union { 0 } else { Self::align_up(next_offset, member_alignment) };
+            let field_name = if member.get_name().is_empty() {
+                format!("field_{:X}", field_offset)
+            } else {
+                member.get_name().to_string()
+            };
+
+            fields.push(Self::create_field_definition(struct_name, field_name, member.get_c_type(), field_offset, warnings));
+
+            next_offset = field_offset + member_size;
+            end_offset = end_offset.max(next_offset);
+            alignment = alignment.max(member_alignment);
+        }
+
+        let size_in_bytes = Self::align_up(end_offset, alignment);
+
+        if size_in_bytes > end_offset {
+            fields.push(SymbolicStructFieldDefinition::new_named(
+                String::new(),
+                DataTypeRef::new(SymbolicStructFieldDefinition::PADDING_TYPE_ID),
+                ContainerType::Array(size_in_bytes - end_offset),
+                Some(end_offset),
+            ));
+        }
+
+        (SymbolicStructDefinition::new(fields), size_in_bytes, alignment)
+    }
+
+    fn create_field_definition(
+        struct_name: &str,
+        field_name: String,
+        c_type: &CType,
+        offset: u64,
+        warnings: &mut Vec<String>,
+    ) -> SymbolicStructFieldDefinition {
+        let (data_type_id, container_type) = match c_type {
+            CType::Primitive { data_type_id, .. } => (data_type_id.to_string(), ContainerType::None),
+            CType::Struct { name, .. } => (name.clone(), ContainerType::None),
+            CType::Pointer(target_type) => (Self::get_pointer_target_type_id(target_type), ContainerType::Pointer),
+            CType::Array(_, _) => {
+                let (element_type, length) = Self::flatten_array(c_type);
+
+                match element_type {
+                    CType::Primitive { data_type_id, .. } => (data_type_id.to_string(), ContainerType::Array(length)),
+                    CType::Struct { name, .. } => (name.clone(), ContainerType::Array(length)),
+                    CType::Pointer(_) => (DataTypeU64::get_data_type_id().to_string(), ContainerType::Array(length)),
+                    _ => return Self::create_unknown_field_definition(struct_name, field_name, element_type, c_type.get_size_in_bytes(), offset, warnings),
+                }
+            }
+            CType::Void | CType::Unknown(_) => {
+                return Self::create_unknown_field_definition(struct_name, field_name, c_type, c_type.get_size_in_bytes(), offset, warnings);
+            }
+        };
+
+        SymbolicStructFieldDefinition::new_named(field_name, DataTypeRef::new(&data_type_id), container_type, Some(offset))
+    }
+
+    fn create_unknown_field_definition(
+        struct_name: &str,
+        field_name: String,
+        c_type: &CType,
+        size_in_bytes: u64,
+        offset: u64,
+        warnings: &mut Vec<String>,
+    ) -> SymbolicStructFieldDefinition {
+        let type_name = match c_type {
+            CType::Unknown(type_name) if !type_name.is_empty() => type_name.as_str(),
+            _ => "void",
+        };
+
+        let warning = format!(
+            "Unknown type '{}' for field '{}.{}', mapping it to {} bytes of padding.",
+            type_name, struct_name, field_name, size_in_bytes
+        );
+
+        log::warn!("{}", warning);
+        warnings.push(warning);
+
+        SymbolicStructFieldDefinition::new_named(
+            field_name,
+            DataTypeRef::new(SymbolicStructFieldDefinition::PADDING_TYPE_ID),
+            ContainerType::Array(size_in_bytes),
+            Some(offset),
+        )
+    }
+
+    fn get_pointer_target_type_id(target_type: &CType) -> String {
+        match target_type {
+            CType::Primitive { data_type_id, .. } => data_type_id.to_string(),
+            CType::Struct { name, .. } => name.clone(),
+            // Forward declared structs may be defined later in the header, or elsewhere in the project.
+            CType::Unknown(type_name) if !type_name.is_empty() => type_name.clone(),
+            CType::Pointer(_) => DataTypeU64::get_data_type_id().to_string(),
+            _ => DataTypeU8::get_data_type_id().to_string(),
+        }
+    }
+
+    /// Flattens multi-dimensional arrays into their element type and total element count.
+    fn flatten_array(c_type: &CType) -> (&CType, u64) {
+        match c_type {
+            CType::Array(element_type, length) => {
+                let (element_type, element_count) = Self::flatten_array(element_type);
+
+                (element_type, element_count * length)
+            }
+            _ => (c_type, 1),
+        }
+    }
+
+    fn align_up(
+        offset: u64,
+        alignment: u64,
+    ) -> u64 {
+        offset.div_ceil(alignment.max(1)) * alignment.max(1)
+    }
+
+    /// Skips attributes such as `__attribute__((packed))`, `__declspec(align(16))`, and `[[nodiscard]]`, returning whether any requested packing.
+    fn skip_attributes(&mut self) -> bool {
+        let mut is_packed = false;
+
+        loop {
+            if self.is_punctuation('[') && self.peek_at(1) == Some(&CHeaderToken::Punctuation('[')) {
+                self.skip_balanced('[', ']');
+                continue;
+            }
+
+            match self.peek_identifier() {
+                Some(identifier) if Self::ATTRIBUTES.contains(&identifier.as_str()) => {
+                    self.advance();
+
+                    if self.is_punctuation('(') {
+                        is_packed |= self
+                            .skip_balanced('(', ')')
+                            .iter()
+                            .any(|token| matches!(token, CHeaderToken::Identifier(identifier) if identifier == "packed" || identifier == "__packed__"));
+                    }
+                }
+                _ => return is_packed,
+            }
+        }
+    }
+
+    fn skip_declarator_qualifiers(&mut self) {
+        loop {
+            match self.peek_identifier() {
+                Some(identifier) if Self::QUALIFIERS.contains(&identifier.as_str()) || Self::CALLING_CONVENTIONS.contains(&identifier.as_str()) => {
+                    self.advance()
+                }
+                Some(identifier) if Self::ATTRIBUTES.contains(&identifier.as_str()) => {
+                    self.skip_attributes();
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn skip_template_arguments(&mut self) {
+        if self.is_punctuation('<') {
+            self.skip_balanced('<', '>');
+        }
+    }
+
+    /// Determines whether the parenthesis at the current position begins a function pointer declarator rather than a parameter list.
+    fn is_function_pointer_declarator(&mut self) -> bool {
+        let mut offset = 1;
+
+        loop {
+            match self.peek_at(offset) {
+                Some(CHeaderToken::Identifier(identifier)) if Self::CALLING_CONVENTIONS.contains(&identifier.as_str()) => offset += 1,
+                Some(CHeaderToken::Punctuation('*' | '&')) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Skips the remainder of a declaration, including any function body, consuming the trailing semicolon if present.
+    /// Stops without consuming the closing brace of an enclosing struct body.
+    fn skip_declaration(&mut self) {
+        let mut depth = 0;
+        let mut has_initializer = false;
+
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                CHeaderToken::Punctuation(';') if depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                CHeaderToken::Punctuation('}') if depth == 0 => return,
+                CHeaderToken::Punctuation('=') if depth == 0 => has_initializer = true,
+                CHeaderToken::Punctuation('{') if depth == 0 && !has_initializer => {
+                    self.skip_balanced('{', '}');
+                    return;
+                }
+                CHeaderToken::Punctuation('(' | '[' | '{') => depth += 1,
+                CHeaderToken::Punctuation(')' | ']' | '}') => depth -= 1,
+                _ => {}
+            }
+
+            self.advance();
+        }
+    }
+
+    /// Skips a member initializer, stopping before the comma or semicolon that ends the declarator.
+    fn skip_until_declarator_end(&mut self) {
+        let mut depth = 0;
+
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                CHeaderToken::Punctuation(',' | ';' | '}') if depth == 0 => return,
+                CHeaderToken::Punctuation('(' | '[' | '{') => depth += 1,
+                CHeaderToken::Punctuation(')' | ']' | '}') => depth -= 1,
+                _ => {}
+            }
+
+            self.advance();
+        }
+    }
+
+    /// Skips a bracketed sequence starting at the current opening bracket, returning the tokens within it.
+    fn skip_balanced(
+        &mut self,
+        open: char,
+        close: char,
+    ) -> Vec<CHeaderToken> {
+        let mut skipped_tokens = vec![];
+        let mut depth = 0;
+
+        while let Some(token) = self.peek().cloned() {
+            self.advance();
+
+            if token == CHeaderToken::Punctuation(open) {
+                depth += 1;
+            } else if token == CHeaderToken::Punctuation(close) {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+
+            skipped_tokens.push(token);
+        }
+
+        skipped_tokens
+    }
+
+    fn take_identifier(&mut self) -> Option<String> {
+        let identifier = self.peek_identifier()?;
+
+        if Self::QUALIFIERS.contains(&identifier.as_str()) {
+            return None;
+        }
+
+        self.advance();
+
+        Some(identifier)
+    }
+
+    fn expect_punctuation(
+        &mut self,
+        punctuation: char,
+    ) -> Result<(), String> {
+        if self.consume_punctuation(punctuation) {
+            Ok(())
+        } else {
+            Err(format!("Expected '{}', but found {:?}.", punctuation, self.peek()))
+        }
+    }
+
+    fn consume_punctuation(
+        &mut self,
+        punctuation: char,
+    ) -> bool {
+        let is_match = self.is_punctuation(punctuation);
+
+        if is_match {
+            self.advance();
+        }
+
+        is_match
+    }
+
+    fn is_punctuation(
+        &mut self,
+        punctuation: char,
+    ) -> bool {
+        self.peek() == Some(&CHeaderToken::Punctuation(punctuation))
+    }
+
+    fn peek_identifier(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(CHeaderToken::Identifier(identifier)) => Some(identifier.clone()),
+            _ => None,
+        }
+    }
+
+    fn peek(&mut self) -> Option<&CHeaderToken> {
+        self.peek_at(0)
+    }
+
+    /// Peeks at an upcoming token, first applying any preprocessor directives at the current position.
+    fn peek_at(
+        &mut self,
+        offset: usize,
+    ) -> Option<&CHeaderToken> {
+        self.apply_directives();
+        self.tokens.get(self.position + offset)
+    }
+
+    fn advance(&mut self) {
+        self.position += 1;
+    }
+
+    fn apply_directives(&mut self) {
+        while let Some(token) = self.tokens.get(self.position) {
+            match token {
+                CHeaderToken::Define(name, value) => {
+                    self.constants.insert(name.clone(), *value as i64);
+                }
+                CHeaderToken::PackPush(pack) => {
+                    self.pack_stack.push(self.pack);
+
+                    if pack.is_some() {
+                        self.pack = *pack;
+                    }
+                }
+                CHeaderToken::PackPop => self.pack = self.pack_stack.pop().unwrap_or(None),
+                CHeaderToken::PackSet(pack) => self.pack = *pack,
+                _ => return,
+            }
+
+            self.position += 1;
+        }
+    }
+}
@@ -0,0 +1,15 @@
+/// A token of a C header, with preprocessor directives that affect struct layout kept as dedicated tokens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CHeaderToken {
+    Identifier(String),
+    Number(u64),
+    Punctuation(char),
+    /// A `#define NAME value` with a numeric value, which may be used for array lengths.
+    Define(String, u64),
+    /// `#pragma pack(push)` or `#pragma pack(push, n)`.
+    PackPush(Option<u64>),
+    /// `#pragma pack(pop)`.
+    PackPop,
+    /// `#pragma pack(n)`, or `#pragma pack()` to restore default packing.
+    PackSet(Option<u64>),
+}
@@ -0,0 +1,194 @@
+use crate::structs::c_header::c_header_token::CHeaderToken;
+
+/// Splits a C header into tokens. Comments, string literals, and preprocessor directives other than
+/// `#pragma pack` and numeric `#define`s are discarded.
+pub struct CHeaderTokenizer {}
+
+impl CHeaderTokenizer {
+    pub fn tokenize(source: &str) -> Vec<CHeaderToken> {
+        // Join line continuations, such that multi-line directives are handled as a single line.
+        let source = source.replace("\\\r\n", " ").replace("\\\n", " ");
+        let source = Self::strip_comments(&source);
+        let mut tokens = vec![];
+
+        for line in source.lines() {
+            match line.trim_start().strip_prefix('#') {
+                Some(directive) => {
+                    if let Some(token) = Self::parse_directive(directive) {
+                        tokens.push(token);
+                    }
+                }
+                None => Self::tokenize_line(line, &mut tokens),
+            }
+        }
+
+        tokens
+    }
+
+    /// Parses a numeric literal, ignoring integer suffixes such as `u` and `ull`.
+    pub fn parse_number(literal: &str) -> Option<u64> {
+        let literal = literal.trim_end_matches(|character: char| matches!(character, 'u' | 'U' | 'l' | 'L'));
+
+        if let Some(hex_digits) = literal
+            .strip_prefix("0x")
+            .or_else(|| literal.strip_prefix("0X"))
+        {
+            u64::from_str_radix(hex_digits, 16).ok()
+        } else if let Some(binary_digits) = literal
+            .strip_prefix("0b")
+            .or_else(|| literal.strip_prefix("0B"))
+        {
+            u64::from_str_radix(binary_digits, 2).ok()
+        } else {
+            literal.parse::<u64>().ok()
+        }
+    }
+
+    /// Replaces comments with whitespace, preserving newlines such that preprocessor directives remain on their own lines.
+    fn strip_comments(source: &str) -> String {
+        let mut result = String::with_capacity(source.len());
+        let mut characters = source.chars().peekable();
+
+        while let Some(character) = characters.next() {
+            match character {
+                '/' if characters.peek() == Some(&'/') => {
+                    while let Some(&next_character) = characters.peek() {
+                        if next_character == '\n' {
+                            break;
+                        }
+
+                        characters.next();
+                    }
+                }
+                '/' if characters.peek() == Some(&'*') => {
+                    characters.next();
+                    result.push(' ');
+
+                    let mut previous_character = '\0';
+
+                    for next_character in characters.by_ref() {
+                        if next_character == '\n' {
+                            result.push('\n');
+                        }
+
+                        if previous_character == '*' && next_character == '/' {
+                            break;
+                        }
+
+                        previous_character = next_character;
+                    }
+                }
+                '"' | '\'' => {
+                    // Literals are kept intact so that comment markers within them are not treated as comments.
+                    result.push(character);
+
+                    while let Some(next_character) = characters.next() {
+                        result.push(next_character);
+
+                        if next_character == '\\' {
+                            if let Some(escaped_character) = characters.next() {
+                                result.push(escaped_character);
+                            }
+                        } else if next_character == character || next_character == '\n' {
+                            break;
+                        }
+                    }
+                }
+                _ => result.push(character),
+            }
+        }
+
+        result
+    }
+
+    fn parse_directive(directive: &str) -> Option<CHeaderToken> {
+        let mut words = directive.split_whitespace();
+
+        match words.next()? {
+            "define" => {
+                let name = words.next()?;
+                let value = Self::parse_number(
+                    words
+                        .next()?
+                        .trim_matches(|character| character == '(' || character == ')'),
+                )?;
+
+                // Function-like macros and macros with expressions are not supported.
+                if name.contains('(') || words.next().is_some() {
+                    return None;
+                }
+
+                Some(CHeaderToken::Define(name.to_string(), value))
+            }
+            "pragma" => {
+                let compact: String = words.collect::<String>();
+                let arguments = compact.strip_prefix("pack(")?.strip_suffix(')')?;
+                let parts: Vec<&str> = arguments.split(',').collect();
+
+                match parts[0] {
+                    "push" => Some(CHeaderToken::PackPush(parts.last().and_then(|part| Self::parse_number(part)))),
+                    "pop" => Some(CHeaderToken::PackPop),
+                    "" => Some(CHeaderToken::PackSet(None)),
+                    pack => Some(CHeaderToken::PackSet(Some(Self::parse_number(pack)?))),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn tokenize_line(
+        line: &str,
+        tokens: &mut Vec<CHeaderToken>,
+    ) {
+        let characters: Vec<char> = line.chars().collect();
+        let mut index = 0;
+
+        while index < characters.len() {
+            let character = characters[index];
+
+            if character.is_whitespace() {
+                index += 1;
+            } else if character.is_alphabetic() || character == '_' {
+                let start_index = index;
+
+                // Scope qualifiers are kept as part of the identifier, ie `std::string`.
+                while index < characters.len()
+                    && (characters[index].is_alphanumeric()
+                        || characters[index] == '_'
+                        || (characters[index] == ':' && characters.get(index + 1) == Some(&':'))
+                        || (characters[index] == ':' && index > 0 && characters[index - 1] == ':'))
+                {
+                    index += 1;
+                }
+
+                tokens.push(CHeaderToken::Identifier(characters[start_index..index].iter().collect()));
+            } else if character.is_ascii_digit() {
+                let start_index = index;
+
+                while index < characters.len() && (characters[index].is_alphanumeric() || characters[index] == '.') {
+                    index += 1;
+                }
+
+                let literal: String = characters[start_index..index].iter().collect();
+
+                // Floating point literals have no bearing on struct layout, and are treated as zero.
+                tokens.push(CHeaderToken::Number(Self::parse_number(&literal).unwrap_or(0)));
+            } else if character == '"' || character == '\'' {
+                index += 1;
+
+                while index < characters.len() && characters[index] != character {
+                    if characters[index] == '\\' {
+                        index += 1;
+                    }
+
+                    index += 1;
+                }
+
+                index += 1;
+            } else {
+                tokens.push(CHeaderToken::Punctuation(character));
+                index += 1;
+            }
+        }
+    }
+}
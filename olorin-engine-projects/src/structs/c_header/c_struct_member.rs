@@ -0,0 +1,32 @@
+use crate::structs::c_header::c_type::CType;
+
+/// A member declared within the body of a C struct or union, prior to layout.
+#[derive(Clone, Debug)]
+pub struct CStructMember {
+    name: String,
+    c_type: CType,
+    bit_width: Option<u64>,
+}
+
+impl CStructMember {
+    pub fn new(
+        name: String,
+        c_type: CType,
+        bit_width: Option<u64>,
+    ) -> Self {
+        CStructMember { name, c_type, bit_width }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_c_type(&self) -> &CType {
+        &self.c_type
+    }
+
+    /// Gets the bit width of this member, if it is a bitfield.
+    pub fn get_bit_width(&self) -> Option<u64> {
+        self.bit_width
+    }
+}
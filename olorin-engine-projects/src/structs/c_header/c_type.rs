@@ -0,0 +1,155 @@
+use olorin_engine_api::structures::data_types::built_in_types::bool8::data_type_bool8::DataTypeBool8;
+use olorin_engine_api::structures::data_types::built_in_types::bool32::data_type_bool32::DataTypeBool32;
+use olorin_engine_api::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+use olorin_engine_api::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+use olorin_engine_api::structures::data_types::built_in_types::i8::data_type_i8::DataTypeI8;
+use olorin_engine_api::structures::data_types::built_in_types::i16::data_type_i16::DataTypeI16;
+use olorin_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use olorin_engine_api::structures::data_types::built_in_types::i64::data_type_i64::DataTypeI64;
+use olorin_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use olorin_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use olorin_engine_api::structures::data_types::built_in_types::u16::data_type_u16::DataTypeU16;
+use olorin_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use olorin_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use olorin_engine_api::structures::structs::symbolic_struct_field_definition::SymbolicStructFieldDefinition;
+
+/// A resolved C type, as used by struct members and typedefs.
+#[derive(Clone, Debug)]
+pub enum CType {
+    Void,
+    Primitive {
+        data_type_id: &'static str,
+        size_in_bytes: u64,
+    },
+    Struct {
+        name: String,
+        size_in_bytes: u64,
+        alignment: u64,
+    },
+    Pointer(Box<CType>),
+    Array(Box<CType>, u64),
+    /// A type that could not be resolved, such as a forward declared struct or a type from an unparsed header.
+    Unknown(String),
+}
+
+impl CType {
+    /// The words that may be combined to form a built-in type, ie `unsigned long long int`.
+    pub const SPECIFIER_WORDS: [&str; 12] = [
+        "signed", "unsigned", "short", "long", "int", "char", "float", "double", "void", "bool", "_Bool", "wchar_t",
+    ];
+
+    /// Resolves a built-in type from its specifier words, ie `["unsigned", "short"]`.
+    pub fn from_specifiers(specifiers: &[String]) -> Option<CType> {
+        let count = |word: &str| {
+            specifiers
+                .iter()
+                .filter(|specifier| specifier.as_str() == word)
+                .count()
+        };
+        let is_unsigned = count("unsigned") > 0;
+        let long_count = count("long");
+
+        let c_type = if count("void") > 0 {
+            CType::Void
+        } else if count("float") > 0 {
+            Self::primitive(DataTypeF32::get_data_type_id(), 4)
+        } else if count("double") > 0 {
+            // MSVC treats `long double` as a double, which is the common case for game structs.
+            Self::primitive(DataTypeF64::get_data_type_id(), 8)
+        } else if count("bool") > 0 || count("_Bool") > 0 {
+            Self::primitive(DataTypeBool8::get_data_type_id(), 1)
+        } else if count("wchar_t") > 0 {
+            Self::primitive(DataTypeU16::get_data_type_id(), 2)
+        } else if count("char") > 0 && count("signed") == 0 && !is_unsigned {
+            // Plain `char` is used for text, whereas signed and unsigned chars are used for bytes.
+            Self::primitive(DataTypeStringUtf8::get_data_type_id(), 1)
+        } else if count("char") > 0 {
+            Self::integer(1, is_unsigned)
+        } else if count("short") > 0 {
+            Self::integer(2, is_unsigned)
+        } else if long_count >= 2 {
+            Self::integer(8, is_unsigned)
+        } else if long_count == 1 || count("int") > 0 || count("signed") > 0 || is_unsigned {
+            // Windows targets are LLP64, such that `long` remains 32 bits.
+            Self::integer(4, is_unsigned)
+        } else {
+            return None;
+        };
+
+        Some(c_type)
+    }
+
+    /// Resolves a single-word type name from `stdint.h`, the Windows headers, or compiler intrinsics.
+    pub fn from_type_name(type_name: &str) -> Option<CType> {
+        let c_type = match type_name {
+            "CHAR" => Self::primitive(DataTypeStringUtf8::get_data_type_id(), 1),
+            "int8_t" | "__int8" | "INT8" | "CCHAR" => Self::integer(1, false),
+            "uint8_t" | "BYTE" | "UCHAR" | "UINT8" | "byte" | "u_char" | "char8_t" => Self::integer(1, true),
+            "int16_t" | "__int16" | "INT16" | "SHORT" => Self::integer(2, false),
+            "uint16_t" | "WORD" | "USHORT" | "UINT16" | "WCHAR" | "char16_t" | "u_short" => Self::integer(2, true),
+            "int32_t" | "__int32" | "INT32" | "INT" | "LONG" | "LONG32" | "HRESULT" | "NTSTATUS" => Self::integer(4, false),
+            "uint32_t" | "DWORD" | "UINT" | "ULONG" | "UINT32" | "ULONG32" | "DWORD32" | "char32_t" | "u_int" => Self::integer(4, true),
+            "int64_t" | "__int64" | "INT64" | "LONGLONG" | "LONG64" | "intptr_t" | "ptrdiff_t" | "ssize_t" | "INT_PTR" | "LONG_PTR" => Self::integer(8, false),
+            "uint64_t" | "QWORD" | "UINT64" | "ULONGLONG" | "ULONG64" | "DWORD64" | "uintptr_t" | "size_t" | "SIZE_T" | "UINT_PTR" | "ULONG_PTR"
+            | "DWORD_PTR" => Self::integer(8, true),
+            "FLOAT" => Self::primitive(DataTypeF32::get_data_type_id(), 4),
+            "DOUBLE" => Self::primitive(DataTypeF64::get_data_type_id(), 8),
+            "BOOLEAN" => Self::primitive(DataTypeBool8::get_data_type_id(), 1),
+            "BOOL" => Self::primitive(DataTypeBool32::get_data_type_id(), 4),
+            "LPSTR" | "LPCSTR" | "PCHAR" | "PSTR" | "PCSTR" => CType::Pointer(Box::new(Self::primitive(DataTypeStringUtf8::get_data_type_id(), 1))),
+            "LPWSTR" | "LPCWSTR" | "PWCHAR" | "PWSTR" | "PCWSTR" => CType::Pointer(Box::new(Self::integer(2, true))),
+            "HANDLE" | "HMODULE" | "HINSTANCE" | "HWND" | "PVOID" | "LPVOID" | "LPCVOID" | "PBYTE" | "LPBYTE" => {
+                CType::Pointer(Box::new(Self::integer(1, true)))
+            }
+            _ => return None,
+        };
+
+        Some(c_type)
+    }
+
+    pub fn get_size_in_bytes(&self) -> u64 {
+        match self {
+            CType::Void => 0,
+            CType::Primitive { size_in_bytes, .. } => *size_in_bytes,
+            CType::Struct { size_in_bytes, .. } => *size_in_bytes,
+            CType::Pointer(_) | CType::Unknown(_) => SymbolicStructFieldDefinition::POINTER_SIZE,
+            CType::Array(element_type, length) => element_type.get_size_in_bytes() * length,
+        }
+    }
+
+    /// Gets the natural alignment of this type, prior to any packing.
+    pub fn get_alignment(&self) -> u64 {
+        match self {
+            CType::Void => 1,
+            CType::Primitive { size_in_bytes, .. } => (*size_in_bytes).max(1),
+            CType::Struct { alignment, .. } => *alignment,
+            CType::Pointer(_) | CType::Unknown(_) => SymbolicStructFieldDefinition::POINTER_SIZE,
+            CType::Array(element_type, _) => element_type.get_alignment(),
+        }
+    }
+
+    fn primitive(
+        data_type_id: &'static str,
+        size_in_bytes: u64,
+    ) -> CType {
+        CType::Primitive { data_type_id, size_in_bytes }
+    }
+
+    fn integer(
+        size_in_bytes: u64,
+        is_unsigned: bool,
+    ) -> CType {
+        let data_type_id = match (size_in_bytes, is_unsigned) {
+            (1, false) => DataTypeI8::get_data_type_id(),
+            (1, true) => DataTypeU8::get_data_type_id(),
+            (2, false) => DataTypeI16::get_data_type_id(),
+            (2, true) => DataTypeU16::get_data_type_id(),
+            (4, false) => DataTypeI32::get_data_type_id(),
+            (4, true) => DataTypeU32::get_data_type_id(),
+            (_, false) => DataTypeI64::get_data_type_id(),
+            (_, true) => DataTypeU64::get_data_type_id(),
+        };
+
+        Self::primitive(data_type_id, size_in_bytes)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod c_constant_expression;
+pub mod c_header_struct_exporter;
+pub mod c_header_struct_importer;
+pub mod c_header_token;
+pub mod c_header_tokenizer;
+pub mod c_struct_member;
+pub mod c_type;
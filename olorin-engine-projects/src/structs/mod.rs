@@ -0,0 +1,3 @@
+pub mod c_header;
+pub mod reclass;
+pub mod struct_import_result;
@@ -0,0 +1 @@
+pub mod reclass_struct_importer;
@@ -0,0 +1,390 @@
+use olorin_engine_api::structures::data_types::built_in_types::bool8::data_type_bool8::DataTypeBool8;
+use olorin_engine_api::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+use olorin_engine_api::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+use olorin_engine_api::structures::data_types::built_in_types::i8::data_type_i8::DataTypeI8;
+use olorin_engine_api::structures::data_types::built_in_types::i16::data_type_i16::DataTypeI16;
+use olorin_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use olorin_engine_api::structures::data_types::built_in_types::i64::data_type_i64::DataTypeI64;
+use olorin_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use olorin_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use olorin_engine_api::structures::data_types::built_in_types::u16::data_type_u16::DataTypeU16;
+use olorin_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use olorin_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use olorin_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+use olorin_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use olorin_engine_api::structures::structs::symbolic_struct_field_definition::SymbolicStructFieldDefinition;
+use crate::structs::struct_import_result::StructImportResult;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+
+/// Imports symbolic struct definitions from ReClass.NET projects (`.rcnet`), which are zip archives containing the project as XML.
+/// Each class becomes a struct, with every node given an explicit offset. Nodes without an equivalent type are mapped to padding.
+pub struct ReClassStructImporter<'a, 'input> {
+    classes_by_uuid: HashMap<&'a str, Node<'a, 'input>>,
+    enum_sizes: HashMap<&'a str, u64>,
+    class_sizes: HashMap<&'a str, u64>,
+    pointer_size: u64,
+    warnings: Vec<String>,
+}
+
+impl ReClassStructImporter<'_, '_> {
+    /// The project data file within a ReClass.NET project archive.
+    const DATA_FILE_NAME: &'static str = "Data.xml";
+
+    pub fn import_file(file_path: &Path) -> Result<StructImportResult, String> {
+        let file = File::open(file_path).map_err(|error| format!("Failed to open ReClass project {}: {}", file_path.display(), error))?;
+        let mut archive = ZipArchive::new(file).map_err(|error| format!("Failed to read ReClass project archive: {}", error))?;
+        let mut data_file = archive
+            .by_name(Self::DATA_FILE_NAME)
+            .map_err(|error| format!("ReClass project is missing {}: {}", Self::DATA_FILE_NAME, error))?;
+        let mut xml = String::new();
+
+        data_file
+            .read_to_string(&mut xml)
+            .map_err(|error| format!("Failed to read ReClass project data: {}", error))?;
+
+        ReClassStructImporter::import_xml(&xml)
+    }
+
+    /// Imports classes from the XML project data of a ReClass.NET project.
+    pub fn import_xml(xml: &str) -> Result<StructImportResult, String> {
+        let document = Document::parse(xml).map_err(|error| format!("Failed to parse ReClass project data: {}", error))?;
+        let root = document.root_element();
+        let classes: Vec<Node> = root
+            .descendants()
+            .filter(|node| node.has_tag_name("class"))
+            .collect();
+        let is_32_bit = root.attribute("platform").or_else(|| root.attribute("type")) == Some("x86");
+        let mut importer = ReClassStructImporter {
+            classes_by_uuid: classes
+                .iter()
+                .filter_map(|class| class.attribute("uuid").map(|uuid| (uuid, *class)))
+                .collect(),
+            enum_sizes: root
+                .descendants()
+                .filter(|node| node.has_tag_name("enum"))
+                .filter_map(|node| Some((node.attribute("name")?, ReClassStructImporter::parse_attribute(node, "size").unwrap_or(4))))
+                .collect(),
+            class_sizes: HashMap::new(),
+            pointer_size: if is_32_bit {
+                SymbolicStructFieldDefinition::POINTER_SIZE_32
+            } else {
+                SymbolicStructFieldDefinition::POINTER_SIZE
+            },
+            warnings: vec![],
+        };
+
+        let struct_definitions = classes
+            .iter()
+            .map(|class| {
+                let class_name = ReClassStructImporter::get_class_name(*class);
+                let mut fields = vec![];
+                let mut offset = 0;
+
+                for node in ReClassStructImporter::get_child_nodes(*class) {
+                    importer.append_field_definitions(&class_name, node, offset, &mut fields);
+                    offset += importer.get_node_size(node, 0);
+                }
+
+                (class_name, SymbolicStructDefinition::new(fields))
+            })
+            .collect();
+
+        Ok(StructImportResult::new(struct_definitions, importer.warnings))
+    }
+}
+
+impl<'a, 'input> ReClassStructImporter<'a, 'input> {
+    fn append_field_definitions(
+        &mut self,
+        class_name: &str,
+        node: Node<'a, 'input>,
+        offset: u64,
+        fields: &mut Vec<SymbolicStructFieldDefinition>,
+    ) {
+        let node_type = node.attribute("type").unwrap_or_default();
+        let field_name = Self::sanitize_name(node.attribute("name").unwrap_or_default());
+        let node_size = self.get_node_size(node, 0);
+
+        let (data_type_id, container_type) = match node_type {
+            "UnionNode" => {
+                // Union members all begin at the offset of the union itself.
+                for child_node in Self::get_child_nodes(node) {
+                    self.append_field_definitions(class_name, child_node, offset, fields);
+                }
+
+                return;
+            }
+            "Hex8Node" | "Hex16Node" | "Hex32Node" | "Hex64Node" => {
+                (SymbolicStructFieldDefinition::PADDING_TYPE_ID.to_string(), ContainerType::Array(node_size))
+            }
+            "Vector2Node" | "Vector3Node" | "Vector4Node" | "Matrix3x3Node" | "Matrix3x4Node" | "Matrix4x4Node" => {
+                (DataTypeF32::get_data_type_id().to_string(), ContainerType::Array(node_size / 4))
+            }
+            "Utf8TextNode" => (DataTypeStringUtf8::get_data_type_id().to_string(), ContainerType::Array(node_size)),
+            "Utf16TextNode" => (DataTypeU16::get_data_type_id().to_string(), ContainerType::Array(node_size / 2)),
+            "Utf32TextNode" => (DataTypeU32::get_data_type_id().to_string(), ContainerType::Array(node_size / 4)),
+            "ClassInstanceNode" => (self.get_referenced_class_name(node), ContainerType::None),
+            "ClassInstanceArrayNode" => (
+                self.get_referenced_class_name(node),
+                ContainerType::Array(Self::parse_attribute(node, "count").unwrap_or(0)),
+            ),
+            "ClassPtrArrayNode" => (
+                self.get_pointer_value_type_id().to_string(),
+                ContainerType::Array(Self::parse_attribute(node, "count").unwrap_or(0)),
+            ),
+            "ArrayNode" => {
+                let count = Self::parse_attribute(node, "count").unwrap_or(0);
+
+                match Self::get_child_nodes(node).next() {
+                    Some(element_node) => match self.get_element_type_id(element_node) {
+                        Some(element_type_id) => (element_type_id, ContainerType::Array(count)),
+                        None => (SymbolicStructFieldDefinition::PADDING_TYPE_ID.to_string(), ContainerType::Array(node_size)),
+                    },
+                    None => (SymbolicStructFieldDefinition::PADDING_TYPE_ID.to_string(), ContainerType::Array(node_size)),
+                }
+            }
+            // Pointer fields are sized by the bitness of the target when resolved, so 32-bit pointers need no special handling.
+            _ if Self::is_pointer_node_type(node_type) => (self.get_pointer_target_type_id(node), ContainerType::Pointer),
+            _ => match self.get_scalar_type_id(node) {
+                Some(data_type_id) => (data_type_id.to_string(), ContainerType::None),
+                None => {
+                    let warning = format!(
+                        "Unsupported ReClass node type '{}' for field '{}.{}', mapping it to {} bytes of padding.",
+                        node_type, class_name, field_name, node_size
+                    );
+
+                    log::warn!("{}", warning);
+                    self.warnings.push(warning);
+
+                    (SymbolicStructFieldDefinition::PADDING_TYPE_ID.to_string(), ContainerType::Array(node_size))
+                }
+            },
+        };
+
+        fields.push(SymbolicStructFieldDefinition::new_named(
+            field_name,
+            DataTypeRef::new(&data_type_id),
+            container_type,
+            Some(offset),
+        ));
+    }
+
+    fn get_node_size(
+        &mut self,
+        node: Node<'a, 'input>,
+        depth: u32,
+    ) -> u64 {
+        let node_type = node.attribute("type").unwrap_or_default();
+        let count = Self::parse_attribute(node, "count").unwrap_or(0);
+        let length = Self::parse_attribute(node, "length").unwrap_or(0);
+
+        match node_type {
+            "Int8Node" | "UInt8Node" | "BoolNode" | "Hex8Node" => 1,
+            "Int16Node" | "UInt16Node" | "Hex16Node" => 2,
+            "Int32Node" | "UInt32Node" | "FloatNode" | "Hex32Node" => 4,
+            "Int64Node" | "UInt64Node" | "DoubleNode" | "Hex64Node" => 8,
+            "NIntNode" | "NUIntNode" => self.pointer_size,
+            "Vector2Node" => 8,
+            "Vector3Node" => 12,
+            "Vector4Node" => 16,
+            "Matrix3x3Node" => 36,
+            "Matrix3x4Node" => 48,
+            "Matrix4x4Node" => 64,
+            "Utf8TextNode" => length,
+            "Utf16TextNode" => length * 2,
+            "Utf32TextNode" => length * 4,
+            "BitFieldNode" => Self::parse_attribute(node, "bits").unwrap_or(8).div_ceil(8),
+            "EnumNode" => node
+                .attribute("reference")
+                .and_then(|enum_name| self.enum_sizes.get(enum_name).copied())
+                .unwrap_or(4),
+            "ClassInstanceNode" => self.get_referenced_class_size(node, depth),
+            "ClassInstanceArrayNode" => count * self.get_referenced_class_size(node, depth),
+            "ClassPtrArrayNode" => count * self.pointer_size,
+            "ArrayNode" => match Self::get_child_nodes(node).next() {
+                Some(element_node) => count * self.get_node_size(element_node, depth),
+                None => 0,
+            },
+            "UnionNode" => Self::get_child_nodes(node)
+                .map(|child_node| self.get_node_size(child_node, depth))
+                .max()
+                .unwrap_or(0),
+            _ if Self::is_pointer_node_type(node_type) => self.pointer_size,
+            _ => Self::parse_attribute(node, "size").unwrap_or(self.pointer_size),
+        }
+    }
+
+    fn get_referenced_class_size(
+        &mut self,
+        node: Node<'a, 'input>,
+        depth: u32,
+    ) -> u64 {
+        let Some(uuid) = node.attribute("reference") else {
+            return 0;
+        };
+
+        if let Some(class_size) = self.class_sizes.get(uuid) {
+            return *class_size;
+        }
+
+        // Guards against classes that contain themselves by value, which ReClass does not prevent.
+        if depth >= SymbolicStructFieldDefinition::MAX_NESTING_DEPTH {
+            return 0;
+        }
+
+        let Some(class) = self.classes_by_uuid.get(uuid).copied() else {
+            return 0;
+        };
+
+        let class_size = Self::get_child_nodes(class)
+            .map(|child_node| self.get_node_size(child_node, depth + 1))
+            .sum();
+
+        self.class_sizes.insert(uuid, class_size);
+
+        class_size
+    }
+
+    /// Gets the type that a pointer node points to, falling back to bytes for untyped pointers, vtables, and functions.
+    fn get_pointer_target_type_id(
+        &self,
+        node: Node<'a, 'input>,
+    ) -> String {
+        match node.attribute("type").unwrap_or_default() {
+            "ClassPointerNode" => self.get_referenced_class_name(node),
+            "Utf8TextPtrNode" => DataTypeStringUtf8::get_data_type_id().to_string(),
+            "Utf16TextPtrNode" => DataTypeU16::get_data_type_id().to_string(),
+            "Utf32TextPtrNode" => DataTypeU32::get_data_type_id().to_string(),
+            "PointerNode" => match Self::get_child_nodes(node).next() {
+                Some(target_node) => match target_node.attribute("type").unwrap_or_default() {
+                    "ClassInstanceNode" => self.get_referenced_class_name(target_node),
+                    _ => self
+                        .get_element_type_id(target_node)
+                        .unwrap_or_else(|| DataTypeU8::get_data_type_id().to_string()),
+                },
+                None => DataTypeU8::get_data_type_id().to_string(),
+            },
+            _ => DataTypeU8::get_data_type_id().to_string(),
+        }
+    }
+
+    /// Gets the element type of an array node, if the element maps to a single data type or struct.
+    fn get_element_type_id(
+        &self,
+        element_node: Node<'a, 'input>,
+    ) -> Option<String> {
+        let node_type = element_node.attribute("type").unwrap_or_default();
+
+        match node_type {
+            "ClassInstanceNode" => Some(self.get_referenced_class_name(element_node)),
+            _ if Self::is_pointer_node_type(node_type) => Some(self.get_pointer_value_type_id().to_string()),
+            _ => self.get_scalar_type_id(element_node).map(str::to_string),
+        }
+    }
+
+    fn get_scalar_type_id(
+        &self,
+        node: Node<'a, 'input>,
+    ) -> Option<&'static str> {
+        let data_type_id = match node.attribute("type").unwrap_or_default() {
+            "Int8Node" => DataTypeI8::get_data_type_id(),
+            "UInt8Node" => DataTypeU8::get_data_type_id(),
+            "Int16Node" => DataTypeI16::get_data_type_id(),
+            "UInt16Node" => DataTypeU16::get_data_type_id(),
+            "Int32Node" => DataTypeI32::get_data_type_id(),
+            "UInt32Node" => DataTypeU32::get_data_type_id(),
+            "Int64Node" => DataTypeI64::get_data_type_id(),
+            "UInt64Node" => DataTypeU64::get_data_type_id(),
+            "FloatNode" => DataTypeF32::get_data_type_id(),
+            "DoubleNode" => DataTypeF64::get_data_type_id(),
+            "BoolNode" => DataTypeBool8::get_data_type_id(),
+            "NIntNode" if self.pointer_size == 4 => DataTypeI32::get_data_type_id(),
+            "NIntNode" => DataTypeI64::get_data_type_id(),
+            "NUIntNode" => self.get_pointer_value_type_id(),
+            "BitFieldNode" | "EnumNode" => match self.get_node_size_without_references(node) {
+                1 => DataTypeU8::get_data_type_id(),
+                2 => DataTypeU16::get_data_type_id(),
+                4 => DataTypeU32::get_data_type_id(),
+                _ => DataTypeU64::get_data_type_id(),
+            },
+            _ => return None,
+        };
+
+        Some(data_type_id)
+    }
+
+    /// Gets the size of bitfield and enum nodes, which do not depend on other classes.
+    fn get_node_size_without_references(
+        &self,
+        node: Node<'a, 'input>,
+    ) -> u64 {
+        match node.attribute("type").unwrap_or_default() {
+            "BitFieldNode" => Self::parse_attribute(node, "bits").unwrap_or(8).div_ceil(8),
+            _ => node
+                .attribute("reference")
+                .and_then(|enum_name| self.enum_sizes.get(enum_name).copied())
+                .unwrap_or(4),
+        }
+    }
+
+    /// Gets the type used for raw pointer values, such as pointer arrays, which match the pointer size of the project.
+    fn get_pointer_value_type_id(&self) -> &'static str {
+        match self.pointer_size {
+            4 => DataTypeU32::get_data_type_id(),
+            _ => DataTypeU64::get_data_type_id(),
+        }
+    }
+
+    fn get_referenced_class_name(
+        &self,
+        node: Node<'a, 'input>,
+    ) -> String {
+        node.attribute("reference")
+            .and_then(|uuid| self.classes_by_uuid.get(uuid))
+            .map(|class| Self::get_class_name(*class))
+            .unwrap_or_else(|| DataTypeU8::get_data_type_id().to_string())
+    }
+
+    fn is_pointer_node_type(node_type: &str) -> bool {
+        matches!(
+            node_type,
+            "PointerNode"
+                | "ClassPointerNode"
+                | "VirtualMethodTableNode"
+                | "FunctionPtrNode"
+                | "FunctionNode"
+                | "Utf8TextPtrNode"
+                | "Utf16TextPtrNode"
+                | "Utf32TextPtrNode"
+        )
+    }
+
+    fn get_child_nodes(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+        node.children()
+            .filter(|child_node| child_node.has_tag_name("node"))
+    }
+
+    fn get_class_name(class: Node<'a, 'input>) -> String {
+        Self::sanitize_name(class.attribute("name").unwrap_or_default())
+    }
+
+    fn parse_attribute(
+        node: Node<'a, 'input>,
+        attribute_name: &str,
+    ) -> Option<u64> {
+        node.attribute(attribute_name)?.trim().parse::<u64>().ok()
+    }
+
+    /// Replaces characters that are not valid in struct and field names, such as the whitespace and namespace separators that ReClass permits.
+    fn sanitize_name(name: &str) -> String {
+        name.chars()
+            .map(|character| if character.is_alphanumeric() || character == '_' { character } else { '_' })
+            .collect()
+    }
+}
@@ -0,0 +1,32 @@
+use olorin_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+
+/// The structs read by a struct importer, along with warnings about anything that could not be imported faithfully,
+/// such as fields of unknown types that were mapped to padding.
+pub struct StructImportResult {
+    symbolic_struct_definitions: Vec<(String, SymbolicStructDefinition)>,
+    warnings: Vec<String>,
+}
+
+impl StructImportResult {
+    pub fn new(
+        symbolic_struct_definitions: Vec<(String, SymbolicStructDefinition)>,
+        warnings: Vec<String>,
+    ) -> Self {
+        Self {
+            symbolic_struct_definitions,
+            warnings,
+        }
+    }
+
+    pub fn get_symbolic_struct_definitions(&self) -> &Vec<(String, SymbolicStructDefinition)> {
+        &self.symbolic_struct_definitions
+    }
+
+    pub fn get_warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+
+    pub fn into_symbolic_struct_definitions(self) -> Vec<(String, SymbolicStructDefinition)> {
+        self.symbolic_struct_definitions
+    }
+}
@@ -0,0 +1,33 @@
+use olorin_engine_api::structures::projects::project_info::ProjectInfo;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_struct::ProjectItemTypeStruct;
+use olorin_engine_api::structures::projects::project_manifest::ProjectManifest;
+use olorin_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use olorin_engine_projects::project::project::Project;
+use std::path::Path;
+use std::str::FromStr;
+
+#[test]
+fn structs_sharing_a_name_keep_the_first_definition() {
+    let project_path = Path::new("struct_library");
+    let mut project_root = ProjectItemTypeDirectory::new_project_item(project_path);
+    let mut nested_directory = ProjectItemTypeDirectory::new_project_item(&project_path.join("nested"));
+    let player = SymbolicStructDefinition::from_str("u32 health").unwrap();
+    let nested_player = SymbolicStructDefinition::from_str("f32 speed; u8 lives").unwrap();
+    let inventory = SymbolicStructDefinition::from_str("u16 slots").unwrap();
+
+    project_root.append_child(ProjectItemTypeStruct::new_project_item(&project_path.join("Player.json"), &player));
+    nested_directory.append_child(ProjectItemTypeStruct::new_project_item(&project_path.join("nested").join("Player.json"), &nested_player));
+    nested_directory.append_child(ProjectItemTypeStruct::new_project_item(&project_path.join("nested").join("Inventory.json"), &inventory));
+    project_root.append_child(nested_directory);
+
+    let project = Project::new(ProjectInfo::new(project_path.to_path_buf(), None, ProjectManifest::default()), project_root);
+    let symbolic_struct_definitions = project.get_symbolic_struct_definitions();
+    let struct_names: Vec<&str> = symbolic_struct_definitions
+        .iter()
+        .map(|(struct_name, _)| struct_name.as_str())
+        .collect();
+
+    assert_eq!(struct_names, vec!["Player", "Inventory"]);
+    assert_eq!(symbolic_struct_definitions[0].1.get_fields().len(), 1);
+}
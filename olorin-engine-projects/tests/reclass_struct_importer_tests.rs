@@ -0,0 +1,27 @@
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+use olorin_engine_projects::structs::reclass::reclass_struct_importer::ReClassStructImporter;
+
+#[test]
+fn imports_32_bit_pointers_and_reports_unsupported_nodes() {
+    let xml = r#"<reclass version="1" type="x86">
+        <classes>
+            <class uuid="player" name="Player">
+                <node type="Int32Node" name="health" />
+                <node type="ClassPointerNode" name="target" reference="player" />
+                <node type="CustomNode" name="unknown" size="6" />
+            </class>
+        </classes>
+    </reclass>"#;
+
+    let struct_import_result = ReClassStructImporter::import_xml(xml).expect("Failed to import the ReClass project.");
+    let (struct_name, symbolic_struct_definition) = &struct_import_result.get_symbolic_struct_definitions()[0];
+    let fields = symbolic_struct_definition.get_fields();
+
+    assert_eq!(struct_name, "Player");
+    assert_eq!(fields[1].get_value().get_data_type_id(), "Player");
+    assert_eq!(fields[1].get_container_type(), ContainerType::Pointer);
+    assert_eq!(fields[2].get_offset(), Some(8));
+    assert_eq!(fields[2].get_container_type(), ContainerType::Array(6));
+    assert_eq!(struct_import_result.get_warnings().len(), 1);
+    assert!(struct_import_result.get_warnings()[0].contains("Player.unknown"));
+}
@@ -1,4 +1,6 @@
 use olorin_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
+use olorin_engine_api::structures::scanning::parameters::mapped::mapped_scan_type::{MappedScanType, ScanParametersScalar};
+use olorin_engine_api::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
 use olorin_engine_api::structures::scanning::{
     filters::{snapshot_region_filter::SnapshotRegionFilter, snapshot_region_filter_collection::SnapshotRegionFilterCollection},
     parameters::{element_scan::element_scan_parameters::ElementScanParameters, mapped::mapped_scan_parameters::MappedScanParameters},
@@ -7,10 +9,13 @@ use olorin_engine_api::structures::scanning::{
 pub struct ElementScanExecutionPlanner {}
 
 impl ElementScanExecutionPlanner {
+    /// Maps the user provided scan parameters into optimized scan parameters for the given filter. The resulting vectorization
+    /// size is clamped to `max_vectorization_size`, and vector scans are demoted to scalar scans if no vectorization is available.
     pub fn map(
         snapshot_region_filter: &SnapshotRegionFilter,
         snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
         element_scan_parameters: &ElementScanParameters,
+        max_vectorization_size: Option<VectorizationSize>,
     ) -> MappedScanParameters {
         let mut mapped_scan_parameters = MappedScanParameters::new(snapshot_region_filter_collection, element_scan_parameters);
 
@@ -28,6 +33,28 @@ impl ElementScanExecutionPlanner {
             Err(error) => log::error!("Error acquiring element scan registry: {}", error),
         }
 
+        Self::clamp_vectorization_size(&mut mapped_scan_parameters, max_vectorization_size);
+
         mapped_scan_parameters
     }
+
+    fn clamp_vectorization_size(
+        mapped_scan_parameters: &mut MappedScanParameters,
+        max_vectorization_size: Option<VectorizationSize>,
+    ) {
+        if !matches!(mapped_scan_parameters.get_mapped_scan_type(), MappedScanType::Vector(_)) {
+            return;
+        }
+
+        match max_vectorization_size {
+            Some(max_vectorization_size) => {
+                if *mapped_scan_parameters.get_vectorization_size() > max_vectorization_size {
+                    mapped_scan_parameters.set_vectorization_size(max_vectorization_size);
+                }
+            }
+            None => {
+                mapped_scan_parameters.set_mapped_scan_type(MappedScanType::Scalar(ScanParametersScalar::ScalarIterative));
+            }
+        }
+    }
 }
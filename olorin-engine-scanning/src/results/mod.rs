@@ -1 +1,3 @@
+pub mod scan_result_reader;
+pub mod scan_results_watch_task;
 pub mod snapshot_scan_result_freeze_task;
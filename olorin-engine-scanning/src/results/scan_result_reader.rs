@@ -0,0 +1,58 @@
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::results::snapshot_scan_result_freeze_list::SnapshotScanResultFreezeList;
+use olorin_engine_api::structures::scan_results::scan_result::ScanResult;
+use olorin_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use olorin_engine_memory::memory_reader::MemoryReader;
+use olorin_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use olorin_engine_memory::symbols::symbol_service::SymbolService;
+
+pub struct ScanResultReader;
+
+/// Reads the latest values and module information for scan results, producing the full scan results needed for display.
+impl ScanResultReader {
+    pub fn read_scan_results(
+        process_info: Option<&OpenedProcessInfo>,
+        scan_results: &Vec<ScanResultValued>,
+        snapshot_scan_result_freeze_list: &SnapshotScanResultFreezeList,
+    ) -> Vec<ScanResult> {
+        // Collect modules if possible so that we can resolve whether individual addresses are static later.
+        let modules = match process_info {
+            Some(process_info) => MemoryQueryer::get_instance().get_modules(process_info),
+            None => vec![],
+        };
+
+        // Wrap each ScanResultValued with a full ScanResult that includes current values and module information.
+        scan_results
+            .iter()
+            .map(|scan_result_valued| {
+                let mut recently_read_value = None;
+                let mut module_name = String::default();
+                let address = scan_result_valued.get_address();
+                let mut module_offset = scan_result_valued.get_address();
+
+                // Best-effort attempt to read the values for this scan result.
+                if let Some(process_info) = process_info {
+                    if let Some(mut data_value) = scan_result_valued.get_current_value().clone() {
+                        if MemoryReader::get_instance().read(process_info, address, &mut data_value) {
+                            recently_read_value = Some(data_value);
+                        }
+                    }
+                }
+
+                // Check whether this scan result belongs to a module (ie check if the address is static).
+                if let Some((found_module_name, address)) = MemoryQueryer::get_instance().address_to_module(address, &modules) {
+                    module_name = found_module_name;
+                    module_offset = address;
+                }
+
+                let symbol = SymbolService::get_instance().address_to_symbol(address, &modules);
+
+                let is_frozen = snapshot_scan_result_freeze_list.is_address_frozen(address);
+
+                ScanResult::new(scan_result_valued.clone(), module_name, module_offset, symbol, recently_read_value, is_frozen)
+            })
+            .collect()
+    }
+}
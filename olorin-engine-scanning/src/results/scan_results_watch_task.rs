@@ -0,0 +1,146 @@
+use crate::results::scan_result_reader::ScanResultReader;
+use olorin_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use olorin_engine_api::events::scan_results::values_changed::scan_results_values_changed_event::ScanResultsValuesChangedEvent;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::results::scan_results_watch_list::ScanResultsWatchList;
+use olorin_engine_api::structures::results::snapshot_scan_result_freeze_list::SnapshotScanResultFreezeList;
+use olorin_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+use olorin_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TASK_NAME: &'static str = "Scan Result Watcher";
+
+/// The longest the watcher will sleep between checks, bounding the latency for newly added watches.
+const MAX_SLEEP_INTERVAL: Duration = Duration::from_millis(50);
+
+pub struct ScanResultsWatchTask;
+
+/// Implementation of a task that reads all watched scan results, publishing any value changes to the subscribed watchers.
+impl ScanResultsWatchTask {
+    pub fn start_task(
+        process_info: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        snapshot_scan_result_freeze_list: Arc<RwLock<SnapshotScanResultFreezeList>>,
+        scan_results_watch_list: Arc<ScanResultsWatchList>,
+        event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        std::thread::spawn(move || {
+            loop {
+                if task_clone.get_cancellation_token().load(Ordering::Acquire) {
+                    break;
+                }
+
+                let next_refresh = Self::refresh_watches_task(&process_info, &snapshot_scan_result_freeze_list, &scan_results_watch_list, &event_emitter);
+                let sleep_interval = next_refresh
+                    .map(|next_refresh| next_refresh.saturating_duration_since(Instant::now()))
+                    .unwrap_or(MAX_SLEEP_INTERVAL)
+                    .clamp(Duration::from_millis(1), MAX_SLEEP_INTERVAL);
+
+                thread::sleep(sleep_interval);
+            }
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    /// Refreshes all watches that are due, returning the time at which the next watch becomes due.
+    fn refresh_watches_task(
+        process_info: &Arc<RwLock<Option<OpenedProcessInfo>>>,
+        snapshot_scan_result_freeze_list: &Arc<RwLock<SnapshotScanResultFreezeList>>,
+        scan_results_watch_list: &Arc<ScanResultsWatchList>,
+        event_emitter: &Arc<dyn Fn(EngineEvent) + Send + Sync>,
+    ) -> Option<Instant> {
+        let now = Instant::now();
+        let mut watches = match scan_results_watch_list.get_watches().write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on scan result watches: {}", error);
+
+                return None;
+            }
+        };
+
+        let due_watch_ids: Vec<String> = watches
+            .iter()
+            .filter(|(_, watch)| watch.is_refresh_due(now))
+            .map(|(watch_id, _)| watch_id.clone())
+            .collect();
+
+        if !due_watch_ids.is_empty() {
+            let process_info = match process_info.read() {
+                Ok(guard) => guard.clone(),
+                Err(error) => {
+                    log::error!("Failed to acquire read lock on process info for watching scan results: {}", error);
+
+                    return None;
+                }
+            };
+
+            let snapshot_scan_result_freeze_list = match snapshot_scan_result_freeze_list.read() {
+                Ok(guard) => guard,
+                Err(error) => {
+                    log::error!("Failed to acquire read lock on freeze list for watching scan results: {}", error);
+
+                    return None;
+                }
+            };
+
+            // Viewers frequently watch the same results, so each unique result is only read once across all due watches.
+            let mut unique_indicies: HashMap<(u64, String), usize> = HashMap::new();
+            let mut unique_scan_results: Vec<ScanResultValued> = vec![];
+
+            for watch_id in &due_watch_ids {
+                if let Some(watch) = watches.get(watch_id) {
+                    for scan_result in watch.get_scan_results() {
+                        let key = (scan_result.get_address(), scan_result.get_data_type().get_data_type_id().to_string());
+
+                        unique_indicies.entry(key).or_insert_with(|| {
+                            unique_scan_results.push(scan_result.clone());
+                            unique_scan_results.len() - 1
+                        });
+                    }
+                }
+            }
+
+            let read_scan_results = ScanResultReader::read_scan_results(process_info.as_ref(), &unique_scan_results, &snapshot_scan_result_freeze_list);
+
+            for watch_id in due_watch_ids {
+                if let Some(watch) = watches.get_mut(&watch_id) {
+                    let scan_results = watch
+                        .get_scan_results()
+                        .iter()
+                        .filter_map(|scan_result| {
+                            let key = (scan_result.get_address(), scan_result.get_data_type().get_data_type_id().to_string());
+
+                            unique_indicies
+                                .get(&key)
+                                .and_then(|index| read_scan_results.get(*index))
+                                .cloned()
+                        })
+                        .collect();
+                    let changed_scan_results = watch.update_scan_results(now, scan_results);
+
+                    if !changed_scan_results.is_empty() {
+                        let scan_results_values_changed_event = ScanResultsValuesChangedEvent {
+                            watch_id,
+                            scan_results: changed_scan_results,
+                        };
+
+                        event_emitter(scan_results_values_changed_event.to_engine_event());
+                    }
+                }
+            }
+        }
+
+        watches.values().map(|watch| watch.get_next_refresh()).min()
+    }
+}
@@ -2,6 +2,7 @@ use serde_json::to_string_pretty;
 use olorin_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use olorin_engine_api::structures::memory::memory_alignment::MemoryAlignment;
 use olorin_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use olorin_engine_api::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
 use olorin_engine_api::structures::settings::scan_settings::ScanSettings;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -186,6 +187,38 @@ impl ScanSettingsConfig {
         }
     }
 
+    pub fn get_vectorization_size_override() -> Option<VectorizationSize> {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.vectorization_size_override
+        } else {
+            ScanSettings::default().vectorization_size_override
+        }
+    }
+
+    pub fn set_vectorization_size_override(value: Option<VectorizationSize>) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.vectorization_size_override = value;
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_chunked_scan_region_size_threshold() -> u64 {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.chunked_scan_region_size_threshold
+        } else {
+            ScanSettings::default().chunked_scan_region_size_threshold
+        }
+    }
+
+    pub fn set_chunked_scan_region_size_threshold(value: u64) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.chunked_scan_region_size_threshold = value;
+        }
+
+        Self::save_config();
+    }
+
     pub fn get_debug_perform_validation_scan() -> bool {
         if let Ok(config) = Self::get_instance().config.read() {
             config.debug_perform_validation_scan
@@ -193,4 +226,20 @@ impl ScanSettingsConfig {
             ScanSettings::default().debug_perform_validation_scan
         }
     }
+
+    pub fn get_scan_history_depth() -> u32 {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.scan_history_depth
+        } else {
+            ScanSettings::default().scan_history_depth
+        }
+    }
+
+    pub fn set_scan_history_depth(value: u32) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.scan_history_depth = value;
+        }
+
+        Self::save_config();
+    }
 }
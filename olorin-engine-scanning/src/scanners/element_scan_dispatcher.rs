@@ -3,12 +3,13 @@ use crate::scanners::scalar::scanner_scalar_byte_array_booyer_moore::ScannerScal
 use crate::scanners::scalar::scanner_scalar_iterative::ScannerScalarIterative;
 use crate::scanners::scalar::scanner_scalar_single_element::ScannerScalarSingleElement;
 use crate::scanners::snapshot_scanner::Scanner;
+use crate::scanners::structures::snapshot_region_filter_run_length_encoder::SnapshotRegionFilterRunLengthEncoder;
 use crate::scanners::vector::scanner_vector_aligned::ScannerVectorAligned;
 use crate::scanners::vector::scanner_vector_overlapping::ScannerVectorOverlapping;
 use crate::scanners::vector::scanner_vector_overlapping_bytewise_periodic::ScannerVectorOverlappingBytewisePeriodic;
 use crate::scanners::vector::scanner_vector_overlapping_bytewise_staggered::ScannerVectorOverlappingBytewiseStaggered;
 use crate::scanners::vector::scanner_vector_sparse::ScannerVectorSparse;
-use rayon::iter::ParallelIterator;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use olorin_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
 use olorin_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
 use olorin_engine_api::structures::scanning::parameters::element_scan::element_scan_parameters::ElementScanParameters;
@@ -18,10 +19,20 @@ use olorin_engine_api::structures::scanning::parameters::mapped::mapped_scan_typ
 };
 use olorin_engine_api::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
 use olorin_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use olorin_engine_architecture::vectors::Vectors;
 use std::cmp;
 
 pub struct ElementScanDispatcher {}
 
+/// The number of chunks to create per worker thread when splitting a large filter for a parallel scan.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// The smallest chunk size to create when splitting a large filter for a parallel scan.
+const MINIMUM_CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// Chunk sizes are rounded down to a multiple of this value, which is the largest supported vector size.
+const CHUNK_SIZE_ALIGNMENT: u64 = 64;
+
 /// Implements a scan dispatcher, which picks the best scanner based on the scan constraints and the region being scanned.
 /// Choosing the best scanner is critical to maintaining high performance scans.
 impl ElementScanDispatcher {
@@ -40,10 +51,19 @@ impl ElementScanDispatcher {
             );
         }
 
-        // The main body of the scan routine performed on a given filter.
-        let snapshot_region_scanner = |snapshot_region_filter: &SnapshotRegionFilter| {
+        let max_vectorization_size = Self::get_max_vectorization_size(element_scan_parameters);
+        let single_thread_scan = element_scan_parameters.is_single_thread_scan();
+        let chunked_scan_threshold = element_scan_parameters.get_chunked_scan_region_size_threshold();
+
+        // The main body of the scan routine performed on a given filter, or on a chunk of a given filter.
+        let snapshot_region_filter_scanner = |snapshot_region_filter: &SnapshotRegionFilter| {
             // Map the user scan parameters into an optimized form for improved scanning efficiency.
-            let mapped_scan_parameters = ElementScanExecutionPlanner::map(snapshot_region_filter, snapshot_region_filter_collection, element_scan_parameters);
+            let mapped_scan_parameters = ElementScanExecutionPlanner::map(
+                snapshot_region_filter,
+                snapshot_region_filter_collection,
+                element_scan_parameters,
+                max_vectorization_size,
+            );
 
             // Execute the scanner that corresponds to the mapped parameters.
             let scanner_instance = Self::aquire_scanner_instance(&mapped_scan_parameters);
@@ -54,11 +74,30 @@ impl ElementScanDispatcher {
                 Self::perform_debug_scan(scanner_instance, &filters, snapshot_region, snapshot_region_filter, &mapped_scan_parameters);
             }
 
+            filters
+        };
+
+        let snapshot_region_scanner = |snapshot_region_filter: &SnapshotRegionFilter| {
+            // Very large filters (ie a single huge heap region on a first scan) would otherwise be scanned by a single thread.
+            // Instead, split these into chunks that are scanned in parallel, and stitch the results back together.
+            let is_chunked_scan =
+                !single_thread_scan && chunked_scan_threshold > 0 && snapshot_region_filter.get_region_size() >= chunked_scan_threshold;
+            let filters = if is_chunked_scan {
+                Self::scan_filter_chunked(
+                    snapshot_region_filter,
+                    snapshot_region_filter_collection,
+                    element_scan_parameters,
+                    max_vectorization_size,
+                    &snapshot_region_filter_scanner,
+                )
+            } else {
+                snapshot_region_filter_scanner(snapshot_region_filter)
+            };
+
             if filters.len() > 0 { Some(filters) } else { None }
         };
 
         // Run the scan either single-threaded or parallel based on settings. Single-thread is not advised unless debugging.
-        let single_thread_scan = element_scan_parameters.is_single_thread_scan();
         let result_snapshot_region_filters = if single_thread_scan {
             snapshot_region_filter_collection
                 .iter()
@@ -78,6 +117,71 @@ impl ElementScanDispatcher {
         )
     }
 
+    /// Gets the largest vector size that scanners are allowed to use. This is the user override if one is provided,
+    /// otherwise it is the widest vector size supported by the host CPU. Returns `None` if vectorized scans are unavailable.
+    pub fn get_max_vectorization_size(element_scan_parameters: &ElementScanParameters) -> Option<VectorizationSize> {
+        match element_scan_parameters.get_vectorization_size_override() {
+            Some(vectorization_size_override) => Some(vectorization_size_override),
+            None => {
+                if Vectors::has_vector_support() {
+                    VectorizationSize::from_max_size_in_bytes(Vectors::get_hardware_vector_size())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Splits a large filter into chunks, scans each chunk in parallel, and stitches the resulting filters together.
+    /// Chunks overlap by the data type padding, such that elements starting near the end of a chunk can still be fully read.
+    fn scan_filter_chunked<F>(
+        snapshot_region_filter: &SnapshotRegionFilter,
+        snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
+        element_scan_parameters: &ElementScanParameters,
+        max_vectorization_size: Option<VectorizationSize>,
+        snapshot_region_filter_scanner: &F,
+    ) -> Vec<SnapshotRegionFilter>
+    where
+        F: Fn(&SnapshotRegionFilter) -> Vec<SnapshotRegionFilter> + Sync,
+    {
+        // Scanners pad each result by the number of bytes that the scanned value extends past its alignment, so we need to do the same.
+        let mapped_scan_parameters = ElementScanExecutionPlanner::map(
+            snapshot_region_filter,
+            snapshot_region_filter_collection,
+            element_scan_parameters,
+            max_vectorization_size,
+        );
+        let memory_alignment_size = mapped_scan_parameters.get_memory_alignment() as u64;
+        let value_size = cmp::max(
+            mapped_scan_parameters.get_data_type().get_unit_size_in_bytes(),
+            mapped_scan_parameters.get_data_value().get_size_in_bytes(),
+        );
+        let length_padding = value_size.saturating_sub(memory_alignment_size);
+
+        // Create a few chunks per thread to keep threads busy if some chunks finish early. Chunk sizes are kept as a multiple
+        // of the largest vector size, which in turn guarantees that every chunk starts at a properly aligned address.
+        let scannable_size = snapshot_region_filter.get_region_size().saturating_sub(length_padding);
+        let chunk_count = (rayon::current_num_threads() * CHUNKS_PER_THREAD) as u64;
+        let chunk_size = cmp::max(scannable_size / chunk_count.max(1), MINIMUM_CHUNK_SIZE) / CHUNK_SIZE_ALIGNMENT * CHUNK_SIZE_ALIGNMENT;
+        let base_address = snapshot_region_filter.get_base_address();
+        let mut chunk_filters = vec![];
+        let mut chunk_offset = 0;
+
+        while chunk_offset < scannable_size {
+            let chunk_scannable_size = cmp::min(chunk_size, scannable_size - chunk_offset);
+
+            chunk_filters.push(SnapshotRegionFilter::new(base_address + chunk_offset, chunk_scannable_size + length_padding));
+            chunk_offset += chunk_scannable_size;
+        }
+
+        let chunk_results: Vec<Vec<SnapshotRegionFilter>> = chunk_filters
+            .par_iter()
+            .map(snapshot_region_filter_scanner)
+            .collect();
+
+        SnapshotRegionFilterRunLengthEncoder::stitch_chunk_results(chunk_results, length_padding)
+    }
+
     fn aquire_scanner_instance(mapped_scan_parameters: &MappedScanParameters) -> &'static dyn Scanner {
         // Execute the scanner that corresponds to the mapped parameters.
         match mapped_scan_parameters.get_mapped_scan_type() {
@@ -10,6 +10,7 @@ use olorin_engine_api::structures::scanning::parameters::element_scan::element_s
 use olorin_engine_api::structures::snapshots::snapshot::Snapshot;
 use olorin_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
 use olorin_engine_api::structures::tasks::trackable_task::TrackableTask;
+use olorin_engine_architecture::vectors::Vectors;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 use std::thread;
@@ -58,6 +59,27 @@ impl ElementScanExecutorTask {
 
         if with_logging {
             log::info!("Performing manual scan...");
+
+            match ElementScanDispatcher::get_max_vectorization_size(element_scan_parameters) {
+                Some(vectorization_size) => match element_scan_parameters.get_vectorization_size_override() {
+                    Some(_) if !Vectors::supports_vector_size(vectorization_size.get_size_in_bytes()) => log::warn!(
+                        "Vectorized scans forced to {} byte vectors, which exceeds host support ({}). Scans will be emulated and slower.",
+                        vectorization_size.get_size_in_bytes(),
+                        Vectors::get_hardware_vector_name()
+                    ),
+                    Some(_) => log::info!(
+                        "Vectorized scans limited to {} byte vectors (user override, host supports {}).",
+                        vectorization_size.get_size_in_bytes(),
+                        Vectors::get_hardware_vector_name()
+                    ),
+                    None => log::info!(
+                        "Vectorized scans limited to {} byte vectors ({}).",
+                        vectorization_size.get_size_in_bytes(),
+                        Vectors::get_hardware_vector_name()
+                    ),
+                },
+                None => log::info!("No vector support detected, falling back to scalar scans."),
+            }
         }
 
         let mut snapshot = match snapshot.write() {
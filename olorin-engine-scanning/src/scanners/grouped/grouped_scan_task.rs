@@ -0,0 +1,92 @@
+use crate::scan_settings_config::ScanSettingsConfig;
+use crate::scanners::grouped::grouped_scanner::GroupedScanner;
+use crate::scanners::value_collector_task::ValueCollectorTask;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::scanning::parameters::element_scan::element_scan_value::ElementScanValue;
+use olorin_engine_api::structures::snapshots::snapshot::Snapshot;
+use olorin_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Instant;
+
+const TASK_NAME: &'static str = "Grouped Scan";
+
+pub struct GroupedScanTask {}
+
+/// Implementation of a task that refreshes the current values of the snapshot, then performs a grouped scan over them.
+impl GroupedScanTask {
+    pub fn start_task(
+        process_info: OpenedProcessInfo,
+        snapshot: Arc<RwLock<Snapshot>>,
+        element_scan_values: Vec<ElementScanValue>,
+        max_span: u64,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            Self::scan_task(&task_clone, process_info, snapshot, element_scan_values, max_span);
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn scan_task(
+        trackable_task: &Arc<TrackableTask>,
+        process_info: OpenedProcessInfo,
+        snapshot: Arc<RwLock<Snapshot>>,
+        element_scan_values: Vec<ElementScanValue>,
+        max_span: u64,
+    ) {
+        let start_time = Instant::now();
+        let cancellation_token = trackable_task.get_cancellation_token();
+
+        // Grouped scans verify against current values, so these must be refreshed first.
+        ValueCollectorTask::start_task(process_info, snapshot.clone(), false).wait_for_completion();
+        trackable_task.set_progress(50.0);
+
+        if cancellation_token.load(Ordering::SeqCst) {
+            log::info!("Grouped scan canceled.");
+            return;
+        }
+
+        let scan_result = match snapshot.read() {
+            Ok(snapshot) => GroupedScanner::scan(
+                &snapshot,
+                &element_scan_values,
+                max_span,
+                ScanSettingsConfig::get_floating_point_tolerance(),
+                ScanSettingsConfig::get_is_single_threaded_scan(),
+                ScanSettingsConfig::get_vectorization_size_override(),
+                ScanSettingsConfig::get_chunked_scan_region_size_threshold(),
+            ),
+            Err(error) => Err(format!("Failed to acquire read lock on snapshot: {}", error)),
+        };
+
+        let grouped_scan_results = match scan_result {
+            Ok(grouped_scan_results) => grouped_scan_results,
+            Err(error) => {
+                log::error!("{}", error);
+                return;
+            }
+        };
+
+        // Members are logged in the form accepted by `results add-to-project -s`, allowing a group to be added as a directory.
+        for grouped_scan_result in &grouped_scan_results {
+            let members = grouped_scan_result
+                .get_scan_results()
+                .iter()
+                .map(|scan_result| format!("{},{}", scan_result.get_address(), scan_result.get_data_type()))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            log::info!("{:X} -> {}", grouped_scan_result.get_anchor_address(), members);
+        }
+
+        log::info!("Grouped scan found {} results in: {:?}", grouped_scan_results.len(), start_time.elapsed());
+    }
+}
@@ -0,0 +1,389 @@
+use crate::scanners::element_scan_dispatcher::ElementScanDispatcher;
+use olorin_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use olorin_engine_api::structures::scan_results::scan_result_base::ScanResultBase;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use olorin_engine_api::structures::scanning::comparisons::scan_function_scalar::ScalarCompareFnImmediate;
+use olorin_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use olorin_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use olorin_engine_api::structures::scanning::grouped::grouped_scan_result::GroupedScanResult;
+use olorin_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use olorin_engine_api::structures::scanning::parameters::element_scan::element_scan_parameters::ElementScanParameters;
+use olorin_engine_api::structures::scanning::parameters::element_scan::element_scan_value::ElementScanValue;
+use olorin_engine_api::structures::scanning::parameters::mapped::mapped_scan_parameters::MappedScanParameters;
+use olorin_engine_api::structures::scanning::parameters::mapped::vectorization_size::VectorizationSize;
+use olorin_engine_api::structures::snapshots::snapshot::Snapshot;
+use olorin_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::cmp::Reverse;
+use std::cmp::max;
+
+/// The largest span supported by grouped scans. Verification cost grows with the span, and values further apart than this are
+/// unlikely to belong to the same struct.
+pub const MAX_GROUPED_SCAN_SPAN: u64 = 64 * 1024;
+
+/// The maximum number of results returned by a grouped scan, to guard against degenerate inputs such as a group of zeroes.
+pub const MAX_GROUPED_SCAN_RESULTS: usize = 16 * 1024;
+
+/// The number of bytes of current values sampled when estimating how selective each value would be as a prefilter.
+const PREFILTER_SAMPLE_SIZE: u64 = 4 * 1024 * 1024;
+
+/// A value in the group, along with the parameters used to verify it against memory.
+struct GroupedScanElement {
+    element_scan_value: ElementScanValue,
+    mapped_scan_parameters: MappedScanParameters,
+    size_in_bytes: u64,
+    alignment: u64,
+}
+
+pub struct GroupedScanner {}
+
+/// Implements a scan for several values that all appear (in any order) within a maximum span of one another.
+/// The most selective value is used as a prefilter, and every other value is verified around each prefilter match
+/// using the current values of the snapshot region.
+impl GroupedScanner {
+    pub fn scan(
+        snapshot: &Snapshot,
+        element_scan_values: &Vec<ElementScanValue>,
+        max_span: u64,
+        floating_point_tolerance: FloatingPointTolerance,
+        is_single_thread_scan: bool,
+        vectorization_size_override: Option<VectorizationSize>,
+        chunked_scan_region_size_threshold: u64,
+    ) -> Result<Vec<GroupedScanResult>, String> {
+        if element_scan_values.len() < 2 {
+            return Err("A grouped scan requires at least two values.".to_string());
+        }
+
+        if max_span > MAX_GROUPED_SCAN_SPAN {
+            return Err(format!("The maximum span of a grouped scan is {} bytes.", MAX_GROUPED_SCAN_SPAN));
+        }
+
+        let elements = element_scan_values
+            .iter()
+            .map(|element_scan_value| Self::create_grouped_scan_element(element_scan_value, floating_point_tolerance))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let total_size_in_bytes: u64 = elements.iter().map(|element| element.size_in_bytes).sum();
+
+        if total_size_in_bytes > max_span {
+            return Err(format!(
+                "The values occupy {} bytes, which cannot fit in a span of {} bytes.",
+                total_size_in_bytes, max_span
+            ));
+        }
+
+        let prefilter_index = Self::select_prefilter_index(snapshot, &elements);
+        let prefilter_value = elements[prefilter_index].element_scan_value.clone();
+        let prefilter_parameters = ElementScanParameters::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            vec![prefilter_value],
+            floating_point_tolerance,
+            MemoryReadMode::Skip,
+            is_single_thread_scan,
+            vectorization_size_override,
+            chunked_scan_region_size_threshold,
+            false,
+        );
+
+        let region_scanner = |snapshot_region: &SnapshotRegion| {
+            Self::scan_region(snapshot_region, &elements, prefilter_index, &prefilter_parameters, max_span)
+        };
+
+        let mut results: Vec<GroupedScanResult> = if is_single_thread_scan {
+            snapshot
+                .get_snapshot_regions()
+                .iter()
+                .flat_map(region_scanner)
+                .collect()
+        } else {
+            snapshot
+                .get_snapshot_regions()
+                .par_iter()
+                .flat_map_iter(region_scanner)
+                .collect()
+        };
+
+        results.sort_by_key(|result| result.get_anchor_address());
+
+        if results.len() > MAX_GROUPED_SCAN_RESULTS {
+            log::warn!(
+                "Grouped scan found {} results, only the first {} will be returned.",
+                results.len(),
+                MAX_GROUPED_SCAN_RESULTS
+            );
+            results.truncate(MAX_GROUPED_SCAN_RESULTS);
+        }
+
+        Ok(results)
+    }
+
+    /// Chooses the value expected to produce the fewest prefilter matches, as each match costs a verification of the whole group.
+    /// Selectivity is estimated by counting matches across a sample of the snapshot. Ties favor larger values, which scan no slower.
+    fn select_prefilter_index(
+        snapshot: &Snapshot,
+        elements: &Vec<GroupedScanElement>,
+    ) -> usize {
+        elements
+            .iter()
+            .enumerate()
+            .min_by_key(|(index, element)| (Self::count_sampled_matches(snapshot, element), Reverse(element.size_in_bytes), *index))
+            .map(|(index, _)| index)
+            .unwrap_or(0)
+    }
+
+    /// Counts the aligned matches of a value within the first `PREFILTER_SAMPLE_SIZE` bytes of current values in the snapshot.
+    fn count_sampled_matches(
+        snapshot: &Snapshot,
+        element: &GroupedScanElement,
+    ) -> u64 {
+        let Some(compare_func) = element
+            .element_scan_value
+            .get_data_type()
+            .get_scalar_compare_func_immediate(&ScanCompareTypeImmediate::Equal, &element.mapped_scan_parameters)
+        else {
+            return u64::MAX;
+        };
+        let mut remaining_sample_size = PREFILTER_SAMPLE_SIZE;
+        let mut match_count = 0u64;
+
+        for snapshot_region in snapshot.get_snapshot_regions() {
+            if remaining_sample_size == 0 {
+                break;
+            }
+
+            if !snapshot_region.has_current_values() {
+                continue;
+            }
+
+            let current_values = snapshot_region.get_current_values();
+            let base_address = snapshot_region.get_base_address();
+            let sample_size = (current_values.len() as u64).min(remaining_sample_size);
+            let mut offset = base_address.div_ceil(element.alignment) * element.alignment - base_address;
+
+            while offset + element.size_in_bytes <= sample_size {
+                if compare_func(unsafe { current_values.as_ptr().add(offset as usize) }) {
+                    match_count += 1;
+                }
+
+                offset += element.alignment;
+            }
+
+            remaining_sample_size -= sample_size;
+        }
+
+        match_count
+    }
+
+    fn create_grouped_scan_element(
+        element_scan_value: &ElementScanValue,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Result<GroupedScanElement, String> {
+        let data_type_ref = element_scan_value.get_data_type();
+        let element_scan_parameters = ElementScanParameters::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            vec![element_scan_value.clone()],
+            floating_point_tolerance,
+            MemoryReadMode::Skip,
+            true,
+            None,
+            0,
+            false,
+        );
+        let snapshot_region_filter_collection = SnapshotRegionFilterCollection::new(vec![], data_type_ref.clone(), element_scan_value.get_memory_alignment());
+        let mapped_scan_parameters = MappedScanParameters::new(&snapshot_region_filter_collection, &element_scan_parameters);
+
+        match data_type_ref.get_scalar_compare_func_immediate(&ScanCompareTypeImmediate::Equal, &mapped_scan_parameters) {
+            Some(_) => Ok(GroupedScanElement {
+                element_scan_value: element_scan_value.clone(),
+                mapped_scan_parameters,
+                size_in_bytes: max(element_scan_value.get_data_value().get_size_in_bytes(), 1),
+                alignment: max(element_scan_value.get_memory_alignment() as u64, 1),
+            }),
+            None => Err(format!("Data type {} does not support grouped scans.", data_type_ref)),
+        }
+    }
+
+    fn scan_region(
+        snapshot_region: &SnapshotRegion,
+        elements: &Vec<GroupedScanElement>,
+        prefilter_index: usize,
+        prefilter_parameters: &ElementScanParameters,
+        max_span: u64,
+    ) -> Vec<GroupedScanResult> {
+        let prefilter_element = &elements[prefilter_index];
+
+        if !snapshot_region.has_current_values() || snapshot_region.get_region_size() < prefilter_element.size_in_bytes {
+            return vec![];
+        }
+
+        let data_type_ref = prefilter_element.element_scan_value.get_data_type();
+        let memory_alignment = prefilter_element.element_scan_value.get_memory_alignment();
+        let aligned_base_address = snapshot_region.get_base_address().div_ceil(prefilter_element.alignment) * prefilter_element.alignment;
+
+        if aligned_base_address + prefilter_element.size_in_bytes > snapshot_region.get_end_address() {
+            return vec![];
+        }
+
+        // Compare functions are not thread safe, so each region builds its own.
+        let compare_funcs: Vec<ScalarCompareFnImmediate> = elements
+            .iter()
+            .filter_map(|element| {
+                element
+                    .element_scan_value
+                    .get_data_type()
+                    .get_scalar_compare_func_immediate(&ScanCompareTypeImmediate::Equal, &element.mapped_scan_parameters)
+            })
+            .collect();
+
+        if compare_funcs.len() != elements.len() {
+            return vec![];
+        }
+
+        let snapshot_region_filter_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(
+                aligned_base_address,
+                snapshot_region.get_end_address() - aligned_base_address,
+            )]],
+            data_type_ref.clone(),
+            memory_alignment,
+        );
+        let prefilter_results = ElementScanDispatcher::dispatch_scan(snapshot_region, &snapshot_region_filter_collection, prefilter_parameters);
+        let mut results = vec![];
+
+        for snapshot_region_filter in prefilter_results.iter() {
+            let element_count = snapshot_region_filter.get_element_count(data_type_ref, memory_alignment);
+
+            for index in 0..element_count {
+                let prefilter_address = snapshot_region_filter.get_base_address() + index * prefilter_element.alignment;
+
+                if let Some(result) = Self::verify_group(snapshot_region, elements, &compare_funcs, prefilter_index, prefilter_address, max_span) {
+                    results.push(result);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Determines whether every other value can be placed, without overlap, within a span that also contains the prefilter match.
+    fn verify_group(
+        snapshot_region: &SnapshotRegion,
+        elements: &Vec<GroupedScanElement>,
+        compare_funcs: &Vec<ScalarCompareFnImmediate>,
+        prefilter_index: usize,
+        prefilter_address: u64,
+        max_span: u64,
+    ) -> Option<GroupedScanResult> {
+        let prefilter_size = elements[prefilter_index].size_in_bytes;
+        let search_start = max(
+            snapshot_region.get_base_address(),
+            (prefilter_address + prefilter_size).saturating_sub(max_span),
+        );
+        let search_end = snapshot_region
+            .get_end_address()
+            .min(prefilter_address.saturating_add(max_span));
+
+        // Collect every location of each remaining value that could possibly fall within a valid span.
+        let mut candidates_by_element: Vec<(usize, Vec<u64>)> = Vec::with_capacity(elements.len() - 1);
+
+        for (element_index, element) in elements.iter().enumerate() {
+            if element_index == prefilter_index {
+                continue;
+            }
+
+            let candidates = Self::find_candidates(snapshot_region, element, &compare_funcs[element_index], search_start, search_end, prefilter_address, prefilter_size);
+
+            if candidates.is_empty() {
+                return None;
+            }
+
+            candidates_by_element.push((element_index, candidates));
+        }
+
+        // Place the most constrained values first, which makes the greedy placement below far more likely to succeed.
+        candidates_by_element.sort_by_key(|(_, candidates)| candidates.len());
+
+        // The span always begins at the lowest placed value, so only those addresses need to be considered as span starts.
+        let mut span_starts: Vec<u64> = candidates_by_element
+            .iter()
+            .flat_map(|(_, candidates)| candidates.iter().copied())
+            .filter(|address| *address < prefilter_address)
+            .collect();
+        span_starts.push(prefilter_address);
+        span_starts.sort_unstable();
+        span_starts.dedup();
+
+        for span_start in span_starts {
+            let span_end = span_start + max_span;
+            let mut placements = vec![(prefilter_address, prefilter_size)];
+            let mut addresses = vec![0u64; elements.len()];
+
+            addresses[prefilter_index] = prefilter_address;
+
+            let is_placed = candidates_by_element.iter().all(|(element_index, candidates)| {
+                let size_in_bytes = elements[*element_index].size_in_bytes;
+                let placement = candidates.iter().copied().find(|address| {
+                    *address >= span_start
+                        && address + size_in_bytes <= span_end
+                        && placements
+                            .iter()
+                            .all(|(placed_address, placed_size)| address + size_in_bytes <= *placed_address || *address >= placed_address + placed_size)
+                });
+
+                match placement {
+                    Some(address) => {
+                        placements.push((address, size_in_bytes));
+                        addresses[*element_index] = address;
+                        true
+                    }
+                    None => false,
+                }
+            });
+
+            if is_placed {
+                let anchor_address = addresses.iter().copied().min().unwrap_or(prefilter_address);
+                let scan_results = elements
+                    .iter()
+                    .zip(addresses)
+                    .map(|(element, address)| ScanResultBase::new(address, element.element_scan_value.get_data_type().clone()))
+                    .collect();
+
+                return Some(GroupedScanResult::new(anchor_address, scan_results));
+            }
+        }
+
+        None
+    }
+
+    /// Finds every aligned location of a value within the given range that does not overlap the prefilter match.
+    fn find_candidates(
+        snapshot_region: &SnapshotRegion,
+        element: &GroupedScanElement,
+        compare_func: &ScalarCompareFnImmediate,
+        search_start: u64,
+        search_end: u64,
+        prefilter_address: u64,
+        prefilter_size: u64,
+    ) -> Vec<u64> {
+        let base_address = snapshot_region.get_base_address();
+        let current_values = snapshot_region.get_current_values();
+        let mut candidates = vec![];
+        let mut address = search_start.div_ceil(element.alignment) * element.alignment;
+
+        while address + element.size_in_bytes <= search_end {
+            let overlaps_prefilter = address < prefilter_address + prefilter_size && prefilter_address < address + element.size_in_bytes;
+            let offset = (address - base_address) as usize;
+
+            if !overlaps_prefilter && offset + element.size_in_bytes as usize <= current_values.len() {
+                if compare_func(unsafe { current_values.as_ptr().add(offset) }) {
+                    candidates.push(address);
+                }
+            }
+
+            address += element.alignment;
+        }
+
+        candidates
+    }
+}
@@ -0,0 +1,2 @@
+pub mod grouped_scan_task;
+pub mod grouped_scanner;
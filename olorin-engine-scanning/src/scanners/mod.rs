@@ -1,6 +1,8 @@
 pub mod element_scan_dispatcher;
 pub mod element_scan_executor_task;
+pub mod grouped;
 pub mod scalar;
+pub mod signature;
 pub mod snapshot_region_memory_reader;
 pub mod snapshot_scanner;
 pub mod structures;
@@ -0,0 +1,170 @@
+use crate::scanners::structures::byte_signature_shift_table::ByteSignatureShiftTable;
+use olorin_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::scanning::signatures::byte_signature::ByteSignature;
+use olorin_engine_api::structures::scanning::signatures::signature_scan_result::SignatureScanResult;
+use olorin_engine_memory::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use olorin_engine_memory::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use olorin_engine_memory::memory_reader::MemoryReader;
+use olorin_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+
+/// The number of bytes read at a time when scanning a region. Chunks overlap by the signature length to catch matches spanning chunks.
+const SIGNATURE_SCAN_CHUNK_SIZE: u64 = 16 * 1024 * 1024;
+
+/// The size of a RIP-relative displacement operand.
+const RIP_DISPLACEMENT_SIZE: u64 = size_of::<i32>() as u64;
+
+pub struct SignatureScanner {}
+
+/// Implements masked array of byte (signature) scanning over the executable regions of a process, optionally restricted to a module.
+impl SignatureScanner {
+    /// Scans the executable regions of the given module (or all executable regions if none is provided) for the signature.
+    /// If a RIP-relative displacement offset is provided, the disp32 at that offset into each match is read, and the target address
+    /// is computed relative to the end of the instruction. The instruction length defaults to the end of the displacement operand.
+    pub fn scan(
+        process_info: &OpenedProcessInfo,
+        byte_signature: &ByteSignature,
+        module_name: Option<&str>,
+        rip_displacement_offset: Option<u64>,
+        instruction_length: Option<u64>,
+    ) -> Result<Vec<SignatureScanResult>, String> {
+        let memory_queryer = MemoryQueryer::get_instance();
+        let modules = memory_queryer.get_modules(process_info);
+        let (start_address, end_address) = match module_name {
+            Some(module_name) => match modules
+                .iter()
+                .find(|module| module.get_module_name().eq_ignore_ascii_case(module_name))
+            {
+                Some(module) => (module.get_base_address(), module.get_base_address() + module.get_region_size()),
+                None => return Err(format!("Module {} not found.", module_name)),
+            },
+            None => (
+                memory_queryer.get_min_usermode_address(process_info),
+                memory_queryer.get_max_usermode_address(process_info),
+            ),
+        };
+
+        let regions = memory_queryer.get_virtual_pages(
+            process_info,
+            MemoryProtectionEnum::EXECUTE,
+            MemoryProtectionEnum::empty(),
+            MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+            start_address,
+            end_address,
+            RegionBoundsHandling::Resize,
+        );
+
+        let shift_table = ByteSignatureShiftTable::new(byte_signature);
+        let mut results = vec![];
+
+        for region in &regions {
+            for address in Self::scan_region(process_info, region, byte_signature, &shift_table) {
+                let (module_name, module_offset) = memory_queryer
+                    .address_to_module(address, &modules)
+                    .unwrap_or((String::new(), address));
+                let resolved_address = rip_displacement_offset
+                    .and_then(|rip_displacement_offset| Self::resolve_rip_relative(process_info, address, rip_displacement_offset, instruction_length));
+                let (resolved_module_name, resolved_module_offset) = match resolved_address {
+                    Some(resolved_address) => memory_queryer
+                        .address_to_module(resolved_address, &modules)
+                        .unwrap_or((String::new(), resolved_address)),
+                    None => (String::new(), 0),
+                };
+
+                results.push(SignatureScanResult::new(
+                    address,
+                    module_name,
+                    module_offset,
+                    resolved_address,
+                    resolved_module_name,
+                    resolved_module_offset,
+                ));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Finds all offsets at which the signature matches within the provided bytes.
+    pub fn scan_bytes(
+        bytes: &[u8],
+        byte_signature: &ByteSignature,
+        shift_table: &ByteSignatureShiftTable,
+    ) -> Vec<usize> {
+        let pattern_length = byte_signature.get_length();
+        let mut match_offsets = vec![];
+        let mut scan_index = 0usize;
+
+        if pattern_length == 0 || bytes.len() < pattern_length {
+            return match_offsets;
+        }
+
+        // Boyer-Moore-Horspool, comparing right to left and shifting based on the byte under the end of the window.
+        while scan_index <= bytes.len() - pattern_length {
+            let window = &bytes[scan_index..scan_index + pattern_length];
+
+            if (0..pattern_length)
+                .rev()
+                .all(|pattern_index| byte_signature.matches_byte_at(pattern_index, window[pattern_index]))
+            {
+                match_offsets.push(scan_index);
+            }
+
+            scan_index += shift_table.get_shift(window[pattern_length - 1]);
+        }
+
+        match_offsets
+    }
+
+    fn scan_region(
+        process_info: &OpenedProcessInfo,
+        region: &NormalizedRegion,
+        byte_signature: &ByteSignature,
+        shift_table: &ByteSignatureShiftTable,
+    ) -> Vec<u64> {
+        let pattern_length = byte_signature.get_length() as u64;
+        let region_end_address = region.get_end_address();
+        let mut chunk_address = region.get_base_address();
+        let mut match_addresses = vec![];
+
+        while chunk_address < region_end_address {
+            let chunk_size = (region_end_address - chunk_address).min(SIGNATURE_SCAN_CHUNK_SIZE + pattern_length.saturating_sub(1));
+            let mut chunk_bytes = vec![0u8; chunk_size as usize];
+
+            if MemoryReader::get_instance().read_bytes(process_info, chunk_address, &mut chunk_bytes) {
+                match_addresses.extend(
+                    Self::scan_bytes(&chunk_bytes, byte_signature, shift_table)
+                        .into_iter()
+                        .filter(|offset| (*offset as u64) < SIGNATURE_SCAN_CHUNK_SIZE)
+                        .map(|offset| chunk_address + offset as u64),
+                );
+            }
+
+            chunk_address = chunk_address.saturating_add(SIGNATURE_SCAN_CHUNK_SIZE);
+        }
+
+        match_addresses
+    }
+
+    /// Reads the disp32 at the given offset into the match, and computes the absolute target address relative to the instruction end.
+    fn resolve_rip_relative(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        rip_displacement_offset: u64,
+        instruction_length: Option<u64>,
+    ) -> Option<u64> {
+        let mut displacement_bytes = [0u8; RIP_DISPLACEMENT_SIZE as usize];
+
+        if !MemoryReader::get_instance().read_bytes(process_info, address.saturating_add(rip_displacement_offset), &mut displacement_bytes) {
+            return None;
+        }
+
+        let displacement = i32::from_le_bytes(displacement_bytes) as i64;
+        let instruction_length = instruction_length.unwrap_or(rip_displacement_offset + RIP_DISPLACEMENT_SIZE);
+
+        Some(address.wrapping_add(instruction_length).wrapping_add_signed(displacement))
+    }
+}
@@ -0,0 +1,34 @@
+use olorin_engine_api::structures::scanning::signatures::byte_signature::ByteSignature;
+
+/// A Boyer-Moore-Horspool style bad character table that respects wildcard masks. Any byte that could match a wildcard
+/// position limits the shift to the distance from that position to the end of the pattern, which keeps skipping safe.
+pub struct ByteSignatureShiftTable {
+    shift_table: Vec<usize>,
+}
+
+impl ByteSignatureShiftTable {
+    pub fn new(byte_signature: &ByteSignature) -> Self {
+        let pattern_length = byte_signature.get_length();
+        let mut shift_table = vec![pattern_length.max(1); u8::MAX as usize + 1usize];
+
+        // The last byte of the pattern is excluded, as it is the byte compared against the table on a mismatch.
+        for pattern_index in 0..pattern_length.saturating_sub(1) {
+            let shift_value = pattern_length - 1 - pattern_index;
+
+            for byte_value in 0..=u8::MAX {
+                if byte_signature.matches_byte_at(pattern_index, byte_value) {
+                    shift_table[byte_value as usize] = shift_table[byte_value as usize].min(shift_value);
+                }
+            }
+        }
+
+        Self { shift_table }
+    }
+
+    pub fn get_shift(
+        &self,
+        value: u8,
+    ) -> usize {
+        self.shift_table[value as usize]
+    }
+}
@@ -1,2 +1,3 @@
 pub mod boyer_moore_table;
+pub mod byte_signature_shift_table;
 pub mod snapshot_region_filter_run_length_encoder;
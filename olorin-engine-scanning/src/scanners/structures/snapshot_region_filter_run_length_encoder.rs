@@ -16,9 +16,10 @@ pub struct SnapshotRegionFilterRunLengthEncoder {
 /// SIMD vs scalar, etc.
 ///
 /// This can be parallelized by the caller by simply creating N run length encoders over a snapshot region filter that has been divided into N chunks.
-/// This requires a post-step of map-reducing the gathered regions and stitching together boundary regions once the run length encoders are complete.
-/// Additionally, some care would need to be taken to avoid discarding any small regions at the start or end of the encoder.
-/// For this reason, we currently do not try to parallelize them, as we can get high CPU utilization by parallelizing in other ways.
+/// This requires a post-step of stitching together boundary regions once the run length encoders are complete, see `stitch_chunk_results`.
+/// Most regions are small enough that we get high CPU utilization by parallelizing across regions instead, so the scan dispatcher only
+/// does this for very large regions. Note that encoders that discard small regions (minimum size filtering) are not safe to chunk,
+/// as a run spanning a chunk boundary may be discarded on both sides before it can be stitched back together.
 impl SnapshotRegionFilterRunLengthEncoder {
     pub fn new(run_length_current_address: u64) -> Self {
         Self {
@@ -118,4 +119,32 @@ impl SnapshotRegionFilterRunLengthEncoder {
 
         self.run_length_current_address += byte_advance_count;
     }
+
+    /// Stitches together the results of run length encoders that were run over adjacent chunks of a single filter, in address order.
+    /// A run that spans a chunk boundary is split into two filters by the encoders, which are merged back together here.
+    pub fn stitch_chunk_results(
+        chunk_results: Vec<Vec<SnapshotRegionFilter>>,
+        // The padding that was added to the length of each encoded run, which must be ignored when checking adjacency.
+        length_padding: u64,
+    ) -> Vec<SnapshotRegionFilter> {
+        let total_filter_count = chunk_results.iter().map(|filters| filters.len()).sum();
+        let mut stitched_regions: Vec<SnapshotRegionFilter> = Vec::with_capacity(total_filter_count);
+
+        for filters in chunk_results {
+            let mut filters = filters.into_iter();
+
+            if let Some(first_filter) = filters.next() {
+                match stitched_regions.last_mut() {
+                    Some(last_filter) if last_filter.get_end_address().saturating_sub(length_padding) == first_filter.get_base_address() => {
+                        last_filter.set_end_address(first_filter.get_end_address());
+                    }
+                    _ => stitched_regions.push(first_filter),
+                }
+
+                stitched_regions.extend(filters);
+            }
+        }
+
+        stitched_regions
+    }
 }
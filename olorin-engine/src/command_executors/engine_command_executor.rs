@@ -18,6 +18,7 @@ impl ExecutableCommand for EngineCommand {
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> EngineCommandResponse {
         match self {
+            EngineCommand::History(command) => command.execute(engine_privileged_state),
             EngineCommand::Memory(command) => command.execute(engine_privileged_state),
             EngineCommand::Process(command) => command.execute(engine_privileged_state),
             EngineCommand::Project(command) => command.execute(engine_privileged_state),
@@ -3,6 +3,7 @@ use crate::engine_privileged_state::EnginePrivilegedState;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use olorin_engine_api::commands::engine_command_request::EngineCommandRequest;
+use olorin_engine_api::commands::engine_command_response::EngineCommandResponse;
 use olorin_engine_api::commands::engine_command_response::TypedEngineCommandResponse;
 use std::sync::Arc;
 
@@ -21,12 +22,28 @@ pub trait EngineCommandRequestExecutor: EngineCommandRequest + Clone + Serialize
     ) where
         F: FnOnce(<Self as EngineCommandRequestExecutor>::ResponseType) + Clone + Send + Sync + 'static,
         <Self as EngineCommandRequestExecutor>::ResponseType: TypedEngineCommandResponse,
+    {
+        self.send_or_else(execution_context, callback, |error| log::error!("{}", error));
+    }
+
+    /// Sends this request to the engine, invoking `on_failed` instead of `callback` if the request fails (ie times out or the engine disconnects).
+    fn send_or_else<F, E>(
+        &self,
+        execution_context: &Arc<EngineExecutionContext>,
+        callback: F,
+        on_failed: E,
+    ) where
+        F: FnOnce(<Self as EngineCommandRequestExecutor>::ResponseType) + Clone + Send + Sync + 'static,
+        E: FnOnce(String) + Send + Sync + 'static,
+        <Self as EngineCommandRequestExecutor>::ResponseType: TypedEngineCommandResponse,
     {
         let command = self.to_engine_command();
 
         execution_context.dispatch_command(command, move |engine_response| {
-            if let Ok(response) = <Self as EngineCommandRequestExecutor>::ResponseType::from_engine_response(engine_response) {
-                callback(response);
+            match <Self as EngineCommandRequestExecutor>::ResponseType::from_engine_response(engine_response) {
+                Ok(response) => callback(response),
+                Err(EngineCommandResponse::Failed(error)) => on_failed(error),
+                Err(_) => {}
             }
         });
     }
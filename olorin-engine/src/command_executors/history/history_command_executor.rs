@@ -0,0 +1,27 @@
+use crate::command_executors::engine_command_executor::EngineCommandExecutor;
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::engine_command_response::{EngineCommandResponse, TypedEngineCommandResponse};
+use olorin_engine_api::commands::history::history_command::HistoryCommand;
+use std::sync::Arc;
+
+impl EngineCommandExecutor for HistoryCommand {
+    type ResponseType = EngineCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandExecutor>::ResponseType {
+        match self {
+            HistoryCommand::Undo { history_undo_request } => history_undo_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            HistoryCommand::Redo { history_redo_request } => history_redo_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            HistoryCommand::List { history_list_request } => history_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::history::list::history_list_request::HistoryListRequest;
+use olorin_engine_api::commands::history::list::history_list_response::HistoryListResponse;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for HistoryListRequest {
+    type ResponseType = HistoryListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let edit_history_manager = engine_privileged_state.get_edit_history_manager();
+
+        HistoryListResponse {
+            undo_entries: edit_history_manager.get_undo_descriptions(),
+            redo_entries: edit_history_manager.get_redo_descriptions(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod history_list_request_executor;
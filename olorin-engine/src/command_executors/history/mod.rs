@@ -0,0 +1,4 @@
+pub mod history_command_executor;
+pub mod list;
+pub mod redo;
+pub mod undo;
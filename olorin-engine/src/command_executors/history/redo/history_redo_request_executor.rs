@@ -0,0 +1,34 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::history::redo::history_redo_request::HistoryRedoRequest;
+use olorin_engine_api::commands::history::redo::history_redo_response::HistoryRedoResponse;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for HistoryRedoRequest {
+    type ResponseType = HistoryRedoResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_edit_history_manager()
+            .redo(engine_privileged_state)
+        {
+            Some((description, success)) => {
+                if !success {
+                    log::warn!("Partially failed to redo: {}", description);
+                }
+
+                HistoryRedoResponse {
+                    redone_entry: Some(description),
+                    success,
+                }
+            }
+            None => {
+                log::info!("Nothing to redo.");
+                HistoryRedoResponse::default()
+            }
+        }
+    }
+}
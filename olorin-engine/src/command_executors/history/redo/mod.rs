@@ -0,0 +1 @@
+pub mod history_redo_request_executor;
@@ -0,0 +1,34 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::history::undo::history_undo_request::HistoryUndoRequest;
+use olorin_engine_api::commands::history::undo::history_undo_response::HistoryUndoResponse;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for HistoryUndoRequest {
+    type ResponseType = HistoryUndoResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_edit_history_manager()
+            .undo(engine_privileged_state)
+        {
+            Some((description, success)) => {
+                if !success {
+                    log::warn!("Partially failed to undo: {}", description);
+                }
+
+                HistoryUndoResponse {
+                    undone_entry: Some(description),
+                    success,
+                }
+            }
+            None => {
+                log::info!("Nothing to undo.");
+                HistoryUndoResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod history_undo_request_executor;
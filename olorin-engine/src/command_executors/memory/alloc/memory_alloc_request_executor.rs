@@ -0,0 +1,49 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::alloc::memory_alloc_request::MemoryAllocRequest;
+use olorin_engine_api::commands::memory::alloc::memory_alloc_response::MemoryAllocResponse;
+use olorin_engine_debuggers::memory_allocation::memory_allocator::MemoryAllocator;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for MemoryAllocRequest {
+    type ResponseType = MemoryAllocResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let failed_response = MemoryAllocResponse {
+            address: None,
+            size: self.size,
+            protection: self.protection,
+        };
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process");
+            return failed_response;
+        };
+
+        if VirtualTargetRegistry::get_instance()
+            .get_target(process_info.get_process_id_raw())
+            .is_some()
+        {
+            log::error!("The opened process {} is a virtual target, which cannot allocate memory.", process_info.get_name());
+            return failed_response;
+        }
+
+        match MemoryAllocator::allocate(&process_info, self.size, self.protection) {
+            Ok(memory_allocation) => MemoryAllocResponse {
+                address: Some(memory_allocation.get_address()),
+                size: memory_allocation.get_size(),
+                protection: memory_allocation.get_protection(),
+            },
+            Err(error) => {
+                log::error!("{}", error);
+                failed_response
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_alloc_request_executor;
@@ -0,0 +1,82 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::diff::memory_diff_request::MemoryDiffRequest;
+use olorin_engine_api::commands::memory::diff::memory_diff_response::MemoryDiffResponse;
+use olorin_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use olorin_engine_memory::memory_dumps::memory_diff_task::MemoryDiffTarget;
+use olorin_engine_memory::memory_dumps::memory_diff_task::MemoryDiffTask;
+use olorin_engine_memory::memory_dumps::memory_dump::MemoryDump;
+use std::sync::Arc;
+use std::thread;
+
+impl EngineCommandRequestExecutor for MemoryDiffRequest {
+    type ResponseType = MemoryDiffResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let base_dump = match MemoryDump::load_from_file(&self.base_dump_path) {
+            Ok(base_dump) => base_dump,
+            Err(error) => {
+                log::error!("{}", error);
+                return MemoryDiffResponse { trackable_task_handle: None };
+            }
+        };
+
+        // Compare against a second dump if provided, otherwise against live memory of the opened process.
+        let diff_target = match &self.compare_dump_path {
+            Some(compare_dump_path) => match MemoryDump::load_from_file(compare_dump_path) {
+                Ok(compare_dump) => MemoryDiffTarget::Dump(compare_dump),
+                Err(error) => {
+                    log::error!("{}", error);
+                    return MemoryDiffResponse { trackable_task_handle: None };
+                }
+            },
+            None => match engine_privileged_state
+                .get_process_manager()
+                .get_opened_process()
+            {
+                Some(process_info) => MemoryDiffTarget::LiveMemory(process_info),
+                None => {
+                    log::error!("No opened process to compare against.");
+                    return MemoryDiffResponse { trackable_task_handle: None };
+                }
+            },
+        };
+
+        let data_type_ref = match &self.data_type_id {
+            Some(data_type_id) => {
+                let data_type_ref = DataTypeRef::new(data_type_id);
+
+                if !data_type_ref.is_valid() {
+                    log::error!("Unknown data type: {}", data_type_id);
+                    return MemoryDiffResponse { trackable_task_handle: None };
+                }
+
+                Some(data_type_ref)
+            }
+            None => None,
+        };
+
+        // Start the task to perform the diff.
+        let task = MemoryDiffTask::start_task(base_dump, diff_target, data_type_ref, self.output_path.clone());
+        let task_handle = task.get_task_handle();
+        let engine_privileged_state = engine_privileged_state.clone();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+        });
+
+        MemoryDiffResponse {
+            trackable_task_handle: Some(task_handle),
+        }
+    }
+}
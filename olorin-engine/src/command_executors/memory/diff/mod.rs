@@ -0,0 +1 @@
+pub mod memory_diff_request_executor;
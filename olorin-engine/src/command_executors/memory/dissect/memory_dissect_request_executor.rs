@@ -0,0 +1,58 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::dissect::memory_dissect_request::MemoryDissectRequest;
+use olorin_engine_api::commands::memory::dissect::memory_dissect_response::MemoryDissectResponse;
+use olorin_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
+use olorin_engine_memory::dissection::struct_dissector::StructDissector;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for MemoryDissectRequest {
+    type ResponseType = MemoryDissectResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process available.");
+            return MemoryDissectResponse::default();
+        };
+
+        let address = match AddressExpressionEvaluator::evaluate(&process_info, &self.address) {
+            Ok(address) => address,
+            Err(error) => {
+                log::error!("{}", error);
+                return MemoryDissectResponse::default();
+            }
+        };
+
+        match StructDissector::dissect(&process_info, address, self.length) {
+            Ok(dissected_fields) => {
+                let symbolic_struct_definition = SymbolicStructDefinition::new(
+                    dissected_fields
+                        .iter()
+                        .map(|dissected_field| dissected_field.to_symbolic_struct_field_definition())
+                        .collect(),
+                );
+
+                MemoryDissectResponse {
+                    address,
+                    dissected_fields,
+                    symbolic_struct_definition: Some(symbolic_struct_definition),
+                }
+            }
+            Err(error) => {
+                log::error!("{}", error);
+
+                MemoryDissectResponse {
+                    address,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+}
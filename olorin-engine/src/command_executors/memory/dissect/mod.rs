@@ -0,0 +1 @@
+pub mod memory_dissect_request_executor;
@@ -0,0 +1,84 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::dump::memory_dump_request::MemoryDumpRequest;
+use olorin_engine_api::commands::memory::dump::memory_dump_response::MemoryDumpResponse;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
+use olorin_engine_memory::memory_dumps::memory_dump::MemoryDump;
+use olorin_engine_memory::memory_dumps::memory_dump_task::MemoryDumpTask;
+use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use std::sync::Arc;
+use std::thread;
+
+impl EngineCommandRequestExecutor for MemoryDumpRequest {
+    type ResponseType = MemoryDumpResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process");
+            return MemoryDumpResponse { trackable_task_handle: None };
+        };
+
+        // Resolve the range to dump, either from a module or from an explicit address and size.
+        let (base_address, size) = match &self.module_name {
+            Some(module_name) => {
+                let modules = MemoryQueryer::get_instance().get_modules(&process_info);
+
+                match modules
+                    .iter()
+                    .find(|module| module.get_module_name().eq_ignore_ascii_case(module_name))
+                {
+                    Some(module) => (module.get_base_address(), module.get_region_size()),
+                    None => {
+                        log::error!("Module {} not found.", module_name);
+                        return MemoryDumpResponse { trackable_task_handle: None };
+                    }
+                }
+            }
+            None => match (&self.address, self.size) {
+                (Some(address_expression), Some(size)) if size > 0 => match AddressExpressionEvaluator::evaluate(&process_info, address_expression) {
+                    Ok(address) => (address, size),
+                    Err(error) => {
+                        log::error!("{}", error);
+                        return MemoryDumpResponse { trackable_task_handle: None };
+                    }
+                },
+                _ => {
+                    log::error!("Either a module name, or an address and a non-zero size must be provided.");
+                    return MemoryDumpResponse { trackable_task_handle: None };
+                }
+            },
+        };
+
+        if size > MemoryDump::MAX_DUMP_SIZE {
+            log::error!("Cannot dump {} bytes, the maximum dump size is {} bytes.", size, MemoryDump::MAX_DUMP_SIZE);
+            return MemoryDumpResponse { trackable_task_handle: None };
+        }
+
+        // Start the task to perform the dump.
+        let task = MemoryDumpTask::start_task(process_info, base_address, size, self.module_name.clone(), self.file_path.clone());
+        let task_handle = task.get_task_handle();
+        let engine_privileged_state = engine_privileged_state.clone();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+        });
+
+        MemoryDumpResponse {
+            trackable_task_handle: Some(task_handle),
+        }
+    }
+}
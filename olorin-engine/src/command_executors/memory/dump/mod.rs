@@ -0,0 +1 @@
+pub mod memory_dump_request_executor;
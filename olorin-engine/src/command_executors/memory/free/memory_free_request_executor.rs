@@ -0,0 +1,49 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::free::memory_free_request::MemoryFreeRequest;
+use olorin_engine_api::commands::memory::free::memory_free_response::MemoryFreeResponse;
+use olorin_engine_debuggers::memory_allocation::memory_allocator::MemoryAllocator;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for MemoryFreeRequest {
+    type ResponseType = MemoryFreeResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process");
+            return MemoryFreeResponse { success: false };
+        };
+
+        if VirtualTargetRegistry::get_instance()
+            .get_target(process_info.get_process_id_raw())
+            .is_some()
+        {
+            log::error!("The opened process {} is a virtual target, which cannot free memory.", process_info.get_name());
+            return MemoryFreeResponse { success: false };
+        }
+
+        let address = match AddressExpressionEvaluator::evaluate(&process_info, &self.address) {
+            Ok(address) => address,
+            Err(error) => {
+                log::error!("{}", error);
+                return MemoryFreeResponse { success: false };
+            }
+        };
+
+        match MemoryAllocator::free(&process_info, address, self.size) {
+            Ok(()) => MemoryFreeResponse { success: true },
+            Err(error) => {
+                log::error!("{}", error);
+                MemoryFreeResponse { success: false }
+            }
+        }
+    }
+}
@@ -19,6 +19,27 @@ impl EngineCommandExecutor for MemoryCommand {
             MemoryCommand::Read { memory_read_request } => memory_read_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            MemoryCommand::Query { memory_query_request } => memory_query_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Dump { memory_dump_request } => memory_dump_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Diff { memory_diff_request } => memory_diff_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Dissect { memory_dissect_request } => memory_dissect_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Alloc { memory_alloc_request } => memory_alloc_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Free { memory_free_request } => memory_free_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Protect { memory_protect_request } => memory_protect_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
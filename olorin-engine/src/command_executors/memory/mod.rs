@@ -1,3 +1,10 @@
+pub mod alloc;
+pub mod diff;
+pub mod dissect;
+pub mod dump;
+pub mod free;
 pub mod memory_command_executor;
+pub mod protect;
+pub mod query;
 pub mod read;
 pub mod write;
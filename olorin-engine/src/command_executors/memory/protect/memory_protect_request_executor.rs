@@ -0,0 +1,52 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::protect::memory_protect_request::MemoryProtectRequest;
+use olorin_engine_api::commands::memory::protect::memory_protect_response::MemoryProtectResponse;
+use olorin_engine_debuggers::memory_allocation::memory_allocator::MemoryAllocator;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for MemoryProtectRequest {
+    type ResponseType = MemoryProtectResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process");
+            return MemoryProtectResponse { address: 0, protection: None };
+        };
+
+        if VirtualTargetRegistry::get_instance()
+            .get_target(process_info.get_process_id_raw())
+            .is_some()
+        {
+            log::error!("The opened process {} is a virtual target, whose memory protection cannot change.", process_info.get_name());
+            return MemoryProtectResponse { address: 0, protection: None };
+        }
+
+        let address = match AddressExpressionEvaluator::evaluate(&process_info, &self.address) {
+            Ok(address) => address,
+            Err(error) => {
+                log::error!("{}", error);
+                return MemoryProtectResponse { address: 0, protection: None };
+            }
+        };
+
+        match MemoryAllocator::protect(&process_info, address, self.size, self.protection) {
+            Ok(()) => MemoryProtectResponse {
+                address,
+                protection: Some(self.protection),
+            },
+            Err(error) => {
+                log::error!("{}", error);
+                MemoryProtectResponse { address, protection: None }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_protect_request_executor;
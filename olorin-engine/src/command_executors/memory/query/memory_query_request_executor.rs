@@ -0,0 +1,83 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::memory::query::memory_query_request::MemoryQueryRequest;
+use olorin_engine_api::commands::memory::query::memory_query_response::MemoryQueryResponse;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
+use olorin_engine_memory::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use olorin_engine_memory::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use olorin_engine_memory::symbols::symbol_service::SymbolService;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for MemoryQueryRequest {
+    type ResponseType = MemoryQueryResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        if let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            let address = match AddressExpressionEvaluator::evaluate(&process_info, &self.address) {
+                Ok(address) => address,
+                Err(error) => {
+                    log::error!("{}", error);
+
+                    return MemoryQueryResponse {
+                        address_error: Some(error),
+                        ..Default::default()
+                    };
+                }
+            };
+            let memory_queryer = MemoryQueryer::get_instance();
+            let mut memory_query_response = MemoryQueryResponse {
+                address,
+                ..Default::default()
+            };
+
+            // Include handling returns the entire region overlapping the address, rather than a region trimmed to the queried range.
+            let regions = memory_queryer.get_virtual_pages(
+                &process_info,
+                MemoryProtectionEnum::empty(),
+                MemoryProtectionEnum::empty(),
+                MemoryTypeEnum::NONE | MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+                address,
+                address.saturating_add(1),
+                RegionBoundsHandling::Include,
+            );
+
+            if let Some(region) = regions
+                .iter()
+                .find(|region| address >= region.get_base_address() && address < region.get_end_address())
+            {
+                memory_query_response.region_base_address = region.get_base_address();
+                memory_query_response.region_size = region.get_region_size();
+                memory_query_response.is_writable = memory_queryer.is_address_writable(&process_info, address);
+                memory_query_response.protection = memory_queryer.get_address_protection(&process_info, address);
+                memory_query_response.success = true;
+            }
+
+            let modules = memory_queryer.get_modules(&process_info);
+
+            if let Some((module_name, module_offset)) = memory_queryer.address_to_module(address, &modules) {
+                memory_query_response.module_name = module_name;
+                memory_query_response.module_offset = module_offset;
+            }
+
+            memory_query_response.symbol = SymbolService::get_instance().address_to_symbol(address, &modules);
+
+            memory_query_response
+        } else {
+            log::error!("No opened process available.");
+
+            MemoryQueryResponse {
+                address: self.address.get_constant_value().unwrap_or_default(),
+                ..Default::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_query_request_executor;
@@ -2,8 +2,10 @@ use crate::command_executors::engine_request_executor::EngineCommandRequestExecu
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
 use olorin_engine_api::commands::memory::read::memory_read_response::MemoryReadResponse;
+use olorin_engine_api::registries::symbolic_structs::symbolic_struct_registry::SymbolicStructRegistry;
 use olorin_engine_api::structures::structs::symbolic_struct_ref::SymbolicStructRef;
 use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
 use olorin_engine_memory::memory_reader::MemoryReader;
 use olorin_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
 use std::sync::Arc;
@@ -19,14 +21,44 @@ impl EngineCommandRequestExecutor for MemoryReadRequest {
             .get_process_manager()
             .get_opened_process()
         {
-            log::info!("Reading value from address {}", self.address);
+            let address = match AddressExpressionEvaluator::evaluate(&process_info, &self.address) {
+                Ok(address) => address,
+                Err(error) => {
+                    log::error!("{}", error);
 
-            let mut out_valued_struct = self.valued_struct.clone();
-            let success = MemoryReader::get_instance().read_struct(&process_info, self.address, &mut out_valued_struct);
+                    return MemoryReadResponse {
+                        valued_struct: ValuedStruct::new(SymbolicStructRef::new(String::new()), vec![]),
+                        address: 0,
+                        success: false,
+                    };
+                }
+            };
+
+            log::debug!("Reading value from address {}", address);
+
+            // Structs given only by name (ie `Player:`) are resolved from the project struct library.
+            let symbolic_struct_name = self.valued_struct.get_symbolic_struct_ref().get_symbolic_struct_namespace();
+            let mut out_valued_struct = if self.valued_struct.get_fields().is_empty() && !symbolic_struct_name.is_empty() {
+                match SymbolicStructRegistry::get_instance().create_valued_struct(symbolic_struct_name, process_info.get_bitness()) {
+                    Some(valued_struct) => valued_struct,
+                    None => {
+                        log::error!("No struct named {} exists in the project.", symbolic_struct_name);
+
+                        return MemoryReadResponse {
+                            valued_struct: self.valued_struct.clone(),
+                            address,
+                            success: false,
+                        };
+                    }
+                }
+            } else {
+                self.valued_struct.clone()
+            };
+            let success = MemoryReader::get_instance().read_struct(&process_info, address, &mut out_valued_struct);
 
             MemoryReadResponse {
                 valued_struct: out_valued_struct,
-                address: self.address,
+                address,
                 success,
             }
         } else {
@@ -34,7 +66,7 @@ impl EngineCommandRequestExecutor for MemoryReadRequest {
 
             MemoryReadResponse {
                 valued_struct: ValuedStruct::new(SymbolicStructRef::new(String::new()), vec![]),
-                address: self.address,
+                address: self.address.get_constant_value().unwrap_or_default(),
                 success: false,
             }
         }
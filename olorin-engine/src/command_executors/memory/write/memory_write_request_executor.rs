@@ -1,9 +1,9 @@
 use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use olorin_engine_api::commands::memory::write::memory_write_response::MemoryWriteResponse;
-use olorin_engine_memory::memory_writer::MemoryWriter;
-use olorin_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
 use std::sync::Arc;
 
 impl EngineCommandRequestExecutor for MemoryWriteRequest {
@@ -17,9 +17,22 @@ impl EngineCommandRequestExecutor for MemoryWriteRequest {
             .get_process_manager()
             .get_opened_process()
         {
-            log::info!("Writing value {:?} to address {}", self.value, self.address);
+            let address = match AddressExpressionEvaluator::evaluate(&process_info, &self.address) {
+                Ok(address) => address,
+                Err(error) => {
+                    log::error!("{}", error);
+                    return MemoryWriteResponse { success: false };
+                }
+            };
 
-            let success = MemoryWriter::get_instance().write_bytes(&process_info, self.address, &self.value);
+            log::info!("Writing value {:?} to address {}", self.value, address);
+
+            let mut edit_history_entry = EditHistoryEntry::new(format!("Write {} bytes to 0x{:X}", self.value.len(), address));
+            let success = edit_history_entry.write_memory(&process_info, address, &self.value);
+
+            engine_privileged_state
+                .get_edit_history_manager()
+                .push_entry(edit_history_entry);
 
             MemoryWriteResponse { success }
         } else {
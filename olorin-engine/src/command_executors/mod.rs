@@ -1,5 +1,6 @@
 pub mod engine_command_executor;
 pub mod engine_request_executor;
+pub mod history;
 pub mod memory;
 pub mod process;
 pub mod project;
@@ -1,5 +1,6 @@
 use crate::{command_executors::engine_request_executor::EngineCommandRequestExecutor, engine_privileged_state::EnginePrivilegedState};
 use olorin_engine_api::commands::process::close::{process_close_request::ProcessCloseRequest, process_close_response::ProcessCloseResponse};
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
 use olorin_engine_processes::process_query::process_queryer::ProcessQuery;
 use std::sync::Arc;
 
@@ -20,11 +21,20 @@ impl EngineCommandRequestExecutor for ProcessCloseRequest {
                 process_info.get_handle()
             );
 
+            if VirtualTargetRegistry::get_instance().has_unsaved_changes(process_info.get_process_id_raw()) {
+                log::warn!("Closing {} with unsaved changes. Use `process save` first to keep them.", process_info.get_name());
+            }
+
             match ProcessQuery::close_process(process_info.get_handle()) {
                 Ok(_) => {
                     engine_privileged_state
                         .get_process_manager()
                         .clear_opened_process();
+
+                    // Virtual targets only exist while registered, so closing one releases it rather than leaving it listed.
+                    if VirtualTargetRegistry::get_instance().unregister_target(process_info.get_process_id_raw()) {
+                        log::info!("Released virtual target {}.", process_info.get_name());
+                    }
                 }
                 Err(error) => {
                     log::error!("Failed to close process handle {}: {}", process_info.get_handle(), error);
@@ -0,0 +1 @@
+pub mod process_eject_request_executor;
@@ -0,0 +1,34 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::eject::process_eject_request::ProcessEjectRequest;
+use olorin_engine_api::commands::process::eject::process_eject_response::ProcessEjectResponse;
+use olorin_engine_debuggers::library_injection::library_injector::LibraryInjector;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessEjectRequest {
+    type ResponseType = ProcessEjectResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process to eject from.");
+                return ProcessEjectResponse { success: false };
+            }
+        };
+
+        match LibraryInjector::unload_library(process_info.get_process_id_raw(), &self.library_path) {
+            Ok(()) => ProcessEjectResponse { success: true },
+            Err(error) => {
+                log::error!("{}", error);
+                ProcessEjectResponse { success: false }
+            }
+        }
+    }
+}
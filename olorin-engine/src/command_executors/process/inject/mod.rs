@@ -0,0 +1 @@
+pub mod process_inject_request_executor;
@@ -0,0 +1,45 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::inject::process_inject_request::ProcessInjectRequest;
+use olorin_engine_api::commands::process::inject::process_inject_response::ProcessInjectResponse;
+use olorin_engine_debuggers::library_injection::library_injector::LibraryInjector;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessInjectRequest {
+    type ResponseType = ProcessInjectResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process to inject into.");
+                return ProcessInjectResponse { module_base_address: None };
+            }
+        };
+
+        if VirtualTargetRegistry::get_instance()
+            .get_target(process_info.get_process_id_raw())
+            .is_some()
+        {
+            log::error!("The opened process {} is a virtual target, which cannot run code.", process_info.get_name());
+            return ProcessInjectResponse { module_base_address: None };
+        }
+
+        match LibraryInjector::inject_library(process_info.get_process_id_raw(), &self.library_path) {
+            Ok(injected_library) => ProcessInjectResponse {
+                module_base_address: Some(injected_library.get_module_base_address()),
+            },
+            Err(error) => {
+                log::error!("{}", error);
+                ProcessInjectResponse { module_base_address: None }
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::launch::process_launch_request::ProcessLaunchRequest;
+use olorin_engine_api::commands::process::launch::process_launch_response::ProcessLaunchResponse;
+use olorin_engine_debuggers::library_injection::library_injector::LibraryInjector;
+use olorin_engine_processes::process_launch::process_launch_options::ProcessLaunchOptions;
+use olorin_engine_processes::process_launch::process_launcher::ProcessLauncher;
+use olorin_engine_processes::process_query::process_queryer::ProcessQuery;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessLaunchRequest {
+    type ResponseType = ProcessLaunchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let mut environment_variables = vec![];
+
+        for environment_variable in &self.environment_variables {
+            match environment_variable.split_once('=') {
+                Some((key, value)) if !key.is_empty() => environment_variables.push((key.to_string(), value.to_string())),
+                _ => {
+                    log::error!("Invalid environment variable '{}', expected the form KEY=VALUE.", environment_variable);
+                    return ProcessLaunchResponse { opened_process_info: None };
+                }
+            }
+        }
+
+        if !self.preload_library_paths.is_empty() {
+            match LibraryInjector::get_preload_environment_variable(&self.preload_library_paths) {
+                Ok((preload_key, preload_value)) => {
+                    // Libraries already being preloaded through an explicit override are kept, and loaded after ours.
+                    match environment_variables.iter_mut().find(|(key, _)| *key == preload_key) {
+                        Some((_, value)) => *value = format!("{}:{}", preload_value, value),
+                        None => environment_variables.push((preload_key, preload_value)),
+                    }
+                }
+                Err(error) => {
+                    log::error!("{}", error);
+                    return ProcessLaunchResponse { opened_process_info: None };
+                }
+            }
+        }
+
+        let process_launch_options = ProcessLaunchOptions {
+            executable_path: self.executable_path.clone(),
+            arguments: self.arguments.clone(),
+            working_directory: self.working_directory.clone(),
+            environment_variables,
+            start_suspended: self.suspended,
+        };
+
+        let process_info = match ProcessLauncher::launch_process(&process_launch_options) {
+            Ok(process_info) => process_info,
+            Err(error) => {
+                log::error!("{}", error);
+                return ProcessLaunchResponse { opened_process_info: None };
+            }
+        };
+
+        match ProcessQuery::open_process(&process_info) {
+            Ok(opened_process_info) => {
+                engine_privileged_state
+                    .get_process_manager()
+                    .set_opened_process(opened_process_info.clone());
+
+                if self.suspended {
+                    log::info!("Process is suspended, use `process resume` to let it run.");
+                }
+
+                ProcessLaunchResponse {
+                    opened_process_info: Some(opened_process_info),
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to attach to launched process {}: {}", process_info.get_process_id_raw(), error);
+
+                // Nothing could resume the process once we fail to attach, so let it run rather than leaving it stopped forever.
+                if self.suspended {
+                    if let Err(error) = ProcessLauncher::resume_process(process_info.get_process_id_raw()) {
+                        log::error!("{}", error);
+                    }
+                }
+
+                ProcessLaunchResponse { opened_process_info: None }
+            }
+        }
+    }
+}
@@ -1,4 +1,11 @@
 pub mod close;
+pub mod eject;
+pub mod inject;
+pub mod launch;
 pub mod list;
 pub mod open;
 pub mod process_command_executor;
+pub mod resume;
+pub mod save;
+pub mod speed;
+pub mod threads;
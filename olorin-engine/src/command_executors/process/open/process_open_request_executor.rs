@@ -2,8 +2,13 @@ use crate::command_executors::engine_request_executor::EngineCommandRequestExecu
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
 use olorin_engine_api::commands::process::open::process_open_response::ProcessOpenResponse;
+use olorin_engine_memory::virtual_targets::elf_core::elf_core_target::ElfCoreTarget;
+use olorin_engine_memory::virtual_targets::file::file_target::FileTarget;
+use olorin_engine_memory::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
 use olorin_engine_processes::process_query::process_query_options::ProcessQueryOptions;
 use olorin_engine_processes::process_query::process_queryer::ProcessQuery;
+use std::fs;
 use std::sync::Arc;
 use sysinfo::Pid;
 
@@ -14,11 +19,70 @@ impl EngineCommandRequestExecutor for ProcessOpenRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
-        if self.process_id.is_none() && self.search_name.is_none() {
-            log::error!("Error: Neither PID nor search name provided. Cannot open process.");
+        if self.process_id.is_none() && self.search_name.is_none() && self.core_file_path.is_none() && self.file_path.is_none() {
+            log::error!("Error: Neither PID, search name, core file, nor file provided. Cannot open process.");
             return ProcessOpenResponse { opened_process_info: None };
         }
 
+        // Core files and plain files are opened as virtual targets. Opening a file that is already registered reuses its target,
+        // such that edits made to it are kept, rather than registering another target for every open.
+        let source_path = self.core_file_path.as_ref().or(self.file_path.as_ref());
+        let source_path = match source_path.map(fs::canonicalize) {
+            Some(Ok(source_path)) => Some(source_path),
+            Some(Err(error)) => {
+                log::error!("Failed to resolve path of virtual target: {}", error);
+                return ProcessOpenResponse { opened_process_info: None };
+            }
+            None => None,
+        };
+        let registered_process_id = source_path
+            .as_ref()
+            .and_then(|source_path| VirtualTargetRegistry::get_instance().find_target_by_source_path(source_path));
+
+        // Opening another target replaces the opened one, so any edits to it that were never saved are no longer reachable.
+        if let Some(opened_process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .filter(|opened_process_info| Some(opened_process_info.get_process_id_raw()) != registered_process_id)
+            .filter(|opened_process_info| VirtualTargetRegistry::get_instance().has_unsaved_changes(opened_process_info.get_process_id_raw()))
+        {
+            log::warn!(
+                "{} has unsaved changes, which will be discarded by opening another target. Use `process save` first to keep them.",
+                opened_process_info.get_name()
+            );
+        }
+
+        if let Some(source_path) = source_path {
+            let opened_process_info = match registered_process_id {
+                Some(process_id) => {
+                    if VirtualTargetRegistry::get_instance().has_unsaved_changes(process_id) {
+                        log::warn!("Reopening {} with unsaved changes, which are kept until saved or closed.", source_path.display());
+                    }
+
+                    engine_privileged_state.open_registered_virtual_target(process_id)
+                }
+                None => {
+                    let virtual_target: Result<Arc<dyn IVirtualMemoryTarget>, String> = if self.core_file_path.is_some() {
+                        ElfCoreTarget::open(&source_path).map(|elf_core_target| Arc::new(elf_core_target) as Arc<dyn IVirtualMemoryTarget>)
+                    } else {
+                        FileTarget::open(&source_path).map(|file_target| Arc::new(file_target) as Arc<dyn IVirtualMemoryTarget>)
+                    };
+
+                    virtual_target.and_then(|virtual_target| engine_privileged_state.open_virtual_target(virtual_target))
+                }
+            };
+
+            return match opened_process_info {
+                Ok(opened_process_info) => ProcessOpenResponse {
+                    opened_process_info: Some(opened_process_info),
+                },
+                Err(error) => {
+                    log::error!("Failed to open virtual target: {}", error);
+                    ProcessOpenResponse { opened_process_info: None }
+                }
+            };
+        }
+
         log::info!("Opening process...");
 
         let options = ProcessQueryOptions {
@@ -22,6 +22,27 @@ impl EngineCommandExecutor for ProcessCommand {
             ProcessCommand::Close { process_close_request } => process_close_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ProcessCommand::Save { process_save_request } => process_save_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::Launch { process_launch_request } => process_launch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::Resume { process_resume_request } => process_resume_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::Inject { process_inject_request } => process_inject_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::Eject { process_eject_request } => process_eject_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::Speed { process_speed_request } => process_speed_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::Threads { process_threads_request } => process_threads_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
@@ -0,0 +1 @@
+pub mod process_resume_request_executor;
@@ -0,0 +1,37 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::resume::process_resume_request::ProcessResumeRequest;
+use olorin_engine_api::commands::process::resume::process_resume_response::ProcessResumeResponse;
+use olorin_engine_processes::process_launch::process_launcher::ProcessLauncher;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessResumeRequest {
+    type ResponseType = ProcessResumeResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process to resume.");
+                return ProcessResumeResponse { success: false };
+            }
+        };
+
+        match ProcessLauncher::resume_process(process_info.get_process_id_raw()) {
+            Ok(()) => {
+                log::info!("Resumed process {}.", process_info.get_name());
+                ProcessResumeResponse { success: true }
+            }
+            Err(error) => {
+                log::error!("{}", error);
+                ProcessResumeResponse { success: false }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod process_save_request_executor;
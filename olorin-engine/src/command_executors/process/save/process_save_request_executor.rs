@@ -0,0 +1,43 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::save::process_save_request::ProcessSaveRequest;
+use olorin_engine_api::commands::process::save::process_save_response::ProcessSaveResponse;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessSaveRequest {
+    type ResponseType = ProcessSaveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process to save.");
+                return ProcessSaveResponse { success: false };
+            }
+        };
+
+        // Only virtual targets have a source to save to, as writes to live processes take effect immediately.
+        let virtual_target = match VirtualTargetRegistry::get_instance().get_target(process_info.get_process_id_raw()) {
+            Some(virtual_target) => virtual_target,
+            None => {
+                log::error!("The opened process {} is not a virtual target, there are no changes to save.", process_info.get_name());
+                return ProcessSaveResponse { success: false };
+            }
+        };
+
+        match virtual_target.save_changes() {
+            Ok(()) => ProcessSaveResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to save changes: {}", error);
+                ProcessSaveResponse { success: false }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod process_speed_request_executor;
@@ -0,0 +1,43 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::speed::process_speed_request::ProcessSpeedRequest;
+use olorin_engine_api::commands::process::speed::process_speed_response::ProcessSpeedResponse;
+use olorin_engine_debuggers::speedhack::speedhack_controller::SpeedhackController;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessSpeedRequest {
+    type ResponseType = ProcessSpeedResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process to set the speed of.");
+                return ProcessSpeedResponse { success: false };
+            }
+        };
+
+        if VirtualTargetRegistry::get_instance()
+            .get_target(process_info.get_process_id_raw())
+            .is_some()
+        {
+            log::error!("The opened process {} is a virtual target, which has no clock to scale.", process_info.get_name());
+            return ProcessSpeedResponse { success: false };
+        }
+
+        match SpeedhackController::set_speed(process_info.get_process_id_raw(), self.speed_factor) {
+            Ok(()) => ProcessSpeedResponse { success: true },
+            Err(error) => {
+                log::error!("{}", error);
+                ProcessSpeedResponse { success: false }
+            }
+        }
+    }
+}
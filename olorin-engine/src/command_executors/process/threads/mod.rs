@@ -0,0 +1 @@
+pub mod process_threads_request_executor;
@@ -0,0 +1,34 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::threads::process_threads_request::ProcessThreadsRequest;
+use olorin_engine_api::commands::process::threads::process_threads_response::ProcessThreadsResponse;
+use olorin_engine_processes::thread_query::thread_queryer::ThreadQuery;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProcessThreadsRequest {
+    type ResponseType = ProcessThreadsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process to list threads of.");
+                return ProcessThreadsResponse { threads: vec![] };
+            }
+        };
+
+        match ThreadQuery::get_threads(process_info.get_process_id_raw()) {
+            Ok(threads) => ProcessThreadsResponse { threads },
+            Err(error) => {
+                log::error!("{}", error);
+                ProcessThreadsResponse { threads: vec![] }
+            }
+        }
+    }
+}
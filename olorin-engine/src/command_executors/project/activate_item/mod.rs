@@ -0,0 +1 @@
+pub mod project_activate_item_request_executor;
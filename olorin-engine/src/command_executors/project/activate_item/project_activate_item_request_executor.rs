@@ -0,0 +1,71 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::project_item_activation::project_item_activation_handler::ProjectItemActivationHandler;
+use olorin_engine_api::commands::project::activate_item::project_activate_item_request::ProjectActivateItemRequest;
+use olorin_engine_api::commands::project::activate_item::project_activate_item_response::ProjectActivateItemResponse;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectActivateItemRequest {
+    type ResponseType = ProjectActivateItemResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let mut opened_project_guard = opened_project_lock.write().unwrap();
+
+        let Some(project) = opened_project_guard.as_mut() else {
+            log::warn!("Unable to activate project item, no opened project.");
+            return ProjectActivateItemResponse { success: false };
+        };
+
+        let project_item_path = project
+            .get_project_root()
+            .get_path()
+            .join(&self.project_item_path);
+        let mut activated_paths_before = vec![];
+        let mut activated_paths_after = vec![];
+        let mut success = true;
+
+        ProjectItemActivationHandler::collect_activated_paths(project.get_project_root(), &mut activated_paths_before);
+
+        if let Err(error) =
+            ProjectItemActivationHandler::set_activated(engine_privileged_state, project.get_project_root_mut(), &project_item_path, !self.deactivate)
+        {
+            log::error!("Unable to activate project item: {}", error);
+            success = false;
+        }
+
+        ProjectItemActivationHandler::collect_activated_paths(project.get_project_root(), &mut activated_paths_after);
+
+        // Record every item whose activation changed, as activating a speed item also deactivates any other.
+        let description = if self.deactivate { "Deactivate" } else { "Activate" };
+        let mut edit_history_entry = EditHistoryEntry::new(format!("{} project item {}", description, self.project_item_path.display()));
+        let activation_changes = activated_paths_before
+            .iter()
+            .filter(|path| !activated_paths_after.contains(path))
+            .map(|path| (path.clone(), false))
+            .chain(
+                activated_paths_after
+                    .iter()
+                    .filter(|path| !activated_paths_before.contains(path))
+                    .map(|path| (path.clone(), true)),
+            )
+            .collect();
+
+        edit_history_entry.record_project_items_activated(activation_changes);
+        engine_privileged_state
+            .get_edit_history_manager()
+            .push_entry(edit_history_entry);
+
+        if let Err(error) = project.save(true) {
+            log::error!("Failed to save project after activating project item: {}", error);
+            success = false;
+        }
+
+        ProjectActivateItemResponse { success }
+    }
+}
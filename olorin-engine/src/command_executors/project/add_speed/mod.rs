@@ -0,0 +1 @@
+pub mod project_add_speed_request_executor;
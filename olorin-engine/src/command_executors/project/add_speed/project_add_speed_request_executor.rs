@@ -0,0 +1,69 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::add_speed::project_add_speed_request::ProjectAddSpeedRequest;
+use olorin_engine_api::commands::project::add_speed::project_add_speed_response::ProjectAddSpeedResponse;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_speed::ProjectItemTypeSpeed;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectAddSpeedRequest {
+    type ResponseType = ProjectAddSpeedResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        if self.item_name.is_empty() {
+            log::error!("Unable to add speed item, an item name is required.");
+            return ProjectAddSpeedResponse { success: false };
+        }
+
+        if !self.speed_factor.is_finite() || self.speed_factor <= 0.0 {
+            log::error!("Unable to add speed item, the speed factor must be greater than zero.");
+            return ProjectAddSpeedResponse { success: false };
+        }
+
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let mut opened_project_guard = opened_project_lock.write().unwrap();
+
+        let Some(project) = opened_project_guard.as_mut() else {
+            log::warn!("Unable to add speed item, no opened project.");
+            return ProjectAddSpeedResponse { success: false };
+        };
+
+        let child_count_before = project.get_project_root().get_children().len();
+        let path = project.get_project_root().get_path().join(&self.item_name);
+
+        if project
+            .get_project_root()
+            .get_children()
+            .iter()
+            .any(|child| child.get_path() == &path)
+        {
+            log::error!("Unable to add speed item, a project item named {} already exists.", self.item_name);
+            return ProjectAddSpeedResponse { success: false };
+        }
+
+        let speed_item = ProjectItemTypeSpeed::new_project_item(&path, self.speed_factor, &self.description);
+
+        project
+            .get_project_root_mut()
+            .append_child(speed_item.clone());
+
+        let mut edit_history_entry = EditHistoryEntry::new(format!("Add speed item {} to project", self.item_name));
+
+        edit_history_entry.record_project_items_added(child_count_before, vec![speed_item]);
+        engine_privileged_state
+            .get_edit_history_manager()
+            .push_entry(edit_history_entry);
+
+        match project.save(true) {
+            Ok(_) => ProjectAddSpeedResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to save project after adding speed item: {}", error);
+                ProjectAddSpeedResponse { success: false }
+            }
+        }
+    }
+}
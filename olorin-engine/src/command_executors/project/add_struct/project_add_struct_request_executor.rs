@@ -0,0 +1,78 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::add_struct::project_add_struct_request::ProjectAddStructRequest;
+use olorin_engine_api::commands::project::add_struct::project_add_struct_response::ProjectAddStructResponse;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_struct::ProjectItemTypeStruct;
+use olorin_engine_projects::project::project::Project;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectAddStructRequest {
+    type ResponseType = ProjectAddStructResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        if self.struct_name.is_empty() {
+            log::error!("Unable to add struct, a struct name is required.");
+            return ProjectAddStructResponse { success: false };
+        }
+
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let mut opened_project_guard = opened_project_lock.write().unwrap();
+
+        let Some(project) = opened_project_guard.as_mut() else {
+            log::warn!("Unable to add struct, no opened project.");
+            return ProjectAddStructResponse { success: false };
+        };
+
+        let child_count_before = project.get_project_root().get_children().len();
+        let path = project.get_project_root().get_path().join(&self.struct_name);
+
+        if project
+            .get_project_root()
+            .get_children()
+            .iter()
+            .any(|child| child.get_path() == &path)
+        {
+            log::error!("Unable to add struct, a project item named {} already exists.", self.struct_name);
+            return ProjectAddStructResponse { success: false };
+        }
+
+        // Structs are registered by file stem, so a struct of the same name elsewhere in the project would make one of them unreachable.
+        let struct_name = Project::get_symbolic_struct_name(&path);
+
+        if project
+            .get_symbolic_struct_definitions()
+            .iter()
+            .any(|(existing_struct_name, _)| existing_struct_name == &struct_name)
+        {
+            log::error!("Unable to add struct, a struct named {} already exists in the project.", struct_name);
+            return ProjectAddStructResponse { success: false };
+        }
+
+        let struct_item = ProjectItemTypeStruct::new_project_item(&path, &self.symbolic_struct_definition);
+
+        project
+            .get_project_root_mut()
+            .append_child(struct_item.clone());
+        project.register_symbolic_structs();
+
+        let mut edit_history_entry = EditHistoryEntry::new(format!("Add struct {} to project", self.struct_name));
+
+        edit_history_entry.record_project_items_added(child_count_before, vec![struct_item]);
+        engine_privileged_state
+            .get_edit_history_manager()
+            .push_entry(edit_history_entry);
+
+        match project.save(true) {
+            Ok(_) => ProjectAddStructResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to save project after adding struct: {}", error);
+                ProjectAddStructResponse { success: false }
+            }
+        }
+    }
+}
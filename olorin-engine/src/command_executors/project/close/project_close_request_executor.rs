@@ -14,6 +14,9 @@ impl EngineCommandRequestExecutor for ProjectCloseRequest {
         engine_privileged_state
             .get_project_manager()
             .clear_opened_project();
+        engine_privileged_state
+            .get_process_manager()
+            .set_process_watch_rule(None);
 
         ProjectCloseResponse {}
     }
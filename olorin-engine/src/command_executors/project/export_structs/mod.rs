@@ -0,0 +1 @@
+pub mod project_export_structs_request_executor;
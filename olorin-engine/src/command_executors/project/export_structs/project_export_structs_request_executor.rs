@@ -0,0 +1,58 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::export_structs::project_export_structs_request::ProjectExportStructsRequest;
+use olorin_engine_api::commands::project::export_structs::project_export_structs_response::ProjectExportStructsResponse;
+use olorin_engine_projects::structs::c_header::c_header_struct_exporter::CHeaderStructExporter;
+use std::fs;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectExportStructsRequest {
+    type ResponseType = ProjectExportStructsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let opened_project_guard = opened_project_lock.read().unwrap();
+
+        let Some(project) = opened_project_guard.as_ref() else {
+            log::warn!("Unable to export structs, no opened project.");
+            return ProjectExportStructsResponse::default();
+        };
+
+        let mut symbolic_struct_definitions = project.get_symbolic_struct_definitions();
+
+        if !self.struct_names.is_empty() {
+            for struct_name in &self.struct_names {
+                if !symbolic_struct_definitions
+                    .iter()
+                    .any(|(name, _)| name == struct_name)
+                {
+                    log::warn!("Unable to export struct {}, no such struct exists in the project.", struct_name);
+                }
+            }
+
+            symbolic_struct_definitions.retain(|(struct_name, _)| self.struct_names.contains(struct_name));
+        }
+
+        if symbolic_struct_definitions.is_empty() {
+            log::error!("Unable to export structs, no structs to export.");
+            return ProjectExportStructsResponse::default();
+        }
+
+        let header = CHeaderStructExporter::export(&symbolic_struct_definitions);
+
+        match fs::write(&self.file_path, header) {
+            Ok(_) => ProjectExportStructsResponse {
+                success: true,
+                exported_struct_count: symbolic_struct_definitions.len() as u64,
+            },
+            Err(error) => {
+                log::error!("Failed to write structs to {}: {}", self.file_path.display(), error);
+                ProjectExportStructsResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod project_import_structs_request_executor;
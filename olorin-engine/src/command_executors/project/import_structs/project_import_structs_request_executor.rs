@@ -0,0 +1,106 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::import_structs::project_import_structs_request::ProjectImportStructsRequest;
+use olorin_engine_api::commands::project::import_structs::project_import_structs_response::ProjectImportStructsResponse;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_struct::ProjectItemTypeStruct;
+use olorin_engine_projects::structs::c_header::c_header_struct_importer::CHeaderStructImporter;
+use olorin_engine_projects::structs::reclass::reclass_struct_importer::ReClassStructImporter;
+use std::collections::HashSet;
+use std::fs;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectImportStructsRequest {
+    type ResponseType = ProjectImportStructsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let is_reclass_project = self
+            .file_path
+            .extension()
+            .map(|extension| extension.eq_ignore_ascii_case("rcnet"))
+            .unwrap_or(false);
+        let import_result = if is_reclass_project {
+            ReClassStructImporter::import_file(&self.file_path)
+        } else {
+            fs::read_to_string(&self.file_path)
+                .map_err(|error| format!("Failed to read {}: {}", self.file_path.display(), error))
+                .and_then(|source| CHeaderStructImporter::import(&source))
+        };
+
+        let struct_import_result = match import_result {
+            Ok(struct_import_result) => struct_import_result,
+            Err(error) => {
+                log::error!("Unable to import structs: {}", error);
+                return ProjectImportStructsResponse::default();
+            }
+        };
+
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let mut opened_project_guard = opened_project_lock.write().unwrap();
+
+        let Some(project) = opened_project_guard.as_mut() else {
+            log::warn!("Unable to import structs, no opened project.");
+            return ProjectImportStructsResponse::default();
+        };
+
+        let existing_struct_names: HashSet<String> = project
+            .get_symbolic_struct_definitions()
+            .into_iter()
+            .map(|(struct_name, _)| struct_name)
+            .collect();
+        let child_count_before = project.get_project_root().get_children().len();
+        let mut struct_items = vec![];
+        let mut response = ProjectImportStructsResponse {
+            warnings: struct_import_result.get_warnings().clone(),
+            ..Default::default()
+        };
+
+        for (struct_name, symbolic_struct_definition) in struct_import_result.into_symbolic_struct_definitions() {
+            let path = project.get_project_root().get_path().join(&struct_name);
+
+            if existing_struct_names.contains(&struct_name)
+                || project
+                    .get_project_root()
+                    .get_children()
+                    .iter()
+                    .any(|child| child.get_path() == &path)
+            {
+                log::warn!("Skipping struct {}, as a project item with this name already exists.", struct_name);
+                response.skipped_struct_names.push(struct_name);
+                continue;
+            }
+
+            let struct_item = ProjectItemTypeStruct::new_project_item(&path, &symbolic_struct_definition);
+
+            project.get_project_root_mut().append_child(struct_item.clone());
+            struct_items.push(struct_item);
+            response.imported_struct_names.push(struct_name);
+        }
+
+        if struct_items.is_empty() {
+            log::warn!("No new structs were imported from {}.", self.file_path.display());
+            response.success = true;
+            return response;
+        }
+
+        project.register_symbolic_structs();
+
+        let mut edit_history_entry = EditHistoryEntry::new(format!("Import {} structs into project", struct_items.len()));
+
+        edit_history_entry.record_project_items_added(child_count_before, struct_items);
+        engine_privileged_state
+            .get_edit_history_manager()
+            .push_entry(edit_history_entry);
+
+        match project.save(true) {
+            Ok(_) => response.success = true,
+            Err(error) => log::error!("Failed to save project after importing structs: {}", error),
+        }
+
+        response
+    }
+}
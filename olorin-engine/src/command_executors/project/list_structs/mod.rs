@@ -0,0 +1 @@
+pub mod project_list_structs_request_executor;
@@ -0,0 +1,20 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::list_structs::project_list_structs_request::ProjectListStructsRequest;
+use olorin_engine_api::commands::project::list_structs::project_list_structs_response::ProjectListStructsResponse;
+use olorin_engine_api::registries::symbolic_structs::symbolic_struct_registry::SymbolicStructRegistry;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectListStructsRequest {
+    type ResponseType = ProjectListStructsResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        // The registry holds the structs of the opened project, and is empty while no project is open.
+        ProjectListStructsResponse {
+            struct_names: SymbolicStructRegistry::get_instance().get_names(),
+        }
+    }
+}
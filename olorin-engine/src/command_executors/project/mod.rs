@@ -1,8 +1,16 @@
+pub mod activate_item;
+pub mod add_speed;
+pub mod add_struct;
 pub mod close;
 pub mod create;
 pub mod export;
+pub mod export_structs;
+pub mod import_structs;
 pub mod list;
+pub mod list_structs;
 pub mod open;
 pub mod project_command_executor;
 pub mod rename;
 pub mod save;
+pub mod set_item_property;
+pub mod watch_process;
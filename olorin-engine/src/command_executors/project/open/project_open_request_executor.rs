@@ -29,6 +29,9 @@ impl EngineCommandRequestExecutor for ProjectOpenRequest {
                 engine_privileged_state
                     .get_project_manager()
                     .set_opened_project(project);
+                engine_privileged_state
+                    .get_process_manager()
+                    .set_process_watch_rule(project_info.get_project_manifest().get_process_watch_rule().clone());
 
                 ProjectOpenResponse {
                     opened_project_info: Some(project_info),
@@ -34,6 +34,40 @@ impl EngineCommandExecutor for ProjectCommand {
             ProjectCommand::List { project_list_request } => project_list_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ProjectCommand::AddStruct { project_add_struct_request } => project_add_struct_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::ImportStructs {
+                project_import_structs_request,
+            } => project_import_structs_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::ExportStructs {
+                project_export_structs_request,
+            } => project_export_structs_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::ListStructs { project_list_structs_request } => project_list_structs_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::WatchProcess {
+                project_watch_process_request,
+            } => project_watch_process_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::AddSpeed { project_add_speed_request } => project_add_speed_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::ActivateItem {
+                project_activate_item_request,
+            } => project_activate_item_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProjectCommand::SetItemProperty {
+                project_set_item_property_request,
+            } => project_set_item_property_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
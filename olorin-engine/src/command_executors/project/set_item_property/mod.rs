@@ -0,0 +1 @@
+pub mod project_set_item_property_request_executor;
@@ -0,0 +1,86 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::set_item_property::project_set_item_property_request::ProjectSetItemPropertyRequest;
+use olorin_engine_api::commands::project::set_item_property::project_set_item_property_response::ProjectSetItemPropertyResponse;
+use olorin_engine_api::structures::structs::valued_struct_field::ValuedStructFieldNode;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectSetItemPropertyRequest {
+    type ResponseType = ProjectSetItemPropertyResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let mut opened_project_guard = opened_project_lock.write().unwrap();
+
+        let Some(project) = opened_project_guard.as_mut() else {
+            log::warn!("Unable to set project item property, no opened project.");
+            return ProjectSetItemPropertyResponse { success: false };
+        };
+
+        let project_item_path = project
+            .get_project_root()
+            .get_path()
+            .join(&self.project_item_path);
+        let Some(project_item) = project
+            .get_project_root_mut()
+            .find_project_item_mut(&project_item_path)
+        else {
+            log::error!("Unable to set project item property, no project item exists at {:?}.", self.project_item_path);
+            return ProjectSetItemPropertyResponse { success: false };
+        };
+
+        // Only existing properties can be set, and only to values of the same type, such that items remain readable by their project item type.
+        let existing_data_type_id = project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == self.field_namespace)
+            .map(|field| {
+                field
+                    .get_data_value()
+                    .map(|data_value| data_value.get_data_type_id().to_string())
+            });
+
+        match existing_data_type_id {
+            None => {
+                log::error!("Project item {:?} has no property named {}.", self.project_item_path, self.field_namespace);
+                return ProjectSetItemPropertyResponse { success: false };
+            }
+            Some(Some(data_type_id)) if data_type_id != self.data_value.get_data_type_id() => {
+                log::error!("Property {} holds {} values, but a {} value was provided.", self.field_namespace, data_type_id, self.data_value.get_data_type_id());
+                return ProjectSetItemPropertyResponse { success: false };
+            }
+            Some(_) => {}
+        }
+
+        let previous_properties = project_item.get_properties().clone();
+
+        project_item
+            .get_properties_mut()
+            .set_field_node(&self.field_namespace, ValuedStructFieldNode::Value(self.data_value.clone()), false);
+        project_item.set_has_unsaved_changes(true);
+
+        let new_properties = project_item.get_properties().clone();
+        let mut edit_history_entry = EditHistoryEntry::new(format!("Set {} of project item {}", self.field_namespace, self.project_item_path.display()));
+
+        edit_history_entry.record_project_item_properties_changed(project_item_path, previous_properties, new_properties);
+        engine_privileged_state
+            .get_edit_history_manager()
+            .push_entry(edit_history_entry);
+
+        project.register_symbolic_structs();
+
+        match project.save(true) {
+            Ok(_) => ProjectSetItemPropertyResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to save project after setting project item property: {}", error);
+                ProjectSetItemPropertyResponse { success: false }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod project_watch_process_request_executor;
@@ -0,0 +1,52 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::watch_process::project_watch_process_request::ProjectWatchProcessRequest;
+use olorin_engine_api::commands::project::watch_process::project_watch_process_response::ProjectWatchProcessResponse;
+use olorin_engine_api::structures::processes::process_watch_rule::ProcessWatchRule;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ProjectWatchProcessRequest {
+    type ResponseType = ProjectWatchProcessResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let process_watch_rule = match self.process_pattern.as_deref().map(str::trim) {
+            Some("") => {
+                log::error!("Unable to watch for process, the process pattern is empty.");
+                return ProjectWatchProcessResponse { success: false };
+            }
+            process_pattern => process_pattern.map(|process_pattern| ProcessWatchRule::new(process_pattern.to_string())),
+        };
+
+        let project_manager = engine_privileged_state.get_project_manager();
+        let opened_project_lock = project_manager.get_opened_project();
+        let mut opened_project_guard = opened_project_lock.write().unwrap();
+
+        let Some(project) = opened_project_guard.as_mut() else {
+            log::warn!("Unable to watch for process, no opened project.");
+            return ProjectWatchProcessResponse { success: false };
+        };
+
+        // The rule is stored in the project, such that it is applied again whenever the project is opened.
+        let project_info = project.get_project_info_mut();
+
+        project_info
+            .get_project_manifest_mut()
+            .set_process_watch_rule(process_watch_rule.clone());
+        project_info.set_has_unsaved_changes(true);
+
+        engine_privileged_state
+            .get_process_manager()
+            .set_process_watch_rule(process_watch_rule);
+
+        match project.save(true) {
+            Ok(_) => ProjectWatchProcessResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to save project after setting the watched process: {}", error);
+                ProjectWatchProcessResponse { success: false }
+            }
+        }
+    }
+}
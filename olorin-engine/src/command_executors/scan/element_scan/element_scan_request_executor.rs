@@ -44,9 +44,22 @@ impl EngineCommandRequestExecutor for ElementScanRequest {
                 ScanSettingsConfig::get_floating_point_tolerance(),
                 ScanSettingsConfig::get_memory_read_mode(),
                 ScanSettingsConfig::get_is_single_threaded_scan(),
+                ScanSettingsConfig::get_vectorization_size_override(),
+                ScanSettingsConfig::get_chunked_scan_region_size_threshold(),
                 ScanSettingsConfig::get_debug_perform_validation_scan(),
             );
 
+            // Checkpoint the current results, allowing this scan to be rolled back if it narrows the results incorrectly.
+            match snapshot.write() {
+                Ok(mut snapshot) => snapshot.create_checkpoint(
+                    self.compare_type.clone(),
+                    self.scan_value.clone(),
+                    self.data_type_ids.clone(),
+                    ScanSettingsConfig::get_scan_history_depth() as usize,
+                ),
+                Err(error) => log::error!("Failed to acquire write lock on snapshot to record scan history: {}", error),
+            }
+
             // Start the task to perform the scan.
             let task = ElementScanExecutorTask::start_task(process_info, snapshot, &scan_parameters, true);
             let task_handle = task.get_task_handle();
@@ -0,0 +1,68 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::scan::grouped_scan::grouped_scan_request::GroupedScanRequest;
+use olorin_engine_api::commands::scan::grouped_scan::grouped_scan_response::GroupedScanResponse;
+use olorin_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use olorin_engine_api::structures::scanning::parameters::element_scan::element_scan_value::ElementScanValue;
+use olorin_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use olorin_engine_scanning::scanners::grouped::grouped_scan_task::GroupedScanTask;
+use std::sync::Arc;
+use std::thread;
+
+impl EngineCommandRequestExecutor for GroupedScanRequest {
+    type ResponseType = GroupedScanResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process available.");
+            return GroupedScanResponse { trackable_task_handle: None };
+        };
+
+        let snapshot = engine_privileged_state.get_snapshot();
+
+        match snapshot.read() {
+            Ok(snapshot) if snapshot.get_region_count() > 0 => {}
+            Ok(_) => {
+                log::error!("No scan in progress. Start a new scan before performing a grouped scan.");
+                return GroupedScanResponse { trackable_task_handle: None };
+            }
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+                return GroupedScanResponse { trackable_task_handle: None };
+            }
+        }
+
+        let alignment = ScanSettingsConfig::get_memory_alignment().unwrap_or(MemoryAlignment::Alignment1);
+        let element_scan_values = self
+            .scan_values
+            .iter()
+            .map(|data_value| ElementScanValue::new(data_value.clone(), alignment))
+            .collect();
+
+        // Start the task to perform the scan. Results are reported through the log as the task completes.
+        let task = GroupedScanTask::start_task(process_info, snapshot, element_scan_values, self.max_span);
+        let task_handle = task.get_task_handle();
+        let engine_privileged_state = engine_privileged_state.clone();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+        });
+
+        GroupedScanResponse {
+            trackable_task_handle: Some(task_handle),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod scan_history_request_executor;
@@ -0,0 +1,34 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::scan::history::scan_history_request::ScanHistoryRequest;
+use olorin_engine_api::commands::scan::history::scan_history_response::ScanHistoryResponse;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ScanHistoryRequest {
+    type ResponseType = ScanHistoryResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.get_snapshot().read() {
+            Ok(snapshot) => {
+                let scan_history_entries = snapshot.get_scan_history();
+                let total_memory_cost = scan_history_entries
+                    .iter()
+                    .map(|scan_history_entry| scan_history_entry.get_memory_cost())
+                    .sum();
+
+                ScanHistoryResponse {
+                    scan_history_entries,
+                    total_memory_cost,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+
+                ScanHistoryResponse::default()
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod collect_values;
 pub mod element_scan;
+pub mod grouped_scan;
+pub mod history;
 pub mod new;
 pub mod reset;
 pub mod scan_command_executor;
+pub mod signature_scan;
 pub mod struct_scan;
+pub mod undo;
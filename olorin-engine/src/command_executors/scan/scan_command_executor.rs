@@ -28,6 +28,18 @@ impl EngineCommandExecutor for ScanCommand {
             ScanCommand::StructScan { struct_scan_request } => struct_scan_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::SignatureScan { signature_scan_request } => signature_scan_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::GroupedScan { grouped_scan_request } => grouped_scan_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::Undo { scan_undo_request } => scan_undo_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::History { scan_history_request } => scan_history_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
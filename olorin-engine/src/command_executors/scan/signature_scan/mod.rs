@@ -0,0 +1 @@
+pub mod signature_scan_request_executor;
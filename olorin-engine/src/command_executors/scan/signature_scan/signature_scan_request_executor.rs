@@ -0,0 +1,50 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::scan::signature_scan::signature_scan_request::SignatureScanRequest;
+use olorin_engine_api::commands::scan::signature_scan::signature_scan_response::SignatureScanResponse;
+use olorin_engine_scanning::scanners::signature::signature_scanner::SignatureScanner;
+use std::sync::Arc;
+use std::time::Instant;
+
+impl EngineCommandRequestExecutor for SignatureScanRequest {
+    type ResponseType = SignatureScanResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process available.");
+            return SignatureScanResponse { signature_scan_results: vec![] };
+        };
+
+        let start_time = Instant::now();
+
+        match SignatureScanner::scan(
+            &process_info,
+            &self.signature,
+            self.module_name.as_deref(),
+            self.rip_offset,
+            self.instruction_length,
+        ) {
+            Ok(signature_scan_results) => {
+                log::info!(
+                    "Signature scan for '{}' found {} results in: {:?}",
+                    self.signature,
+                    signature_scan_results.len(),
+                    start_time.elapsed()
+                );
+
+                SignatureScanResponse { signature_scan_results }
+            }
+            Err(error) => {
+                log::error!("{}", error);
+
+                SignatureScanResponse { signature_scan_results: vec![] }
+            }
+        }
+    }
+}
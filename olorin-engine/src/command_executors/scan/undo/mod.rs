@@ -0,0 +1 @@
+pub mod scan_undo_request_executor;
@@ -0,0 +1,47 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::scan::undo::scan_undo_request::ScanUndoRequest;
+use olorin_engine_api::commands::scan::undo::scan_undo_response::ScanUndoResponse;
+use olorin_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use std::sync::Arc;
+
+impl EngineCommandRequestExecutor for ScanUndoRequest {
+    type ResponseType = ScanUndoResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let mut snapshot = match snapshot.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanUndoResponse::default();
+            }
+        };
+
+        let scans_undone = snapshot.rollback(self.scan_count as usize);
+
+        if scans_undone == 0 {
+            log::info!("No scans to undo.");
+
+            return ScanUndoResponse::default();
+        }
+
+        let result_count = snapshot.get_number_of_results();
+
+        // Release the snapshot before notifying listeners, as they will immediately query the restored results.
+        drop(snapshot);
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+
+        log::info!("Rolled back {} scan(s), {} results restored.", scans_undone, result_count);
+
+        ScanUndoResponse {
+            scans_undone: scans_undone as u32,
+            result_count,
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::scan_results::add_to_project::scan_results_add_to_project_request::ScanResultsAddToProjectRequest;
 use olorin_engine_api::commands::scan_results::add_to_project::scan_results_add_to_project_response::ScanResultsAddToProjectResponse;
+use olorin_engine_api::structures::address_expressions::address_expression::AddressExpression;
 use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory;
+use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use std::path::Component;
+use std::path::Path;
 use std::sync::Arc;
 
 impl EngineCommandRequestExecutor for ScanResultsAddToProjectRequest {
@@ -12,23 +19,89 @@ impl EngineCommandRequestExecutor for ScanResultsAddToProjectRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        // The directory is created beneath the project root, so only a plain name is accepted (no separators, roots, or `..`).
+        if let Some(directory_name) = &self.directory_name {
+            let mut components = Path::new(directory_name).components();
+            let is_single_normal_component = match (components.next(), components.next()) {
+                (Some(Component::Normal(component)), None) => *component == **directory_name,
+                _ => false,
+            };
+
+            if !is_single_normal_component {
+                log::error!("Invalid directory name '{}', expected a single directory name.", directory_name);
+
+                return ScanResultsAddToProjectResponse {};
+            }
+        }
+
         let project_manager = engine_privileged_state.get_project_manager();
         let opened_project_lock = project_manager.get_opened_project();
         let mut opened_project_guard = opened_project_lock.write().unwrap();
 
         if let Some(project) = opened_project_guard.as_mut() {
+            let child_count_before = project.get_project_root().get_children().len();
+            let root_path = project.get_project_root().get_path().to_path_buf();
+
+            // Grouped results are kept together in a directory, otherwise each result is added directly to the project root.
+            let mut directory_item = self
+                .directory_name
+                .as_ref()
+                .map(|directory_name| ProjectItemTypeDirectory::new_project_item(&root_path.join(directory_name)));
+            let parent_path = directory_item
+                .as_ref()
+                .map(|directory_item| directory_item.get_path().to_path_buf())
+                .unwrap_or(root_path);
+            let mut address_items = vec![];
+
+            // Collect modules so that static addresses can be stored relative to their module, surviving process restarts.
+            let modules = match engine_privileged_state
+                .get_process_manager()
+                .get_opened_process()
+            {
+                Some(process_info) => MemoryQueryer::get_instance().get_modules(&process_info),
+                None => vec![],
+            };
+
             for scan_result in &self.scan_results {
                 let address = scan_result.get_address();
                 let data_type = scan_result.get_data_type().clone();
                 let data_value = data_type.get_default_value().unwrap_or_default();
-                let path = project.get_project_root().get_path().join("Address");
+                let path = parent_path.join("Address");
                 let description = String::new();
-                let address_item = ProjectItemTypeAddress::new_project_item(&path, address, &description, data_value);
+                let mut address_item = ProjectItemTypeAddress::new_project_item(&path, address, &description, data_value);
 
-                // Add to project root.
-                project.get_project_root_mut().append_child(address_item);
+                if let Some(address_expression_node) = MemoryQueryer::get_instance().address_to_module_expression(address, &modules) {
+                    let address_expression = AddressExpression::new(address_expression_node.to_string(), address_expression_node);
+
+                    ProjectItemTypeAddress::set_field_address_expression(&mut address_item, &address_expression);
+                }
+
+                address_items.push(address_item);
+            }
+
+            let result_count = address_items.len();
+            let added_items = match directory_item.as_mut() {
+                Some(directory_item) => {
+                    for address_item in address_items {
+                        directory_item.append_child(address_item);
+                    }
+
+                    vec![directory_item.clone()]
+                }
+                None => address_items,
+            };
+
+            for added_item in &added_items {
+                project.get_project_root_mut().append_child(added_item.clone());
             }
 
+            let mut edit_history_entry = EditHistoryEntry::new(format!("Add {} scan result(s) to project", result_count));
+
+            edit_history_entry.record_project_items_added(child_count_before, added_items);
+            engine_privileged_state
+                .get_edit_history_manager()
+                .push_entry(edit_history_entry);
+
             if let Err(err) = project.save(true) {
                 log::error!("Failed to save project after adding scan results: {}", err);
             }
@@ -1,4 +1,5 @@
 use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
 use olorin_engine_api::commands::scan_results::freeze::scan_results_freeze_response::ScanResultsFreezeResponse;
@@ -13,12 +14,17 @@ impl EngineCommandRequestExecutor for ScanResultsFreezeRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let description = if self.is_frozen { "Freeze" } else { "Unfreeze" };
+        let mut edit_history_entry = EditHistoryEntry::new(format!("{} {} scan result(s)", description, self.scan_results.len()));
+
         if let Ok(snapshot_scan_result_freeze_list) = engine_privileged_state
             .get_snapshot_scan_result_freeze_list()
             .read()
         {
             for scan_result in &self.scan_results {
                 let address = scan_result.get_address();
+                let previous_frozen_value = snapshot_scan_result_freeze_list.get_address_frozen_data_value(address);
+
                 if self.is_frozen {
                     if let Some(opened_process_info) = engine_privileged_state
                         .get_process_manager()
@@ -26,15 +32,22 @@ impl EngineCommandRequestExecutor for ScanResultsFreezeRequest {
                     {
                         if let Some(mut data_value) = scan_result.get_data_type().get_default_value() {
                             if MemoryReader::get_instance().read(&opened_process_info, address, &mut data_value) {
+                                edit_history_entry.record_freeze(address, previous_frozen_value, Some(data_value.clone()));
                                 snapshot_scan_result_freeze_list.set_address_frozen(address, data_value);
                             }
                         }
                     }
-                } else {
+                } else if previous_frozen_value.is_some() {
+                    edit_history_entry.record_freeze(address, previous_frozen_value, None);
                     snapshot_scan_result_freeze_list.set_address_unfrozen(address);
                 }
             }
         }
+
+        engine_privileged_state
+            .get_edit_history_manager()
+            .push_entry(edit_history_entry);
+
         ScanResultsFreezeResponse {}
     }
 }
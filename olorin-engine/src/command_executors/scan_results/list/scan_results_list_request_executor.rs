@@ -7,6 +7,7 @@ use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
 use olorin_engine_memory::memory_reader::MemoryReader;
 use olorin_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use olorin_engine_memory::symbols::symbol_service::SymbolService;
 use olorin_engine_scanning::scan_settings_config::ScanSettingsConfig;
 use std::sync::Arc;
 
@@ -73,6 +74,8 @@ impl EngineCommandRequestExecutor for ScanResultsListRequest {
                     module_offset = address;
                 }
 
+                let symbol = SymbolService::get_instance().address_to_symbol(address, &modules);
+
                 let is_frozen = if let Ok(snapshot_scan_result_freeze_list) = engine_privileged_state
                     .get_snapshot_scan_result_freeze_list()
                     .read()
@@ -82,7 +85,7 @@ impl EngineCommandRequestExecutor for ScanResultsListRequest {
                     false
                 };
 
-                scan_results_list.push(ScanResult::new(scan_result_base, module_name, module_offset, recently_read_value, is_frozen));
+                scan_results_list.push(ScanResult::new(scan_result_base, module_name, module_offset, symbol, recently_read_value, is_frozen));
             }
         }
 
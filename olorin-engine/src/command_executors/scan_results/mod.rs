@@ -6,3 +6,4 @@ pub mod query;
 pub mod refresh;
 pub mod scan_results_command_executor;
 pub mod set_property;
+pub mod watch;
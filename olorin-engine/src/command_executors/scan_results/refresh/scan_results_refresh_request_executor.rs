@@ -2,11 +2,7 @@ use crate::command_executors::engine_request_executor::EngineCommandRequestExecu
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
 use olorin_engine_api::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
-use olorin_engine_api::structures::scan_results::scan_result::ScanResult;
-use olorin_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
-use olorin_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
-use olorin_engine_memory::memory_reader::MemoryReader;
-use olorin_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use olorin_engine_scanning::results::scan_result_reader::ScanResultReader;
 use std::sync::Arc;
 
 impl EngineCommandRequestExecutor for ScanResultsRefreshRequest {
@@ -16,57 +12,22 @@ impl EngineCommandRequestExecutor for ScanResultsRefreshRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
-        let mut scan_results_list = vec![];
-
-        // Collect modules if possible so that we can resolve whether individual addresses are static later.
-        let modules = if let Some(opened_process_info) = engine_privileged_state
+        let opened_process_info = engine_privileged_state
             .get_process_manager()
-            .get_opened_process()
+            .get_opened_process();
+        let scan_results = match engine_privileged_state
+            .get_snapshot_scan_result_freeze_list()
+            .read()
         {
-            MemoryQueryer::get_instance().get_modules(&opened_process_info)
-        } else {
-            vec![]
-        };
-
-        // Wrap each ScanResultBase with a full ScanResult that includes current values and module information.
-        for scan_result_base in self.scan_results.clone().into_iter() {
-            let mut recently_read_value = None;
-            let mut module_name = String::default();
-            let address = scan_result_base.get_address();
-            let mut module_offset = scan_result_base.get_address();
-
-            // Best-effort attempt to read the values for this scan result.
-            if let Some(opened_process_info) = engine_privileged_state
-                .get_process_manager()
-                .get_opened_process()
-            {
-                if let Some(mut data_value) = scan_result_base.get_current_value().clone() {
-                    if MemoryReader::get_instance().read(&opened_process_info, address, &mut data_value) {
-                        recently_read_value = Some(data_value);
-                    }
-                }
+            Ok(snapshot_scan_result_freeze_list) => {
+                ScanResultReader::read_scan_results(opened_process_info.as_ref(), &self.scan_results, &snapshot_scan_result_freeze_list)
             }
-
-            // Check whether this scan result belongs to a module (ie check if the address is static).
-            if let Some((found_module_name, address)) = MemoryQueryer::get_instance().address_to_module(address, &modules) {
-                module_name = found_module_name;
-                module_offset = address;
+            Err(error) => {
+                log::error!("Failed to acquire read lock on freeze list to refresh scan results: {}", error);
+                vec![]
             }
+        };
 
-            let is_frozen = if let Ok(snapshot_scan_result_freeze_list) = engine_privileged_state
-                .get_snapshot_scan_result_freeze_list()
-                .read()
-            {
-                snapshot_scan_result_freeze_list.is_address_frozen(address)
-            } else {
-                false
-            };
-
-            scan_results_list.push(ScanResult::new(scan_result_base, module_name, module_offset, recently_read_value, is_frozen));
-        }
-
-        ScanResultsRefreshResponse {
-            scan_results: scan_results_list,
-        }
+        ScanResultsRefreshResponse { scan_results }
     }
 }
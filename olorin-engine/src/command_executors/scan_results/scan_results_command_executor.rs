@@ -22,6 +22,9 @@ impl EngineCommandExecutor for ScanResultsCommand {
             ScanResultsCommand::Refresh { results_refresh_request } => results_refresh_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanResultsCommand::Watch { results_watch_request } => results_watch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ScanResultsCommand::AddToProject {
                 results_add_to_project_request,
             } => results_add_to_project_request
@@ -1,11 +1,10 @@
 use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
 use olorin_engine_api::commands::scan_results::set_property::scan_results_set_property_request::ScanResultsSetPropertyRequest;
 use olorin_engine_api::commands::scan_results::set_property::scan_results_set_property_response::ScanResultsSetPropertyResponse;
 use olorin_engine_api::structures::scan_results::scan_result::ScanResult;
-use olorin_engine_memory::memory_writer::MemoryWriter;
-use olorin_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
 use std::sync::Arc;
 
 impl EngineCommandRequestExecutor for ScanResultsSetPropertyRequest {
@@ -18,6 +17,7 @@ impl EngineCommandRequestExecutor for ScanResultsSetPropertyRequest {
         match self.field_namespace.as_str() {
             ScanResult::PROPERTY_NAME_VALUE => {
                 let value_bytes = self.data_value.get_value_bytes();
+                let mut edit_history_entry = EditHistoryEntry::new(format!("Set value of {} scan result(s)", self.scan_results.len()));
 
                 for scan_result in &self.scan_results {
                     let address = scan_result.get_address();
@@ -26,9 +26,13 @@ impl EngineCommandRequestExecutor for ScanResultsSetPropertyRequest {
                         .get_opened_process()
                     {
                         // Best-effort attempt to write the property bytes.
-                        let _ = MemoryWriter::get_instance().write_bytes(&opened_process_info, address, &value_bytes);
+                        let _ = edit_history_entry.write_memory(&opened_process_info, address, &value_bytes);
                     }
                 }
+
+                engine_privileged_state
+                    .get_edit_history_manager()
+                    .push_entry(edit_history_entry);
             }
             ScanResult::PROPERTY_NAME_IS_FROZEN => {
                 let is_frozen = self.data_value.get_value_bytes().iter().any(|&byte| byte != 0);
@@ -0,0 +1 @@
+pub mod scan_results_watch_request_executor;
@@ -0,0 +1,54 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::scan_results::watch::scan_results_watch_request::ScanResultsWatchRequest;
+use olorin_engine_api::commands::scan_results::watch::scan_results_watch_response::ScanResultsWatchResponse;
+use olorin_engine_api::structures::results::scan_results_watch::ScanResultsWatch;
+use olorin_engine_scanning::results::scan_result_reader::ScanResultReader;
+use olorin_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use std::sync::Arc;
+use std::time::Duration;
+
+impl EngineCommandRequestExecutor for ScanResultsWatchRequest {
+    type ResponseType = ScanResultsWatchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as EngineCommandRequestExecutor>::ResponseType {
+        let scan_results_watch_list = engine_privileged_state.get_scan_results_watch_list();
+
+        if self.scan_results.is_empty() {
+            scan_results_watch_list.remove_watch(&self.watch_id);
+
+            return ScanResultsWatchResponse::default();
+        }
+
+        let opened_process_info = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process();
+        let scan_results = match engine_privileged_state
+            .get_snapshot_scan_result_freeze_list()
+            .read()
+        {
+            Ok(snapshot_scan_result_freeze_list) => {
+                ScanResultReader::read_scan_results(opened_process_info.as_ref(), &self.scan_results, &snapshot_scan_result_freeze_list)
+            }
+            Err(error) => {
+                log::error!("Failed to acquire read lock on freeze list to watch scan results: {}", error);
+                vec![]
+            }
+        };
+        let refresh_interval_ms = self
+            .refresh_interval_ms
+            .unwrap_or_else(ScanSettingsConfig::get_results_read_interval)
+            .max(1);
+
+        // The initial values are returned directly, such that the watch only needs to publish subsequent changes.
+        scan_results_watch_list.set_watch(
+            self.watch_id.clone(),
+            ScanResultsWatch::new(self.scan_results.clone(), Duration::from_millis(refresh_interval_ms), scan_results.clone()),
+        );
+
+        ScanResultsWatchResponse { scan_results }
+    }
+}
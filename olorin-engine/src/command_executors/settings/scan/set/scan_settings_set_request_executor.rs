@@ -40,6 +40,18 @@ impl EngineCommandRequestExecutor for ScanSettingsSetRequest {
             ScanSettingsConfig::set_floating_point_tolerance(floating_point_tolerance);
         }
 
+        if let Some(vectorization_size_override) = self.vectorization_size_override {
+            ScanSettingsConfig::set_vectorization_size_override(vectorization_size_override.get_vectorization_size());
+        }
+
+        if let Some(chunked_scan_region_size_threshold) = self.chunked_scan_region_size_threshold {
+            ScanSettingsConfig::set_chunked_scan_region_size_threshold(chunked_scan_region_size_threshold);
+        }
+
+        if let Some(scan_history_depth) = self.scan_history_depth {
+            ScanSettingsConfig::set_scan_history_depth(scan_history_depth);
+        }
+
         ScanSettingsSetResponse {}
     }
 }
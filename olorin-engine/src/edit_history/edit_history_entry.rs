@@ -0,0 +1,111 @@
+use crate::edit_history::edit_history_operation::EditHistoryOperation;
+use olorin_engine_api::structures::data_values::data_value::DataValue;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
+use olorin_engine_memory::memory_reader::MemoryReader;
+use olorin_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use olorin_engine_memory::memory_writer::MemoryWriter;
+use olorin_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use std::path::PathBuf;
+
+/// A group of operations that are undone and redone together, such as writing a value to every selected scan result.
+pub struct EditHistoryEntry {
+    description: String,
+    operations: Vec<EditHistoryOperation>,
+}
+
+impl EditHistoryEntry {
+    pub fn new(description: String) -> Self {
+        Self {
+            description,
+            operations: vec![],
+        }
+    }
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn get_operations(&self) -> &Vec<EditHistoryOperation> {
+        &self.operations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Writes bytes to process memory, capturing the original bytes first so that the write can be undone.
+    /// Writes to memory that cannot be read are still performed, but are not recorded.
+    pub fn write_memory(
+        &mut self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        new_bytes: &[u8],
+    ) -> bool {
+        let mut original_bytes = vec![0u8; new_bytes.len()];
+        let can_undo = MemoryReader::get_instance().read_bytes(process_info, address, &mut original_bytes);
+        let success = MemoryWriter::get_instance().write_bytes(process_info, address, new_bytes);
+
+        if success && can_undo {
+            self.operations.push(EditHistoryOperation::MemoryWrite {
+                address,
+                original_bytes,
+                new_bytes: new_bytes.to_vec(),
+            });
+        }
+
+        success
+    }
+
+    pub fn record_freeze(
+        &mut self,
+        address: u64,
+        previous_frozen_value: Option<DataValue>,
+        new_frozen_value: Option<DataValue>,
+    ) {
+        self.operations.push(EditHistoryOperation::Freeze {
+            address,
+            previous_frozen_value,
+            new_frozen_value,
+        });
+    }
+
+    pub fn record_project_items_added(
+        &mut self,
+        child_count_before: usize,
+        added_items: Vec<ProjectItem>,
+    ) {
+        self.operations.push(EditHistoryOperation::ProjectItemsAdded {
+            child_count_before,
+            added_items,
+        });
+    }
+
+    /// Records a change to the properties of a project item, such as its description, address, or freeze value.
+    pub fn record_project_item_properties_changed(
+        &mut self,
+        project_item_path: PathBuf,
+        previous_properties: ValuedStruct,
+        new_properties: ValuedStruct,
+    ) {
+        self.operations.push(EditHistoryOperation::ProjectItemPropertiesChanged {
+            project_item_path,
+            previous_properties,
+            new_properties,
+        });
+    }
+
+    /// Records the project items whose activation changed, along with the activation state each was changed to.
+    pub fn record_project_items_activated(
+        &mut self,
+        activation_changes: Vec<(PathBuf, bool)>,
+    ) {
+        if activation_changes.is_empty() {
+            return;
+        }
+
+        self.operations
+            .push(EditHistoryOperation::ProjectItemsActivated { activation_changes });
+    }
+}
@@ -0,0 +1,115 @@
+use crate::edit_history::edit_history_entry::EditHistoryEntry;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+/// The maximum number of entries retained for undo. The oldest entries are discarded first.
+const MAX_HISTORY_ENTRIES: usize = 256;
+
+/// Journals reversible edits made by the engine, such as memory writes, freezes, and project edits, to support undo and redo.
+pub struct EditHistoryManager {
+    undo_entries: RwLock<Vec<EditHistoryEntry>>,
+    redo_entries: RwLock<Vec<EditHistoryEntry>>,
+}
+
+impl EditHistoryManager {
+    pub fn new() -> Self {
+        Self {
+            undo_entries: RwLock::new(vec![]),
+            redo_entries: RwLock::new(vec![]),
+        }
+    }
+
+    /// Records a new edit. Any edits that were previously undone can no longer be redone.
+    pub fn push_entry(
+        &self,
+        edit_history_entry: EditHistoryEntry,
+    ) {
+        if edit_history_entry.is_empty() {
+            return;
+        }
+
+        if let Ok(mut redo_entries) = self.redo_entries.write() {
+            redo_entries.clear();
+        }
+
+        if let Ok(mut undo_entries) = self.undo_entries.write() {
+            undo_entries.push(edit_history_entry);
+
+            if undo_entries.len() > MAX_HISTORY_ENTRIES {
+                let excess_count = undo_entries.len() - MAX_HISTORY_ENTRIES;
+                undo_entries.drain(..excess_count);
+            }
+        }
+    }
+
+    /// Reverts the most recent edit, returning its description and whether every operation was reverted.
+    pub fn undo(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> Option<(String, bool)> {
+        let edit_history_entry = self.undo_entries.write().ok()?.pop()?;
+        let success = edit_history_entry
+            .get_operations()
+            .iter()
+            .rev()
+            .fold(true, |success, operation| operation.undo(engine_privileged_state) && success);
+        let description = edit_history_entry.get_description().to_string();
+
+        if let Ok(mut redo_entries) = self.redo_entries.write() {
+            redo_entries.push(edit_history_entry);
+        }
+
+        Some((description, success))
+    }
+
+    /// Reapplies the most recently undone edit, returning its description and whether every operation was reapplied.
+    pub fn redo(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> Option<(String, bool)> {
+        let edit_history_entry = self.redo_entries.write().ok()?.pop()?;
+        let success = edit_history_entry
+            .get_operations()
+            .iter()
+            .fold(true, |success, operation| operation.redo(engine_privileged_state) && success);
+        let description = edit_history_entry.get_description().to_string();
+
+        if let Ok(mut undo_entries) = self.undo_entries.write() {
+            undo_entries.push(edit_history_entry);
+        }
+
+        Some((description, success))
+    }
+
+    /// Gets the descriptions of all edits that can be undone, most recent last.
+    pub fn get_undo_descriptions(&self) -> Vec<String> {
+        Self::get_descriptions(&self.undo_entries)
+    }
+
+    /// Gets the descriptions of all edits that can be redone, most recently undone last.
+    pub fn get_redo_descriptions(&self) -> Vec<String> {
+        Self::get_descriptions(&self.redo_entries)
+    }
+
+    /// Clears all history. Called when the opened process changes, as recorded addresses are meaningless in a new process.
+    pub fn clear(&self) {
+        if let Ok(mut undo_entries) = self.undo_entries.write() {
+            undo_entries.clear();
+        }
+
+        if let Ok(mut redo_entries) = self.redo_entries.write() {
+            redo_entries.clear();
+        }
+    }
+
+    fn get_descriptions(entries: &RwLock<Vec<EditHistoryEntry>>) -> Vec<String> {
+        match entries.read() {
+            Ok(entries) => entries
+                .iter()
+                .map(|entry| entry.get_description().to_string())
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+}
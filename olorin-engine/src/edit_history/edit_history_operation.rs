@@ -0,0 +1,263 @@
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::project_item_activation::project_item_activation_handler::ProjectItemActivationHandler;
+use olorin_engine_api::structures::data_values::data_value::DataValue;
+use olorin_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
+use olorin_engine_memory::memory_writer::MemoryWriter;
+use olorin_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// A single reversible change made by the engine, capturing enough state to both revert and reapply it.
+pub enum EditHistoryOperation {
+    /// Bytes written to process memory, along with the bytes they replaced.
+    MemoryWrite {
+        address: u64,
+        original_bytes: Vec<u8>,
+        new_bytes: Vec<u8>,
+    },
+
+    /// A change to the frozen state of an address. A `None` value indicates that the address is not frozen.
+    Freeze {
+        address: u64,
+        previous_frozen_value: Option<DataValue>,
+        new_frozen_value: Option<DataValue>,
+    },
+
+    /// Project items appended to the root of the opened project.
+    ProjectItemsAdded {
+        child_count_before: usize,
+        added_items: Vec<ProjectItem>,
+    },
+
+    /// Properties of a project item changed, such as by renaming it or changing its address, along with the properties they replaced.
+    ProjectItemPropertiesChanged {
+        project_item_path: PathBuf,
+        previous_properties: ValuedStruct,
+        new_properties: ValuedStruct,
+    },
+
+    /// Project items activated or deactivated, with the activation state each item was changed to.
+    /// Activating a speed item deactivates any other, so a single activation can change several items.
+    ProjectItemsActivated { activation_changes: Vec<(PathBuf, bool)> },
+}
+
+impl EditHistoryOperation {
+    /// Reverts this operation, returning whether it was successfully reverted.
+    pub fn undo(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> bool {
+        match self {
+            EditHistoryOperation::MemoryWrite { address, original_bytes, .. } => Self::write_memory(engine_privileged_state, *address, original_bytes),
+            EditHistoryOperation::Freeze {
+                address, previous_frozen_value, ..
+            } => Self::set_frozen_value(engine_privileged_state, *address, previous_frozen_value),
+            EditHistoryOperation::ProjectItemsAdded {
+                child_count_before,
+                added_items,
+            } => Self::remove_project_items(engine_privileged_state, *child_count_before, added_items.len()),
+            EditHistoryOperation::ProjectItemPropertiesChanged {
+                project_item_path,
+                previous_properties,
+                ..
+            } => Self::set_project_item_properties(engine_privileged_state, project_item_path, previous_properties),
+            EditHistoryOperation::ProjectItemsActivated { activation_changes } => Self::set_project_items_activated(engine_privileged_state, activation_changes, true),
+        }
+    }
+
+    /// Reapplies this operation after it has been reverted, returning whether it was successfully reapplied.
+    pub fn redo(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> bool {
+        match self {
+            EditHistoryOperation::MemoryWrite { address, new_bytes, .. } => Self::write_memory(engine_privileged_state, *address, new_bytes),
+            EditHistoryOperation::Freeze { address, new_frozen_value, .. } => Self::set_frozen_value(engine_privileged_state, *address, new_frozen_value),
+            EditHistoryOperation::ProjectItemsAdded {
+                child_count_before,
+                added_items,
+            } => Self::append_project_items(engine_privileged_state, *child_count_before, added_items),
+            EditHistoryOperation::ProjectItemPropertiesChanged {
+                project_item_path,
+                new_properties,
+                ..
+            } => Self::set_project_item_properties(engine_privileged_state, project_item_path, new_properties),
+            EditHistoryOperation::ProjectItemsActivated { activation_changes } => Self::set_project_items_activated(engine_privileged_state, activation_changes, false),
+        }
+    }
+
+    fn write_memory(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        address: u64,
+        bytes: &[u8],
+    ) -> bool {
+        match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => MemoryWriter::get_instance().write_bytes(&opened_process_info, address, bytes),
+            None => false,
+        }
+    }
+
+    fn set_frozen_value(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        address: u64,
+        frozen_value: &Option<DataValue>,
+    ) -> bool {
+        match engine_privileged_state
+            .get_snapshot_scan_result_freeze_list()
+            .read()
+        {
+            Ok(snapshot_scan_result_freeze_list) => {
+                match frozen_value {
+                    Some(frozen_value) => snapshot_scan_result_freeze_list.set_address_frozen(address, frozen_value.clone()),
+                    None => snapshot_scan_result_freeze_list.set_address_unfrozen(address),
+                }
+
+                true
+            }
+            Err(error) => {
+                log::error!("Failed to acquire read lock on freeze list: {}", error);
+                false
+            }
+        }
+    }
+
+    fn remove_project_items(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        child_count_before: usize,
+        added_count: usize,
+    ) -> bool {
+        let opened_project = engine_privileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let Ok(mut opened_project_guard) = opened_project.write() else {
+            return false;
+        };
+        let Some(project) = opened_project_guard.as_mut() else {
+            return false;
+        };
+        let children = project.get_project_root_mut().get_children_mut();
+
+        // Only revert if the project root is in the state this operation left it in, otherwise we could remove unrelated items.
+        if children.len() != child_count_before + added_count {
+            log::warn!("Project items have changed since they were added, unable to undo.");
+            return false;
+        }
+
+        children.truncate(child_count_before);
+        project.register_symbolic_structs();
+
+        if let Err(error) = project.save(true) {
+            log::error!("Failed to save project after undoing project item addition: {}", error);
+        }
+
+        true
+    }
+
+    fn append_project_items(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        child_count_before: usize,
+        added_items: &[ProjectItem],
+    ) -> bool {
+        let opened_project = engine_privileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let Ok(mut opened_project_guard) = opened_project.write() else {
+            return false;
+        };
+        let Some(project) = opened_project_guard.as_mut() else {
+            return false;
+        };
+        let project_root = project.get_project_root_mut();
+
+        if project_root.get_children().len() != child_count_before {
+            log::warn!("Project items have changed since they were removed, unable to redo.");
+            return false;
+        }
+
+        for added_item in added_items {
+            project_root.append_child(added_item.clone());
+        }
+
+        project.register_symbolic_structs();
+
+        if let Err(error) = project.save(true) {
+            log::error!("Failed to save project after redoing project item addition: {}", error);
+        }
+
+        true
+    }
+
+    fn set_project_item_properties(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        project_item_path: &Path,
+        properties: &ValuedStruct,
+    ) -> bool {
+        let opened_project = engine_privileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let Ok(mut opened_project_guard) = opened_project.write() else {
+            return false;
+        };
+        let Some(project) = opened_project_guard.as_mut() else {
+            return false;
+        };
+        let Some(project_item) = project
+            .get_project_root_mut()
+            .find_project_item_mut(project_item_path)
+        else {
+            log::warn!("Project item {:?} no longer exists, unable to restore its properties.", project_item_path);
+            return false;
+        };
+
+        project_item.set_properties(properties.clone());
+        project_item.set_has_unsaved_changes(true);
+        project.register_symbolic_structs();
+
+        if let Err(error) = project.save(true) {
+            log::error!("Failed to save project after restoring project item properties: {}", error);
+        }
+
+        true
+    }
+
+    fn set_project_items_activated(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        activation_changes: &[(PathBuf, bool)],
+        is_undo: bool,
+    ) -> bool {
+        let opened_project = engine_privileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let Ok(mut opened_project_guard) = opened_project.write() else {
+            return false;
+        };
+        let Some(project) = opened_project_guard.as_mut() else {
+            return false;
+        };
+        let mut activation_states: Vec<(&PathBuf, bool)> = activation_changes
+            .iter()
+            .map(|(project_item_path, is_activated)| (project_item_path, *is_activated != is_undo))
+            .collect();
+        let mut success = true;
+
+        // Deactivations are applied first, such that an activated speed item is applied last rather than being reset to real time.
+        activation_states.sort_by_key(|(_, is_activated)| *is_activated);
+
+        for (project_item_path, is_activated) in activation_states {
+            if let Err(error) = ProjectItemActivationHandler::set_activated(engine_privileged_state, project.get_project_root_mut(), project_item_path, is_activated) {
+                log::warn!("Unable to restore project item activation: {}", error);
+                success = false;
+            }
+        }
+
+        if let Err(error) = project.save(true) {
+            log::error!("Failed to save project after restoring project item activation: {}", error);
+        }
+
+        success
+    }
+}
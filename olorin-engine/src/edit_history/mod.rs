@@ -0,0 +1,3 @@
+pub mod edit_history_entry;
+pub mod edit_history_manager;
+pub mod edit_history_operation;
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// The version of the interprocess protocol. This must be bumped whenever the framing or the engine command layout changes
+/// in a way that would cause a host and shell from different builds to misinterpret each other.
+pub const INTERPROCESS_PROTOCOL_VERSION: u32 = 2;
+
+/// The privileged shell executes engine commands sent by the host.
+pub const CAPABILITY_ENGINE_COMMANDS: &str = "engine_commands";
+
+/// The privileged shell forwards engine events to the host.
+pub const CAPABILITY_ENGINE_EVENTS: &str = "engine_events";
+
+/// The privileged shell forwards its log messages to the host.
+pub const CAPABILITY_ENGINE_LOGS: &str = "engine_logs";
+
+/// Sent by the privileged shell immediately after connecting to the host.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterprocessHandshake {
+    pub protocol_version: u32,
+    pub engine_version: String,
+    pub capabilities: Vec<String>,
+    pub session_token: String,
+}
+
+/// Sent by the host in reply to a handshake. A handshake is accepted only if no error is present.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterprocessHandshakeResponse {
+    pub protocol_version: u32,
+    pub engine_version: String,
+    pub capabilities: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl InterprocessHandshake {
+    pub fn new(session_token: &str) -> Self {
+        Self {
+            protocol_version: INTERPROCESS_PROTOCOL_VERSION,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            capabilities: Self::get_local_capabilities(),
+            session_token: session_token.to_string(),
+        }
+    }
+
+    /// Gets the capabilities that this build supports.
+    pub fn get_local_capabilities() -> Vec<String> {
+        vec![
+            CAPABILITY_ENGINE_COMMANDS.to_string(),
+            CAPABILITY_ENGINE_EVENTS.to_string(),
+            CAPABILITY_ENGINE_LOGS.to_string(),
+        ]
+    }
+}
+
+impl InterprocessHandshakeResponse {
+    pub fn accept() -> Self {
+        Self {
+            protocol_version: INTERPROCESS_PROTOCOL_VERSION,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            capabilities: InterprocessHandshake::get_local_capabilities(),
+            error: None,
+        }
+    }
+
+    pub fn reject(error: String) -> Self {
+        Self {
+            error: Some(error),
+            ..Self::accept()
+        }
+    }
+}
@@ -2,22 +2,35 @@ use crate::engine_bindings::engine_egress::EngineEgress;
 use crate::engine_bindings::engine_ingress::EngineIngress;
 use crate::engine_bindings::engine_ingress::ExecutableCommand;
 use crate::engine_bindings::engine_priviliged_bindings::EnginePrivilegedBindings;
+use crate::engine_bindings::interprocess::interprocess_handshake::INTERPROCESS_PROTOCOL_VERSION;
+use crate::engine_bindings::interprocess::interprocess_handshake::InterprocessHandshake;
+use crate::engine_bindings::interprocess::interprocess_handshake::InterprocessHandshakeResponse;
+use crate::engine_bindings::interprocess::interprocess_session::InterprocessSession;
 use crate::engine_bindings::interprocess::pipes::interprocess_pipe_bidirectional::InterprocessPipeBidirectional;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use crossbeam_channel::Receiver;
 use crossbeam_channel::Sender;
 use olorin_engine_api::events::engine_event::EngineEvent;
+use olorin_engine_api::events::engine_event::EngineEventRequest;
+use olorin_engine_api::events::log::message::log_message_event::LogMessageEvent;
+use olorin_engine_common::logging::file_system_logger::FileSystemLogger;
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
 use uuid::Uuid;
 
+/// How long the shell waits for the host to accept its connection.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
+
 pub struct InterprocessPrivilegedShell {
     /// The bidirectional connection to the host process.
     ipc_connection: Arc<RwLock<Option<InterprocessPipeBidirectional>>>,
 
     /// The list of subscribers to which we send engine events.
     event_senders: Arc<RwLock<Vec<Sender<EngineEvent>>>>,
+
+    /// Routes logs to the shell log file, as well as to the host for display, since the shell itself has no visible output.
+    file_system_logger: Arc<FileSystemLogger>,
 }
 
 impl EnginePrivilegedBindings for InterprocessPrivilegedShell {
@@ -27,14 +40,15 @@ impl EnginePrivilegedBindings for InterprocessPrivilegedShell {
     ) -> Result<(), String> {
         if let Some(engine_privileged_state) = engine_privileged_state {
             if let Ok(mut ipc_connection) = self.ipc_connection.write() {
-                match InterprocessPipeBidirectional::create() {
-                    Ok(new_connection) => {
-                        *ipc_connection = Some(new_connection);
-                        self.listen_for_host_requests(&engine_privileged_state);
-                        Ok(())
-                    }
-                    Err(error) => Err(error),
-                }
+                let session = InterprocessSession::read_from_stdin()?;
+                let new_connection = InterprocessPipeBidirectional::bind(&session, CONNECTION_TIMEOUT)?;
+
+                Self::perform_handshake(&new_connection, &session)?;
+
+                *ipc_connection = Some(new_connection);
+                self.forward_logs_to_host()?;
+                self.listen_for_host_requests(&engine_privileged_state);
+                Ok(())
             } else {
                 Err("Failed to acquire write lock on bidirectional interprocess connection.".to_string())
             }
@@ -74,6 +88,7 @@ impl InterprocessPrivilegedShell {
         let instance = InterprocessPrivilegedShell {
             ipc_connection: Arc::new(RwLock::new(None)),
             event_senders: Arc::new(RwLock::new(vec![])),
+            file_system_logger: Arc::new(FileSystemLogger::new()),
         };
 
         instance
@@ -94,6 +109,54 @@ impl InterprocessPrivilegedShell {
         Ok(())
     }
 
+    /// Sends each log message to the host as an engine event, starting with any logged before the host connected.
+    fn forward_logs_to_host(&self) -> Result<(), String> {
+        let log_receiver = self.file_system_logger.subscribe_to_logs()?;
+        let ipc_connection = self.ipc_connection.clone();
+
+        thread::spawn(move || {
+            while let Ok(log_message) = log_receiver.recv() {
+                let log_message_event = LogMessageEvent { log_message };
+
+                // Failures are not logged, as each would be forwarded in turn and fail again.
+                let _ = Self::dispatch_response(ipc_connection.clone(), EngineEgress::EngineEvent(log_message_event.to_engine_event()), Uuid::nil());
+            }
+        });
+
+        self.file_system_logger.start_log_event_sender();
+
+        Ok(())
+    }
+
+    /// Presents our protocol version, capabilities, and the session token to the host, and waits for the host to accept.
+    fn perform_handshake(
+        ipc_connection: &InterprocessPipeBidirectional,
+        session: &InterprocessSession,
+    ) -> Result<(), String> {
+        ipc_connection.send_handshake(InterprocessHandshake::new(session.get_session_token()))?;
+
+        let handshake_response = ipc_connection.receive_handshake::<InterprocessHandshakeResponse>()?;
+
+        if let Some(error) = handshake_response.error {
+            return Err(format!("Host rejected handshake: {}", error));
+        }
+
+        if handshake_response.protocol_version != INTERPROCESS_PROTOCOL_VERSION {
+            return Err(format!(
+                "Host protocol version {} does not match shell protocol version {}.",
+                handshake_response.protocol_version, INTERPROCESS_PROTOCOL_VERSION
+            ));
+        }
+
+        log::info!(
+            "Handshake accepted by host (engine version {}, capabilities: {:?}).",
+            handshake_response.engine_version,
+            handshake_response.capabilities
+        );
+
+        Ok(())
+    }
+
     fn listen_for_host_requests(
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
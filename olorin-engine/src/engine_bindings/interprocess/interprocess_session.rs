@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::io::BufRead;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Identifies a single host-to-shell session. The host generates a session when spawning the privileged shell, and passes it
+/// over the shell's stdin so that the secret token never appears in process arguments.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterprocessSession {
+    /// A unique identifier for this session, used to build socket names that do not collide with other Olorin instances.
+    session_id: String,
+
+    /// A shared secret that the shell must present during the handshake.
+    session_token: String,
+
+    /// The directory in which socket files are created. Unused on platforms with named pipes or abstract sockets.
+    socket_directory: PathBuf,
+}
+
+impl InterprocessSession {
+    pub fn create() -> Self {
+        Self {
+            session_id: Uuid::new_v4().simple().to_string(),
+            session_token: format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()),
+            socket_directory: Self::get_default_socket_directory(),
+        }
+    }
+
+    /// Reads the session that the host wrote to our stdin when spawning this process.
+    pub fn read_from_stdin() -> Result<Self, String> {
+        let mut session_json = String::new();
+
+        io::stdin()
+            .lock()
+            .read_line(&mut session_json)
+            .map_err(|error| format!("Failed to read interprocess session: {}", error))?;
+
+        serde_json::from_str(session_json.trim()).map_err(|error| format!("Failed to parse interprocess session: {}", error))
+    }
+
+    /// Serializes this session into a single line, suitable for writing to the shell's stdin.
+    pub fn to_stdin_line(&self) -> Result<String, String> {
+        serde_json::to_string(self)
+            .map(|session_json| session_json + "\n")
+            .map_err(|error| format!("Failed to serialize interprocess session: {}", error))
+    }
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub fn get_session_token(&self) -> &str {
+        &self.session_token
+    }
+
+    /// Compares the provided token against this session's token without exiting early on the first mismatched byte.
+    pub fn is_token_valid(
+        &self,
+        session_token: &str,
+    ) -> bool {
+        let expected_bytes = self.session_token.as_bytes();
+        let provided_bytes = session_token.as_bytes();
+
+        expected_bytes.len() == provided_bytes.len()
+            && expected_bytes
+                .iter()
+                .zip(provided_bytes.iter())
+                .fold(0u8, |difference, (expected, provided)| difference | (expected ^ provided))
+                == 0
+    }
+
+    #[cfg(windows)]
+    pub fn get_socket_name(
+        &self,
+        to_shell: bool,
+    ) -> String {
+        format!("\\\\.\\pipe\\olorin-{}-{}", self.session_id, Self::get_direction_suffix(to_shell))
+    }
+
+    #[cfg(all(not(windows), not(target_os = "android")))]
+    pub fn get_socket_name(
+        &self,
+        to_shell: bool,
+    ) -> String {
+        self.socket_directory
+            .join(format!("olorin-{}-{}.sock", self.session_id, Self::get_direction_suffix(to_shell)))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[cfg(target_os = "android")]
+    pub fn get_socket_name(
+        &self,
+        to_shell: bool,
+    ) -> String {
+        format!("olorin-{}-{}", self.session_id, Self::get_direction_suffix(to_shell))
+    }
+
+    fn get_direction_suffix(to_shell: bool) -> &'static str {
+        if to_shell { "to-shell" } else { "from-shell" }
+    }
+
+    /// Prefers the per-user runtime directory, which is only accessible by the current user, falling back to the temp directory.
+    fn get_default_socket_directory() -> PathBuf {
+        match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(runtime_directory) if !runtime_directory.is_empty() => PathBuf::from(runtime_directory),
+            _ => std::env::temp_dir(),
+        }
+    }
+}
@@ -1,31 +1,60 @@
 use crate::engine_bindings::engine_egress::EngineEgress;
 use crate::engine_bindings::engine_unprivileged_bindings::EngineUnprivilegedBindings;
+use crate::engine_bindings::interprocess::interprocess_handshake::CAPABILITY_ENGINE_COMMANDS;
+use crate::engine_bindings::interprocess::interprocess_handshake::INTERPROCESS_PROTOCOL_VERSION;
+use crate::engine_bindings::interprocess::interprocess_handshake::InterprocessHandshake;
+use crate::engine_bindings::interprocess::interprocess_handshake::InterprocessHandshakeResponse;
+use crate::engine_bindings::interprocess::interprocess_session::InterprocessSession;
 use crate::engine_bindings::interprocess::pipes::interprocess_pipe_bidirectional::InterprocessPipeBidirectional;
+use crate::engine_bindings::interprocess::privileged_shell_launcher::PrivilegedShellLauncher;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use crossbeam_channel::Receiver;
 use crossbeam_channel::Sender;
 use olorin_engine_api::commands::engine_command::EngineCommand;
 use olorin_engine_api::commands::engine_command_response::EngineCommandResponse;
+use olorin_engine_api::commands::memory::memory_command::MemoryCommand;
+use olorin_engine_api::commands::process::process_command::ProcessCommand;
+use olorin_engine_api::commands::project::project_command::ProjectCommand;
+use olorin_engine_api::commands::scan::scan_command::ScanCommand;
+use olorin_engine_api::commands::scan_results::scan_results_command::ScanResultsCommand;
 use olorin_engine_api::events::engine_event::EngineEvent;
 use std::collections::HashMap;
-use std::io;
+use std::io::Write;
 use std::process::Child;
-use std::process::Command;
 use std::sync::Mutex;
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
+use std::time::Instant;
 use uuid::Uuid;
 
+/// A callback awaiting an engine response, along with the deadline after which its request is failed, if any.
+type RequestHandles = Arc<Mutex<HashMap<Uuid, (Option<Instant>, Box<dyn FnOnce(EngineCommandResponse) + Send + Sync>)>>>;
+
+/// How long to wait for the privileged shell to connect. This is generous, as the user may need to enter a password.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How long a request that is answered immediately may remain unanswered before its callback is failed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The interval at which pending requests are checked for timeouts.
+const REQUEST_TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The delay before respawning a privileged shell that has exited or failed to connect.
+const RESPAWN_DELAY: Duration = Duration::from_secs(1);
+
+/// The number of consecutive failed spawns after which we stop trying to respawn the privileged shell.
+const MAX_CONSECUTIVE_SPAWN_FAILURES: u32 = 5;
+
 pub struct InterprocessUnprivilegedHost {
     /// The spawned shell process with system privileges.
     privileged_shell_process: Arc<RwLock<Option<Child>>>,
 
     /// The bidirectional connection to the shell process.
-    ipc_connection: Arc<RwLock<Option<InterprocessPipeBidirectional>>>,
+    ipc_connection: Arc<RwLock<Option<Arc<InterprocessPipeBidirectional>>>>,
 
     /// A map of outgoing requests that are awaiting an engine response.
-    request_handles: Arc<Mutex<HashMap<Uuid, Box<dyn FnOnce(EngineCommandResponse) + Send + Sync>>>>,
+    request_handles: RequestHandles,
 
     /// The list of subscribers to which we send engine events, after having received them from the engine.
     event_senders: Arc<RwLock<Vec<Sender<EngineEvent>>>>,
@@ -46,23 +75,31 @@ impl EngineUnprivilegedBindings for InterprocessUnprivilegedHost {
         command: EngineCommand,
         callback: Box<dyn FnOnce(EngineCommandResponse) + Send + Sync + 'static>,
     ) -> Result<(), String> {
+        let ipc_connection = match self.ipc_connection.read() {
+            Ok(ipc_connection) => ipc_connection.clone(),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let Some(ipc_connection) = ipc_connection else {
+            return Err("Privileged shell is not connected.".to_string());
+        };
+
         let request_id = Uuid::new_v4();
+        let deadline = Self::get_request_timeout(&command).map(|request_timeout| Instant::now() + request_timeout);
 
         if let Ok(mut request_handles) = self.request_handles.lock() {
-            request_handles.insert(request_id, Box::new(callback));
+            request_handles.insert(request_id, (deadline, Box::new(callback)));
         }
 
-        if let Ok(ipc_connection) = self.ipc_connection.read() {
-            if let Some(ipc_connection) = ipc_connection.as_ref() {
-                if let Err(error) = ipc_connection.send(command, request_id) {
-                    return Err(error.to_string());
-                } else {
-                    return Ok(());
-                }
+        if let Err(error) = ipc_connection.send(command, request_id) {
+            if let Ok(mut request_handles) = self.request_handles.lock() {
+                request_handles.remove(&request_id);
             }
+
+            return Err(error);
         }
 
-        Err("Failed to dispatch command.".to_string())
+        Ok(())
     }
 
     /// Requests to listen to all engine events.
@@ -95,28 +132,231 @@ impl InterprocessUnprivilegedHost {
         let request_handles = self.request_handles.clone();
         let event_senders = self.event_senders.clone();
 
+        Self::start_request_timeout_monitor(self.request_handles.clone());
+
+        // Keep the privileged shell alive for the lifetime of the host, respawning it if it crashes.
         thread::spawn(move || {
-            if let Err(error) = Self::spawn_privileged_cli(privileged_shell_process) {
-                log::error!("Failed to spawn privileged cli: {}", error);
+            let privileged_shell_launcher = PrivilegedShellLauncher::new();
+            let mut consecutive_spawn_failures = 0;
+
+            while consecutive_spawn_failures < MAX_CONSECUTIVE_SPAWN_FAILURES {
+                match Self::start_privileged_shell(&privileged_shell_launcher, &privileged_shell_process, &ipc_connection) {
+                    Ok(()) => {
+                        consecutive_spawn_failures = 0;
+
+                        // Blocks until the connection to the shell is lost.
+                        Self::listen_for_shell_responses(&request_handles, &event_senders, &ipc_connection);
+                        log::error!("Lost connection to the privileged shell, respawning...");
+                    }
+                    Err(error) => {
+                        // Do not respawn if the user refused elevation, as this would just prompt them again.
+                        if let Some(refusal_message) = Self::get_refusal_message(&privileged_shell_launcher, &privileged_shell_process) {
+                            log::error!("Failed to start privileged shell. {}", refusal_message);
+                            Self::stop_privileged_shell(&privileged_shell_process, &ipc_connection, &request_handles);
+                            return;
+                        }
+
+                        consecutive_spawn_failures += 1;
+                        log::error!(
+                            "Failed to start privileged shell (attempt {}/{}): {}",
+                            consecutive_spawn_failures,
+                            MAX_CONSECUTIVE_SPAWN_FAILURES,
+                            error
+                        );
+                    }
+                }
+
+                Self::stop_privileged_shell(&privileged_shell_process, &ipc_connection, &request_handles);
+                thread::sleep(RESPAWN_DELAY);
+            }
+
+            log::error!("Giving up on starting the privileged shell. Engine commands will be non-functional.");
+        });
+    }
+
+    /// Spawns a new privileged shell with a fresh session, waits for it to connect, and validates its handshake.
+    fn start_privileged_shell(
+        privileged_shell_launcher: &PrivilegedShellLauncher,
+        privileged_shell_process: &Arc<RwLock<Option<Child>>>,
+        ipc_connection: &Arc<RwLock<Option<Arc<InterprocessPipeBidirectional>>>>,
+    ) -> Result<(), String> {
+        let session = InterprocessSession::create();
+
+        Self::spawn_privileged_cli(privileged_shell_launcher, privileged_shell_process, &session)?;
+
+        // Stop waiting for a connection as soon as the shell exits, ie if elevation was refused.
+        let is_shell_alive = || match privileged_shell_process.write() {
+            Ok(mut privileged_shell_process) => match privileged_shell_process.as_mut() {
+                Some(child) => matches!(child.try_wait(), Ok(None)),
+                None => false,
+            },
+            Err(_) => false,
+        };
+        let new_connection = InterprocessPipeBidirectional::create(&session, CONNECTION_TIMEOUT, &is_shell_alive)?;
+
+        Self::perform_handshake(&new_connection, &session)?;
+
+        match ipc_connection.write() {
+            Ok(mut ipc_connection) => {
+                *ipc_connection = Some(Arc::new(new_connection));
+                Ok(())
             }
+            Err(_error) => Err("Failed to acquire write lock on bidirectional interprocess connection.".to_string()),
+        }
+    }
+
+    /// Checks whether the privileged shell exited because elevation was refused, returning a user facing explanation if so.
+    fn get_refusal_message(
+        privileged_shell_launcher: &PrivilegedShellLauncher,
+        privileged_shell_process: &Arc<RwLock<Option<Child>>>,
+    ) -> Option<String> {
+        let mut privileged_shell_process = privileged_shell_process.write().ok()?;
+        let exit_status = privileged_shell_process.as_mut()?.try_wait().ok()??;
+
+        privileged_shell_launcher.get_refusal_message(exit_status)
+    }
 
-            if let Err(error) = Self::bind_to_interprocess_pipe(ipc_connection.clone()) {
-                log::error!("Failed to bind to inter process pipe: {}", error);
+    /// Kills the privileged shell if it is still running, drops the connection, and fails any requests still awaiting a response.
+    fn stop_privileged_shell(
+        privileged_shell_process: &Arc<RwLock<Option<Child>>>,
+        ipc_connection: &Arc<RwLock<Option<Arc<InterprocessPipeBidirectional>>>>,
+        request_handles: &RequestHandles,
+    ) {
+        if let Ok(mut ipc_connection) = ipc_connection.write() {
+            *ipc_connection = None;
+        }
+
+        if let Ok(mut privileged_shell_process) = privileged_shell_process.write() {
+            if let Some(mut child) = privileged_shell_process.take() {
+                let _ = child.kill();
+                let _ = child.wait();
             }
+        }
+
+        // Take the callbacks out before failing them, such that the lock is not held while the callbacks run.
+        let pending_requests: Vec<_> = match request_handles.lock() {
+            Ok(mut request_handles) => request_handles.drain().collect(),
+            Err(_) => vec![],
+        };
 
-            Self::listen_for_shell_responses(request_handles, event_senders, ipc_connection);
+        for (request_id, (_deadline, callback)) in pending_requests {
+            callback(EngineCommandResponse::Failed(format!(
+                "Engine request {} failed, as the privileged shell disconnected.",
+                request_id
+            )));
+        }
+    }
+
+    /// Validates the handshake sent by a newly connected shell, and replies with whether it was accepted.
+    fn perform_handshake(
+        ipc_connection: &InterprocessPipeBidirectional,
+        session: &InterprocessSession,
+    ) -> Result<(), String> {
+        let handshake = ipc_connection.receive_handshake::<InterprocessHandshake>()?;
+        let rejection = if !session.is_token_valid(&handshake.session_token) {
+            Some("Invalid session token.".to_string())
+        } else if handshake.protocol_version != INTERPROCESS_PROTOCOL_VERSION {
+            Some(format!(
+                "Shell protocol version {} (engine version {}) does not match host protocol version {}.",
+                handshake.protocol_version, handshake.engine_version, INTERPROCESS_PROTOCOL_VERSION
+            ))
+        } else if !handshake
+            .capabilities
+            .iter()
+            .any(|capability| capability == CAPABILITY_ENGINE_COMMANDS)
+        {
+            Some("Shell does not support engine commands.".to_string())
+        } else {
+            None
+        };
+
+        match rejection {
+            Some(error) => {
+                let _ = ipc_connection.send_handshake(InterprocessHandshakeResponse::reject(error.clone()));
+
+                Err(format!("Rejected privileged shell handshake: {}", error))
+            }
+            None => {
+                ipc_connection.send_handshake(InterprocessHandshakeResponse::accept())?;
+
+                log::info!(
+                    "Connected to privileged shell (engine version {}, capabilities: {:?}).",
+                    handshake.engine_version,
+                    handshake.capabilities
+                );
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Gets how long a request may remain unanswered before it is failed. Commands that do their work synchronously
+    /// (rather than through a trackable task) can legitimately outlast the request timeout, so these are only failed on disconnect.
+    fn get_request_timeout(command: &EngineCommand) -> Option<Duration> {
+        match command {
+            EngineCommand::Memory(MemoryCommand::Dissect { .. }) => None,
+            EngineCommand::Process(
+                ProcessCommand::Open { .. } | ProcessCommand::Save { .. } | ProcessCommand::Launch { .. } | ProcessCommand::Inject { .. },
+            ) => None,
+            EngineCommand::Project(
+                ProjectCommand::Open { .. }
+                | ProjectCommand::Save { .. }
+                | ProjectCommand::Export { .. }
+                | ProjectCommand::ImportStructs { .. }
+                | ProjectCommand::ExportStructs { .. },
+            ) => None,
+            EngineCommand::Results(ScanResultsCommand::Refresh { .. }) => None,
+            EngineCommand::Scan(ScanCommand::StructScan { .. } | ScanCommand::SignatureScan { .. }) => None,
+            _ => Some(REQUEST_TIMEOUT),
+        }
+    }
+
+    /// Periodically fails any requests that have gone unanswered past their deadline.
+    fn start_request_timeout_monitor(request_handles: RequestHandles) {
+        thread::spawn(move || {
+            loop {
+                let timed_out_requests: Vec<_> = match request_handles.lock() {
+                    Ok(mut request_handles) => {
+                        let timed_out_request_ids: Vec<Uuid> = request_handles
+                            .iter()
+                            .filter(|(_, (deadline, _))| deadline.is_some_and(|deadline| Instant::now() >= deadline))
+                            .map(|(request_id, _)| *request_id)
+                            .collect();
+
+                        timed_out_request_ids
+                            .into_iter()
+                            .filter_map(|request_id| Some((request_id, request_handles.remove(&request_id)?)))
+                            .collect()
+                    }
+                    Err(_) => vec![],
+                };
+
+                // Fail the callbacks outside of the lock, as they may dispatch further requests.
+                for (request_id, (_deadline, callback)) in timed_out_requests {
+                    callback(EngineCommandResponse::Failed(format!(
+                        "Engine request {} timed out after {:?}.",
+                        request_id, REQUEST_TIMEOUT
+                    )));
+                }
+
+                thread::sleep(REQUEST_TIMEOUT_POLL_INTERVAL);
+            }
         });
     }
 
     fn handle_engine_response(
-        request_handles: &Arc<Mutex<HashMap<Uuid, Box<dyn FnOnce(EngineCommandResponse) + Send + Sync>>>>,
+        request_handles: &RequestHandles,
         engine_response: EngineCommandResponse,
         request_id: Uuid,
     ) {
-        if let Ok(mut request_handles) = request_handles.lock() {
-            if let Some(callback) = request_handles.remove(&request_id) {
-                callback(engine_response);
-            }
+        // Take the callback out before invoking it, such that the lock is not held while the callback runs.
+        let callback = match request_handles.lock() {
+            Ok(mut request_handles) => request_handles.remove(&request_id),
+            Err(_) => None,
+        };
+
+        if let Some((_deadline, callback)) = callback {
+            callback(engine_response);
         }
     }
 
@@ -133,87 +373,59 @@ impl InterprocessUnprivilegedHost {
         }
     }
 
+    /// Listens for responses and events from the shell until the connection is lost.
     fn listen_for_shell_responses(
-        request_handles: Arc<Mutex<HashMap<Uuid, Box<dyn FnOnce(EngineCommandResponse) + Send + Sync>>>>,
-        event_senders: Arc<RwLock<Vec<Sender<EngineEvent>>>>,
-        ipc_connection: Arc<RwLock<Option<InterprocessPipeBidirectional>>>,
+        request_handles: &RequestHandles,
+        event_senders: &Arc<RwLock<Vec<Sender<EngineEvent>>>>,
+        ipc_connection: &Arc<RwLock<Option<Arc<InterprocessPipeBidirectional>>>>,
     ) {
-        let request_handles = request_handles.clone();
-        let event_senders = event_senders.clone();
-
-        thread::spawn(move || {
-            loop {
-                if let Ok(ipc_connection) = ipc_connection.read() {
-                    if let Some(ipc_connection) = ipc_connection.as_ref() {
-                        match ipc_connection.receive::<EngineEgress>() {
-                            Ok((interprocess_egress, request_id)) => match interprocess_egress {
-                                EngineEgress::EngineCommandResponse(engine_response) => {
-                                    Self::handle_engine_response(&request_handles, engine_response, request_id)
-                                }
-                                EngineEgress::EngineEvent(engine_event) => Self::handle_engine_event(&event_senders, engine_event),
-                            },
-                            Err(_error) => {
-                                std::process::exit(1);
-                            }
-                        }
-                    }
-                }
+        let ipc_connection = match ipc_connection.read() {
+            Ok(ipc_connection) => ipc_connection.clone(),
+            Err(_) => None,
+        };
 
-                thread::sleep(Duration::from_millis(1));
-            }
-        });
-    }
+        let Some(ipc_connection) = ipc_connection else {
+            return;
+        };
 
-    fn spawn_privileged_cli(privileged_shell_process: Arc<RwLock<Option<Child>>>) -> io::Result<()> {
-        match Self::spawn_olorin_cli_as_root() {
-            Ok(child) => {
-                // Update the server handle
-                if let Ok(mut server) = privileged_shell_process.write() {
-                    *server = Some(child);
+        loop {
+            match ipc_connection.receive::<EngineEgress>() {
+                Ok((interprocess_egress, request_id)) => match interprocess_egress {
+                    EngineEgress::EngineCommandResponse(engine_response) => Self::handle_engine_response(request_handles, engine_response, request_id),
+                    EngineEgress::EngineEvent(engine_event) => Self::handle_engine_event(event_senders, engine_event),
+                },
+                Err(error) => {
+                    log::error!("Error receiving from privileged shell: {}", error);
+                    return;
                 }
-
-                Ok(())
             }
-            Err(error) => Err(error),
         }
     }
 
-    fn bind_to_interprocess_pipe(ipc_connection: Arc<RwLock<Option<InterprocessPipeBidirectional>>>) -> Result<(), String> {
-        if let Ok(mut ipc_connection) = ipc_connection.write() {
-            match InterprocessPipeBidirectional::bind() {
-                Ok(bound_connection) => {
-                    *ipc_connection = Some(bound_connection);
-                    Ok(())
-                }
-                Err(error) => Err(error),
-            }
-        } else {
-            Err("Failed to acquire write lock on bidirectional interprocess connection.".to_string())
-        }
-    }
+    fn spawn_privileged_cli(
+        privileged_shell_launcher: &PrivilegedShellLauncher,
+        privileged_shell_process: &Arc<RwLock<Option<Child>>>,
+        session: &InterprocessSession,
+    ) -> Result<(), String> {
+        let mut child = privileged_shell_launcher.spawn()?;
 
-    #[cfg(any(target_os = "android"))]
-    fn spawn_olorin_cli_as_root() -> std::io::Result<std::process::Child> {
-        Logger::log(LogLevel::Info, "Spawning privileged worker...", None);
+        // Hand the session to the shell over stdin, keeping the token out of the process arguments. The stdin handle is kept
+        // open for the lifetime of the child, as the shell exits once its stdin is closed.
+        let session_line = session.to_stdin_line()?;
 
-        let child = Command::new("su")
-            .arg("-c")
-            .arg("/data/data/rust.olorin_android/files/olorin-cli")
-            .arg("--ipc-mode")
-            .spawn()?;
+        if let Some(stdin) = child.stdin.as_mut() {
+            if let Err(error) = stdin.write_all(session_line.as_bytes()).and_then(|_| stdin.flush()) {
+                let _ = child.kill();
 
-        Ok(child)
-    }
+                return Err(format!("Failed to send session to privileged shell: {}", error));
+            }
+        }
 
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    fn spawn_olorin_cli_as_root() -> io::Result<Child> {
-        Command::new("sudo").arg("olorin-cli").arg("--ipc-mode").spawn()
-    }
+        // Update the server handle.
+        if let Ok(mut server) = privileged_shell_process.write() {
+            *server = Some(child);
+        }
 
-    #[cfg(windows)]
-    fn spawn_olorin_cli_as_root() -> io::Result<Child> {
-        // No actual privilege escallation for windows -- this feature is not supposed to be used on windows at all.
-        // So, just spawn it normally for the rare occasion that we are testing this feature on windows.
-        Command::new("olorin-cli").arg("--ipc-mode").spawn()
+        Ok(())
     }
 }
@@ -1,3 +1,7 @@
+pub mod interprocess_handshake;
 pub mod interprocess_privileged_shell;
+pub mod interprocess_session;
 pub mod interprocess_unprivileged_host;
+pub mod privilege_elevation_method;
+pub mod privileged_shell_launcher;
 mod pipes;
@@ -1,6 +1,8 @@
+use crate::engine_bindings::interprocess::interprocess_session::InterprocessSession;
 use crate::engine_bindings::interprocess::pipes::interprocess_pipe_unidirectional::InterprocessPipeUnidirectional;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::time::Duration;
 use uuid::Uuid;
 
 pub struct InterprocessPipeBidirectional {
@@ -9,15 +11,24 @@ pub struct InterprocessPipeBidirectional {
 }
 
 impl InterprocessPipeBidirectional {
-    pub fn create() -> Result<Self, String> {
-        let pipe_receive = InterprocessPipeUnidirectional::create(true)?;
-        let pipe_send = InterprocessPipeUnidirectional::create(false)?;
+    /// Creates the session pipes and waits for the privileged shell to connect. Used by the host.
+    pub fn create(
+        session: &InterprocessSession,
+        timeout: Duration,
+        is_peer_alive: &dyn Fn() -> bool,
+    ) -> Result<Self, String> {
+        let pipe_send = InterprocessPipeUnidirectional::create(&session.get_socket_name(true), timeout, is_peer_alive)?;
+        let pipe_receive = InterprocessPipeUnidirectional::create(&session.get_socket_name(false), timeout, is_peer_alive)?;
         Ok(Self { pipe_receive, pipe_send })
     }
 
-    pub fn bind() -> Result<Self, String> {
-        let pipe_send = InterprocessPipeUnidirectional::bind(true)?;
-        let pipe_receive = InterprocessPipeUnidirectional::bind(false)?;
+    /// Connects to the session pipes created by the host. Used by the privileged shell.
+    pub fn bind(
+        session: &InterprocessSession,
+        timeout: Duration,
+    ) -> Result<Self, String> {
+        let pipe_receive = InterprocessPipeUnidirectional::bind(&session.get_socket_name(true), timeout)?;
+        let pipe_send = InterprocessPipeUnidirectional::bind(&session.get_socket_name(false), timeout)?;
         Ok(Self { pipe_receive, pipe_send })
     }
 
@@ -32,4 +43,15 @@ impl InterprocessPipeBidirectional {
     pub fn receive<T: DeserializeOwned>(&self) -> Result<(T, Uuid), String> {
         self.pipe_receive.ipc_receive()
     }
+
+    pub fn send_handshake<T: Serialize>(
+        &self,
+        value: T,
+    ) -> Result<(), String> {
+        self.pipe_send.ipc_send_handshake(value)
+    }
+
+    pub fn receive_handshake<T: DeserializeOwned>(&self) -> Result<T, String> {
+        self.pipe_receive.ipc_receive_handshake()
+    }
 }
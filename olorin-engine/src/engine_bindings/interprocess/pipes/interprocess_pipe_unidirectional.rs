@@ -1,15 +1,19 @@
+use interprocess::local_socket::ListenerNonblockingMode;
 use interprocess::local_socket::ListenerOptions;
 use interprocess::local_socket::Name;
 use interprocess::local_socket::prelude::LocalSocketStream;
-use interprocess::local_socket::traits::ListenerExt;
+use interprocess::local_socket::traits::Listener;
 use interprocess::local_socket::traits::Stream;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Write;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 use uuid::Uuid;
 
 #[cfg(not(target_os = "android"))]
@@ -24,27 +28,35 @@ use interprocess::local_socket::GenericNamespaced as NamedPipeType;
 #[cfg(windows)]
 use interprocess::os::windows::local_socket::NamedPipe as NamedPipeType;
 
-#[cfg(windows)]
-const IPC_SOCKET_PATH_TO_SHELL: &str = "\\\\.\\pipe\\interprocess-shell-to-shell";
 #[cfg(all(not(windows), not(target_os = "android")))]
-const IPC_SOCKET_PATH_TO_SHELL: &str = "/tmp/interprocess-shell-to-shell.sock";
-#[cfg(target_os = "android")]
-const IPC_SOCKET_PATH_TO_SHELL: &str = "interprocess-shell-to-shell";
-
-#[cfg(windows)]
-const IPC_SOCKET_PATH_OUTBOND: &str = "\\\\.\\pipe\\interprocess-shell-from-shell";
+use interprocess::os::unix::local_socket::ListenerOptionsExt;
 #[cfg(all(not(windows), not(target_os = "android")))]
-const IPC_SOCKET_PATH_OUTBOND: &str = "/tmp/interprocess-shell-from-shell.sock";
-#[cfg(target_os = "android")]
-const IPC_SOCKET_PATH_OUTBOND: &str = "interprocess-shell-from-shell";
+use std::fs;
+#[cfg(all(not(windows), not(target_os = "android")))]
+use std::path::Path;
+
+/// Prefixes handshake payloads, such that a mismatched build is rejected before any attempt at deserializing its messages.
+const HANDSHAKE_MAGIC: &[u8; 8] = b"OLORNIPC";
+
+/// The largest frame we are willing to allocate for. Anything larger is assumed to be a corrupt or malicious stream.
+const MAX_FRAME_SIZE: usize = 512 * 1024 * 1024;
+
+/// The interval at which pending connections are polled while waiting for the other side of the pipe.
+const CONNECTION_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 pub struct InterprocessPipeUnidirectional {
     socket_stream: Arc<Mutex<Option<LocalSocketStream>>>,
 }
 
 impl InterprocessPipeUnidirectional {
-    pub fn create(to_shell: bool) -> Result<Self, String> {
-        match Self::create_interprocess_pipe(to_shell) {
+    /// Creates the pipe by listening on the given socket name and accepting exactly one connection within the timeout.
+    /// Gives up early if the provided check reports that the process expected to connect is no longer alive.
+    pub fn create(
+        socket_name: &str,
+        timeout: Duration,
+        is_peer_alive: &dyn Fn() -> bool,
+    ) -> Result<Self, String> {
+        match Self::create_interprocess_pipe(socket_name, timeout, is_peer_alive) {
             Ok(socket_stream) => Ok(Self {
                 socket_stream: Arc::new(Mutex::new(Some(socket_stream))),
             }),
@@ -52,8 +64,12 @@ impl InterprocessPipeUnidirectional {
         }
     }
 
-    pub fn bind(to_shell: bool) -> Result<Self, String> {
-        match Self::bind_to_interprocess_pipe(to_shell) {
+    /// Binds to a pipe created by another process, retrying until the timeout elapses.
+    pub fn bind(
+        socket_name: &str,
+        timeout: Duration,
+    ) -> Result<Self, String> {
+        match Self::bind_to_interprocess_pipe(socket_name, timeout) {
             Ok(socket_stream) => Ok(Self {
                 socket_stream: Arc::new(Mutex::new(Some(socket_stream))),
             }),
@@ -63,89 +79,97 @@ impl InterprocessPipeUnidirectional {
 
     /// Creates a single manager connection: effectively "binds" to the socket
     /// (or named pipe on Windows), listens, and accepts exactly one incoming connection.
-    fn create_interprocess_pipe(to_shell: bool) -> Result<LocalSocketStream, String> {
-        let ipc_socket_path = if to_shell { IPC_SOCKET_PATH_TO_SHELL } else { IPC_SOCKET_PATH_OUTBOND };
-
-        // On Unix-like non-Android systems, remove any leftover socket file
+    fn create_interprocess_pipe(
+        socket_name: &str,
+        timeout: Duration,
+        is_peer_alive: &dyn Fn() -> bool,
+    ) -> Result<LocalSocketStream, String> {
+        // On Unix-like non-Android systems, remove any leftover socket file.
         #[cfg(all(not(windows), not(target_os = "android")))]
         {
-            if Path::new(ipc_socket_path).exists() {
-                fs::remove_file(ipc_socket_path)?;
+            if Path::new(socket_name).exists() {
+                fs::remove_file(socket_name).map_err(|error| error.to_string())?;
             }
         }
 
-        #[cfg(not(target_os = "android"))]
-        let name: Name<'_> = match ipc_socket_path.to_fs_name::<NamedPipeType>() {
-            Ok(name) => name,
-            Err(error) => {
-                return Err(error.to_string());
-            }
-        };
+        let name = Self::to_socket_name(socket_name)?;
+        let listener_options = ListenerOptions::new().name(name);
 
-        #[cfg(target_os = "android")]
-        let name: Name<'_> = match ipc_socket_path.to_ns_name::<NamedPipeType>() {
-            Ok(name) => name,
-            Err(error) => {
-                return Err(error.to_string());
-            }
-        };
+        // Only the current user may connect to the socket. The privileged shell can still connect, as it runs as root.
+        #[cfg(all(not(windows), not(target_os = "android")))]
+        let listener_options = listener_options.mode(0o600);
 
-        // Create the listener using ListenerOptions
-        let listener = match ListenerOptions::new().name(name).create_sync() {
+        // Create the listener using ListenerOptions.
+        let listener = match listener_options.create_sync() {
             Ok(listener) => listener,
             Err(error) => {
                 return Err(error.to_string());
             }
         };
 
-        // Accept one connection
-        let stream = match listener.incoming().next() {
-            Some(Ok(conn)) => conn,
-            Some(Err(error)) => return Err(error.to_string()),
-            None => return Err("Manager: no connection arrived.".to_string()),
-        };
+        // Accept is non-blocking so that we can give up if the other process never connects.
+        listener
+            .set_nonblocking(ListenerNonblockingMode::Accept)
+            .map_err(|error| error.to_string())?;
 
-        Ok(stream)
-    }
+        let start_time = Instant::now();
 
-    fn bind_to_interprocess_pipe(to_shell: bool) -> Result<LocalSocketStream, String> {
-        const MAX_RETRIES: u32 = 256;
-        let ipc_socket_path = if to_shell { IPC_SOCKET_PATH_TO_SHELL } else { IPC_SOCKET_PATH_OUTBOND };
-        let retry_delay = std::time::Duration::from_millis(100);
+        loop {
+            match listener.accept() {
+                Ok(stream) => return Ok(stream),
+                Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                    if start_time.elapsed() >= timeout {
+                        return Err(format!("No connection arrived on {} within {:?}.", socket_name, timeout));
+                    }
 
-        #[cfg(not(target_os = "android"))]
-        let name: Name<'_> = match ipc_socket_path.to_fs_name::<NamedPipeType>() {
-            Ok(name) => name,
-            Err(error) => {
-                return Err(error.to_string());
-            }
-        };
+                    if !is_peer_alive() {
+                        return Err(format!("The process expected to connect to {} exited.", socket_name));
+                    }
 
-        #[cfg(target_os = "android")]
-        let name: Name<'_> = match ipc_socket_path.to_ns_name::<NamedPipeType>() {
-            Ok(name) => name,
-            Err(error) => {
-                return Err(error.to_string());
+                    thread::sleep(CONNECTION_POLL_INTERVAL);
+                }
+                Err(error) => return Err(error.to_string()),
             }
-        };
+        }
+    }
+
+    fn bind_to_interprocess_pipe(
+        socket_name: &str,
+        timeout: Duration,
+    ) -> Result<LocalSocketStream, String> {
+        let name = Self::to_socket_name(socket_name)?;
+        let start_time = Instant::now();
 
-        for _attempt in 1..=MAX_RETRIES {
-            thread::sleep(retry_delay);
+        while start_time.elapsed() < timeout {
+            thread::sleep(CONNECTION_POLL_INTERVAL);
             match LocalSocketStream::connect(name.clone()) {
                 Ok(stream) => {
                     return Ok(stream);
                 }
                 Err(_) => {
-                    // Ignore, we will keep retrying up to the max retries.
+                    // Ignore, we will keep retrying until the timeout elapses.
                 }
             }
         }
 
-        Err("Failed to create IPC connection!".to_string())
+        Err(format!("Failed to connect to {} within {:?}.", socket_name, timeout))
+    }
+
+    #[cfg(not(target_os = "android"))]
+    fn to_socket_name(socket_name: &str) -> Result<Name<'_>, String> {
+        socket_name
+            .to_fs_name::<NamedPipeType>()
+            .map_err(|error| error.to_string())
+    }
+
+    #[cfg(target_os = "android")]
+    fn to_socket_name(socket_name: &str) -> Result<Name<'_>, String> {
+        socket_name
+            .to_ns_name::<NamedPipeType>()
+            .map_err(|error| error.to_string())
     }
 
     /// Sends a value of generic type `T` (which must implement `Serialize`) over the IPC connection.
-    /// Returns the serialized bytes on success.
     pub fn ipc_send<T: Serialize>(
         &self,
         value: T,
@@ -154,11 +178,52 @@ impl InterprocessPipeUnidirectional {
         // Serialize the data.
         let serialized_data = bincode::serialize(&value).map_err(|error| format!("Serialize error: {}", error))?;
 
+        self.write_frame(&serialized_data, request_id)
+    }
+
+    /// Receives a value of generic type `T` (which must implement `DeserializeOwned`) from the IPC connection.
+    pub fn ipc_receive<T: DeserializeOwned>(&self) -> Result<(T, Uuid), String> {
+        let (data_buf, request_id) = self.read_frame()?;
+
+        // Deserialize the data into T.
+        let value = bincode::deserialize::<T>(&data_buf).map_err(|error| format!("Deserialize error: {}", error))?;
+
+        Ok((value, request_id))
+    }
+
+    /// Sends a handshake message. Unlike regular messages, handshakes are serialized as JSON behind a magic prefix,
+    /// which keeps them readable by builds that disagree on the layout of engine commands.
+    pub fn ipc_send_handshake<T: Serialize>(
+        &self,
+        value: T,
+    ) -> Result<(), String> {
+        let mut serialized_data = HANDSHAKE_MAGIC.to_vec();
+
+        serialized_data.extend(serde_json::to_vec(&value).map_err(|error| format!("Serialize handshake error: {}", error))?);
+
+        self.write_frame(&serialized_data, Uuid::nil())
+    }
+
+    /// Receives a handshake message, rejecting anything that does not carry the handshake magic.
+    pub fn ipc_receive_handshake<T: DeserializeOwned>(&self) -> Result<T, String> {
+        let (data_buf, _request_id) = self.read_frame()?;
+
+        match data_buf.strip_prefix(HANDSHAKE_MAGIC.as_slice()) {
+            Some(handshake_data) => serde_json::from_slice::<T>(handshake_data).map_err(|error| format!("Deserialize handshake error: {}", error)),
+            None => Err("Received an invalid handshake. The other process is likely from an incompatible build.".to_string()),
+        }
+    }
+
+    fn write_frame(
+        &self,
+        data: &[u8],
+        request_id: Uuid,
+    ) -> Result<(), String> {
         // Acquire write lock on the connection to send in a thread-safe manner.
         if let Ok(stream) = self.socket_stream.lock() {
             if let Some(mut stream) = stream.as_ref() {
                 let request_id_bytes = request_id.as_bytes();
-                let len = (request_id_bytes.len() + serialized_data.len()) as u32;
+                let len = (request_id_bytes.len() + data.len()) as u32;
 
                 // First send length as u32 in little-endian.
                 stream
@@ -172,7 +237,7 @@ impl InterprocessPipeUnidirectional {
 
                 // Then send the actual data.
                 stream
-                    .write_all(&serialized_data)
+                    .write_all(data)
                     .map_err(|error| format!("Write serialized data error: {}", error))?;
 
                 stream
@@ -188,9 +253,8 @@ impl InterprocessPipeUnidirectional {
         }
     }
 
-    /// Receives a value of generic type `T` (which must implement `DeserializeOwned`) from the IPC connection.
-    pub fn ipc_receive<T: DeserializeOwned>(&self) -> Result<(T, Uuid), String> {
-        // Acquire read lock on the connection
+    fn read_frame(&self) -> Result<(Vec<u8>, Uuid), String> {
+        // Acquire read lock on the connection.
         if let Ok(stream) = self.socket_stream.lock() {
             if let Some(mut stream) = stream.as_ref() {
                 // First read the length (4 bytes).
@@ -198,7 +262,11 @@ impl InterprocessPipeUnidirectional {
                 stream
                     .read_exact(&mut len_buf)
                     .map_err(|error| format!("Read length error: {}", error))?;
-                let total_len = u32::from_le_bytes(len_buf);
+                let total_len = u32::from_le_bytes(len_buf) as usize;
+
+                if total_len < size_of::<Uuid>() || total_len > MAX_FRAME_SIZE {
+                    return Err(format!("Invalid frame length: {}", total_len));
+                }
 
                 // Next read the uuid (16 bytes).
                 let mut request_id_buf = [0u8; size_of::<Uuid>()];
@@ -208,18 +276,15 @@ impl InterprocessPipeUnidirectional {
                 let request_id = Uuid::from_bytes(request_id_buf);
 
                 // Finally read the remaining data (total_len - request_id size).
-                let data_len = total_len as usize - size_of::<Uuid>();
+                let data_len = total_len - size_of::<Uuid>();
                 let mut data_buf = vec![0u8; data_len];
                 stream
                     .read_exact(&mut data_buf)
                     .map_err(|error| format!("Read data error: {}", error))?;
 
-                // Deserialize the data into T
-                let value = bincode::deserialize::<T>(&data_buf).map_err(|error| format!("Deserialize error: {}", error))?;
-
-                Ok((value, request_id))
+                Ok((data_buf, request_id))
             } else {
-                Err("No stream set, failed to send data.".to_string())
+                Err("No stream set, failed to receive data.".to_string())
             }
         } else {
             Err("Failed to acquire connection lock".to_string())
@@ -0,0 +1,58 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines how the privileged shell is granted the privileges required for memory access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrivilegeElevationMethod {
+    /// The shell is launched directly. Used when the host is already elevated, or on platforms without privilege separation.
+    None,
+
+    /// The shell is launched through pkexec, which shows a graphical polkit authentication prompt.
+    Pkexec,
+
+    /// The shell is launched through `sudo -A`, which prompts for a password using the program in `SUDO_ASKPASS`.
+    SudoAskpass,
+
+    /// The shell is launched through sudo, which prompts for a password on the terminal.
+    Sudo,
+
+    /// The shell is launched directly, relying on file capabilities (ie `setcap cap_sys_ptrace+ep`) set on the shell executable.
+    SetcapHelper,
+
+    /// The shell is launched through su. Used on rooted Android devices.
+    Su,
+}
+
+impl fmt::Display for PrivilegeElevationMethod {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        let name = match self {
+            PrivilegeElevationMethod::None => "none",
+            PrivilegeElevationMethod::Pkexec => "pkexec",
+            PrivilegeElevationMethod::SudoAskpass => "sudo-askpass",
+            PrivilegeElevationMethod::Sudo => "sudo",
+            PrivilegeElevationMethod::SetcapHelper => "setcap",
+            PrivilegeElevationMethod::Su => "su",
+        };
+
+        write!(formatter, "{}", name)
+    }
+}
+
+impl FromStr for PrivilegeElevationMethod {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "none" => Ok(PrivilegeElevationMethod::None),
+            "pkexec" => Ok(PrivilegeElevationMethod::Pkexec),
+            "sudo-askpass" => Ok(PrivilegeElevationMethod::SudoAskpass),
+            "sudo" => Ok(PrivilegeElevationMethod::Sudo),
+            "setcap" => Ok(PrivilegeElevationMethod::SetcapHelper),
+            "su" => Ok(PrivilegeElevationMethod::Su),
+            _ => Err(format!("Invalid privilege elevation method: '{}'", string)),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::engine_bindings::interprocess::privilege_elevation_method::PrivilegeElevationMethod;
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::Command;
+use std::process::ExitStatus;
+use std::process::Stdio;
+
+/// An environment variable that overrides the automatically selected privilege elevation method.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+const ELEVATION_METHOD_ENVIRONMENT_VARIABLE: &str = "OLORIN_ELEVATION";
+
+/// The name of the executable that runs as the privileged shell.
+const SHELL_EXECUTABLE_NAME: &str = "olorin-cli";
+
+/// The argument that instructs the shell executable to run in privileged shell mode.
+const SHELL_IPC_MODE_ARGUMENT: &str = "--ipc-mode";
+
+#[cfg(target_os = "android")]
+const ANDROID_SHELL_PATH: &str = "/data/data/rust.olorin_android/files/olorin-cli";
+
+/// Locates the privileged shell executable, and launches it with the appropriate privilege elevation method for this platform.
+pub struct PrivilegedShellLauncher {
+    elevation_method: PrivilegeElevationMethod,
+    shell_path: PathBuf,
+}
+
+impl PrivilegedShellLauncher {
+    pub fn new() -> Self {
+        let shell_path = Self::find_shell_executable();
+        let elevation_method = Self::resolve_elevation_method(&shell_path);
+
+        Self { elevation_method, shell_path }
+    }
+
+    pub fn get_elevation_method(&self) -> PrivilegeElevationMethod {
+        self.elevation_method
+    }
+
+    pub fn get_shell_path(&self) -> &Path {
+        &self.shell_path
+    }
+
+    /// Spawns the privileged shell with a piped stdin, over which the host passes the interprocess session.
+    pub fn spawn(&self) -> Result<Child, String> {
+        let (program, mut command) = match self.elevation_method {
+            PrivilegeElevationMethod::None | PrivilegeElevationMethod::SetcapHelper => {
+                let mut command = Command::new(&self.shell_path);
+                command.arg(SHELL_IPC_MODE_ARGUMENT);
+                (self.shell_path.display().to_string(), command)
+            }
+            PrivilegeElevationMethod::Pkexec => {
+                // Pkexec requires an absolute path, which find_shell_executable guarantees when the shell can be found.
+                let mut command = Command::new("pkexec");
+                command.arg(&self.shell_path).arg(SHELL_IPC_MODE_ARGUMENT);
+                ("pkexec".to_string(), command)
+            }
+            PrivilegeElevationMethod::SudoAskpass => {
+                let mut command = Command::new("sudo");
+                command
+                    .arg("-A")
+                    .arg("--")
+                    .arg(&self.shell_path)
+                    .arg(SHELL_IPC_MODE_ARGUMENT);
+                ("sudo".to_string(), command)
+            }
+            PrivilegeElevationMethod::Sudo => {
+                let mut command = Command::new("sudo");
+                command
+                    .arg("--")
+                    .arg(&self.shell_path)
+                    .arg(SHELL_IPC_MODE_ARGUMENT);
+                ("sudo".to_string(), command)
+            }
+            PrivilegeElevationMethod::Su => {
+                let mut command = Command::new("su");
+                command
+                    .arg("-c")
+                    .arg(format!("{} {}", self.shell_path.display(), SHELL_IPC_MODE_ARGUMENT));
+                ("su".to_string(), command)
+            }
+        };
+
+        log::info!(
+            "Spawning privileged shell {} (elevation method: {})...",
+            self.shell_path.display(),
+            self.elevation_method
+        );
+
+        command
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|error| format!("Failed to launch {}: {}", program, error))
+    }
+
+    /// Interprets the exit status of a shell that exited before connecting. Returns a message if the exit indicates that
+    /// the user refused (or failed) elevation, in which case respawning would only prompt the user again.
+    pub fn get_refusal_message(
+        &self,
+        exit_status: ExitStatus,
+    ) -> Option<String> {
+        match (self.elevation_method, exit_status.code()) {
+            (PrivilegeElevationMethod::Pkexec, Some(126)) => {
+                Some("Elevation was refused: the polkit authentication dialog was dismissed, or the action is not authorized.".to_string())
+            }
+            (PrivilegeElevationMethod::Pkexec, Some(127)) => Some("Elevation was refused: polkit authentication failed.".to_string()),
+            (PrivilegeElevationMethod::SudoAskpass, Some(1)) => Some(
+                "Elevation was refused by sudo. Check that SUDO_ASKPASS points to a working askpass program, and that this user may use sudo."
+                    .to_string(),
+            ),
+            (PrivilegeElevationMethod::Sudo, Some(1)) => Some("Elevation was refused by sudo. Check the password, and that this user may use sudo.".to_string()),
+            (PrivilegeElevationMethod::Su, Some(1)) => Some("Elevation was refused by su. Check that root access was granted to Olorin.".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Determines whether the current process is already running as root.
+    #[cfg(unix)]
+    pub fn is_running_as_root() -> bool {
+        unsafe { libc::geteuid() == 0 }
+    }
+
+    /// Determines whether the current process is already running as root.
+    #[cfg(not(unix))]
+    pub fn is_running_as_root() -> bool {
+        false
+    }
+
+    #[cfg(target_os = "android")]
+    fn find_shell_executable() -> PathBuf {
+        PathBuf::from(ANDROID_SHELL_PATH)
+    }
+
+    /// Prefers the shell next to the current executable, falling back to searching the path.
+    #[cfg(not(target_os = "android"))]
+    fn find_shell_executable() -> PathBuf {
+        let shell_executable_name = format!("{}{}", SHELL_EXECUTABLE_NAME, env::consts::EXE_SUFFIX);
+
+        if let Ok(current_executable) = env::current_exe() {
+            if let Some(executable_directory) = current_executable.parent() {
+                let shell_path = executable_directory.join(&shell_executable_name);
+
+                if shell_path.is_file() {
+                    return shell_path;
+                }
+            }
+        }
+
+        Self::find_in_path(&shell_executable_name).unwrap_or_else(|| PathBuf::from(shell_executable_name))
+    }
+
+    fn find_in_path(program: &str) -> Option<PathBuf> {
+        let path_variable = env::var_os("PATH")?;
+
+        env::split_paths(&path_variable)
+            .map(|directory| directory.join(program))
+            .find(|candidate| candidate.is_file())
+    }
+
+    #[cfg(target_os = "android")]
+    fn resolve_elevation_method(_shell_path: &Path) -> PrivilegeElevationMethod {
+        PrivilegeElevationMethod::Su
+    }
+
+    #[cfg(windows)]
+    fn resolve_elevation_method(_shell_path: &Path) -> PrivilegeElevationMethod {
+        // No actual privilege escallation for windows -- this feature is not supposed to be used on windows at all.
+        // So, just spawn it normally for the rare occasion that we are testing this feature on windows.
+        PrivilegeElevationMethod::None
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    fn resolve_elevation_method(shell_path: &Path) -> PrivilegeElevationMethod {
+        if Self::is_running_as_root() {
+            return PrivilegeElevationMethod::None;
+        }
+
+        if let Ok(elevation_method) = env::var(ELEVATION_METHOD_ENVIRONMENT_VARIABLE) {
+            match elevation_method.parse::<PrivilegeElevationMethod>() {
+                Ok(elevation_method) => return elevation_method,
+                Err(error) => log::warn!("{}, falling back to automatic selection.", error),
+            }
+        }
+
+        if Self::has_file_capabilities(shell_path) {
+            PrivilegeElevationMethod::SetcapHelper
+        } else if cfg!(target_os = "linux") && Self::find_in_path("pkexec").is_some() && Self::has_graphical_session() {
+            PrivilegeElevationMethod::Pkexec
+        } else if env::var_os("SUDO_ASKPASS").is_some() {
+            PrivilegeElevationMethod::SudoAskpass
+        } else {
+            PrivilegeElevationMethod::Sudo
+        }
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    fn has_graphical_session() -> bool {
+        env::var_os("WAYLAND_DISPLAY").is_some() || env::var_os("DISPLAY").is_some()
+    }
+
+    /// Checks whether file capabilities have been granted to the given executable, ie via `setcap cap_sys_ptrace+ep`.
+    #[cfg(target_os = "linux")]
+    fn has_file_capabilities(executable_path: &Path) -> bool {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let Ok(executable_path) = CString::new(executable_path.as_os_str().as_bytes()) else {
+            return false;
+        };
+
+        unsafe { libc::getxattr(executable_path.as_ptr(), c"security.capability".as_ptr(), std::ptr::null_mut(), 0) > 0 }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn has_file_capabilities(_executable_path: &Path) -> bool {
+        false
+    }
+}
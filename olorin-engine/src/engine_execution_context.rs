@@ -5,6 +5,7 @@ use crate::engine_mode::EngineMode;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use olorin_engine_api::commands::engine_command_response::EngineCommandResponse;
 use olorin_engine_api::events::engine_event::EngineEventRequest;
+use olorin_engine_api::events::log::log_event::LogEvent;
 use olorin_engine_api::events::process::process_event::ProcessEvent;
 use olorin_engine_api::events::project::project_event::ProjectEvent;
 use olorin_engine_api::events::scan_results::scan_results_event::ScanResultsEvent;
@@ -132,11 +133,12 @@ impl EngineExecutionContext {
             }
         };
         let event_listeners = self.event_listeners.clone();
+        let file_system_logger = self.file_system_logger.clone();
 
         std::thread::spawn(move || {
             loop {
                 match event_receiver.recv() {
-                    Ok(engine_event) => Self::route_engine_event(&event_listeners, engine_event),
+                    Ok(engine_event) => Self::route_engine_event(&event_listeners, &file_system_logger, engine_event),
                     Err(error) => {
                         log::error!("Fatal error listening for engine events: {}", error);
                         return;
@@ -149,6 +151,7 @@ impl EngineExecutionContext {
     /// Deconstructs an engine event to extract the particular event structure being sent, and routes it to the proper event listeners.
     fn route_engine_event(
         event_listeners: &Arc<RwLock<HashMap<TypeId, Vec<Box<dyn Fn(&dyn Any) + Send + Sync>>>>>,
+        file_system_logger: &Arc<FileSystemLogger>,
         engine_event: EngineEvent,
     ) {
         match engine_event {
@@ -172,12 +175,23 @@ impl EngineExecutionContext {
                 ScanResultsEvent::ScanResultsUpdated { scan_results_updated_event } => {
                     Self::dispatch_engine_event(&event_listeners, scan_results_updated_event);
                 }
+                ScanResultsEvent::ScanResultsValuesChanged {
+                    scan_results_values_changed_event,
+                } => {
+                    Self::dispatch_engine_event(&event_listeners, scan_results_values_changed_event);
+                }
             },
             EngineEvent::TrackableTask(trackable_task_event) => match trackable_task_event {
                 TrackableTaskEvent::ProgressChanged { progress_changed_event } => {
                     Self::dispatch_engine_event(&event_listeners, progress_changed_event);
                 }
             },
+            // Logs from a privileged shell are shown alongside local logs, such as in the output view.
+            EngineEvent::Log(log_event) => match log_event {
+                LogEvent::LogMessage { log_message_event } => {
+                    file_system_logger.forward_log_message(log_message_event.log_message);
+                }
+            },
         }
     }
 
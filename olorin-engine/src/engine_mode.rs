@@ -1,14 +1,15 @@
 /// Defines the mode in which the engine is running.
 /// - Standalone engine is self-handling. This is the most common way Olorin is used.
-/// - Unprivileged host sends data via ipc. Used on platforms like Android.
-/// - Privileged shell returns data via ipc. Used on platforms like Android.
+/// - Unprivileged host sends data via ipc. Used on platforms like Android, and by the Linux gui.
+/// - Privileged shell returns data via ipc. Used on platforms like Android, and by the Linux gui.
 #[derive(Clone, Copy, PartialEq)]
 pub enum EngineMode {
     /// Standalone mode grants full functionality. This is the most common mode.
     Standalone,
 
     /// In Unprivileged Host mode, we only send and receive engine commands from the privileged shell.
-    /// This is necessary on some platforms like Android, where the main process may be unprivileged.
+    /// This is necessary on some platforms like Android, where the main process may be unprivileged, and on Linux,
+    /// where running the gui as root breaks Wayland/X authentication.
     UnprivilegedHost,
 
     /// The privileged shell does heavy lifting (scanning, debugging, etc) and sends responses to the host.
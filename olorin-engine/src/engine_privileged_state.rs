@@ -1,16 +1,28 @@
+use crate::edit_history::edit_history_manager::EditHistoryManager;
 use crate::engine_bindings::interprocess::interprocess_privileged_shell::InterprocessPrivilegedShell;
 use crate::engine_bindings::{engine_priviliged_bindings::EnginePrivilegedBindings, standalone::standalone_privileged_engine::StandalonePrivilegedEngine};
 use crate::engine_mode::EngineMode;
+use crate::process_reattach::process_reattach_handler::ProcessReattachHandler;
 use crate::tasks::trackable_task_manager::TrackableTaskManager;
 use crossbeam_channel::Receiver;
 use olorin_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use olorin_engine_api::events::process::changed::process_change_kind::ProcessChangeKind;
+use olorin_engine_api::events::process::process_event::ProcessEvent;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::results::scan_results_watch_list::ScanResultsWatchList;
 use olorin_engine_api::structures::results::snapshot_scan_result_freeze_list::SnapshotScanResultFreezeList;
 use olorin_engine_api::structures::snapshots::snapshot::Snapshot;
+use olorin_engine_memory::virtual_targets::virtual_memory_target_trait::IVirtualMemoryTarget;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
 use olorin_engine_processes::process::process_manager::ProcessManager;
+use olorin_engine_processes::process_query::process_query_options::ProcessQueryOptions;
 use olorin_engine_processes::process_query::process_queryer::ProcessQuery;
+use olorin_engine_processes::thread_query::thread_stack_module_provider::ThreadStackModuleProvider;
 use olorin_engine_projects::project::project_manager::ProjectManager;
+use olorin_engine_scanning::results::scan_results_watch_task::ScanResultsWatchTask;
 use olorin_engine_scanning::results::snapshot_scan_result_freeze_task::SnapshotScanResultFreezeTask;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, Weak};
+use sysinfo::Pid;
 
 /// Tracks critical engine state for internal use. This includes executing engine tasks, commands, and events.
 pub struct EnginePrivilegedState {
@@ -29,6 +41,12 @@ pub struct EnginePrivilegedState {
     // The list of frozen scan results.
     snapshot_scan_result_freeze_list: Arc<RwLock<SnapshotScanResultFreezeList>>,
 
+    /// The scan results that subscribers are watching for value changes.
+    scan_results_watch_list: Arc<ScanResultsWatchList>,
+
+    /// The journal of reversible edits, such as memory writes and freezes, used to support undo and redo.
+    edit_history_manager: Arc<EditHistoryManager>,
+
     /// Defines functionality that can be invoked by the engine for the GUI or CLI to handle.
     engine_bindings: Arc<RwLock<dyn EnginePrivilegedBindings>>,
 }
@@ -41,25 +59,36 @@ impl EnginePrivilegedState {
             EngineMode::UnprivilegedHost => unreachable!("Privileged state should never be created on an unprivileged host."),
         };
 
-        let event_emitter = Self::create_event_emitter(engine_bindings.clone());
-        let process_manager = ProcessManager::new(event_emitter.clone());
-        let project_manager = ProjectManager::new(event_emitter);
-        let task_manager = TrackableTaskManager::new();
-        let snapshot = Arc::new(RwLock::new(Snapshot::new()));
-        let snapshot_scan_result_freeze_list = Arc::new(RwLock::new(SnapshotScanResultFreezeList::new()));
-
-        SnapshotScanResultFreezeTask::start_task(process_manager.get_opened_process_ref(), snapshot_scan_result_freeze_list.clone());
-
-        let execution_context = Arc::new(EnginePrivilegedState {
-            process_manager,
-            project_manager,
-            task_manager,
-            snapshot,
-            snapshot_scan_result_freeze_list,
-            engine_bindings,
-        });
-
-        execution_context
+        // The event emitter holds a weak reference back to this state, such that process events can act on the opened project.
+        Arc::new_cyclic(|engine_privileged_state| {
+            let edit_history_manager = Arc::new(EditHistoryManager::new());
+            let event_emitter = Self::create_event_emitter(engine_bindings.clone(), edit_history_manager.clone(), engine_privileged_state.clone());
+            let process_manager = ProcessManager::new(event_emitter.clone());
+            let scan_results_watch_list = Arc::new(ScanResultsWatchList::new());
+            let project_manager = ProjectManager::new(event_emitter.clone());
+            let task_manager = TrackableTaskManager::new();
+            let snapshot = Arc::new(RwLock::new(Snapshot::new()));
+            let snapshot_scan_result_freeze_list = Arc::new(RwLock::new(SnapshotScanResultFreezeList::new()));
+
+            SnapshotScanResultFreezeTask::start_task(process_manager.get_opened_process_ref(), snapshot_scan_result_freeze_list.clone());
+            ScanResultsWatchTask::start_task(
+                process_manager.get_opened_process_ref(),
+                snapshot_scan_result_freeze_list.clone(),
+                scan_results_watch_list.clone(),
+                event_emitter.clone(),
+            );
+
+            EnginePrivilegedState {
+                process_manager,
+                project_manager,
+                task_manager,
+                snapshot,
+                snapshot_scan_result_freeze_list,
+                scan_results_watch_list,
+                edit_history_manager,
+                engine_bindings,
+            }
+        })
     }
 
     pub fn initialize(
@@ -80,6 +109,9 @@ impl EnginePrivilegedState {
         if let Err(error) = ProcessQuery::start_monitoring() {
             log::error!("Failed to monitor system processes: {}", error);
         }
+
+        // List thread stacks alongside modules, such that pointer paths rooted on a stack can be stored relative to it.
+        ThreadStackModuleProvider::register();
     }
 
     /// Gets the project manager for this session.
@@ -92,6 +124,41 @@ impl EnginePrivilegedState {
         &self.process_manager
     }
 
+    /// Registers a virtual target, such as a mock target, and attaches to it in place of a live process.
+    pub fn open_virtual_target(
+        &self,
+        virtual_target: Arc<dyn IVirtualMemoryTarget>,
+    ) -> Result<OpenedProcessInfo, String> {
+        let process_id = VirtualTargetRegistry::get_instance().register_target(virtual_target);
+
+        self.open_registered_virtual_target(process_id)
+    }
+
+    /// Attaches to a virtual target that is already registered under the given process id, in place of a live process.
+    pub fn open_registered_virtual_target(
+        &self,
+        process_id: u32,
+    ) -> Result<OpenedProcessInfo, String> {
+        let options = ProcessQueryOptions {
+            search_name: None,
+            required_process_id: Some(Pid::from_u32(process_id)),
+            require_windowed: false,
+            match_case: false,
+            fetch_icons: false,
+            limit: Some(1),
+        };
+        let process_info = ProcessQuery::get_processes(options)
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("Virtual target {} was not listed after registration.", process_id))?;
+        let opened_process_info = ProcessQuery::open_process(&process_info)?;
+
+        self.process_manager
+            .set_opened_process(opened_process_info.clone());
+
+        Ok(opened_process_info)
+    }
+
     pub fn get_trackable_task_manager(&self) -> &TrackableTaskManager {
         &self.task_manager
     }
@@ -106,6 +173,16 @@ impl EnginePrivilegedState {
         self.snapshot_scan_result_freeze_list.clone()
     }
 
+    /// Gets the list of scan results that subscribers are watching for value changes.
+    pub fn get_scan_results_watch_list(&self) -> Arc<ScanResultsWatchList> {
+        self.scan_results_watch_list.clone()
+    }
+
+    /// Gets the journal of reversible edits made during this session.
+    pub fn get_edit_history_manager(&self) -> Arc<EditHistoryManager> {
+        self.edit_history_manager.clone()
+    }
+
     /// Dispatches an event from the engine.
     pub fn subscribe_to_engine_events(&self) -> Result<Receiver<EngineEvent>, String> {
         match self.engine_bindings.read() {
@@ -133,9 +210,28 @@ impl EnginePrivilegedState {
         }
     }
 
-    fn create_event_emitter(engine_bindings: Arc<RwLock<dyn EnginePrivilegedBindings>>) -> Arc<dyn Fn(EngineEvent) + Send + Sync> {
+    fn create_event_emitter(
+        engine_bindings: Arc<RwLock<dyn EnginePrivilegedBindings>>,
+        edit_history_manager: Arc<EditHistoryManager>,
+        engine_privileged_state: Weak<EnginePrivilegedState>,
+    ) -> Arc<dyn Fn(EngineEvent) + Send + Sync> {
         let engine_bindings = engine_bindings.clone();
         Arc::new(move |event: EngineEvent| {
+            if let EngineEvent::Process(ProcessEvent::ProcessChanged { process_changed_event }) = &event {
+                // Recorded edits refer to addresses in the previous process, so they can no longer be safely undone or redone.
+                edit_history_manager.clear();
+
+                // Project items must be re-resolved before subscribers are notified, such that they observe the new addresses.
+                // Any attach can place modules at new addresses, so this is not limited to re-attaching to a restarted process.
+                if let (ProcessChangeKind::Attached | ProcessChangeKind::Reattached, Some(process_info), Some(engine_privileged_state)) = (
+                    process_changed_event.change_kind,
+                    &process_changed_event.process_info,
+                    engine_privileged_state.upgrade(),
+                ) {
+                    ProcessReattachHandler::handle_attach(&engine_privileged_state, process_info);
+                }
+            }
+
             if let Ok(bindings) = engine_bindings.read() {
                 if let Err(error) = bindings.emit_event(event) {
                     log::error!("Error dispatching engine event: {}", error);
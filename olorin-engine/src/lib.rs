@@ -1,8 +1,11 @@
 pub mod app_provisioner;
 pub mod command_executors;
+pub mod edit_history;
 pub mod engine_bindings;
 pub mod engine_execution_context;
 pub mod engine_mode;
-mod engine_privileged_state;
+pub mod engine_privileged_state;
 pub mod olorin_engine;
+pub mod process_reattach;
+pub mod project_item_activation;
 pub mod tasks;
@@ -0,0 +1 @@
+pub mod process_reattach_handler;
@@ -0,0 +1,112 @@
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use olorin_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use olorin_engine_api::structures::results::snapshot_scan_result_freeze_list::SnapshotScanResultFreezeList;
+use olorin_engine_memory::address_expressions::address_expression_evaluator::AddressExpressionEvaluator;
+
+/// Restores project state after attaching to a process, including a new instance of a process that died, such as after a game restart.
+/// Addresses stored in the project may refer to a previous instance, so any address item with a module-relative or pointer
+/// expression is re-resolved against the attached instance, and any freezes on those items are moved to the re-resolved address.
+pub struct ProcessReattachHandler;
+
+impl ProcessReattachHandler {
+    pub fn handle_attach(
+        engine_privileged_state: &EnginePrivilegedState,
+        process_info: &OpenedProcessInfo,
+    ) {
+        let opened_project_lock = engine_privileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let mut opened_project_guard = match opened_project_lock.write() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock to re-resolve project items: {}", error);
+                return;
+            }
+        };
+        let Some(project) = opened_project_guard.as_mut() else {
+            return;
+        };
+        let snapshot_scan_result_freeze_list_lock = engine_privileged_state.get_snapshot_scan_result_freeze_list();
+        let snapshot_scan_result_freeze_list = match snapshot_scan_result_freeze_list_lock.read() {
+            Ok(snapshot_scan_result_freeze_list) => snapshot_scan_result_freeze_list,
+            Err(error) => {
+                log::error!("Failed to acquire freeze list lock to re-enable freezes: {}", error);
+                return;
+            }
+        };
+        let mut resolved_item_count = 0;
+
+        Self::resolve_project_item(
+            process_info,
+            &snapshot_scan_result_freeze_list,
+            project.get_project_root_mut(),
+            &mut resolved_item_count,
+        );
+
+        log::info!(
+            "Attached to {}, re-resolved {} project item address(es).",
+            process_info.get_name(),
+            resolved_item_count
+        );
+
+        if resolved_item_count > 0 {
+            if let Err(error) = project.save(true) {
+                log::error!("Failed to save project after re-resolving project items: {}", error);
+            }
+        }
+    }
+
+    fn resolve_project_item(
+        process_info: &OpenedProcessInfo,
+        snapshot_scan_result_freeze_list: &SnapshotScanResultFreezeList,
+        project_item: &mut ProjectItem,
+        resolved_item_count: &mut usize,
+    ) {
+        if project_item.get_is_container_type() {
+            for child in project_item.get_children_mut() {
+                Self::resolve_project_item(process_info, snapshot_scan_result_freeze_list, child, resolved_item_count);
+            }
+
+            return;
+        }
+
+        if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID {
+            return;
+        }
+
+        // Plain addresses have nothing to re-resolve, and are assumed to remain valid.
+        let address_expression = match ProjectItemTypeAddress::get_field_address_expression(project_item) {
+            Some(address_expression) if address_expression.get_constant_value().is_none() => address_expression,
+            _ => return,
+        };
+        let previous_address = ProjectItemTypeAddress::get_field_address(project_item);
+        let address = match AddressExpressionEvaluator::evaluate(process_info, &address_expression) {
+            Ok(address) => address,
+            Err(error) => {
+                log::warn!("Unable to re-resolve project item {:?}: {}", project_item.get_path(), error);
+                return;
+            }
+        };
+
+        if address != previous_address {
+            ProjectItemTypeAddress::set_field_address(project_item, address);
+            project_item.set_has_unsaved_changes(true);
+            *resolved_item_count += 1;
+
+            // Freezes are tracked by address, so a freeze on the previous address is moved to follow the item.
+            if let Some(frozen_value) = snapshot_scan_result_freeze_list.get_address_frozen_data_value(previous_address) {
+                snapshot_scan_result_freeze_list.set_address_unfrozen(previous_address);
+                snapshot_scan_result_freeze_list.set_address_frozen(address, frozen_value);
+            }
+        }
+
+        // Activated items are frozen to their stored value, which must be re-applied to the new instance.
+        if project_item.get_is_activated() && !snapshot_scan_result_freeze_list.is_address_frozen(address) {
+            if let Some(freeze_value) = ProjectItemTypeAddress::get_field_freeze_value(project_item) {
+                snapshot_scan_result_freeze_list.set_address_frozen(address, freeze_value);
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod project_item_activation_handler;
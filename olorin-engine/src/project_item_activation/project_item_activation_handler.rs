@@ -0,0 +1,96 @@
+use crate::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::speed::process_speed_request::ProcessSpeedRequest;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_speed::ProjectItemTypeSpeed;
+use olorin_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Activates and deactivates project items, applying the effect of the item type to the opened process.
+pub struct ProjectItemActivationHandler;
+
+impl ProjectItemActivationHandler {
+    pub fn set_activated(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        project_root: &mut ProjectItem,
+        project_item_path: &Path,
+        is_activated: bool,
+    ) -> Result<(), String> {
+        let project_item = match project_root.find_project_item_mut(project_item_path) {
+            Some(project_item) => project_item,
+            None => return Err(format!("No project item exists at {:?}.", project_item_path)),
+        };
+
+        project_item.set_activated(is_activated);
+        project_item.set_has_unsaved_changes(true);
+
+        if project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeSpeed::PROJECT_ITEM_TYPE_ID {
+            let speed_factor = ProjectItemTypeSpeed::get_field_speed_factor(project_item);
+
+            // Only one speed can apply at a time, so activating a speed item deactivates any other, and deactivating it restores real time.
+            if is_activated {
+                Self::deactivate_other_speed_items(project_root, project_item_path);
+            }
+
+            Self::apply_speed(engine_privileged_state, if is_activated { speed_factor } else { 1.0 })?;
+        }
+
+        Ok(())
+    }
+
+    /// Collects the paths of all activated project items under the given item.
+    pub fn collect_activated_paths(
+        project_item: &ProjectItem,
+        activated_paths: &mut Vec<PathBuf>,
+    ) {
+        if project_item.get_is_activated() {
+            activated_paths.push(project_item.get_path().clone());
+        }
+
+        if project_item.get_is_container_type() {
+            for child in project_item.get_children() {
+                Self::collect_activated_paths(child, activated_paths);
+            }
+        }
+    }
+
+    fn apply_speed(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        speed_factor: f64,
+    ) -> Result<(), String> {
+        // Without an opened process the item is simply left activated, as there is nothing to apply it to yet.
+        if engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .is_none()
+        {
+            log::warn!("No opened process to apply the speed to.");
+            return Ok(());
+        }
+
+        let process_speed_request = ProcessSpeedRequest { speed_factor };
+
+        if process_speed_request.execute(engine_privileged_state).success {
+            Ok(())
+        } else {
+            Err(format!("Failed to set the speed of the opened process to {}x.", speed_factor))
+        }
+    }
+
+    fn deactivate_other_speed_items(
+        project_item: &mut ProjectItem,
+        activated_item_path: &Path,
+    ) {
+        if project_item.get_is_container_type() {
+            for child in project_item.get_children_mut() {
+                Self::deactivate_other_speed_items(child, activated_item_path);
+            }
+        } else if project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeSpeed::PROJECT_ITEM_TYPE_ID
+            && project_item.get_is_activated()
+            && project_item.get_path() != activated_item_path
+        {
+            project_item.set_activated(false);
+            project_item.set_has_unsaved_changes(true);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use olorin_engine::engine_mode::EngineMode;
+use olorin_engine::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::process::close::process_close_request::ProcessCloseRequest;
+use olorin_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::fs;
+use std::path::PathBuf;
+
+fn create_file_open_request(file_path: PathBuf) -> ProcessOpenRequest {
+    ProcessOpenRequest {
+        process_id: None,
+        search_name: None,
+        match_case: false,
+        core_file_path: None,
+        file_path: Some(file_path),
+    }
+}
+
+#[test]
+fn reopening_a_file_reuses_its_target_until_closed() {
+    let file_name = format!("olorin_file_target_open_{}.dat", std::process::id());
+    let file_path = std::env::temp_dir().join(&file_name);
+
+    fs::write(&file_path, [1, 2, 3, 4]).expect("Failed to write the fixture file.");
+
+    let engine_privileged_state = EnginePrivilegedState::new(EngineMode::Standalone);
+    let first_process_info = create_file_open_request(file_path.clone())
+        .execute(&engine_privileged_state)
+        .opened_process_info
+        .expect("Failed to open the fixture file.");
+    let process_id = first_process_info.get_process_id_raw();
+    let target = VirtualTargetRegistry::get_instance()
+        .get_target(process_id)
+        .expect("The opened file was not registered.");
+
+    assert!(target.write_bytes(0, &[5]));
+    drop(target);
+
+    // The same file reached through a different path must resolve to the existing target, keeping its unsaved edits.
+    let second_process_info = create_file_open_request(std::env::temp_dir().join(".").join(&file_name))
+        .execute(&engine_privileged_state)
+        .opened_process_info
+        .expect("Failed to reopen the fixture file.");
+
+    assert_eq!(second_process_info.get_process_id_raw(), process_id);
+    assert!(VirtualTargetRegistry::get_instance().has_unsaved_changes(process_id));
+
+    ProcessCloseRequest {}.execute(&engine_privileged_state);
+
+    assert!(VirtualTargetRegistry::get_instance().get_target(process_id).is_none());
+
+    let _ = fs::remove_file(&file_path);
+}
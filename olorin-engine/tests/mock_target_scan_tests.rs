@@ -0,0 +1,344 @@
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use olorin_engine::engine_mode::EngineMode;
+use olorin_engine::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::create::project_create_request::ProjectCreateRequest;
+use olorin_engine_api::commands::project::open::project_open_request::ProjectOpenRequest;
+use olorin_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use olorin_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use olorin_engine_api::commands::scan::undo::scan_undo_request::ScanUndoRequest;
+use olorin_engine_api::commands::scan_results::add_to_project::scan_results_add_to_project_request::ScanResultsAddToProjectRequest;
+use olorin_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
+use olorin_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
+use olorin_engine_api::structures::data_values::anonymous_value::AnonymousValue;
+use olorin_engine_api::structures::data_values::display_value::DisplayValue;
+use olorin_engine_api::structures::data_values::display_value_type::DisplayValueType;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use olorin_engine_api::structures::scan_results::scan_result::ScanResult;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+use olorin_engine_memory::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_memory::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use olorin_engine_memory::virtual_targets::mock::mock_memory_mutation::MockMemoryMutation;
+use olorin_engine_memory::virtual_targets::mock::mock_memory_target::MockMemoryTarget;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const MODULE_NAME: &str = "game.exe";
+const MODULE_BASE_ADDRESS: u64 = 0x10000;
+const HEAP_BASE_ADDRESS: u64 = 0x20000;
+const UNREADABLE_BASE_ADDRESS: u64 = 0x30000;
+const REGION_SIZE: usize = 0x1000;
+const HEALTH_VALUE: i32 = 1337;
+
+/// Mirrors a small game with a module holding two copies of a value, a heap holding a third, and an unreadable page holding a fourth.
+fn create_mock_target() -> Arc<MockMemoryTarget> {
+    let mock_target = Arc::new(MockMemoryTarget::new("mock_game", Bitness::Bit64));
+    let mut module_bytes = vec![0u8; REGION_SIZE];
+    let mut heap_bytes = vec![0u8; REGION_SIZE];
+    let mut unreadable_bytes = vec![0u8; REGION_SIZE];
+
+    module_bytes[0x100..0x104].copy_from_slice(&HEALTH_VALUE.to_le_bytes());
+    module_bytes[0x200..0x204].copy_from_slice(&HEALTH_VALUE.to_le_bytes());
+    heap_bytes[0x40..0x44].copy_from_slice(&HEALTH_VALUE.to_le_bytes());
+    unreadable_bytes[0x80..0x84].copy_from_slice(&HEALTH_VALUE.to_le_bytes());
+
+    mock_target.add_region(
+        MODULE_BASE_ADDRESS,
+        module_bytes,
+        MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+        MemoryTypeEnum::IMAGE,
+    );
+    mock_target.add_region(
+        HEAP_BASE_ADDRESS,
+        heap_bytes,
+        MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+        MemoryTypeEnum::PRIVATE,
+    );
+    mock_target.add_region(
+        UNREADABLE_BASE_ADDRESS,
+        unreadable_bytes,
+        MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+        MemoryTypeEnum::PRIVATE,
+    );
+    mock_target.set_region_readable(UNREADABLE_BASE_ADDRESS, false);
+    mock_target.add_module(MODULE_NAME, MODULE_BASE_ADDRESS, REGION_SIZE as u64);
+
+    mock_target
+}
+
+fn attach_mock_target(mock_target: &Arc<MockMemoryTarget>) -> Arc<EnginePrivilegedState> {
+    let engine_privileged_state = EnginePrivilegedState::new(EngineMode::Standalone);
+
+    engine_privileged_state
+        .open_virtual_target(mock_target.clone())
+        .expect("Failed to attach to the mock target.");
+
+    engine_privileged_state
+}
+
+/// Runs an i32 element scan and blocks until the scan task completes. Relative scans ignore the value, but still require one to select the data type.
+fn run_element_scan(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    scan_value: i32,
+    compare_type: ScanCompareType,
+) {
+    let display_value = DisplayValue::new(scan_value.to_string(), DisplayValueType::Decimal, ContainerType::None);
+    let element_scan_request = ElementScanRequest {
+        scan_value: Some(AnonymousValue::new(&scan_value.to_string(), display_value)),
+        data_type_ids: vec!["i32".to_string()],
+        compare_type,
+    };
+    let task_identifier = element_scan_request
+        .execute(engine_privileged_state)
+        .trackable_task_handle
+        .expect("Element scan did not start a task.")
+        .task_identifier;
+
+    let is_complete = wait_until(|| {
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .get_task_handle(&task_identifier)
+            .is_none()
+    });
+
+    assert!(is_complete, "Element scan did not complete.");
+}
+
+fn query_scan_results(engine_privileged_state: &Arc<EnginePrivilegedState>) -> Vec<ScanResult> {
+    ScanResultsQueryRequest { page_index: 0 }
+        .execute(engine_privileged_state)
+        .scan_results
+}
+
+fn get_result_addresses(scan_results: &[ScanResult]) -> Vec<u64> {
+    let mut addresses: Vec<u64> = scan_results
+        .iter()
+        .map(|scan_result| scan_result.get_address())
+        .collect();
+
+    addresses.sort();
+    addresses
+}
+
+fn read_i32(
+    mock_target: &MockMemoryTarget,
+    address: u64,
+) -> i32 {
+    let bytes = mock_target
+        .get_bytes(address, 4)
+        .expect("Address is not readable.");
+
+    i32::from_le_bytes(bytes.try_into().unwrap())
+}
+
+fn wait_until<F>(condition: F) -> bool
+where
+    F: Fn() -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    false
+}
+
+#[test]
+fn new_scan_narrow_freeze_and_add_to_project() {
+    let mock_target = create_mock_target();
+    let engine_privileged_state = attach_mock_target(&mock_target);
+    let first_module_address = MODULE_BASE_ADDRESS + 0x100;
+    let second_module_address = MODULE_BASE_ADDRESS + 0x200;
+    let heap_address = HEAP_BASE_ADDRESS + 0x40;
+
+    // New scan for the initial value.
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    run_element_scan(&engine_privileged_state, HEALTH_VALUE, ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
+
+    assert_eq!(
+        get_result_addresses(&query_scan_results(&engine_privileged_state)),
+        vec![first_module_address, second_module_address, heap_address]
+    );
+
+    // Narrow after the mocked process changes one of the values.
+    assert!(mock_target.set_bytes(second_module_address, &100i32.to_le_bytes()));
+    run_element_scan(&engine_privileged_state, HEALTH_VALUE, ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
+
+    let scan_results = query_scan_results(&engine_privileged_state);
+
+    assert_eq!(get_result_addresses(&scan_results), vec![first_module_address, heap_address]);
+
+    let module_result = scan_results
+        .iter()
+        .find(|scan_result| scan_result.get_address() == first_module_address)
+        .unwrap();
+
+    assert_eq!(module_result.get_module(), MODULE_NAME);
+    assert_eq!(module_result.get_module_offset(), 0x100);
+
+    // Freeze the module result, then have the mocked process overwrite it. The freeze task should restore it.
+    ScanResultsFreezeRequest {
+        scan_results: vec![module_result.get_base_result().clone()],
+        is_frozen: true,
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(
+        engine_privileged_state
+            .get_snapshot_scan_result_freeze_list()
+            .read()
+            .unwrap()
+            .is_address_frozen(first_module_address)
+    );
+
+    let write_count_before = mock_target.get_write_count();
+
+    assert!(mock_target.set_bytes(first_module_address, &0i32.to_le_bytes()));
+    assert!(wait_until(|| read_i32(&mock_target, first_module_address) == HEALTH_VALUE));
+    assert!(mock_target.get_write_count() > write_count_before);
+
+    // Add the remaining results to a new project.
+    let project_directory = tempfile::tempdir().unwrap();
+    let project_path = project_directory.path().join("mock_project");
+
+    ProjectCreateRequest {
+        project_path: Some(project_path.clone()),
+        project_name: None,
+    }
+    .execute(&engine_privileged_state)
+    .created_project_info
+    .expect("Failed to create project.");
+    ProjectOpenRequest {
+        project_path: Some(project_path),
+        project_name: None,
+    }
+    .execute(&engine_privileged_state)
+    .opened_project_info
+    .expect("Failed to open project.");
+
+    ScanResultsAddToProjectRequest {
+        scan_results: scan_results
+            .iter()
+            .map(|scan_result| scan_result.get_base_result().clone())
+            .collect(),
+        directory_name: None,
+    }
+    .execute(&engine_privileged_state);
+
+    let opened_project = engine_privileged_state
+        .get_project_manager()
+        .get_opened_project();
+    let opened_project_guard = opened_project.read().unwrap();
+    let project_items = opened_project_guard
+        .as_ref()
+        .expect("No opened project.")
+        .get_project_root()
+        .get_children();
+
+    assert_eq!(project_items.len(), 2);
+
+    // Only the module result should be stored relative to its module, as the heap result is not static.
+    let module_relative_item_count = project_items
+        .iter()
+        .filter(|project_item| ProjectItemTypeAddress::get_field_address_expression(project_item).is_some())
+        .count();
+
+    assert_eq!(module_relative_item_count, 1);
+}
+
+#[test]
+fn unreadable_regions_produce_no_results() {
+    let mock_target = create_mock_target();
+    let engine_privileged_state = attach_mock_target(&mock_target);
+    let unreadable_address = UNREADABLE_BASE_ADDRESS + 0x80;
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    run_element_scan(&engine_privileged_state, HEALTH_VALUE, ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
+
+    assert!(!get_result_addresses(&query_scan_results(&engine_privileged_state)).contains(&unreadable_address));
+
+    // Once the page becomes readable, a new scan should find the value it holds.
+    assert!(mock_target.set_region_readable(UNREADABLE_BASE_ADDRESS, true));
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    run_element_scan(&engine_privileged_state, HEALTH_VALUE, ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
+
+    assert!(get_result_addresses(&query_scan_results(&engine_privileged_state)).contains(&unreadable_address));
+}
+
+#[test]
+fn scripted_mutations_are_applied_between_scans() {
+    let mock_target = create_mock_target();
+    let engine_privileged_state = attach_mock_target(&mock_target);
+    let heap_address = HEAP_BASE_ADDRESS + 0x40;
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    run_element_scan(&engine_privileged_state, HEALTH_VALUE, ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
+
+    // Script the heap value to drop as soon as the engine next reads memory.
+    mock_target.queue_mutation(MockMemoryMutation::new(
+        mock_target.get_read_count(),
+        heap_address,
+        (HEALTH_VALUE - 10).to_le_bytes().to_vec(),
+    ));
+
+    run_element_scan(&engine_privileged_state, 0, ScanCompareType::Relative(ScanCompareTypeRelative::Changed));
+
+    assert_eq!(get_result_addresses(&query_scan_results(&engine_privileged_state)), vec![heap_address]);
+    assert_eq!(read_i32(&mock_target, heap_address), HEALTH_VALUE - 10);
+
+    run_element_scan(&engine_privileged_state, 0, ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged));
+
+    assert_eq!(get_result_addresses(&query_scan_results(&engine_privileged_state)), vec![heap_address]);
+}
+
+#[test]
+fn undone_scans_restore_the_values_that_relative_scans_compare_against() {
+    let mock_target = create_mock_target();
+    let engine_privileged_state = attach_mock_target(&mock_target);
+    let heap_address = HEAP_BASE_ADDRESS + 0x40;
+    let module_addresses = vec![MODULE_BASE_ADDRESS + 0x100, MODULE_BASE_ADDRESS + 0x200];
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    run_element_scan(&engine_privileged_state, HEALTH_VALUE, ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
+
+    mock_target.queue_mutation(MockMemoryMutation::new(
+        mock_target.get_read_count(),
+        heap_address,
+        (HEALTH_VALUE - 10).to_le_bytes().to_vec(),
+    ));
+
+    // Narrowing to the heap value discards the module region entirely.
+    run_element_scan(&engine_privileged_state, 0, ScanCompareType::Relative(ScanCompareTypeRelative::Changed));
+
+    assert_eq!(get_result_addresses(&query_scan_results(&engine_privileged_state)), vec![heap_address]);
+
+    let scan_undo_response = ScanUndoRequest { scan_count: 1 }.execute(&engine_privileged_state);
+    let mut all_addresses = module_addresses.clone();
+
+    all_addresses.push(heap_address);
+
+    assert_eq!(scan_undo_response.scans_undone, 1);
+    assert_eq!(get_result_addresses(&query_scan_results(&engine_privileged_state)), all_addresses);
+
+    // Relative scans compare against the values prior to the undone scan, so the heap value that changed before the undo is not
+    // reported as unchanged, and the restored module region is narrowed rather than skipped.
+    mock_target.queue_mutation(MockMemoryMutation::new(
+        mock_target.get_read_count(),
+        module_addresses[0],
+        (HEALTH_VALUE + 1).to_le_bytes().to_vec(),
+    ));
+
+    run_element_scan(&engine_privileged_state, 0, ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged));
+
+    assert_eq!(get_result_addresses(&query_scan_results(&engine_privileged_state)), vec![module_addresses[1]]);
+}
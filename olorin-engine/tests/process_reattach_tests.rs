@@ -0,0 +1,191 @@
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use olorin_engine::engine_mode::EngineMode;
+use olorin_engine::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::project::create::project_create_request::ProjectCreateRequest;
+use olorin_engine_api::commands::project::open::project_open_request::ProjectOpenRequest;
+use olorin_engine_api::commands::project::watch_process::project_watch_process_request::ProjectWatchProcessRequest;
+use olorin_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use olorin_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use olorin_engine_api::commands::scan_results::add_to_project::scan_results_add_to_project_request::ScanResultsAddToProjectRequest;
+use olorin_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
+use olorin_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
+use olorin_engine_api::structures::data_values::anonymous_value::AnonymousValue;
+use olorin_engine_api::structures::data_values::display_value::DisplayValue;
+use olorin_engine_api::structures::data_values::display_value_type::DisplayValueType;
+use olorin_engine_api::structures::memory::bitness::Bitness;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use olorin_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use olorin_engine_api::structures::structs::container_type::ContainerType;
+use olorin_engine_memory::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use olorin_engine_memory::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use olorin_engine_memory::virtual_targets::mock::mock_memory_target::MockMemoryTarget;
+use olorin_engine_memory::virtual_targets::virtual_target_registry::VirtualTargetRegistry;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const PROCESS_NAME: &str = "reattach_game";
+const MODULE_NAME: &str = "game.exe";
+const VALUE_OFFSET: u64 = 0x100;
+const HEALTH_VALUE: i32 = 1337;
+
+/// Creates an instance of the game, with its module loaded at the given base address as though relocated by ASLR.
+fn create_game_instance(
+    module_base_address: u64,
+    health_value: i32,
+) -> Arc<MockMemoryTarget> {
+    let mock_target = Arc::new(MockMemoryTarget::new(PROCESS_NAME, Bitness::Bit64));
+    let mut module_bytes = vec![0u8; 0x1000];
+
+    module_bytes[VALUE_OFFSET as usize..VALUE_OFFSET as usize + 4].copy_from_slice(&health_value.to_le_bytes());
+    mock_target.add_region(
+        module_base_address,
+        module_bytes,
+        MemoryProtectionEnum::READ | MemoryProtectionEnum::WRITE,
+        MemoryTypeEnum::IMAGE,
+    );
+    mock_target.add_module(MODULE_NAME, module_base_address, 0x1000);
+
+    mock_target
+}
+
+fn wait_until<F>(condition: F) -> bool
+where
+    F: Fn() -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(10);
+
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    false
+}
+
+fn get_project_item_addresses(engine_privileged_state: &Arc<EnginePrivilegedState>) -> Vec<u64> {
+    let opened_project = engine_privileged_state
+        .get_project_manager()
+        .get_opened_project();
+    let opened_project_guard = opened_project.read().unwrap();
+
+    opened_project_guard
+        .as_ref()
+        .expect("No opened project.")
+        .get_project_root()
+        .get_children()
+        .iter()
+        .map(ProjectItemTypeAddress::get_field_address)
+        .collect()
+}
+
+#[test]
+fn restarted_process_is_reattached_with_project_items_re_resolved() {
+    let first_instance = create_game_instance(0x10000, HEALTH_VALUE);
+    let engine_privileged_state = EnginePrivilegedState::new(EngineMode::Standalone);
+    let first_process_info = engine_privileged_state
+        .open_virtual_target(first_instance.clone())
+        .expect("Failed to attach to the first instance.");
+    let first_address = 0x10000 + VALUE_OFFSET;
+
+    // Find the value, freeze it, and add it to a project that watches for the game.
+    let display_value = DisplayValue::new(HEALTH_VALUE.to_string(), DisplayValueType::Decimal, ContainerType::None);
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+
+    let task_identifier = ElementScanRequest {
+        scan_value: Some(AnonymousValue::new(&HEALTH_VALUE.to_string(), display_value)),
+        data_type_ids: vec!["i32".to_string()],
+        compare_type: ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+    }
+    .execute(&engine_privileged_state)
+    .trackable_task_handle
+    .expect("Element scan did not start a task.")
+    .task_identifier;
+
+    assert!(wait_until(|| {
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .get_task_handle(&task_identifier)
+            .is_none()
+    }));
+
+    let scan_results: Vec<_> = ScanResultsQueryRequest { page_index: 0 }
+        .execute(&engine_privileged_state)
+        .scan_results
+        .iter()
+        .map(|scan_result| scan_result.get_base_result().clone())
+        .collect();
+
+    assert_eq!(scan_results.len(), 1);
+
+    let project_directory = tempfile::tempdir().unwrap();
+    let project_path = project_directory.path().join("reattach_project");
+
+    ProjectCreateRequest {
+        project_path: Some(project_path.clone()),
+        project_name: None,
+    }
+    .execute(&engine_privileged_state);
+    ProjectOpenRequest {
+        project_path: Some(project_path),
+        project_name: None,
+    }
+    .execute(&engine_privileged_state)
+    .opened_project_info
+    .expect("Failed to open project.");
+    ScanResultsAddToProjectRequest {
+        scan_results: scan_results.clone(),
+        directory_name: None,
+    }
+    .execute(&engine_privileged_state);
+    ScanResultsFreezeRequest {
+        scan_results,
+        is_frozen: true,
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(
+        ProjectWatchProcessRequest {
+            process_pattern: Some("REATTACH_*".to_string()),
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+    assert_eq!(get_project_item_addresses(&engine_privileged_state), vec![first_address]);
+
+    // Kill the first instance, which should detach the engine.
+    VirtualTargetRegistry::get_instance().unregister_target(first_process_info.get_process_id_raw());
+
+    assert!(wait_until(|| {
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .is_none()
+    }));
+
+    // Restart the game with its module relocated. The engine should attach on its own, and follow the module.
+    let second_instance = create_game_instance(0x50000, 0);
+    let second_process_id = VirtualTargetRegistry::get_instance().register_target(second_instance.clone());
+    let second_address = 0x50000 + VALUE_OFFSET;
+
+    assert!(wait_until(|| {
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|process_info| process_info.get_process_id_raw())
+            == Some(second_process_id)
+    }));
+    assert!(wait_until(|| get_project_item_addresses(&engine_privileged_state) == vec![second_address]));
+
+    // The freeze should have moved to the re-resolved address, restoring the frozen value in the new instance.
+    let freeze_list = engine_privileged_state.get_snapshot_scan_result_freeze_list();
+
+    assert!(freeze_list.read().unwrap().is_address_frozen(second_address));
+    assert!(!freeze_list.read().unwrap().is_address_frozen(first_address));
+    assert!(wait_until(|| second_instance.get_bytes(second_address, 4) == Some(HEALTH_VALUE.to_le_bytes().to_vec())));
+}
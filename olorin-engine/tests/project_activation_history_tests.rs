@@ -0,0 +1,158 @@
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use olorin_engine::engine_mode::EngineMode;
+use olorin_engine::engine_privileged_state::EnginePrivilegedState;
+use olorin_engine_api::commands::history::redo::history_redo_request::HistoryRedoRequest;
+use olorin_engine_api::commands::history::undo::history_undo_request::HistoryUndoRequest;
+use olorin_engine_api::commands::project::activate_item::project_activate_item_request::ProjectActivateItemRequest;
+use olorin_engine_api::commands::project::add_speed::project_add_speed_request::ProjectAddSpeedRequest;
+use olorin_engine_api::commands::project::create::project_create_request::ProjectCreateRequest;
+use olorin_engine_api::commands::project::open::project_open_request::ProjectOpenRequest;
+use olorin_engine_api::commands::project::set_item_property::project_set_item_property_request::ProjectSetItemPropertyRequest;
+use olorin_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use olorin_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use olorin_engine_api::structures::projects::project_items::built_in_types::project_item_type_speed::ProjectItemTypeSpeed;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Gets the names of the activated items at the root of the opened project.
+fn get_activated_item_names(engine_privileged_state: &Arc<EnginePrivilegedState>) -> Vec<String> {
+    let opened_project = engine_privileged_state
+        .get_project_manager()
+        .get_opened_project();
+    let opened_project_guard = opened_project.read().unwrap();
+
+    opened_project_guard
+        .as_ref()
+        .expect("No opened project.")
+        .get_project_root()
+        .get_children()
+        .iter()
+        .filter(|project_item| project_item.get_is_activated())
+        .filter_map(|project_item| project_item.get_path().file_name())
+        .map(|file_name| file_name.to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Gets the description of the item with the given name at the root of the opened project.
+fn get_item_description(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    item_name: &str,
+) -> String {
+    let opened_project = engine_privileged_state
+        .get_project_manager()
+        .get_opened_project();
+    let opened_project_guard = opened_project.read().unwrap();
+    let project_item = opened_project_guard
+        .as_ref()
+        .expect("No opened project.")
+        .get_project_root()
+        .get_children()
+        .iter()
+        .find(|project_item| project_item.get_path().file_name() == Some(item_name.as_ref()))
+        .expect("No project item with the given name.");
+
+    ProjectItemTypeSpeed::get_field_description(project_item)
+        .trim_end()
+        .to_string()
+}
+
+/// Creates and opens a project in the given directory.
+fn open_new_project(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    project_path: PathBuf,
+) {
+    ProjectCreateRequest {
+        project_path: Some(project_path.clone()),
+        project_name: None,
+    }
+    .execute(engine_privileged_state);
+    ProjectOpenRequest {
+        project_path: Some(project_path),
+        project_name: None,
+    }
+    .execute(engine_privileged_state)
+    .opened_project_info
+    .expect("Failed to open project.");
+}
+
+#[test]
+fn activating_project_items_can_be_undone_and_redone() {
+    let engine_privileged_state = EnginePrivilegedState::new(EngineMode::Standalone);
+    let project_directory = tempfile::tempdir().unwrap();
+    let project_path = project_directory.path().join("activation_project");
+
+    open_new_project(&engine_privileged_state, project_path);
+
+    for (item_name, speed_factor) in [("fast", 2.0), ("slow", 0.5)] {
+        assert!(
+            ProjectAddSpeedRequest {
+                item_name: item_name.to_string(),
+                speed_factor,
+                description: String::new(),
+            }
+            .execute(&engine_privileged_state)
+            .success
+        );
+    }
+
+    // Without an opened process, speed items are activated without being applied.
+    for item_name in ["fast", "slow"] {
+        assert!(
+            ProjectActivateItemRequest {
+                project_item_path: PathBuf::from(item_name),
+                deactivate: false,
+            }
+            .execute(&engine_privileged_state)
+            .success
+        );
+    }
+
+    // Activating the slow item deactivated the fast item, so undoing it should restore both.
+    assert_eq!(get_activated_item_names(&engine_privileged_state), vec!["slow"]);
+    assert!(HistoryUndoRequest {}.execute(&engine_privileged_state).success);
+    assert_eq!(get_activated_item_names(&engine_privileged_state), vec!["fast"]);
+    assert!(HistoryRedoRequest {}.execute(&engine_privileged_state).success);
+    assert_eq!(get_activated_item_names(&engine_privileged_state), vec!["slow"]);
+}
+
+#[test]
+fn project_item_property_changes_can_be_undone_and_redone() {
+    let engine_privileged_state = EnginePrivilegedState::new(EngineMode::Standalone);
+    let project_directory = tempfile::tempdir().unwrap();
+
+    open_new_project(&engine_privileged_state, project_directory.path().join("property_project"));
+
+    assert!(
+        ProjectAddSpeedRequest {
+            item_name: "fast".to_string(),
+            speed_factor: 2.0,
+            description: "Fast".to_string(),
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+
+    let set_description_request = |data_value| ProjectSetItemPropertyRequest {
+        project_item_path: PathBuf::from("fast"),
+        data_value,
+        field_namespace: ProjectItemTypeSpeed::PROPERTY_DESCRIPTION.to_string(),
+    };
+
+    // Properties keep their type, so a description cannot be replaced with a number.
+    assert!(
+        !set_description_request(DataTypeU32::get_value_from_primitive(5))
+            .execute(&engine_privileged_state)
+            .success
+    );
+    assert!(
+        set_description_request(DataTypeStringUtf8::get_value_from_primitive_string("Double speed"))
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    assert_eq!(get_item_description(&engine_privileged_state, "fast"), "Double speed");
+    assert!(HistoryUndoRequest {}.execute(&engine_privileged_state).success);
+    assert_eq!(get_item_description(&engine_privileged_state, "fast"), "Fast");
+    assert!(HistoryRedoRequest {}.execute(&engine_privileged_state).success);
+    assert_eq!(get_item_description(&engine_privileged_state, "fast"), "Double speed");
+}
@@ -0,0 +1,70 @@
+use crate::MemoryViewerByteViewData;
+use crate::MemoryViewerRowViewData;
+use crate::view_models::memory_viewer::memory_viewer_row::MemoryViewerRow;
+use slint::{ModelRc, VecModel};
+use slint_mvvm::convert_to_view_data::ConvertToViewData;
+
+pub struct MemoryViewerRowConverter {}
+
+impl MemoryViewerRowConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ConvertToViewData<MemoryViewerRow, MemoryViewerRowViewData> for MemoryViewerRowConverter {
+    fn convert_collection(
+        &self,
+        memory_viewer_rows: &Vec<MemoryViewerRow>,
+    ) -> Vec<MemoryViewerRowViewData> {
+        memory_viewer_rows
+            .into_iter()
+            .map(|item| self.convert_to_view_data(item))
+            .collect()
+    }
+
+    fn convert_to_view_data(
+        &self,
+        memory_viewer_row: &MemoryViewerRow,
+    ) -> MemoryViewerRowViewData {
+        let address = memory_viewer_row.get_address();
+        let address_string = if address <= u32::MAX as u64 {
+            format!("{:08X}", address)
+        } else {
+            format!("{:016X}", address)
+        };
+
+        let bytes = memory_viewer_row
+            .get_bytes()
+            .iter()
+            .zip(memory_viewer_row.get_changed_bytes().iter())
+            .map(|(byte, is_changed)| match byte {
+                Some(byte) => MemoryViewerByteViewData {
+                    value: format!("{:02X}", byte).into(),
+                    is_readable: true,
+                    is_changed: *is_changed,
+                },
+                None => MemoryViewerByteViewData {
+                    value: "??".into(),
+                    is_readable: false,
+                    is_changed: false,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let ascii = memory_viewer_row
+            .get_bytes()
+            .iter()
+            .map(|byte| match byte {
+                Some(byte) if byte.is_ascii_graphic() || *byte == b' ' => *byte as char,
+                _ => '.',
+            })
+            .collect::<String>();
+
+        MemoryViewerRowViewData {
+            address: address_string.into(),
+            bytes: ModelRc::new(VecModel::from(bytes)),
+            ascii: ascii.into(),
+        }
+    }
+}
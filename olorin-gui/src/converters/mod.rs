@@ -8,11 +8,13 @@ pub mod dock_window_converter;
 pub mod floating_point_tolerance_converter;
 pub mod memory_alignment_converter;
 pub mod memory_read_mode_converter;
+pub mod memory_viewer_row_converter;
 pub mod opened_process_info_converter;
 pub mod process_info_converter;
 pub mod project_info_converter;
 pub mod project_item_converter;
 pub mod scan_constraint_converter;
+pub mod scan_history_entry_converter;
 pub mod scan_result_converter;
 pub mod symbolic_struct_ref_converter;
 pub mod valued_struct_converter;
@@ -0,0 +1,41 @@
+use crate::ScanHistoryEntryViewData;
+use olorin_engine_api::conversions::conversions::Conversions;
+use olorin_engine_api::structures::scanning::history::scan_history_entry::ScanHistoryEntry;
+use slint_mvvm::convert_to_view_data::ConvertToViewData;
+
+pub struct ScanHistoryEntryConverter {}
+
+impl ScanHistoryEntryConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ConvertToViewData<ScanHistoryEntry, ScanHistoryEntryViewData> for ScanHistoryEntryConverter {
+    fn convert_collection(
+        &self,
+        scan_history_entries: &Vec<ScanHistoryEntry>,
+    ) -> Vec<ScanHistoryEntryViewData> {
+        scan_history_entries
+            .into_iter()
+            .map(|item| self.convert_to_view_data(item))
+            .collect()
+    }
+
+    fn convert_to_view_data(
+        &self,
+        scan_history_entry: &ScanHistoryEntry,
+    ) -> ScanHistoryEntryViewData {
+        let scan_value = match scan_history_entry.get_scan_value() {
+            Some(scan_value) => scan_value.to_string(),
+            None => String::new(),
+        };
+
+        ScanHistoryEntryViewData {
+            compare_type: scan_history_entry.get_compare_type().to_string().into(),
+            scan_value: scan_value.into(),
+            result_count: scan_history_entry.get_result_count().to_string().into(),
+            memory_cost: Conversions::value_to_metric_size(scan_history_entry.get_memory_cost()).into(),
+        }
+    }
+}
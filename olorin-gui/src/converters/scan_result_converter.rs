@@ -27,7 +27,9 @@ impl ConvertToViewData<ScanResult, ScanResultViewData> for ScanResultConverter {
     ) -> ScanResultViewData {
         let address = scan_result.get_address();
 
-        let address_string = if scan_result.is_module() {
+        let address_string = if let Some(symbol) = scan_result.get_symbol() {
+            symbol.clone()
+        } else if scan_result.is_module() {
             format!("{}+{:X}", scan_result.get_module(), scan_result.get_module_offset())
         } else if address <= u32::MAX as u64 {
             format!("{:08X}", address)
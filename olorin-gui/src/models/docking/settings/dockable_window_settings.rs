@@ -40,7 +40,12 @@ impl DockSettingsConfig {
                             )
                             .push_child(0.5, DockBuilder::window("struct_viewer")),
                     )
-                    .push_child(0.5, DockBuilder::window("output")),
+                    .push_child(
+                        0.5,
+                        DockBuilder::tab_node("output")
+                            .push_tab(DockBuilder::window("output"))
+                            .push_tab(DockBuilder::window("memory_viewer")),
+                    ),
             )
             .push_child(
                 0.4,
@@ -69,7 +74,12 @@ impl DockSettingsConfig {
                     ),
             )
             .push_child(0.25, DockBuilder::window("struct_viewer"))
-            .push_child(0.2, DockBuilder::window("output"))
+            .push_child(
+                0.2,
+                DockBuilder::tab_node("output")
+                    .push_tab(DockBuilder::window("output"))
+                    .push_tab(DockBuilder::window("memory_viewer")),
+            )
             .build();
 
         default_layout
@@ -182,6 +182,7 @@ impl DockRootViewModel {
             "settings" => "Settings".into(),
             "scan_results" => "Memory Scanner".into(),
             "output" => "Output".into(),
+            "memory_viewer" => "Memory Viewer".into(),
             "process_selector" => "Process Selector".into(),
             "struct_viewer" => "Struct Viewer".into(),
             "project_explorer" => "Project Explorer".into(),
@@ -379,6 +380,9 @@ impl DockRootViewModel {
                     "output" => {
                         dock_root_bindings.set_output_window(view_data);
                     }
+                    "memory_viewer" => {
+                        dock_root_bindings.set_memory_viewer_window(view_data);
+                    }
                     "process_selector" => {
                         dock_root_bindings.set_process_selector_window(view_data);
                     }
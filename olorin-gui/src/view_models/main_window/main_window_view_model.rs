@@ -3,6 +3,7 @@ use crate::WindowViewModelBindings;
 use crate::models::audio::audio_player::AudioPlayer;
 use crate::view_models::conversions_view_model::conversions_view_model::ConversionsViewModel;
 use crate::view_models::docking::dock_root_view_model::DockRootViewModel;
+use crate::view_models::memory_viewer::memory_viewer_view_model::MemoryViewerViewModel;
 use crate::view_models::output::output_view_model::OutputViewModel;
 use crate::view_models::process_selector::process_selector_view_model::ProcessSelectorViewModel;
 use crate::view_models::project_explorer::project_explorer_view_model::ProjectExplorerViewModel;
@@ -12,6 +13,10 @@ use crate::view_models::settings::memory_settings_view_model::MemorySettingsView
 use crate::view_models::settings::scan_settings_view_model::ScanSettingsViewModel;
 use crate::view_models::struct_viewer::struct_viewer_view_model::StructViewerViewModel;
 use crate::view_models::validation_view_model::validation_view_model::ValidationViewModel;
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use olorin_engine::engine_execution_context::EngineExecutionContext;
+use olorin_engine_api::commands::history::redo::history_redo_request::HistoryRedoRequest;
+use olorin_engine_api::commands::history::undo::history_undo_request::HistoryUndoRequest;
 use slint::ComponentHandle;
 use slint_mvvm::view_binding::ViewBinding;
 use slint_mvvm_macros::create_view_bindings;
@@ -37,6 +42,7 @@ impl MainWindowViewModel {
         ScanSettingsViewModel::register(dependency_container);
         ScanResultsViewModel::register(dependency_container);
         StructViewerViewModel::register(dependency_container);
+        MemoryViewerViewModel::register(dependency_container);
         ConversionsViewModel::register(dependency_container);
         ValidationViewModel::register(dependency_container);
 
@@ -55,6 +61,19 @@ impl MainWindowViewModel {
         Self::show(view_binding);
 
         dependency_container.register::<MainWindowViewModel>(view_model);
+        dependency_container.resolve_all(Self::on_dependencies_resolved);
+    }
+
+    fn on_dependencies_resolved(
+        _dependency_container: DependencyContainer,
+        (view_binding, engine_execution_context): (Arc<ViewBinding<MainWindowView>>, Arc<EngineExecutionContext>),
+    ) {
+        create_view_bindings!(view_binding, {
+            WindowViewModelBindings => {
+                on_undo() -> [engine_execution_context] -> Self::on_undo,
+                on_redo() -> [engine_execution_context] -> Self::on_redo
+            }
+        });
     }
 
     pub fn show(view_binding: Arc<ViewBinding<MainWindowView>>) {
@@ -104,6 +123,26 @@ impl MainWindowViewModel {
         });
     }
 
+    fn on_undo(engine_execution_context: Arc<EngineExecutionContext>) {
+        let history_undo_request = HistoryUndoRequest {};
+
+        history_undo_request.send(&engine_execution_context, move |history_undo_response| match history_undo_response.undone_entry {
+            Some(undone_entry) if history_undo_response.success => log::info!("Undid: {}", undone_entry),
+            Some(undone_entry) => log::error!("Failed to fully undo: {}", undone_entry),
+            None => log::info!("Nothing to undo."),
+        });
+    }
+
+    fn on_redo(engine_execution_context: Arc<EngineExecutionContext>) {
+        let history_redo_request = HistoryRedoRequest {};
+
+        history_redo_request.send(&engine_execution_context, move |history_redo_response| match history_redo_response.redone_entry {
+            Some(redone_entry) if history_redo_response.success => log::info!("Redid: {}", redone_entry),
+            Some(redone_entry) => log::error!("Failed to fully redo: {}", redone_entry),
+            None => log::info!("Nothing to redo."),
+        });
+    }
+
     fn on_drag(
         view_binding: Arc<ViewBinding<MainWindowView>>,
         delta_x: i32,
@@ -0,0 +1,55 @@
+use std::time::Duration;
+use std::time::Instant;
+
+/// A cached, independently read page of memory, along with the time at which each byte was last seen changing.
+pub struct MemoryViewerPage {
+    bytes: Option<Vec<u8>>,
+    change_times: Vec<Option<Instant>>,
+}
+
+impl MemoryViewerPage {
+    pub fn new(page_size: u64) -> Self {
+        Self {
+            bytes: None,
+            change_times: vec![None; page_size as usize],
+        }
+    }
+
+    /// Gets the bytes of this page, or `None` if the page could not be read.
+    pub fn get_bytes(&self) -> &Option<Vec<u8>> {
+        &self.bytes
+    }
+
+    /// Replaces the bytes of this page, recording a change time for each byte that differs from the previous read.
+    /// Pages that become readable or unreadable are not treated as changed, as this is not a change in the underlying value.
+    pub fn update_bytes(
+        &mut self,
+        bytes: Option<Vec<u8>>,
+    ) {
+        if let (Some(previous_bytes), Some(new_bytes)) = (&self.bytes, &bytes) {
+            let now = Instant::now();
+
+            for (index, (previous_byte, new_byte)) in previous_bytes.iter().zip(new_bytes.iter()).enumerate() {
+                if previous_byte != new_byte {
+                    if let Some(change_time) = self.change_times.get_mut(index) {
+                        *change_time = Some(now);
+                    }
+                }
+            }
+        }
+
+        self.bytes = bytes;
+    }
+
+    /// Gets whether the byte at the given page offset changed within the provided duration.
+    pub fn was_changed_within(
+        &self,
+        page_offset: usize,
+        duration: Duration,
+    ) -> bool {
+        match self.change_times.get(page_offset) {
+            Some(Some(change_time)) => change_time.elapsed() <= duration,
+            _ => false,
+        }
+    }
+}
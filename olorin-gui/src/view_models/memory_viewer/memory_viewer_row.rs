@@ -0,0 +1,35 @@
+/// A single row of bytes displayed by the memory viewer.
+#[derive(Clone)]
+pub struct MemoryViewerRow {
+    address: u64,
+    bytes: Vec<Option<u8>>,
+    changed_bytes: Vec<bool>,
+}
+
+impl MemoryViewerRow {
+    pub fn new(
+        address: u64,
+        bytes: Vec<Option<u8>>,
+        changed_bytes: Vec<bool>,
+    ) -> Self {
+        Self {
+            address,
+            bytes,
+            changed_bytes,
+        }
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    /// Gets the bytes of this row, where `None` represents a byte that could not be read.
+    pub fn get_bytes(&self) -> &Vec<Option<u8>> {
+        &self.bytes
+    }
+
+    /// Gets whether each byte of this row has recently changed.
+    pub fn get_changed_bytes(&self) -> &Vec<bool> {
+        &self.changed_bytes
+    }
+}
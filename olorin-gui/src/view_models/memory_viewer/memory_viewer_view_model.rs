@@ -0,0 +1,543 @@
+use crate::DockRootViewModelBindings;
+use crate::MainWindowView;
+use crate::MemoryViewerInterpretationViewData;
+use crate::MemoryViewerRowViewData;
+use crate::MemoryViewerViewModelBindings;
+use crate::converters::memory_viewer_row_converter::MemoryViewerRowConverter;
+use crate::view_models::memory_viewer::memory_viewer_page::MemoryViewerPage;
+use crate::view_models::memory_viewer::memory_viewer_row::MemoryViewerRow;
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
+use olorin_engine::engine_execution_context::EngineExecutionContext;
+use olorin_engine_api::commands::memory::query::memory_query_request::MemoryQueryRequest;
+use olorin_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
+use olorin_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use olorin_engine_api::conversions::conversions::Conversions;
+use olorin_engine_api::dependency_injection::dependency_container::DependencyContainer;
+use olorin_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent;
+use olorin_engine_api::registries::data_types::data_type_registry::DataTypeRegistry;
+use olorin_engine_api::structures::address_expressions::address_expression::AddressExpression;
+use olorin_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use olorin_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use olorin_engine_api::structures::data_values::data_value::DataValue;
+use slint::ComponentHandle;
+use slint::Model;
+use slint::ModelRc;
+use slint::SharedString;
+use slint::VecModel;
+use slint_mvvm::view_binding::ViewBinding;
+use slint_mvvm::view_collection_binding::ViewCollectionBinding;
+use slint_mvvm_macros::create_view_bindings;
+use slint_mvvm_macros::create_view_model_collection;
+use std::cmp;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicI32;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+pub struct MemoryViewerViewModel {
+    view_binding: Arc<ViewBinding<MainWindowView>>,
+    engine_execution_context: Arc<EngineExecutionContext>,
+    memory_viewer_rows_collection: ViewCollectionBinding<MemoryViewerRowViewData, MemoryViewerRow, MainWindowView>,
+    current_address: AtomicU64,
+    is_process_opened: AtomicBool,
+    pages: RwLock<HashMap<u64, MemoryViewerPage>>,
+    selection_index_start: AtomicI32,
+    selection_index_end: AtomicI32,
+}
+
+impl MemoryViewerViewModel {
+    const BYTES_PER_ROW: u64 = 16;
+    const VISIBLE_ROW_COUNT: u64 = 16;
+    const VISIBLE_BYTE_COUNT: u64 = Self::BYTES_PER_ROW * Self::VISIBLE_ROW_COUNT;
+
+    /// The size of each read request. This evenly divides the smallest OS page size, such that a single read never straddles
+    /// a readable and an unreadable page. This allows unreadable memory to be displayed without failing the entire view.
+    const READ_PAGE_SIZE: u64 = 0x100;
+
+    /// The maximum number of selected bytes interpreted as data types, to keep the displayed values short.
+    const MAX_INTERPRETATION_SIZE: usize = 64;
+
+    /// How long a byte remains highlighted after its value changes.
+    const CHANGE_HIGHLIGHT_DURATION: Duration = Duration::from_millis(1000);
+
+    pub fn register(dependency_container: &DependencyContainer) {
+        dependency_container.resolve_all(Self::on_dependencies_resolved);
+    }
+
+    fn on_dependencies_resolved(
+        dependency_container: DependencyContainer,
+        (view_binding, engine_execution_context): (Arc<ViewBinding<MainWindowView>>, Arc<EngineExecutionContext>),
+    ) {
+        let memory_viewer_rows_collection = create_view_model_collection!(
+            view_binding -> MainWindowView,
+            MemoryViewerViewModelBindings -> { set_rows, get_rows },
+            MemoryViewerRowConverter -> [],
+        );
+
+        let view_model = Arc::new(MemoryViewerViewModel {
+            view_binding: view_binding.clone(),
+            engine_execution_context: engine_execution_context.clone(),
+            memory_viewer_rows_collection,
+            current_address: AtomicU64::new(0),
+            is_process_opened: AtomicBool::new(false),
+            pages: RwLock::new(HashMap::new()),
+            selection_index_start: AtomicI32::new(-1),
+            selection_index_end: AtomicI32::new(-1),
+        });
+
+        {
+            let view_model = view_model.clone();
+
+            create_view_bindings!(view_binding, {
+                MemoryViewerViewModelBindings => {
+                    on_navigate_to_address(address_text: SharedString) -> [view_model] -> Self::on_navigate_to_address,
+                    on_navigate_previous_page() -> [view_model] -> Self::on_navigate_previous_page,
+                    on_navigate_next_page() -> [view_model] -> Self::on_navigate_next_page,
+                    on_scroll_rows(row_delta: i32) -> [view_model] -> Self::on_scroll_rows,
+                    on_set_byte_selection_start(local_byte_index: i32) -> [view_model] -> Self::on_set_byte_selection_start,
+                    on_set_byte_selection_end(local_byte_index: i32) -> [view_model] -> Self::on_set_byte_selection_end,
+                    on_commit_byte_edit(local_byte_index: i32, new_value: SharedString) -> [view_model] -> Self::on_commit_byte_edit,
+                },
+            });
+        }
+
+        Self::listen_for_process_change(view_model.clone());
+        Self::poll_memory(view_model.clone());
+
+        dependency_container.register::<MemoryViewerViewModel>(view_model);
+    }
+
+    /// Navigates the memory viewer such that the given address is the first displayed row.
+    pub fn navigate_to_address(
+        view_model: Arc<MemoryViewerViewModel>,
+        address: u64,
+    ) {
+        let address = address - address % Self::BYTES_PER_ROW;
+        let address_string = format!("{:X}", address);
+
+        view_model.current_address.store(address, Ordering::Release);
+        view_model.selection_index_start.store(-1, Ordering::Release);
+        view_model.selection_index_end.store(-1, Ordering::Release);
+
+        view_model
+            .view_binding
+            .execute_on_ui_thread(move |main_window_view, _view_binding| {
+                let memory_viewer_bindings = main_window_view.global::<MemoryViewerViewModelBindings>();
+
+                memory_viewer_bindings.set_address_string(address_string.into());
+                memory_viewer_bindings.set_selected_index_start(-1);
+                memory_viewer_bindings.set_selected_index_end(-1);
+                memory_viewer_bindings.set_selection_interpretations(ModelRc::default());
+            });
+
+        Self::query_address_labels(view_model.clone(), address);
+        Self::refresh_memory(view_model);
+    }
+
+    fn listen_for_process_change(view_model: Arc<MemoryViewerViewModel>) {
+        let engine_execution_context = view_model.engine_execution_context.clone();
+
+        engine_execution_context.listen_for_engine_event::<ProcessChangedEvent>(move |process_changed_event| {
+            view_model
+                .is_process_opened
+                .store(process_changed_event.process_info.is_some(), Ordering::Release);
+        });
+    }
+
+    fn poll_memory(view_model: Arc<MemoryViewerViewModel>) {
+        // Refresh memory on a loop. JIRA: This should be coming from settings, same as scan results.
+        thread::spawn(move || {
+            loop {
+                if view_model.is_process_opened.load(Ordering::Acquire) {
+                    Self::refresh_memory_if_visible(view_model.clone());
+                }
+
+                thread::sleep(Duration::from_millis(100));
+            }
+        });
+    }
+
+    /// Refreshes memory only while the memory viewer is shown and has an address, as reads are otherwise wasted on the engine.
+    fn refresh_memory_if_visible(view_model: Arc<MemoryViewerViewModel>) {
+        let view_binding = view_model.view_binding.clone();
+
+        view_binding.execute_on_ui_thread(move |main_window_view, _view_binding| {
+            let memory_viewer_window = main_window_view
+                .global::<DockRootViewModelBindings>()
+                .get_memory_viewer_window();
+
+            // Mirrors the visibility of the docked window view, which hides inactive tabs.
+            let is_visible = memory_viewer_window.is_visible
+                && (memory_viewer_window.tab_ids.row_count() <= 1 || memory_viewer_window.active_tab_id == memory_viewer_window.identifier);
+            let has_address = !main_window_view
+                .global::<MemoryViewerViewModelBindings>()
+                .get_address_string()
+                .trim()
+                .is_empty();
+
+            if is_visible && has_address {
+                Self::refresh_memory(view_model);
+            }
+        });
+    }
+
+    /// Gets the base addresses of each read page overlapping the visible address range.
+    fn get_visible_page_addresses(address: u64) -> Vec<u64> {
+        let first_page_address = address - address % Self::READ_PAGE_SIZE;
+        let end_address = address.saturating_add(Self::VISIBLE_BYTE_COUNT);
+
+        (first_page_address..end_address)
+            .step_by(Self::READ_PAGE_SIZE as usize)
+            .collect()
+    }
+
+    /// Issues a paginated read for each page overlapping the visible address range. Each response updates the page cache and the view.
+    fn refresh_memory(view_model: Arc<MemoryViewerViewModel>) {
+        let page_addresses = Self::get_visible_page_addresses(view_model.current_address.load(Ordering::Acquire));
+
+        // Drop any cached pages that have scrolled out of view.
+        if let Ok(mut pages) = view_model.pages.write() {
+            pages.retain(|page_address, _| page_addresses.contains(page_address));
+        }
+
+        for page_address in page_addresses {
+            let memory_read_request = MemoryReadRequest {
+                address: AddressExpression::from(page_address),
+                valued_struct: DataValue::new(DataTypeRef::new(DataTypeU8::get_data_type_id()), vec![0u8; Self::READ_PAGE_SIZE as usize])
+                    .to_anonymous_valued_struct(false),
+            };
+            let view_model = view_model.clone();
+
+            memory_read_request.send(&view_model.engine_execution_context.clone(), move |memory_read_response| {
+                let bytes = if memory_read_response.success {
+                    Some(memory_read_response.valued_struct.get_bytes())
+                } else {
+                    None
+                };
+
+                if let Ok(mut pages) = view_model.pages.write() {
+                    pages
+                        .entry(page_address)
+                        .or_insert_with(|| MemoryViewerPage::new(Self::READ_PAGE_SIZE))
+                        .update_bytes(bytes);
+                }
+
+                Self::update_view(&view_model);
+            });
+        }
+    }
+
+    /// Rebuilds the visible rows and selection interpretations from the page cache.
+    fn update_view(view_model: &Arc<MemoryViewerViewModel>) {
+        let address = view_model.current_address.load(Ordering::Acquire);
+        let pages = match view_model.pages.read() {
+            Ok(pages) => pages,
+            Err(error) => {
+                log::error!("Failed to acquire memory viewer page cache: {}", error);
+                return;
+            }
+        };
+
+        let rows = (0..Self::VISIBLE_ROW_COUNT)
+            .map(|row_index| {
+                let row_address = address.saturating_add(row_index * Self::BYTES_PER_ROW);
+                let row_addresses = (0..Self::BYTES_PER_ROW).map(|column_index| row_address.saturating_add(column_index));
+                let bytes = row_addresses
+                    .clone()
+                    .map(|byte_address| Self::get_cached_byte(&pages, byte_address))
+                    .collect();
+                let changed_bytes = row_addresses
+                    .map(|byte_address| Self::was_cached_byte_changed(&pages, byte_address))
+                    .collect();
+
+                MemoryViewerRow::new(row_address, bytes, changed_bytes)
+            })
+            .collect();
+
+        let selected_bytes = Self::collect_selected_bytes(view_model, &pages);
+
+        drop(pages);
+
+        view_model
+            .memory_viewer_rows_collection
+            .update_from_source(rows);
+
+        if let Some(selected_bytes) = selected_bytes {
+            let interpretations = Self::interpret_bytes(&selected_bytes);
+
+            view_model
+                .view_binding
+                .execute_on_ui_thread(move |main_window_view, _view_binding| {
+                    let memory_viewer_bindings = main_window_view.global::<MemoryViewerViewModelBindings>();
+
+                    memory_viewer_bindings.set_selection_interpretations(ModelRc::new(VecModel::from(interpretations)));
+                });
+        }
+    }
+
+    fn get_cached_byte(
+        pages: &HashMap<u64, MemoryViewerPage>,
+        address: u64,
+    ) -> Option<u8> {
+        let page_offset = address % Self::READ_PAGE_SIZE;
+
+        pages
+            .get(&(address - page_offset))
+            .and_then(|page| page.get_bytes().as_ref())
+            .and_then(|bytes| bytes.get(page_offset as usize).copied())
+    }
+
+    fn was_cached_byte_changed(
+        pages: &HashMap<u64, MemoryViewerPage>,
+        address: u64,
+    ) -> bool {
+        let page_offset = address % Self::READ_PAGE_SIZE;
+
+        match pages.get(&(address - page_offset)) {
+            Some(page) => page.was_changed_within(page_offset as usize, Self::CHANGE_HIGHLIGHT_DURATION),
+            None => false,
+        }
+    }
+
+    /// Gets the address range of the current selection, if any, as an inclusive start and exclusive end address.
+    fn get_selected_address_range(view_model: &Arc<MemoryViewerViewModel>) -> Option<(u64, u64)> {
+        let mut selection_index_start = view_model.selection_index_start.load(Ordering::Acquire);
+        let mut selection_index_end = view_model.selection_index_end.load(Ordering::Acquire);
+
+        // If either start or end is invalid, set the start and end to the same value (single selection).
+        if selection_index_start < 0 && selection_index_end >= 0 {
+            selection_index_start = selection_index_end;
+        } else if selection_index_end < 0 && selection_index_start >= 0 {
+            selection_index_end = selection_index_start;
+        }
+
+        if selection_index_start < 0 || selection_index_end < 0 {
+            return None;
+        }
+
+        let address = view_model.current_address.load(Ordering::Acquire);
+        let start_address = address.saturating_add(cmp::min(selection_index_start, selection_index_end) as u64);
+        let end_address = address.saturating_add(cmp::max(selection_index_start, selection_index_end) as u64 + 1);
+
+        Some((start_address, end_address))
+    }
+
+    /// Collects the selected bytes from the page cache. Returns `None` if there is no selection, or if any selected byte is unreadable.
+    fn collect_selected_bytes(
+        view_model: &Arc<MemoryViewerViewModel>,
+        pages: &HashMap<u64, MemoryViewerPage>,
+    ) -> Option<Vec<u8>> {
+        let (start_address, end_address) = Self::get_selected_address_range(view_model)?;
+
+        (start_address..end_address)
+            .take(Self::MAX_INTERPRETATION_SIZE)
+            .map(|address| Self::get_cached_byte(pages, address))
+            .collect()
+    }
+
+    /// Interprets the given bytes as every registered data type that fits within them, using each type's default display value.
+    fn interpret_bytes(bytes: &[u8]) -> Vec<MemoryViewerInterpretationViewData> {
+        let mut data_types = match DataTypeRegistry::get_instance().get_registry().read() {
+            Ok(registry) => registry.values().cloned().collect::<Vec<_>>(),
+            Err(error) => {
+                log::error!("Error reading from data type registry: {}", error);
+                return vec![];
+            }
+        };
+
+        data_types.sort_by(|data_type_a, data_type_b| {
+            data_type_a
+                .get_unit_size_in_bytes()
+                .cmp(&data_type_b.get_unit_size_in_bytes())
+                .then_with(|| data_type_a.get_data_type_id().cmp(data_type_b.get_data_type_id()))
+        });
+
+        data_types
+            .iter()
+            .filter_map(|data_type| {
+                let unit_size = data_type.get_unit_size_in_bytes() as usize;
+
+                if unit_size == 0 || unit_size > bytes.len() {
+                    return None;
+                }
+
+                // Interpret as many whole elements as fit in the selection.
+                let value_bytes = bytes[..bytes.len() - bytes.len() % unit_size].to_vec();
+                let data_value = DataValue::new(data_type.get_ref(), value_bytes);
+
+                Some(MemoryViewerInterpretationViewData {
+                    data_type_id: data_type.get_data_type_id().into(),
+                    icon_id: data_type.get_icon_id().into(),
+                    display_value: data_value.get_default_display_value_string().into(),
+                })
+            })
+            .collect()
+    }
+
+    /// Queries the region and module containing the given address, and displays them as labels.
+    fn query_address_labels(
+        view_model: Arc<MemoryViewerViewModel>,
+        address: u64,
+    ) {
+        let memory_query_request = MemoryQueryRequest {
+            address: AddressExpression::from(address),
+        };
+        let view_binding = view_model.view_binding.clone();
+
+        memory_query_request.send(&view_model.engine_execution_context, move |memory_query_response| {
+            let region_label = if memory_query_response.success {
+                format!(
+                    "Region {:X}-{:X} ({})",
+                    memory_query_response.region_base_address,
+                    memory_query_response
+                        .region_base_address
+                        .saturating_add(memory_query_response.region_size),
+                    memory_query_response.protection
+                )
+            } else {
+                "Unmapped".to_string()
+            };
+
+            let module_label = if memory_query_response.module_name.is_empty() {
+                String::new()
+            } else {
+                format!("{}+{:X}", memory_query_response.module_name, memory_query_response.module_offset)
+            };
+
+            view_binding.execute_on_ui_thread(move |main_window_view, _view_binding| {
+                let memory_viewer_bindings = main_window_view.global::<MemoryViewerViewModelBindings>();
+
+                memory_viewer_bindings.set_region_label(region_label.into());
+                memory_viewer_bindings.set_module_label(module_label.into());
+            });
+        });
+    }
+
+    fn on_navigate_to_address(
+        view_model: Arc<MemoryViewerViewModel>,
+        address_text: SharedString,
+    ) {
+        let address_expression = match address_text.parse::<AddressExpression>() {
+            Ok(address_expression) => address_expression,
+            Err(error) => {
+                log::warn!("{}", error);
+                return;
+            }
+        };
+
+        // Plain addresses navigate immediately, whereas module offsets and pointers must be resolved by the engine first.
+        if let Some(address) = address_expression.get_constant_value() {
+            Self::navigate_to_address(view_model, address);
+            return;
+        }
+
+        let memory_query_request = MemoryQueryRequest { address: address_expression };
+
+        memory_query_request.send(&view_model.engine_execution_context.clone(), move |memory_query_response| {
+            if memory_query_response.address_error.is_none() {
+                Self::navigate_to_address(view_model, memory_query_response.address);
+            }
+        });
+    }
+
+    fn on_navigate_previous_page(view_model: Arc<MemoryViewerViewModel>) {
+        let address = view_model
+            .current_address
+            .load(Ordering::Acquire)
+            .saturating_sub(Self::VISIBLE_BYTE_COUNT);
+
+        Self::navigate_to_address(view_model, address);
+    }
+
+    fn on_navigate_next_page(view_model: Arc<MemoryViewerViewModel>) {
+        let address = view_model
+            .current_address
+            .load(Ordering::Acquire)
+            .saturating_add(Self::VISIBLE_BYTE_COUNT);
+
+        Self::navigate_to_address(view_model, address);
+    }
+
+    fn on_scroll_rows(
+        view_model: Arc<MemoryViewerViewModel>,
+        row_delta: i32,
+    ) {
+        let current_address = view_model.current_address.load(Ordering::Acquire);
+        let byte_delta = row_delta.unsigned_abs() as u64 * Self::BYTES_PER_ROW;
+        let address = if row_delta < 0 {
+            current_address.saturating_sub(byte_delta)
+        } else {
+            current_address.saturating_add(byte_delta)
+        };
+
+        Self::navigate_to_address(view_model, address);
+    }
+
+    fn on_set_byte_selection_start(
+        view_model: Arc<MemoryViewerViewModel>,
+        local_byte_index: i32,
+    ) {
+        view_model
+            .selection_index_start
+            .store(local_byte_index, Ordering::Release);
+
+        if let Some((start_address, _end_address)) = Self::get_selected_address_range(&view_model) {
+            Self::query_address_labels(view_model.clone(), start_address);
+        }
+
+        Self::update_view(&view_model);
+    }
+
+    fn on_set_byte_selection_end(
+        view_model: Arc<MemoryViewerViewModel>,
+        local_byte_index: i32,
+    ) {
+        view_model
+            .selection_index_end
+            .store(local_byte_index, Ordering::Release);
+
+        Self::update_view(&view_model);
+    }
+
+    fn on_commit_byte_edit(
+        view_model: Arc<MemoryViewerViewModel>,
+        local_byte_index: i32,
+        new_value: SharedString,
+    ) {
+        if local_byte_index < 0 {
+            return;
+        }
+
+        // Allow entering several bytes at once, which are written starting at the edited byte.
+        let value = match Conversions::hex_to_bytes(new_value.as_str()) {
+            Ok(value) if !value.is_empty() => value,
+            Ok(_) => return,
+            Err(error) => {
+                log::warn!("Invalid byte value '{}': {}", new_value, error);
+                return;
+            }
+        };
+
+        let address = view_model
+            .current_address
+            .load(Ordering::Acquire)
+            .saturating_add(local_byte_index as u64);
+        let memory_write_request = MemoryWriteRequest {
+            address: AddressExpression::from(address),
+            value,
+        };
+
+        memory_write_request.send(&view_model.engine_execution_context.clone(), move |memory_write_response| {
+            if !memory_write_response.success {
+                log::error!("Failed to write memory at address {:X}.", address);
+            }
+
+            Self::refresh_memory(view_model);
+        });
+    }
+}
@@ -0,0 +1,3 @@
+pub mod memory_viewer_page;
+pub mod memory_viewer_row;
+pub mod memory_viewer_view_model;
@@ -1,6 +1,7 @@
 pub mod conversions_view_model;
 pub mod docking;
 pub mod main_window;
+pub mod memory_viewer;
 pub mod output;
 pub mod process_selector;
 pub mod project_explorer;
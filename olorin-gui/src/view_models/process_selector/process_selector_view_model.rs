@@ -7,6 +7,7 @@ use olorin_engine::command_executors::engine_request_executor::EngineCommandRequ
 use olorin_engine::engine_execution_context::EngineExecutionContext;
 use olorin_engine_api::commands::process::list::process_list_request::ProcessListRequest;
 use olorin_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
+use olorin_engine_api::commands::process::speed::process_speed_request::ProcessSpeedRequest;
 use olorin_engine_api::dependency_injection::dependency_container::DependencyContainer;
 use olorin_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent;
 use olorin_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
@@ -66,6 +67,7 @@ impl ProcessSelectorViewModel {
                     on_refresh_full_process_list() -> [view_model] -> Self::on_refresh_full_process_list
                     on_refresh_windowed_process_list() -> [view_model] -> Self::on_refresh_windowed_process_list
                     on_select_process(process_entry: ProcessViewData) -> [view_model] -> Self::on_select_process
+                    on_set_speed_factor(speed_factor: f32) -> [view_model] -> Self::on_set_speed_factor
                 }
             });
         }
@@ -92,6 +94,9 @@ impl ProcessSelectorViewModel {
             .execute_on_ui_thread(move |main_window_view, _| {
                 let process_selector_bindings = main_window_view.global::<ProcessSelectorViewModelBindings>();
 
+                // Newly opened processes always start at real time speed.
+                process_selector_bindings.set_speed_factor(1.0);
+
                 if let Some(process_info) = process_info {
                     process_selector_bindings.set_selected_process(OpenedProcessInfoConverter::new().convert_to_view_data(&process_info));
                 } else {
@@ -147,6 +152,8 @@ impl ProcessSelectorViewModel {
             process_id: Some(process_entry.process_id as u32),
             search_name: None,
             match_case: false,
+            core_file_path: None,
+            file_path: None,
         };
         let engine_execution_context = view_model.engine_execution_context.clone();
 
@@ -154,4 +161,39 @@ impl ProcessSelectorViewModel {
             Self::refresh_opened_process(view_model.clone(), process_open_response.opened_process_info)
         });
     }
+
+    fn on_set_speed_factor(
+        view_model: Arc<ProcessSelectorViewModel>,
+        speed_factor: f32,
+    ) {
+        let process_speed_request = ProcessSpeedRequest {
+            speed_factor: speed_factor as f64,
+        };
+        let engine_execution_context = view_model.engine_execution_context.clone();
+
+        let failed_view_model = view_model.clone();
+
+        process_speed_request.send_or_else(
+            &engine_execution_context,
+            move |process_speed_response| {
+                if !process_speed_response.success {
+                    Self::reset_speed_factor(&view_model);
+                }
+            },
+            move |error| {
+                log::error!("Failed to set speed factor: {}", error);
+                Self::reset_speed_factor(&failed_view_model);
+            },
+        );
+    }
+
+    fn reset_speed_factor(view_model: &Arc<ProcessSelectorViewModel>) {
+        view_model
+            .view_binding
+            .execute_on_ui_thread(move |main_window_view, _| {
+                main_window_view
+                    .global::<ProcessSelectorViewModelBindings>()
+                    .set_speed_factor(1.0);
+            });
+    }
 }
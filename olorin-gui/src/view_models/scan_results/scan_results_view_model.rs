@@ -12,11 +12,12 @@ use olorin_engine_api::commands::scan_results::add_to_project::scan_results_add_
 use olorin_engine_api::commands::scan_results::delete::scan_results_delete_request::ScanResultsDeleteRequest;
 use olorin_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
 use olorin_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
-use olorin_engine_api::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
 use olorin_engine_api::commands::scan_results::set_property::scan_results_set_property_request::ScanResultsSetPropertyRequest;
+use olorin_engine_api::commands::scan_results::watch::scan_results_watch_request::ScanResultsWatchRequest;
 use olorin_engine_api::conversions::conversions::Conversions;
 use olorin_engine_api::dependency_injection::dependency_container::DependencyContainer;
 use olorin_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use olorin_engine_api::events::scan_results::values_changed::scan_results_values_changed_event::ScanResultsValuesChangedEvent;
 use olorin_engine_api::structures::data_values::data_value::DataValue;
 use olorin_engine_api::structures::scan_results::scan_result::ScanResult;
 use olorin_engine_api::structures::scan_results::scan_result_base::ScanResultBase;
@@ -32,13 +33,12 @@ use std::sync::RwLock;
 use std::sync::atomic::AtomicI32;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
-use std::thread;
-use std::time::Duration;
 
 pub struct ScanResultsViewModel {
     view_binding: Arc<ViewBinding<MainWindowView>>,
     audio_player: Arc<AudioPlayer>,
     base_scan_results_collection: Arc<RwLock<Vec<ScanResult>>>,
+    watched_scan_results_collection: Arc<RwLock<Vec<ScanResult>>>,
     scan_results_collection: ViewCollectionBinding<ScanResultViewData, ScanResult, MainWindowView>,
     engine_execution_context: Arc<EngineExecutionContext>,
     current_page_index: Arc<AtomicU64>,
@@ -49,6 +49,9 @@ pub struct ScanResultsViewModel {
 }
 
 impl ScanResultsViewModel {
+    /// The id under which this view subscribes to value changes for the scan results on the current page.
+    const SCAN_RESULTS_WATCH_ID: &str = "scan_results_view";
+
     pub fn register(dependency_container: &DependencyContainer) {
         dependency_container.resolve_all(Self::on_dependencies_resolved);
     }
@@ -77,6 +80,7 @@ impl ScanResultsViewModel {
             view_binding: view_binding.clone(),
             audio_player,
             base_scan_results_collection: base_scan_results_collection.clone(),
+            watched_scan_results_collection: Arc::new(RwLock::new(vec![])),
             scan_results_collection: scan_results_collection.clone(),
             engine_execution_context: engine_execution_context.clone(),
             current_page_index: current_page_index.clone(),
@@ -106,7 +110,7 @@ impl ScanResultsViewModel {
             });
         }
 
-        Self::poll_scan_results(view_model.clone());
+        Self::listen_for_scan_result_changes(view_model.clone());
 
         dependency_container.register::<ScanResultsViewModel>(view_model);
     }
@@ -136,7 +140,7 @@ impl ScanResultsViewModel {
         scan_results_set_property_request.send(&self.engine_execution_context, move |scan_results_set_property_response| {});
     }
 
-    fn poll_scan_results(view_model: Arc<ScanResultsViewModel>) {
+    fn listen_for_scan_result_changes(view_model: Arc<ScanResultsViewModel>) {
         let engine_execution_context = &view_model.engine_execution_context;
 
         // Requery all scan results if they update.
@@ -149,14 +153,39 @@ impl ScanResultsViewModel {
             });
         }
 
-        // Refresh scan values on a loop. JIRA: This should be coming from settings. We can probably cache, and have some mechanism for getting latest val.
-        thread::spawn(move || {
-            loop {
-                Self::refresh_scan_results(view_model.clone());
+        // Merge value changes pushed by the engine for the watched scan results into the displayed scan results.
+        {
+            let view_model = view_model.clone();
 
-                thread::sleep(Duration::from_millis(100));
-            }
-        });
+            engine_execution_context.listen_for_engine_event::<ScanResultsValuesChangedEvent>(move |scan_results_values_changed_event| {
+                if scan_results_values_changed_event.watch_id != Self::SCAN_RESULTS_WATCH_ID {
+                    return;
+                }
+
+                let scan_results = match view_model.watched_scan_results_collection.write() {
+                    Ok(mut watched_scan_results_collection) => {
+                        for changed_scan_result in &scan_results_values_changed_event.scan_results {
+                            for scan_result in watched_scan_results_collection.iter_mut().filter(|scan_result| {
+                                scan_result.get_address() == changed_scan_result.get_address()
+                                    && scan_result.get_data_type() == changed_scan_result.get_data_type()
+                            }) {
+                                *scan_result = changed_scan_result.clone();
+                            }
+                        }
+
+                        watched_scan_results_collection.clone()
+                    }
+                    Err(error) => {
+                        log::error!("Failed to acquire write lock on watched scan results: {}", error);
+                        return;
+                    }
+                };
+
+                view_model
+                    .scan_results_collection
+                    .update_from_source(scan_results);
+            });
+        }
     }
 
     fn load_current_page_index(view_model: &Arc<ScanResultsViewModel>) -> u64 {
@@ -206,34 +235,41 @@ impl ScanResultsViewModel {
                     }
                 }
 
-                Self::refresh_scan_results(view_model);
+                Self::watch_scan_results(view_model);
             });
         });
     }
 
-    /// Fetches up-to-date values and module information for the current scan results, then updates the UI.
-    fn refresh_scan_results(view_model: Arc<ScanResultsViewModel>) {
+    /// Subscribes to value changes for the current page of scan results, updating the UI with their up-to-date values.
+    fn watch_scan_results(view_model: Arc<ScanResultsViewModel>) {
         let scan_results_collection = view_model.scan_results_collection.clone();
         let base_scan_results_collection = &view_model.base_scan_results_collection;
+        let watched_scan_results_collection = view_model.watched_scan_results_collection.clone();
         let engine_execution_context = &view_model.engine_execution_context;
 
         // Gather the current/incomplete scan results.
-        let scan_results_to_refresh = match base_scan_results_collection.read() {
+        let scan_results_to_watch = match base_scan_results_collection.read() {
             Ok(base_scan_results_collection) => base_scan_results_collection.clone(),
             Err(_) => vec![],
         };
 
-        // Fire a request to get all scan result data needed for display.
-        let scan_results_refresh_request = ScanResultsRefreshRequest {
-            scan_results: scan_results_to_refresh
+        // Replace the prior subscription, which also fetches all scan result data needed for display.
+        let scan_results_watch_request = ScanResultsWatchRequest {
+            watch_id: Self::SCAN_RESULTS_WATCH_ID.to_string(),
+            scan_results: scan_results_to_watch
                 .iter()
                 .map(|scan_result| scan_result.get_valued_result().clone())
                 .collect(),
+            refresh_interval_ms: None,
         };
 
-        scan_results_refresh_request.send(engine_execution_context, move |scan_results_refresh_response| {
-            // Update UI with refreshed, full scan result values.
-            scan_results_collection.update_from_source(scan_results_refresh_response.scan_results);
+        scan_results_watch_request.send(engine_execution_context, move |scan_results_watch_response| {
+            if let Ok(mut watched_scan_results_collection) = watched_scan_results_collection.write() {
+                *watched_scan_results_collection = scan_results_watch_response.scan_results.clone();
+            }
+
+            // Update UI with full scan result values.
+            scan_results_collection.update_from_source(scan_results_watch_response.scan_results);
         });
     }
 
@@ -354,7 +390,10 @@ impl ScanResultsViewModel {
 
         if !scan_results.is_empty() {
             let engine_execution_context = &view_model.engine_execution_context;
-            let scan_results_add_to_project_request = ScanResultsAddToProjectRequest { scan_results };
+            let scan_results_add_to_project_request = ScanResultsAddToProjectRequest {
+                scan_results,
+                directory_name: None,
+            };
 
             scan_results_add_to_project_request.send(engine_execution_context, |_response| {});
         }
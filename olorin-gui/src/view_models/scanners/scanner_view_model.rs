@@ -1,11 +1,13 @@
 use crate::DisplayValueViewData;
 use crate::MainWindowView;
 use crate::ScanConstraintTypeView;
+use crate::ScanHistoryEntryViewData;
 use crate::ScannerViewModelBindings;
 use crate::ValueCollectorViewModelBindings;
 use crate::converters::data_value_converter::DataValueConverter;
 use crate::converters::display_value_converter::DisplayValueConverter;
 use crate::converters::scan_constraint_converter::ScanConstraintConverter;
+use crate::converters::scan_history_entry_converter::ScanHistoryEntryConverter;
 use slint::ComponentHandle;
 use slint::Model;
 use slint::ModelRc;
@@ -13,16 +15,23 @@ use slint::SharedString;
 use slint_mvvm::convert_from_view_data::ConvertFromViewData;
 use slint_mvvm::convert_to_view_data::ConvertToViewData;
 use slint_mvvm::view_binding::ViewBinding;
+use slint_mvvm::view_collection_binding::ViewCollectionBinding;
 use slint_mvvm_macros::create_view_bindings;
+use slint_mvvm_macros::create_view_model_collection;
 use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
 use olorin_engine::engine_execution_context::EngineExecutionContext;
 use olorin_engine_api::commands::scan::collect_values::scan_collect_values_request::ScanCollectValuesRequest;
 use olorin_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use olorin_engine_api::commands::scan::history::scan_history_request::ScanHistoryRequest;
 use olorin_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
 use olorin_engine_api::commands::scan::reset::scan_reset_request::ScanResetRequest;
+use olorin_engine_api::commands::scan::undo::scan_undo_request::ScanUndoRequest;
+use olorin_engine_api::conversions::conversions::Conversions;
 use olorin_engine_api::dependency_injection::dependency_container::DependencyContainer;
+use olorin_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
 use olorin_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
 use olorin_engine_api::structures::data_values::anonymous_value::AnonymousValue;
+use olorin_engine_api::structures::scanning::history::scan_history_entry::ScanHistoryEntry;
 use std::sync::Arc;
 use std::sync::RwLock;
 
@@ -37,6 +46,7 @@ pub struct ScannerViewModel {
     view_binding: Arc<ViewBinding<MainWindowView>>,
     engine_execution_context: Arc<EngineExecutionContext>,
     scan_view_model_state: RwLock<ScanViewModelState>,
+    scan_history_collection: ViewCollectionBinding<ScanHistoryEntryViewData, ScanHistoryEntry, MainWindowView>,
 }
 
 impl ScannerViewModel {
@@ -48,10 +58,17 @@ impl ScannerViewModel {
         dependency_container: DependencyContainer,
         (view_binding, engine_execution_context): (Arc<ViewBinding<MainWindowView>>, Arc<EngineExecutionContext>),
     ) {
+        let scan_history_collection = create_view_model_collection!(
+            view_binding -> MainWindowView,
+            ScannerViewModelBindings -> { set_scan_history, get_scan_history },
+            ScanHistoryEntryConverter -> [],
+        );
+
         let view_model = Arc::new(ScannerViewModel {
             view_binding: view_binding.clone(),
             engine_execution_context: engine_execution_context.clone(),
             scan_view_model_state: RwLock::new(ScanViewModelState::NoResults),
+            scan_history_collection,
         });
 
         {
@@ -60,6 +77,7 @@ impl ScannerViewModel {
             create_view_bindings!(view_model.view_binding, {
                 ScannerViewModelBindings => {
                     on_reset_scan() -> [view_model] -> Self::on_reset_scan,
+                    on_undo_scan(scan_count: i32) -> [view_model] -> Self::on_undo_scan,
                     on_start_scan(scan_value: SharedString, data_type_ids: ModelRc<SharedString>, display_value: DisplayValueViewData, scan_constraint: ScanConstraintTypeView) -> [view_model] -> Self::on_start_scan,
                 },
                 ValueCollectorViewModelBindings => {
@@ -69,6 +87,7 @@ impl ScannerViewModel {
         }
 
         Self::set_default_selection(view_model.clone());
+        Self::listen_for_scan_history_changes(view_model.clone());
 
         dependency_container.register::<ScannerViewModel>(view_model);
     }
@@ -84,6 +103,46 @@ impl ScannerViewModel {
             });
     }
 
+    fn listen_for_scan_history_changes(view_model: Arc<ScannerViewModel>) {
+        let engine_execution_context = view_model.engine_execution_context.clone();
+
+        engine_execution_context.listen_for_engine_event::<ScanResultsUpdatedEvent>(move |_scan_results_updated_event| {
+            Self::query_scan_history(view_model.clone());
+        });
+    }
+
+    fn query_scan_history(view_model: Arc<ScannerViewModel>) {
+        let scan_history_request = ScanHistoryRequest {};
+        let engine_execution_context = &view_model.engine_execution_context;
+        let view_model = view_model.clone();
+
+        scan_history_request.send(engine_execution_context, move |scan_history_response| {
+            let memory_cost = Conversions::value_to_metric_size(scan_history_response.total_memory_cost);
+
+            view_model
+                .scan_history_collection
+                .update_from_source(scan_history_response.scan_history_entries);
+            view_model
+                .view_binding
+                .execute_on_ui_thread(move |main_window_view, _view_binding| {
+                    let scanner_view_model_bindings = main_window_view.global::<ScannerViewModelBindings>();
+
+                    scanner_view_model_bindings.set_scan_history_memory_cost(memory_cost.into());
+                });
+        });
+    }
+
+    fn on_undo_scan(
+        view_model: Arc<ScannerViewModel>,
+        scan_count: i32,
+    ) {
+        let scan_undo_request = ScanUndoRequest {
+            scan_count: scan_count.max(0) as u32,
+        };
+
+        scan_undo_request.send(&view_model.engine_execution_context, |_scan_undo_response| {});
+    }
+
     fn on_reset_scan(view_model: Arc<ScannerViewModel>) {
         let scan_reset_request = ScanResetRequest {};
         let engine_execution_context = &view_model.engine_execution_context;
@@ -176,17 +235,31 @@ impl ScannerViewModel {
             compare_type: ScanConstraintConverter::new().convert_from_view_data(&scan_constraint),
         };
 
-        element_scan_request.send(&engine_execution_context, move |scan_execute_response| {
-            let scan_view_model_state = &view_model.scan_view_model_state;
+        let failed_view_model = view_model.clone();
+        let mut previous_scan_view_model_state = ScanViewModelState::NoResults;
 
-            if let Ok(mut scan_view_model_state) = scan_view_model_state.write() {
-                *scan_view_model_state = ScanViewModelState::ScanInProgress;
-            }
-            // JIRA: We actually need to wait for the task to complete, which can be tricky with our request/response architecture.
-            // For now we just set it immediately to avoid being stuck in in progress state.
-            if let Ok(mut scan_view_model_state) = scan_view_model_state.write() {
-                *scan_view_model_state = ScanViewModelState::HasResults;
-            }
-        });
+        if let Ok(mut scan_view_model_state) = view_model.scan_view_model_state.write() {
+            previous_scan_view_model_state = *scan_view_model_state;
+            *scan_view_model_state = ScanViewModelState::ScanInProgress;
+        }
+
+        element_scan_request.send_or_else(
+            &engine_execution_context,
+            move |_scan_execute_response| {
+                // JIRA: We actually need to wait for the task to complete, which can be tricky with our request/response architecture.
+                // For now we just set it immediately to avoid being stuck in in progress state.
+                if let Ok(mut scan_view_model_state) = view_model.scan_view_model_state.write() {
+                    *scan_view_model_state = ScanViewModelState::HasResults;
+                }
+            },
+            move |error| {
+                log::error!("Failed to start scan: {}", error);
+
+                // Restore the prior state rather than remaining stuck in progress.
+                if let Ok(mut scan_view_model_state) = failed_view_model.scan_view_model_state.write() {
+                    *scan_view_model_state = previous_scan_view_model_state;
+                }
+            },
+        );
     }
 }
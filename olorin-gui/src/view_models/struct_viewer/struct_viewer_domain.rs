@@ -1,4 +1,8 @@
+use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
+
 pub enum StructViewerDomain {
     None,
     ScanResult,
+    /// A project struct resolved by name and read from memory at the given address.
+    Memory { address: u64, valued_struct: ValuedStruct },
 }
@@ -7,13 +7,22 @@ use crate::converters::display_value_converter::DisplayValueConverter;
 use crate::converters::valued_struct_converter::ValuedStructConverter;
 use crate::view_models::scan_results::scan_results_view_model::ScanResultsViewModel;
 use crate::view_models::struct_viewer::struct_viewer_domain::StructViewerDomain;
+use olorin_engine::command_executors::engine_request_executor::EngineCommandRequestExecutor;
 use olorin_engine::engine_execution_context::EngineExecutionContext;
+use olorin_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
+use olorin_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use olorin_engine_api::commands::project::list_structs::project_list_structs_request::ProjectListStructsRequest;
 use olorin_engine_api::dependency_injection::dependency_container::DependencyContainer;
 use olorin_engine_api::dependency_injection::lazy::Lazy;
+use olorin_engine_api::events::project::closed::project_closed_event::ProjectClosedEvent;
+use olorin_engine_api::structures::address_expressions::address_expression::AddressExpression;
 use olorin_engine_api::structures::data_values::anonymous_value::AnonymousValue;
+use olorin_engine_api::structures::structs::symbolic_struct_ref::SymbolicStructRef;
 use olorin_engine_api::structures::structs::valued_struct::ValuedStruct;
 use slint::ComponentHandle;
+use slint::ModelRc;
 use slint::SharedString;
+use slint::VecModel;
 use slint_mvvm::convert_from_view_data::ConvertFromViewData;
 use slint_mvvm::convert_to_view_data::ConvertToViewData;
 use slint_mvvm::view_binding::ViewBinding;
@@ -51,11 +60,15 @@ impl StructViewerViewModel {
             // Route all view bindings to Rust.
             create_view_bindings!(view_binding, {
                 StructViewerViewModelBindings => {
+                    on_refresh_struct_names() -> [view_model] -> Self::on_refresh_struct_names,
+                    on_view_struct(struct_name: SharedString, address_text: SharedString) -> [view_model] -> Self::on_view_struct,
                     on_commit_field_change(field_namespace: SharedString, new_value: SharedString, display_value: DisplayValueViewData, data_type_ref: DataTypeRefViewData) -> [view_model] -> Self::on_commit_field_change
                 }
             });
         }
 
+        Self::listen_for_project_changes(view_model.clone());
+
         dependency_container.register::<StructViewerViewModel>(view_model);
     }
 
@@ -66,6 +79,13 @@ impl StructViewerViewModel {
     ) {
         let view_binding = &self.view_binding;
         let selected_struct = ValuedStruct::combine_exclusive(&selected_structs);
+        let viewed_struct_name = match &struct_viewer_domain {
+            StructViewerDomain::Memory { valued_struct, .. } => valued_struct
+                .get_symbolic_struct_ref()
+                .get_symbolic_struct_namespace()
+                .to_string(),
+            _ => String::new(),
+        };
 
         if let Ok(mut struct_viewer_domain_lock) = self.struct_viewer_domain.write() {
             *struct_viewer_domain_lock = struct_viewer_domain
@@ -74,7 +94,70 @@ impl StructViewerViewModel {
         view_binding.execute_on_ui_thread(move |main_window_view, _view_binding| {
             let struct_viewer_bindings = main_window_view.global::<StructViewerViewModelBindings>();
 
-            struct_viewer_bindings.set_struct_under_view(ValuedStructConverter {}.convert_to_view_data(&selected_struct))
+            struct_viewer_bindings.set_struct_under_view(ValuedStructConverter {}.convert_to_view_data(&selected_struct));
+            struct_viewer_bindings.set_viewed_struct_name(viewed_struct_name.into());
+        });
+    }
+
+    fn listen_for_project_changes(view_model: Arc<StructViewerViewModel>) {
+        let engine_execution_context = view_model.engine_execution_context.clone();
+
+        engine_execution_context.listen_for_engine_event::<ProjectClosedEvent>(move |_project_closed_event| {
+            Self::on_refresh_struct_names(view_model.clone());
+        });
+    }
+
+    fn on_refresh_struct_names(view_model: Arc<StructViewerViewModel>) {
+        let project_list_structs_request = ProjectListStructsRequest {};
+        let view_binding = view_model.view_binding.clone();
+
+        project_list_structs_request.send(&view_model.engine_execution_context, move |project_list_structs_response| {
+            view_binding.execute_on_ui_thread(move |main_window_view, _view_binding| {
+                let struct_viewer_bindings = main_window_view.global::<StructViewerViewModelBindings>();
+                let struct_names: Vec<SharedString> = project_list_structs_response
+                    .struct_names
+                    .into_iter()
+                    .map(SharedString::from)
+                    .collect();
+
+                struct_viewer_bindings.set_struct_names(ModelRc::new(VecModel::from(struct_names)));
+            });
+        });
+    }
+
+    /// Resolves the named project struct in the engine, which lays it out for the opened process and reads it at the given address.
+    fn on_view_struct(
+        view_model: Arc<StructViewerViewModel>,
+        struct_name: SharedString,
+        address_text: SharedString,
+    ) {
+        let address = match address_text.parse::<AddressExpression>() {
+            Ok(address) => address,
+            Err(error) => {
+                log::warn!("{}", error);
+                return;
+            }
+        };
+        let memory_read_request = MemoryReadRequest {
+            address,
+            valued_struct: ValuedStruct::new(SymbolicStructRef::new(struct_name.to_string()), vec![]),
+        };
+
+        memory_read_request.send(&view_model.engine_execution_context.clone(), move |memory_read_response| {
+            if !memory_read_response.success {
+                log::error!("Failed to read struct {} at address {}.", struct_name, address_text);
+                return;
+            }
+
+            let valued_struct = memory_read_response.valued_struct;
+
+            view_model.set_selected_structs(
+                StructViewerDomain::Memory {
+                    address: memory_read_response.address,
+                    valued_struct: valued_struct.clone(),
+                },
+                vec![valued_struct],
+            );
         });
     }
 
@@ -115,6 +198,43 @@ impl StructViewerViewModel {
 
                 scan_results_view_model.set_selected_scan_results_value(field_namespace.to_string(), data_value);
             }
+            StructViewerDomain::Memory { address, ref valued_struct } => {
+                let Some(field_offset) = valued_struct.get_field_offset(&field_namespace) else {
+                    log::warn!("Failed to find field {} in the viewed struct.", field_namespace);
+                    return;
+                };
+                let struct_name = valued_struct
+                    .get_symbolic_struct_ref()
+                    .get_symbolic_struct_namespace()
+                    .to_string();
+
+                // Release the domain before writing, as the re-read replaces it.
+                drop(struct_viewer_domain_lock);
+
+                Self::write_struct_field(view_model.clone(), struct_name, address, field_offset, data_value.get_value_bytes().clone());
+            }
         }
     }
+
+    fn write_struct_field(
+        view_model: Arc<StructViewerViewModel>,
+        struct_name: String,
+        address: u64,
+        field_offset: u64,
+        value: Vec<u8>,
+    ) {
+        let memory_write_request = MemoryWriteRequest {
+            address: AddressExpression::from(address.saturating_add(field_offset)),
+            value,
+        };
+
+        memory_write_request.send(&view_model.engine_execution_context.clone(), move |memory_write_response| {
+            if !memory_write_response.success {
+                log::error!("Failed to write struct {} at offset {:X}.", struct_name, field_offset);
+            }
+
+            // Re-read the struct, such that the view shows the value that was actually written.
+            Self::on_view_struct(view_model, struct_name.into(), format!("0x{:X}", address).into());
+        });
+    }
 }
@@ -0,0 +1,6 @@
+//! A library loaded into a target process that scales the passage of time within it, by hooking the clock and sleep functions of
+//! the C library.
+//! The speed factor is controlled by the engine through a shared memory file, see `SpeedhackController`.
+
+#[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod linux;
@@ -0,0 +1,176 @@
+use crate::linux::scaled_clock::ScaledClock;
+use crate::linux::speedhack_library::SpeedhackLibrary;
+use std::sync::Mutex;
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The scaled state of each clock, indexed by clock id. Clock ids past the end, such as CPU time clocks, are never scaled.
+static SCALED_CLOCKS: Mutex<[ScaledClock; 12]> = Mutex::new([const { ScaledClock::new() }; 12]);
+
+/// Replacements for the clock functions of the C library. These get real time straight from the kernel, as the originals are
+/// overwritten by the jumps to these hooks.
+pub struct ClockHooks;
+
+impl ClockHooks {
+    pub unsafe extern "C" fn clock_gettime_hook(
+        clock_id: libc::clockid_t,
+        time: *mut libc::timespec,
+    ) -> libc::c_int {
+        let result = unsafe { libc::syscall(libc::SYS_clock_gettime, clock_id, time) } as libc::c_int;
+
+        if result != 0 || !Self::is_scaled_clock(clock_id) {
+            return result;
+        }
+
+        let real_nanoseconds = Self::timespec_to_nanoseconds(unsafe { &*time });
+        let speed_factor = SpeedhackLibrary::get_speed_factor();
+        let scaled_nanoseconds = match SCALED_CLOCKS.lock() {
+            Ok(mut scaled_clocks) => scaled_clocks[clock_id as usize].scale(real_nanoseconds, speed_factor),
+            Err(_) => real_nanoseconds,
+        };
+
+        unsafe {
+            *time = Self::nanoseconds_to_timespec(scaled_nanoseconds);
+        }
+
+        0
+    }
+
+    pub unsafe extern "C" fn gettimeofday_hook(
+        time: *mut libc::timeval,
+        time_zone: *mut libc::c_void,
+    ) -> libc::c_int {
+        // The time zone is obsolete and unaffected by speed, so it is left to the kernel.
+        if !time_zone.is_null() && unsafe { libc::syscall(libc::SYS_gettimeofday, std::ptr::null_mut::<libc::timeval>(), time_zone) } != 0 {
+            return -1;
+        }
+
+        if time.is_null() {
+            return 0;
+        }
+
+        let mut timespec = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+
+        if unsafe { Self::clock_gettime_hook(libc::CLOCK_REALTIME, &mut timespec) } != 0 {
+            return -1;
+        }
+
+        unsafe {
+            (*time).tv_sec = timespec.tv_sec;
+            (*time).tv_usec = timespec.tv_nsec / 1000;
+        }
+
+        0
+    }
+
+    pub unsafe extern "C" fn nanosleep_hook(
+        requested_time: *const libc::timespec,
+        remaining_time: *mut libc::timespec,
+    ) -> libc::c_int {
+        let speed_factor = SpeedhackLibrary::get_speed_factor();
+
+        // Invalid requests are passed through unchanged, such that the kernel reports them.
+        if speed_factor == 1.0 || requested_time.is_null() || !(0..NANOSECONDS_PER_SECOND as libc::c_long).contains(&unsafe { (*requested_time).tv_nsec }) {
+            return unsafe { libc::syscall(libc::SYS_nanosleep, requested_time, remaining_time) } as libc::c_int;
+        }
+
+        let requested_nanoseconds = Self::timespec_to_nanoseconds(unsafe { &*requested_time });
+        let scaled_requested_time = Self::nanoseconds_to_timespec((requested_nanoseconds as f64 / speed_factor) as i128);
+        let mut scaled_remaining_time = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+        let result = unsafe { libc::syscall(libc::SYS_nanosleep, &scaled_requested_time, &mut scaled_remaining_time) } as libc::c_int;
+
+        // An interrupted sleep reports how much of the requested, rather than the scaled, time remains.
+        if result != 0 && !remaining_time.is_null() {
+            let remaining_nanoseconds = Self::timespec_to_nanoseconds(&scaled_remaining_time);
+
+            unsafe {
+                *remaining_time = Self::nanoseconds_to_timespec((remaining_nanoseconds as f64 * speed_factor) as i128);
+            }
+        }
+
+        result
+    }
+
+    pub unsafe extern "C" fn clock_nanosleep_hook(
+        clock_id: libc::clockid_t,
+        flags: libc::c_int,
+        requested_time: *const libc::timespec,
+        remaining_time: *mut libc::timespec,
+    ) -> libc::c_int {
+        let speed_factor = SpeedhackLibrary::get_speed_factor();
+
+        // Unlike the other functions, this one returns the error number rather than setting errno.
+        if speed_factor == 1.0
+            || !Self::is_scaled_clock(clock_id)
+            || requested_time.is_null()
+            || !(0..NANOSECONDS_PER_SECOND as libc::c_long).contains(&unsafe { (*requested_time).tv_nsec })
+        {
+            return match unsafe { libc::syscall(libc::SYS_clock_nanosleep, clock_id, flags, requested_time, remaining_time) } {
+                0 => 0,
+                _ => Self::get_error_number(),
+            };
+        }
+
+        let is_absolute = flags & libc::TIMER_ABSTIME != 0;
+        let mut requested_nanoseconds = Self::timespec_to_nanoseconds(unsafe { &*requested_time });
+
+        // An absolute deadline is in scaled time, so sleep for however much scaled time remains until it.
+        if is_absolute {
+            let mut current_time = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+
+            if unsafe { Self::clock_gettime_hook(clock_id, &mut current_time) } != 0 {
+                return Self::get_error_number();
+            }
+
+            requested_nanoseconds = (requested_nanoseconds - Self::timespec_to_nanoseconds(&current_time)).max(0);
+        }
+
+        let scaled_requested_time = Self::nanoseconds_to_timespec((requested_nanoseconds as f64 / speed_factor) as i128);
+        let mut scaled_remaining_time = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+
+        if unsafe { libc::syscall(libc::SYS_clock_nanosleep, clock_id, 0, &scaled_requested_time, &mut scaled_remaining_time) } == 0 {
+            return 0;
+        }
+
+        let error_number = Self::get_error_number();
+
+        // Interrupted absolute sleeps report no remaining time, as they are simply restarted with the same deadline.
+        if error_number == libc::EINTR && !is_absolute && !remaining_time.is_null() {
+            let remaining_nanoseconds = Self::timespec_to_nanoseconds(&scaled_remaining_time);
+
+            unsafe {
+                *remaining_time = Self::nanoseconds_to_timespec((remaining_nanoseconds as f64 * speed_factor) as i128);
+            }
+        }
+
+        error_number
+    }
+
+    /// Determines whether a clock measures the passage of time, rather than time spent running on a processor.
+    fn is_scaled_clock(clock_id: libc::clockid_t) -> bool {
+        matches!(
+            clock_id,
+            libc::CLOCK_REALTIME
+                | libc::CLOCK_MONOTONIC
+                | libc::CLOCK_MONOTONIC_RAW
+                | libc::CLOCK_REALTIME_COARSE
+                | libc::CLOCK_MONOTONIC_COARSE
+                | libc::CLOCK_BOOTTIME
+        )
+    }
+
+    fn get_error_number() -> libc::c_int {
+        std::io::Error::last_os_error().raw_os_error().unwrap_or(libc::EINVAL)
+    }
+
+    fn timespec_to_nanoseconds(timespec: &libc::timespec) -> i128 {
+        timespec.tv_sec as i128 * NANOSECONDS_PER_SECOND + timespec.tv_nsec as i128
+    }
+
+    fn nanoseconds_to_timespec(nanoseconds: i128) -> libc::timespec {
+        libc::timespec {
+            tv_sec: nanoseconds.div_euclid(NANOSECONDS_PER_SECOND) as libc::time_t,
+            tv_nsec: nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as libc::c_long,
+        }
+    }
+}
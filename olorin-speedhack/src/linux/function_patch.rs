@@ -0,0 +1,120 @@
+use std::io;
+
+#[cfg(target_arch = "x86_64")]
+const PATCH_SIZE: usize = 13;
+
+#[cfg(target_arch = "aarch64")]
+const PATCH_SIZE: usize = 16;
+
+/// Overwrites the start of a function with an absolute jump to a hook, keeping the original bytes such that it can be restored.
+/// The original function cannot be called while patched, so hooks must do the work of the original themselves.
+pub struct FunctionPatch {
+    function_address: usize,
+    original_bytes: [u8; PATCH_SIZE],
+}
+
+impl FunctionPatch {
+    pub fn install(
+        function_address: usize,
+        hook_address: usize,
+    ) -> Result<Self, String> {
+        let mut original_bytes = [0u8; PATCH_SIZE];
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(function_address as *const u8, original_bytes.as_mut_ptr(), PATCH_SIZE);
+        }
+
+        Self::write_code(function_address, &Self::assemble_jump(hook_address))?;
+
+        Ok(Self {
+            function_address,
+            original_bytes,
+        })
+    }
+
+    pub fn restore(&self) -> Result<(), String> {
+        Self::write_code(self.function_address, &self.original_bytes)
+    }
+
+    /// Assembles `movabs r11, hook; jmp r11`, where r11 is a scratch register that is free at function entry.
+    #[cfg(target_arch = "x86_64")]
+    fn assemble_jump(hook_address: usize) -> [u8; PATCH_SIZE] {
+        let mut jump_bytes = [0u8; PATCH_SIZE];
+
+        jump_bytes[0..2].copy_from_slice(&[0x49, 0xBB]);
+        jump_bytes[2..10].copy_from_slice(&(hook_address as u64).to_le_bytes());
+        jump_bytes[10..13].copy_from_slice(&[0x41, 0xFF, 0xE3]);
+
+        jump_bytes
+    }
+
+    /// Assembles `ldr x17, #8; br x17` followed by the hook address, where x17 is the intra procedure call scratch register.
+    #[cfg(target_arch = "aarch64")]
+    fn assemble_jump(hook_address: usize) -> [u8; PATCH_SIZE] {
+        let mut jump_bytes = [0u8; PATCH_SIZE];
+
+        jump_bytes[0..4].copy_from_slice(&0x5800_0051u32.to_le_bytes());
+        jump_bytes[4..8].copy_from_slice(&0xD61F_0220u32.to_le_bytes());
+        jump_bytes[8..16].copy_from_slice(&(hook_address as u64).to_le_bytes());
+
+        jump_bytes
+    }
+
+    /// Writes over code by briefly making its pages writable. Code pages are assumed to be readable and executable otherwise.
+    fn write_code(
+        address: usize,
+        bytes: &[u8],
+    ) -> Result<(), String> {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let page_start = address & !(page_size - 1);
+        let page_end = (address + bytes.len()).div_ceil(page_size) * page_size;
+        let protect = |protection| unsafe { libc::mprotect(page_start as *mut libc::c_void, page_end - page_start, protection) };
+
+        if protect(libc::PROT_READ | libc::PROT_WRITE | libc::PROT_EXEC) != 0 {
+            return Err(format!("Failed to make the code at 0x{:X} writable: {}", address, io::Error::last_os_error()));
+        }
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), address as *mut u8, bytes.len());
+        }
+
+        Self::flush_instruction_cache(address, bytes.len());
+
+        if protect(libc::PROT_READ | libc::PROT_EXEC) != 0 {
+            return Err(format!("Failed to restore the protection of the code at 0x{:X}: {}", address, io::Error::last_os_error()));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn flush_instruction_cache(
+        _address: usize,
+        _length: usize,
+    ) {
+        // The instruction cache is coherent with data writes on x86.
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn flush_instruction_cache(
+        address: usize,
+        length: usize,
+    ) {
+        // Stepping by the smallest possible cache line size touches every line the patch spans, whatever the actual line size.
+        let line_addresses = ((address & !0x3)..(address + length)).step_by(4);
+
+        unsafe {
+            for line_address in line_addresses.clone() {
+                std::arch::asm!("dc cvau, {0}", in(reg) line_address);
+            }
+
+            std::arch::asm!("dsb ish");
+
+            for line_address in line_addresses {
+                std::arch::asm!("ic ivau, {0}", in(reg) line_address);
+            }
+
+            std::arch::asm!("dsb ish", "isb");
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::ffi::CStr;
+use std::io;
+use std::ops::Range;
+
+const DT_NULL: i64 = 0;
+const DT_PLTRELSZ: i64 = 2;
+const DT_STRTAB: i64 = 5;
+const DT_SYMTAB: i64 = 6;
+const DT_RELA: i64 = 7;
+const DT_RELASZ: i64 = 8;
+const DT_JMPREL: i64 = 23;
+
+#[cfg(target_arch = "x86_64")]
+const IMPORT_RELOCATION_TYPES: [u32; 2] = [6, 7]; // R_X86_64_GLOB_DAT, R_X86_64_JUMP_SLOT
+
+#[cfg(target_arch = "aarch64")]
+const IMPORT_RELOCATION_TYPES: [u32; 2] = [1025, 1026]; // R_AARCH64_GLOB_DAT, R_AARCH64_JUMP_SLOT
+
+#[repr(C)]
+struct Elf64Dyn {
+    d_tag: i64,
+    d_val: u64,
+}
+
+#[repr(C)]
+struct Elf64Rela {
+    r_offset: u64,
+    r_info: u64,
+    r_addend: i64,
+}
+
+#[repr(C)]
+struct Elf64Sym {
+    st_name: u32,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: u16,
+    st_value: u64,
+    st_size: u64,
+}
+
+/// A module loaded in this process, as reported by the loader.
+struct LoadedModule {
+    base_address: usize,
+    dynamic_address: usize,
+    read_only_after_relocation_range: Option<Range<usize>>,
+}
+
+/// Redirects the import of a function by a loaded module to a hook, by overwriting the slot that the loader resolved it into.
+/// This is the fallback for functions that cannot be patched in place, such as those the C library resolves into the vDSO,
+/// and only affects modules that are loaded at the time the patch is installed.
+pub struct ImportPatch {
+    slot_address: usize,
+    original_address: usize,
+    is_read_only: bool,
+}
+
+impl ImportPatch {
+    /// Redirects the imports of the given function by every loaded module to the hook.
+    pub fn install_all(
+        function_name: &CStr,
+        hook_address: usize,
+    ) -> Result<Vec<Self>, String> {
+        let mut import_patches = vec![];
+
+        for loaded_module in Self::get_loaded_modules() {
+            for (slot_address, is_read_only) in Self::find_import_slots(&loaded_module, function_name) {
+                let import_patch = Self {
+                    slot_address,
+                    original_address: unsafe { std::ptr::read_volatile(slot_address as *const usize) },
+                    is_read_only,
+                };
+
+                if let Err(error) = Self::write_slot(slot_address, hook_address, is_read_only) {
+                    for import_patch in &import_patches {
+                        let _ = Self::restore(import_patch);
+                    }
+
+                    return Err(error);
+                }
+
+                import_patches.push(import_patch);
+            }
+        }
+
+        Ok(import_patches)
+    }
+
+    pub fn restore(&self) -> Result<(), String> {
+        Self::write_slot(self.slot_address, self.original_address, self.is_read_only)
+    }
+
+    fn get_loaded_modules() -> Vec<LoadedModule> {
+        unsafe extern "C" fn collect_loaded_module(
+            module_info: *mut libc::dl_phdr_info,
+            _module_info_size: libc::size_t,
+            loaded_modules: *mut libc::c_void,
+        ) -> libc::c_int {
+            let module_info = unsafe { &*module_info };
+            let loaded_modules = unsafe { &mut *(loaded_modules as *mut Vec<LoadedModule>) };
+            let base_address = module_info.dlpi_addr as usize;
+            let mut dynamic_address = None;
+            let mut read_only_after_relocation_range = None;
+
+            for program_header_index in 0..module_info.dlpi_phnum as usize {
+                let program_header = unsafe { &*module_info.dlpi_phdr.add(program_header_index) };
+                let segment_address = base_address + program_header.p_vaddr as usize;
+
+                match program_header.p_type {
+                    libc::PT_DYNAMIC => dynamic_address = Some(segment_address),
+                    libc::PT_GNU_RELRO => read_only_after_relocation_range = Some(segment_address..segment_address + program_header.p_memsz as usize),
+                    _ => {}
+                }
+            }
+
+            if let Some(dynamic_address) = dynamic_address {
+                loaded_modules.push(LoadedModule {
+                    base_address,
+                    dynamic_address,
+                    read_only_after_relocation_range,
+                });
+            }
+
+            0
+        }
+
+        let mut loaded_modules: Vec<LoadedModule> = vec![];
+
+        unsafe {
+            libc::dl_iterate_phdr(Some(collect_loaded_module), &mut loaded_modules as *mut Vec<LoadedModule> as *mut libc::c_void);
+        }
+
+        loaded_modules
+    }
+
+    /// Finds the slots into which the module imports the given function, along with whether each is made read only after relocation.
+    fn find_import_slots(
+        loaded_module: &LoadedModule,
+        function_name: &CStr,
+    ) -> Vec<(usize, bool)> {
+        let mut string_table_address = 0;
+        let mut symbol_table_address = 0;
+        let mut relocation_tables = [(0usize, 0usize); 2];
+        let mut dynamic_entry = loaded_module.dynamic_address as *const Elf64Dyn;
+
+        // glibc rebases the addresses in the dynamic section of most modules in place, whereas musl and the vDSO leave them relative.
+        let resolve_address = |address: u64| match address as usize {
+            address if address < loaded_module.base_address => loaded_module.base_address + address,
+            address => address,
+        };
+
+        unsafe {
+            while (*dynamic_entry).d_tag != DT_NULL {
+                let dynamic_value = (*dynamic_entry).d_val;
+
+                match (*dynamic_entry).d_tag {
+                    DT_STRTAB => string_table_address = resolve_address(dynamic_value),
+                    DT_SYMTAB => symbol_table_address = resolve_address(dynamic_value),
+                    DT_JMPREL => relocation_tables[0].0 = resolve_address(dynamic_value),
+                    DT_PLTRELSZ => relocation_tables[0].1 = dynamic_value as usize,
+                    DT_RELA => relocation_tables[1].0 = resolve_address(dynamic_value),
+                    DT_RELASZ => relocation_tables[1].1 = dynamic_value as usize,
+                    _ => {}
+                }
+
+                dynamic_entry = dynamic_entry.add(1);
+            }
+        }
+
+        if string_table_address == 0 || symbol_table_address == 0 {
+            return vec![];
+        }
+
+        let mut import_slots = vec![];
+
+        for (relocation_table_address, relocation_table_size) in relocation_tables {
+            if relocation_table_address == 0 {
+                continue;
+            }
+
+            let relocations = unsafe {
+                std::slice::from_raw_parts(
+                    relocation_table_address as *const Elf64Rela,
+                    relocation_table_size / std::mem::size_of::<Elf64Rela>(),
+                )
+            };
+
+            for relocation in relocations {
+                let relocation_type = (relocation.r_info & 0xFFFF_FFFF) as u32;
+                let symbol_index = (relocation.r_info >> 32) as usize;
+
+                if !IMPORT_RELOCATION_TYPES.contains(&relocation_type) || symbol_index == 0 {
+                    continue;
+                }
+
+                let symbol_name = unsafe {
+                    let symbol = &*(symbol_table_address as *const Elf64Sym).add(symbol_index);
+
+                    CStr::from_ptr((string_table_address + symbol.st_name as usize) as *const libc::c_char)
+                };
+
+                if symbol_name == function_name {
+                    let slot_address = loaded_module.base_address + relocation.r_offset as usize;
+                    let is_read_only = loaded_module
+                        .read_only_after_relocation_range
+                        .as_ref()
+                        .is_some_and(|range| range.contains(&slot_address));
+
+                    import_slots.push((slot_address, is_read_only));
+                }
+            }
+        }
+
+        import_slots
+    }
+
+    fn write_slot(
+        slot_address: usize,
+        address: usize,
+        is_read_only: bool,
+    ) -> Result<(), String> {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let page_start = slot_address & !(page_size - 1);
+
+        if is_read_only && unsafe { libc::mprotect(page_start as *mut libc::c_void, page_size, libc::PROT_READ | libc::PROT_WRITE) } != 0 {
+            return Err(format!("Failed to make the import at 0x{:X} writable: {}", slot_address, io::Error::last_os_error()));
+        }
+
+        // Slots are pointer aligned, so other threads calling through this one see either the old or the new address.
+        unsafe {
+            std::ptr::write_volatile(slot_address as *mut usize, address);
+        }
+
+        if is_read_only && unsafe { libc::mprotect(page_start as *mut libc::c_void, page_size, libc::PROT_READ) } != 0 {
+            return Err(format!("Failed to restore the protection of the import at 0x{:X}: {}", slot_address, io::Error::last_os_error()));
+        }
+
+        Ok(())
+    }
+}
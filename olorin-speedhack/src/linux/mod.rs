@@ -0,0 +1,5 @@
+pub mod clock_hooks;
+pub mod function_patch;
+pub mod import_patch;
+pub mod scaled_clock;
+pub mod speedhack_library;
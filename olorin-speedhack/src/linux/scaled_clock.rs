@@ -0,0 +1,49 @@
+/// Tracks the scaled time of a single clock. Scaled time advances at the speed factor times the rate of real time, and is rebased
+/// whenever the factor changes, such that it stays continuous rather than jumping when the speed is changed.
+pub struct ScaledClock {
+    real_base_nanoseconds: i128,
+    scaled_base_nanoseconds: i128,
+    speed_factor: f64,
+    is_initialized: bool,
+}
+
+impl ScaledClock {
+    pub const fn new() -> Self {
+        Self {
+            real_base_nanoseconds: 0,
+            scaled_base_nanoseconds: 0,
+            speed_factor: 1.0,
+            is_initialized: false,
+        }
+    }
+
+    /// Converts a reading of the real clock to scaled time under the given speed factor.
+    pub fn scale(
+        &mut self,
+        real_nanoseconds: i128,
+        speed_factor: f64,
+    ) -> i128 {
+        if !self.is_initialized {
+            // Scaled time starts out in sync with real time at the first reading.
+            self.real_base_nanoseconds = real_nanoseconds;
+            self.scaled_base_nanoseconds = real_nanoseconds;
+            self.speed_factor = speed_factor;
+            self.is_initialized = true;
+        } else if speed_factor != self.speed_factor {
+            self.scaled_base_nanoseconds = self.get_scaled_nanoseconds(real_nanoseconds);
+            self.real_base_nanoseconds = real_nanoseconds;
+            self.speed_factor = speed_factor;
+        }
+
+        self.get_scaled_nanoseconds(real_nanoseconds)
+    }
+
+    fn get_scaled_nanoseconds(
+        &self,
+        real_nanoseconds: i128,
+    ) -> i128 {
+        let elapsed_nanoseconds = real_nanoseconds - self.real_base_nanoseconds;
+
+        self.scaled_base_nanoseconds + (elapsed_nanoseconds as f64 * self.speed_factor) as i128
+    }
+}
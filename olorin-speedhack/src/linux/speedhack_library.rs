@@ -0,0 +1,157 @@
+use crate::linux::clock_hooks::ClockHooks;
+use crate::linux::function_patch::FunctionPatch;
+use crate::linux::import_patch::ImportPatch;
+use olorin_engine_debuggers::speedhack::speedhack_shared_state::SpeedhackSharedState;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::os::fd::AsRawFd;
+use std::os::unix::fs::{MetadataExt, OpenOptionsExt};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// Run by the loader once the library is loaded, whether preloaded at launch or injected at runtime.
+#[used]
+#[unsafe(link_section = ".init_array")]
+static INITIALIZE_SPEEDHACK_LIBRARY: extern "C" fn() = SpeedhackLibrary::initialize;
+
+/// Run by the loader when the library is unloaded or the process exits.
+#[used]
+#[unsafe(link_section = ".fini_array")]
+static UNINITIALIZE_SPEEDHACK_LIBRARY: extern "C" fn() = SpeedhackLibrary::uninitialize;
+
+static SHARED_STATE: AtomicPtr<SpeedhackSharedState> = AtomicPtr::new(std::ptr::null_mut());
+static FUNCTION_PATCHES: Mutex<Vec<FunctionPatch>> = Mutex::new(vec![]);
+static IMPORT_PATCHES: Mutex<Vec<ImportPatch>> = Mutex::new(vec![]);
+
+pub struct SpeedhackLibrary;
+
+impl SpeedhackLibrary {
+    /// Gets the speed factor last written by the engine, or real time if the shared state could not be mapped.
+    pub fn get_speed_factor() -> f64 {
+        let shared_state = SHARED_STATE.load(Ordering::Acquire);
+
+        if shared_state.is_null() {
+            1.0
+        } else {
+            unsafe { (*shared_state).get_speed_factor() }
+        }
+    }
+
+    extern "C" fn initialize() {
+        if let Err(error) = Self::map_shared_state() {
+            eprintln!("olorin-speedhack: {}", error);
+            return;
+        }
+
+        let hooks: [(&std::ffi::CStr, usize); 4] = [
+            (c"clock_gettime", ClockHooks::clock_gettime_hook as *const () as usize),
+            (c"gettimeofday", ClockHooks::gettimeofday_hook as *const () as usize),
+            (c"nanosleep", ClockHooks::nanosleep_hook as *const () as usize),
+            (c"clock_nanosleep", ClockHooks::clock_nanosleep_hook as *const () as usize),
+        ];
+        let (mut function_patches, mut import_patches) = match (FUNCTION_PATCHES.lock(), IMPORT_PATCHES.lock()) {
+            (Ok(function_patches), Ok(import_patches)) => (function_patches, import_patches),
+            _ => return,
+        };
+
+        for (function_name, hook_address) in hooks {
+            let function_address = unsafe { libc::dlsym(libc::RTLD_DEFAULT, function_name.as_ptr()) } as usize;
+
+            if function_address == 0 {
+                eprintln!("olorin-speedhack: Failed to find {}.", function_name.to_string_lossy());
+                continue;
+            }
+
+            // Functions that resolve into the vDSO cannot be patched in place, so calls to them are redirected at each import instead.
+            match FunctionPatch::install(function_address, hook_address) {
+                Ok(function_patch) => function_patches.push(function_patch),
+                Err(function_patch_error) => match ImportPatch::install_all(function_name, hook_address) {
+                    Ok(function_import_patches) => import_patches.extend(function_import_patches),
+                    Err(import_patch_error) => eprintln!(
+                        "olorin-speedhack: Failed to hook {}: {} {}",
+                        function_name.to_string_lossy(),
+                        function_patch_error,
+                        import_patch_error
+                    ),
+                },
+            }
+        }
+    }
+
+    extern "C" fn uninitialize() {
+        // The original functions must be restored before the hooks they jump to are unmapped along with this library.
+        if let Ok(mut function_patches) = FUNCTION_PATCHES.lock() {
+            for function_patch in function_patches.drain(..) {
+                if let Err(error) = function_patch.restore() {
+                    eprintln!("olorin-speedhack: {}", error);
+                }
+            }
+        }
+
+        if let Ok(mut import_patches) = IMPORT_PATCHES.lock() {
+            for import_patch in import_patches.drain(..) {
+                if let Err(error) = import_patch.restore() {
+                    eprintln!("olorin-speedhack: {}", error);
+                }
+            }
+        }
+
+        let shared_state = SHARED_STATE.swap(std::ptr::null_mut(), Ordering::AcqRel);
+
+        if !shared_state.is_null() {
+            unsafe {
+                libc::munmap(shared_state as *mut libc::c_void, SpeedhackSharedState::get_size());
+            }
+        }
+
+        let _ = fs::remove_file(SpeedhackSharedState::get_shared_memory_path(std::process::id()));
+    }
+
+    /// Maps the shared state for this process, creating it if the engine has not yet done so, as is the case when preloaded.
+    fn map_shared_state() -> Result<(), String> {
+        let shared_memory_path = SpeedhackSharedState::get_shared_memory_path(std::process::id());
+        let shared_memory_size = SpeedhackSharedState::get_size();
+        let shared_memory_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .mode(0o600)
+            .custom_flags(libc::O_NOFOLLOW)
+            .open(&shared_memory_path)
+            .map_err(|error| format!("Failed to open {}: {}", shared_memory_path.display(), error))?;
+        let metadata = shared_memory_file
+            .metadata()
+            .map_err(|error| format!("Failed to inspect {}: {}", shared_memory_path.display(), error))?;
+
+        // The engine hands the file to this process when it creates it, so any other owner means the file was planted.
+        if !metadata.file_type().is_file() || metadata.nlink() != 1 || metadata.uid() != unsafe { libc::geteuid() } {
+            return Err(format!("Refusing to use {}, as it is not a file owned by this process.", shared_memory_path.display()));
+        }
+
+        if shared_memory_file.metadata().map(|metadata| metadata.len()).unwrap_or(0) < shared_memory_size as u64 {
+            shared_memory_file
+                .set_len(shared_memory_size as u64)
+                .map_err(|error| format!("Failed to resize {}: {}", shared_memory_path.display(), error))?;
+        }
+
+        let mapping = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                shared_memory_size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                shared_memory_file.as_raw_fd(),
+                0,
+            )
+        };
+
+        if mapping == libc::MAP_FAILED {
+            return Err(format!("Failed to map {}: {}", shared_memory_path.display(), io::Error::last_os_error()));
+        }
+
+        SHARED_STATE.store(mapping as *mut SpeedhackSharedState, Ordering::Release);
+
+        Ok(())
+    }
+}
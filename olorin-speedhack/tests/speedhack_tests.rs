@@ -0,0 +1,145 @@
+#![cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+
+use olorin_engine_debuggers::library_injection::library_injector::LibraryInjector;
+use olorin_engine_debuggers::speedhack::speedhack_controller::SpeedhackController;
+use std::io::{BufRead, BufReader, Lines, Write};
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
+
+/// The fixture runs as this test binary filtered down to the fixture test, which only does anything when this variable is set.
+const FIXTURE_ENVIRONMENT_VARIABLE: &str = "OLORIN_SPEEDHACK_FIXTURE";
+
+fn get_gettimeofday_seconds() -> f64 {
+    let mut time = libc::timeval { tv_sec: 0, tv_usec: 0 };
+
+    unsafe {
+        libc::gettimeofday(&mut time, std::ptr::null_mut());
+    }
+
+    time.tv_sec as f64 + time.tv_usec as f64 / 1_000_000.0
+}
+
+/// Waits for a sleep duration on stdin, then sleeps for it and reports how long the monotonic clock, the real time clock and
+/// `gettimeofday` each say the sleep took.
+#[test]
+fn speedhack_fixture() {
+    if std::env::var_os(FIXTURE_ENVIRONMENT_VARIABLE).is_none() {
+        return;
+    }
+
+    println!("FIXTURE_READY");
+
+    let mut sleep_seconds = String::new();
+
+    std::io::stdin()
+        .read_line(&mut sleep_seconds)
+        .expect("Failed to read the sleep duration.");
+
+    let monotonic_start = Instant::now();
+    let real_time_start = SystemTime::now();
+    let gettimeofday_start = get_gettimeofday_seconds();
+
+    std::thread::sleep(Duration::from_secs_f64(sleep_seconds.trim().parse().expect("Invalid sleep duration.")));
+
+    println!(
+        "FIXTURE_ELAPSED {} {} {}",
+        monotonic_start.elapsed().as_secs_f64(),
+        real_time_start.elapsed().unwrap_or_default().as_secs_f64(),
+        get_gettimeofday_seconds() - gettimeofday_start
+    );
+}
+
+fn spawn_fixture(preload: bool) -> (Child, Lines<BufReader<ChildStdout>>) {
+    let mut command = Command::new(std::env::current_exe().expect("Failed to get the test executable."));
+
+    command
+        .args(["speedhack_fixture", "--exact", "--nocapture", "--test-threads=1"])
+        .env(FIXTURE_ENVIRONMENT_VARIABLE, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped());
+
+    if preload {
+        let library_path = SpeedhackController::get_speedhack_library_path().expect("Failed to find the speedhack library.");
+        let (preload_key, preload_value) = LibraryInjector::get_preload_environment_variable(&[library_path]).expect("Failed to preload the speedhack library.");
+
+        command.env(preload_key, preload_value);
+    }
+
+    let mut fixture = command.spawn().expect("Failed to spawn the fixture process.");
+    let mut fixture_output = BufReader::new(fixture.stdout.take().unwrap()).lines();
+
+    assert!(
+        fixture_output
+            .by_ref()
+            .map_while(Result::ok)
+            .any(|line| line.ends_with("FIXTURE_READY"))
+    );
+
+    (fixture, fixture_output)
+}
+
+/// Has the fixture sleep for the given duration, returning the real time that passed along with the elapsed times it reported.
+fn run_fixture_sleep(
+    fixture: &mut Child,
+    fixture_output: &mut Lines<BufReader<ChildStdout>>,
+    sleep_seconds: f64,
+) -> (f64, Vec<f64>) {
+    let real_start = Instant::now();
+
+    writeln!(fixture.stdin.as_mut().unwrap(), "{}", sleep_seconds).expect("Failed to signal the fixture.");
+
+    let elapsed_line = fixture_output
+        .map_while(Result::ok)
+        .find(|line| line.starts_with("FIXTURE_ELAPSED"))
+        .expect("The fixture did not report its elapsed times.");
+    let real_elapsed_seconds = real_start.elapsed().as_secs_f64();
+    let reported_elapsed_seconds = elapsed_line
+        .split_whitespace()
+        .skip(1)
+        .map(|elapsed_seconds| elapsed_seconds.parse().unwrap())
+        .collect();
+
+    (real_elapsed_seconds, reported_elapsed_seconds)
+}
+
+#[test]
+fn injected_speedhack_speeds_up_clocks_and_sleeps() {
+    let (mut fixture, mut fixture_output) = spawn_fixture(false);
+    let process_id = fixture.id();
+
+    SpeedhackController::set_speed(process_id, 4.0).expect("Failed to set the speed of the fixture.");
+
+    assert_eq!(LibraryInjector::get_injected_libraries(process_id).len(), 1);
+
+    let (real_elapsed_seconds, reported_elapsed_seconds) = run_fixture_sleep(&mut fixture, &mut fixture_output, 1.0);
+
+    // A one second sleep takes a quarter of a second in real time, while the target still observes a full second pass.
+    assert!(real_elapsed_seconds < 0.75, "real time elapsed: {}", real_elapsed_seconds);
+
+    for reported_elapsed_seconds in reported_elapsed_seconds {
+        assert!((0.9..1.5).contains(&reported_elapsed_seconds), "target time elapsed: {}", reported_elapsed_seconds);
+    }
+
+    assert!(fixture.wait().expect("Failed to wait for the fixture.").success());
+}
+
+#[test]
+fn preloaded_speedhack_slows_down_clocks_and_sleeps() {
+    let (mut fixture, mut fixture_output) = spawn_fixture(true);
+    let process_id = fixture.id();
+
+    SpeedhackController::set_speed(process_id, 0.5).expect("Failed to set the speed of the fixture.");
+
+    // The preloaded library is already mapped, so it is controlled without being injected a second time.
+    assert!(LibraryInjector::get_injected_libraries(process_id).is_empty());
+
+    let (real_elapsed_seconds, reported_elapsed_seconds) = run_fixture_sleep(&mut fixture, &mut fixture_output, 0.25);
+
+    assert!(real_elapsed_seconds >= 0.45, "real time elapsed: {}", real_elapsed_seconds);
+
+    for reported_elapsed_seconds in reported_elapsed_seconds {
+        assert!((0.2..0.4).contains(&reported_elapsed_seconds), "target time elapsed: {}", reported_elapsed_seconds);
+    }
+
+    assert!(fixture.wait().expect("Failed to wait for the fixture.").success());
+}
@@ -1,6 +1,7 @@
 // Disable terminal from spawning. All relevant output is routed to the output view anyways.
 #![windows_subsystem = "windows"]
 
+use olorin_engine::engine_bindings::interprocess::privileged_shell_launcher::PrivilegedShellLauncher;
 use olorin_engine::engine_mode::EngineMode;
 use olorin_engine::olorin_engine::OlorinEngine;
 use olorin_gui::view_models::main_window::main_window_view_model::MainWindowViewModel;
@@ -12,8 +13,16 @@ pub fn main() {
         std::env::set_var("SLINT_BACKEND", "winit-software");
     }
 
-    // Create a standalone engine (same process for gui and engine).
-    let mut olorin_engine = match OlorinEngine::new(EngineMode::Standalone) {
+    // On Linux, the gui runs unprivileged and spawns an elevated engine shell, since running a gui as root breaks Wayland/X auth.
+    // Otherwise (or if already root, or if explicitly requested), create a standalone engine (same process for gui and engine).
+    let args: Vec<String> = std::env::args().collect();
+    let engine_mode = if cfg!(target_os = "linux") && !args.contains(&"--standalone".to_string()) && !PrivilegedShellLauncher::is_running_as_root() {
+        EngineMode::UnprivilegedHost
+    } else {
+        EngineMode::Standalone
+    };
+
+    let mut olorin_engine = match OlorinEngine::new(engine_mode) {
         Ok(olorin_engine) => olorin_engine,
         Err(error) => panic!("Fatal error initializing Olorin engine: {}", error),
     };